@@ -0,0 +1,112 @@
+use std::io::Cursor;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::OnceLock;
+
+use rodio::{Decoder, OutputStream, Sink};
+
+const LOG_TAG_AUDIO_FEEDBACK: &str = "[AUDIO_FEEDBACK]";
+const AUDIO_FEEDBACK_THREAD_NAME: &str = "audio-feedback";
+const FEEDBACK_SOUND_VOLUME: f32 = 0.4;
+const CAPTURE_CLIP: &[u8] = include_bytes!("../assets/sounds/capture.wav");
+const SUCCESS_CLIP: &[u8] = include_bytes!("../assets/sounds/success.wav");
+const FAILURE_CLIP: &[u8] = include_bytes!("../assets/sounds/failure.wav");
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedbackSound {
+    Capture,
+    Success,
+    Failure,
+}
+
+impl FeedbackSound {
+    fn clip(self) -> &'static [u8] {
+        match self {
+            FeedbackSound::Capture => CAPTURE_CLIP,
+            FeedbackSound::Success => SUCCESS_CLIP,
+            FeedbackSound::Failure => FAILURE_CLIP,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct AudioFeedbackPlayer {
+    sound_sender: OnceLock<Sender<FeedbackSound>>,
+}
+
+impl AudioFeedbackPlayer {
+    pub fn play(&self, sound: FeedbackSound) {
+        log::debug!("{} Playing {:?} sound", LOG_TAG_AUDIO_FEEDBACK, sound);
+
+        let sound_sender = self.sound_sender.get_or_init(spawn_playback_thread);
+        if sound_sender.send(sound).is_err() {
+            log::debug!(
+                "{} Audio output unavailable, dropping {:?} sound",
+                LOG_TAG_AUDIO_FEEDBACK,
+                sound
+            );
+        }
+    }
+}
+
+fn spawn_playback_thread() -> Sender<FeedbackSound> {
+    let (sound_sender, sound_receiver) = mpsc::channel();
+
+    let spawn_result = std::thread::Builder::new()
+        .name(AUDIO_FEEDBACK_THREAD_NAME.to_string())
+        .spawn(move || {
+            if let Err(playback_error) = run_playback_loop(sound_receiver) {
+                log::warn!(
+                    "{} Audio output unavailable: {}",
+                    LOG_TAG_AUDIO_FEEDBACK,
+                    playback_error
+                );
+            }
+        });
+    if let Err(spawn_error) = spawn_result {
+        log::warn!(
+            "{} Failed to start playback thread: {}",
+            LOG_TAG_AUDIO_FEEDBACK,
+            spawn_error
+        );
+    }
+
+    sound_sender
+}
+
+fn run_playback_loop(sound_receiver: Receiver<FeedbackSound>) -> anyhow::Result<()> {
+    let (_stream, stream_handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&stream_handle)?;
+    sink.set_volume(FEEDBACK_SOUND_VOLUME);
+
+    for sound in sound_receiver {
+        match Decoder::new(Cursor::new(sound.clip())) {
+            Ok(clip) => sink.append(clip),
+            Err(decode_error) => log::warn!(
+                "{} Failed to decode {:?} sound: {}",
+                LOG_TAG_AUDIO_FEEDBACK,
+                sound,
+                decode_error
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_clips_decode_into_samples() {
+        for sound in [
+            FeedbackSound::Capture,
+            FeedbackSound::Success,
+            FeedbackSound::Failure,
+        ] {
+            let clip = Decoder::new(Cursor::new(sound.clip())).unwrap();
+
+            assert!(clip.count() > 0, "{:?} clip has no samples", sound);
+        }
+    }
+}
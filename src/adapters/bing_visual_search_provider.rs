@@ -0,0 +1,98 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::core::interfaces::adapters::{ImageHostingService, ReverseImageSearchProvider};
+use crate::core::models::{CaptureBuffer, ImageSearchOutcome, UserSettings};
+use crate::global_constants;
+
+pub struct BingVisualSearchProvider {
+    image_hosting_service: Arc<dyn ImageHostingService>,
+    open_in_private_window: bool,
+}
+
+impl BingVisualSearchProvider {
+    pub fn from_user_settings(
+        image_hosting_service: Arc<dyn ImageHostingService>,
+        settings: &UserSettings,
+    ) -> Self {
+        Self {
+            image_hosting_service,
+            open_in_private_window: settings.open_search_in_private_window,
+        }
+    }
+
+    fn construct_search_url(&self, image_url: &str, query: Option<&str>) -> String {
+        let encoded_url = urlencoding::encode(image_url);
+        let mut url = global_constants::BING_VISUAL_SEARCH_URL_TEMPLATE.replace("{}", &encoded_url);
+
+        if let Some(q) = query.filter(|q| !q.trim().is_empty()) {
+            url.push('+');
+            url.push_str(&urlencoding::encode(q.trim()));
+        }
+
+        url
+    }
+}
+
+#[async_trait]
+impl ReverseImageSearchProvider for BingVisualSearchProvider {
+    async fn perform_search(
+        &self,
+        buffer: &CaptureBuffer,
+        query: Option<&str>,
+    ) -> Result<ImageSearchOutcome> {
+        let image_url = self.image_hosting_service.upload_image(buffer).await?;
+
+        let search_url = self.construct_search_url(&image_url, query);
+
+        log::info!("[BING_VISUAL_SEARCH] Opening Bing Visual Search");
+        log::debug!("[BING_VISUAL_SEARCH] Image URL: {}", image_url);
+        log::debug!("[BING_VISUAL_SEARCH] Search URL: {}", search_url);
+
+        crate::infrastructure::utils::open_search_url(&search_url, self.open_in_private_window)
+            .map_err(anyhow::Error::msg)?;
+
+        Ok(ImageSearchOutcome {
+            search_url,
+            hosted_image_url: image_url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedImageHostingService;
+
+    #[async_trait]
+    impl ImageHostingService for FixedImageHostingService {
+        async fn upload_image(&self, _buffer: &CaptureBuffer) -> Result<String> {
+            Ok("https://hosted.com/img.png".to_string())
+        }
+    }
+
+    fn create_provider() -> BingVisualSearchProvider {
+        BingVisualSearchProvider::from_user_settings(
+            Arc::new(FixedImageHostingService),
+            &UserSettings::default(),
+        )
+    }
+
+    #[test]
+    fn test_construct_search_url_targets_bing_with_encoded_image_url() {
+        let result = create_provider().construct_search_url("https://test.com/my image.jpg", None);
+
+        assert!(result.starts_with("https://www.bing.com/images/search?"));
+        assert!(result.contains("imgurl:https%3A%2F%2Ftest.com%2Fmy%20image.jpg"));
+    }
+
+    #[test]
+    fn test_construct_search_url_appends_query() {
+        let result =
+            create_provider().construct_search_url("https://test.com/image.jpg", Some("red shoes"));
+
+        assert!(result.ends_with("+red%20shoes"));
+    }
+}
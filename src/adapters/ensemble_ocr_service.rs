@@ -0,0 +1,163 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use image::DynamicImage;
+use std::sync::Arc;
+
+use crate::core::interfaces::adapters::OcrService;
+use crate::core::models::OcrResult;
+
+pub struct EnsembleOcrService {
+    engines: Vec<Arc<dyn OcrService>>,
+}
+
+impl EnsembleOcrService {
+    pub fn new(engines: Vec<Arc<dyn OcrService>>) -> Self {
+        log::info!(
+            "[ENSEMBLE_OCR] Initialized with {} engine(s)",
+            engines.len()
+        );
+        Self { engines }
+    }
+}
+
+#[async_trait]
+impl OcrService for EnsembleOcrService {
+    async fn extract_text_from_image(&self, image: &DynamicImage) -> Result<OcrResult> {
+        log::info!(
+            "[ENSEMBLE_OCR] Running {} engine(s) on image",
+            self.engines.len()
+        );
+
+        let mut engine_results = Vec::new();
+        let mut last_error = None;
+
+        for (engine_index, engine) in self.engines.iter().enumerate() {
+            match engine.extract_text_from_image(image).await {
+                Ok(result) => {
+                    log::debug!(
+                        "[ENSEMBLE_OCR] Engine {} found {} blocks",
+                        engine_index,
+                        result.text_blocks.len()
+                    );
+                    engine_results.push(result);
+                }
+                Err(engine_error) => {
+                    log::warn!(
+                        "[ENSEMBLE_OCR] Engine {} failed: {}",
+                        engine_index,
+                        engine_error
+                    );
+                    last_error = Some(engine_error);
+                }
+            }
+        }
+
+        if engine_results.is_empty() {
+            return Err(last_error.unwrap_or_else(|| anyhow!("No OCR engines configured")));
+        }
+
+        let merged_result = OcrResult::merge(engine_results);
+        log::info!(
+            "[ENSEMBLE_OCR] Merged result contains {} blocks",
+            merged_result.text_blocks.len()
+        );
+
+        Ok(merged_result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::{DetectedText, DetectedWord};
+
+    struct FixedOcrService {
+        result: Option<OcrResult>,
+    }
+
+    #[async_trait]
+    impl OcrService for FixedOcrService {
+        async fn extract_text_from_image(&self, _image: &DynamicImage) -> Result<OcrResult> {
+            self.result
+                .clone()
+                .ok_or_else(|| anyhow!("engine unavailable"))
+        }
+    }
+
+    fn create_single_word_result(content: &str, x: f32, confidence: f32) -> OcrResult {
+        OcrResult {
+            text_blocks: vec![DetectedText::new(
+                content.to_string(),
+                x,
+                0.0,
+                40.0,
+                12.0,
+                confidence,
+                vec![DetectedWord::new(content.to_string(), x, 0.0, 40.0, 12.0)],
+            )],
+            full_text: content.to_string(),
+        }
+    }
+
+    fn create_engine(result: Option<OcrResult>) -> Arc<dyn OcrService> {
+        Arc::new(FixedOcrService { result })
+    }
+
+    #[tokio::test]
+    async fn test_extract_text_prefers_highest_confidence_for_same_region() {
+        let ensemble = EnsembleOcrService::new(vec![
+            create_engine(Some(create_single_word_result("he1lo", 0.0, 0.4))),
+            create_engine(Some(create_single_word_result("hello", 1.0, 0.9))),
+        ]);
+
+        let result = ensemble
+            .extract_text_from_image(&DynamicImage::new_rgb8(64, 16))
+            .await
+            .unwrap();
+
+        assert_eq!(result.text_blocks.len(), 1);
+        assert_eq!(result.full_text, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_extract_text_merges_distinct_blocks_from_every_engine() {
+        let ensemble = EnsembleOcrService::new(vec![
+            create_engine(Some(create_single_word_result("world", 60.0, 0.8))),
+            create_engine(Some(create_single_word_result("hello", 0.0, 0.7))),
+        ]);
+
+        let result = ensemble
+            .extract_text_from_image(&DynamicImage::new_rgb8(128, 16))
+            .await
+            .unwrap();
+
+        assert_eq!(result.text_blocks.len(), 2);
+        assert_eq!(result.full_text, "hello world");
+    }
+
+    #[tokio::test]
+    async fn test_extract_text_ignores_failed_engines() {
+        let ensemble = EnsembleOcrService::new(vec![
+            create_engine(None),
+            create_engine(Some(create_single_word_result("hello", 0.0, 0.6))),
+        ]);
+
+        let result = ensemble
+            .extract_text_from_image(&DynamicImage::new_rgb8(64, 16))
+            .await
+            .unwrap();
+
+        assert_eq!(result.full_text, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_extract_text_fails_when_every_engine_fails() {
+        let ensemble = EnsembleOcrService::new(vec![create_engine(None), create_engine(None)]);
+
+        let result = ensemble
+            .extract_text_from_image(&DynamicImage::new_rgb8(64, 16))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
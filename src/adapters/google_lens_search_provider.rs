@@ -3,11 +3,12 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::core::interfaces::adapters::{ImageHostingService, ReverseImageSearchProvider};
-use crate::core::models::CaptureBuffer;
+use crate::core::models::{CaptureBuffer, ImageSearchOutcome, UserSettings};
 
 pub struct GoogleLensSearchProvider {
     image_hosting_service: Arc<dyn ImageHostingService>,
     search_url_template: String,
+    open_in_private_window: bool,
 }
 
 impl GoogleLensSearchProvider {
@@ -18,6 +19,20 @@ impl GoogleLensSearchProvider {
         Self {
             image_hosting_service,
             search_url_template,
+            open_in_private_window: false,
+        }
+    }
+
+    pub fn from_user_settings(
+        image_hosting_service: Arc<dyn ImageHostingService>,
+        settings: &UserSettings,
+    ) -> Self {
+        Self {
+            open_in_private_window: settings.open_search_in_private_window,
+            ..Self::new(
+                image_hosting_service,
+                settings.image_search_url_template.clone(),
+            )
         }
     }
 
@@ -37,7 +52,11 @@ impl GoogleLensSearchProvider {
 
 #[async_trait]
 impl ReverseImageSearchProvider for GoogleLensSearchProvider {
-    async fn perform_search(&self, buffer: &CaptureBuffer, query: Option<&str>) -> Result<String> {
+    async fn perform_search(
+        &self,
+        buffer: &CaptureBuffer,
+        query: Option<&str>,
+    ) -> Result<ImageSearchOutcome> {
         let image_url = self.image_hosting_service.upload_image(buffer).await?;
 
         let search_url = self.construct_search_url(&image_url, query);
@@ -49,9 +68,13 @@ impl ReverseImageSearchProvider for GoogleLensSearchProvider {
             log::debug!("[GOOGLE_LENS] Query: {}", q);
         }
 
-        open::that(&search_url)?;
+        crate::infrastructure::utils::open_search_url(&search_url, self.open_in_private_window)
+            .map_err(anyhow::Error::msg)?;
 
-        Ok(search_url)
+        Ok(ImageSearchOutcome {
+            search_url,
+            hosted_image_url: image_url,
+        })
     }
 }
 
@@ -164,5 +187,23 @@ mod tests {
         let provider = GoogleLensSearchProvider::new(mock_service, template.clone());
 
         assert_eq!(provider.search_url_template, template);
+        assert!(!provider.open_in_private_window);
+    }
+
+    #[test]
+    fn test_from_user_settings_uses_private_window_preference() {
+        let mock_service = Arc::new(MockImageHostingService::new("test".to_string()));
+        let settings = UserSettings {
+            open_search_in_private_window: true,
+            ..UserSettings::default()
+        };
+
+        let provider = GoogleLensSearchProvider::from_user_settings(mock_service, &settings);
+
+        assert!(provider.open_in_private_window);
+        assert_eq!(
+            provider.search_url_template,
+            settings.image_search_url_template
+        );
     }
 }
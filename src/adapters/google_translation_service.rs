@@ -0,0 +1,230 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::core::interfaces::adapters::TranslationService;
+use crate::core::models::UserSettings;
+use crate::global_constants;
+
+const TRANSLATE_QUERY_CLIENT: &str = "gtx";
+const TRANSLATE_QUERY_SOURCE_AUTO: &str = "auto";
+const TRANSLATE_QUERY_DATA_TEXT: &str = "t";
+const TRANSLATE_CLOUD_FORMAT_TEXT: &str = "text";
+const TRANSLATE_ERROR_REQUEST_FAILED_PREFIX: &str = "Translation request failed: ";
+const TRANSLATE_ERROR_UNEXPECTED_RESPONSE: &str = "Unexpected translation response format";
+const TRANSLATE_ERROR_KEYLESS_HINT: &str =
+    " (the keyless Google Translate endpoint is unofficial and best-effort; add a Google Cloud Translation API key in Settings for reliable translation)";
+
+enum TranslationEndpoint {
+    Keyless { api_url: String },
+    CloudTranslation { api_url: String, api_key: String },
+}
+
+pub struct GoogleTranslationService {
+    endpoint: TranslationEndpoint,
+}
+
+impl GoogleTranslationService {
+    pub fn new() -> Self {
+        Self {
+            endpoint: TranslationEndpoint::Keyless {
+                api_url: global_constants::GOOGLE_TRANSLATE_KEYLESS_API_URL.to_string(),
+            },
+        }
+    }
+
+    pub fn with_api_key(api_key: String) -> Self {
+        Self {
+            endpoint: TranslationEndpoint::CloudTranslation {
+                api_url: global_constants::GOOGLE_CLOUD_TRANSLATE_API_URL.to_string(),
+                api_key,
+            },
+        }
+    }
+
+    pub fn from_user_settings(settings: &UserSettings) -> Self {
+        let api_key = settings.translation_api_key.trim();
+        if api_key.is_empty() {
+            Self::new()
+        } else {
+            Self::with_api_key(api_key.to_string())
+        }
+    }
+
+    pub fn is_best_effort(&self) -> bool {
+        matches!(self.endpoint, TranslationEndpoint::Keyless { .. })
+    }
+
+    async fn translate_keyless(api_url: &str, text: &str, target_lang: &str) -> Result<String> {
+        let response = reqwest::Client::new()
+            .get(api_url)
+            .query(&[
+                ("client", TRANSLATE_QUERY_CLIENT),
+                ("sl", TRANSLATE_QUERY_SOURCE_AUTO),
+                ("tl", target_lang),
+                ("dt", TRANSLATE_QUERY_DATA_TEXT),
+                ("q", text),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "{}{} {}{}",
+                TRANSLATE_ERROR_REQUEST_FAILED_PREFIX,
+                status,
+                response_text,
+                TRANSLATE_ERROR_KEYLESS_HINT
+            )
+        }
+
+        log::debug!("[TRANSLATION] Keyless response: {}", response_text);
+        parse_keyless_translation_response(&response_text)
+            .map_err(|error| anyhow::anyhow!("{}{}", error, TRANSLATE_ERROR_KEYLESS_HINT))
+    }
+
+    async fn translate_with_cloud_api(
+        api_url: &str,
+        api_key: &str,
+        text: &str,
+        target_lang: &str,
+    ) -> Result<String> {
+        let response = reqwest::Client::new()
+            .post(api_url)
+            .query(&[("key", api_key)])
+            .json(&serde_json::json!({
+                "q": text,
+                "target": target_lang,
+                "format": TRANSLATE_CLOUD_FORMAT_TEXT,
+            }))
+            .send()
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "{}{} {}",
+                TRANSLATE_ERROR_REQUEST_FAILED_PREFIX,
+                status,
+                response_text
+            )
+        }
+
+        log::debug!("[TRANSLATION] Cloud response: {}", response_text);
+        parse_cloud_translation_response(&response_text)
+    }
+}
+
+impl Default for GoogleTranslationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse_keyless_translation_response(response_text: &str) -> Result<String> {
+    let json: serde_json::Value = serde_json::from_str(response_text)?;
+    let segments = json[0]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("{}", TRANSLATE_ERROR_UNEXPECTED_RESPONSE))?;
+
+    Ok(segments
+        .iter()
+        .filter_map(|segment| segment[0].as_str())
+        .collect())
+}
+
+fn parse_cloud_translation_response(response_text: &str) -> Result<String> {
+    let json: serde_json::Value = serde_json::from_str(response_text)?;
+    json["data"]["translations"][0]["translatedText"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("{}", TRANSLATE_ERROR_UNEXPECTED_RESPONSE))
+}
+
+#[async_trait]
+impl TranslationService for GoogleTranslationService {
+    async fn translate(&self, text: &str, target_lang: &str) -> Result<String> {
+        log::info!(
+            "[TRANSLATION] Translating {} characters to {} (best_effort: {})",
+            text.chars().count(),
+            target_lang,
+            self.is_best_effort()
+        );
+
+        match &self.endpoint {
+            TranslationEndpoint::Keyless { api_url } => {
+                Self::translate_keyless(api_url, text, target_lang).await
+            }
+            TranslationEndpoint::CloudTranslation { api_url, api_key } => {
+                Self::translate_with_cloud_api(api_url, api_key, text, target_lang).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keyless_translation_response_joins_segments() {
+        let response_text =
+            r#"[[["Hello ","Hola ",null,null,10],["world","mundo",null,null,10]],null,"es"]"#;
+
+        let translated = parse_keyless_translation_response(response_text).unwrap();
+
+        assert_eq!(translated, "Hello world");
+    }
+
+    #[test]
+    fn test_parse_keyless_translation_response_rejects_unexpected_shape() {
+        let result = parse_keyless_translation_response(r#"{"error":"bad request"}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_cloud_translation_response_reads_translated_text() {
+        let response_text = r#"{"data":{"translations":[{"translatedText":"Hello world","detectedSourceLanguage":"es"}]}}"#;
+
+        let translated = parse_cloud_translation_response(response_text).unwrap();
+
+        assert_eq!(translated, "Hello world");
+    }
+
+    #[test]
+    fn test_parse_cloud_translation_response_rejects_error_body() {
+        let result = parse_cloud_translation_response(
+            r#"{"error":{"code":400,"message":"API key not valid"}}"#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_user_settings_without_key_is_best_effort() {
+        let settings = UserSettings::default();
+
+        let service = GoogleTranslationService::from_user_settings(&settings);
+
+        assert!(service.is_best_effort());
+    }
+
+    #[test]
+    fn test_from_user_settings_with_key_uses_cloud_api() {
+        let settings = UserSettings {
+            translation_api_key: "  test-key  ".to_string(),
+            ..UserSettings::default()
+        };
+
+        let service = GoogleTranslationService::from_user_settings(&settings);
+
+        assert!(!service.is_best_effort());
+        assert!(matches!(
+            service.endpoint,
+            TranslationEndpoint::CloudTranslation { ref api_key, .. } if api_key == "test-key"
+        ));
+    }
+}
@@ -7,15 +7,52 @@ use crate::core::interfaces::adapters::ImageHostingService;
 use crate::core::models::{
     CaptureBuffer, ImageHostingAuthMode, ImageUploadHttpMethod, UserSettings,
 };
-use crate::global_constants;
 
-const IMGBB_TEMP_IMAGE_FILENAME: &str = "circle_to_search_image.png";
 const IMGBB_FORM_FIELD_EXPIRATION: &str = "expiration";
 const IMGBB_ERROR_PROVIDER_URL_EMPTY: &str = "Image hosting provider URL is empty";
 const IMGBB_ERROR_PUBLIC_KEY_NAME_EMPTY: &str = "Image hosting public key name is empty";
 const IMGBB_ERROR_PUBLIC_KEY_EMPTY: &str = "Image hosting public key is empty";
 const IMGBB_ERROR_UPLOAD_FAILED_PREFIX: &str = "Image upload failed: ";
 const IMGBB_ERROR_URL_EXTRACT_FAILED: &str = "Failed to extract image URL from imgbb response";
+const IMGBB_DEFAULT_MAX_UPLOAD_ATTEMPTS: u32 = 3;
+const IMGBB_DEFAULT_INITIAL_BACKOFF_MS: u64 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UploadRetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: std::time::Duration,
+}
+
+impl Default for UploadRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: IMGBB_DEFAULT_MAX_UPLOAD_ATTEMPTS,
+            initial_backoff: std::time::Duration::from_millis(IMGBB_DEFAULT_INITIAL_BACKOFF_MS),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum UploadAttemptError {
+    Transient(anyhow::Error),
+    Permanent(anyhow::Error),
+}
+
+impl UploadAttemptError {
+    fn from_status(status: reqwest::StatusCode, response_text: &str) -> Self {
+        let upload_error = anyhow::anyhow!(
+            "{}{} {}",
+            IMGBB_ERROR_UPLOAD_FAILED_PREFIX,
+            status,
+            response_text
+        );
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Self::Transient(upload_error)
+        } else {
+            Self::Permanent(upload_error)
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ImgbbImageHostingConfig {
@@ -26,6 +63,7 @@ pub struct ImgbbImageHostingConfig {
     pub expiration_seconds: String,
     pub http_method: ImageUploadHttpMethod,
     pub image_field_name: String,
+    pub retry_policy: UploadRetryPolicy,
 }
 
 impl ImgbbImageHostingConfig {
@@ -38,6 +76,7 @@ impl ImgbbImageHostingConfig {
             expiration_seconds: settings.image_hosting_expiration_seconds.clone(),
             http_method: settings.image_hosting_http_method.clone(),
             image_field_name: settings.image_hosting_image_field_name.clone(),
+            retry_policy: UploadRetryPolicy::default(),
         }
     }
 }
@@ -60,16 +99,11 @@ impl ImgbbImageHostingService {
 
     async fn save_buffer_to_temp_file(&self, buffer: &CaptureBuffer) -> Result<std::path::PathBuf> {
         let temp_dir = std::env::temp_dir();
-        let image_path = temp_dir.join(IMGBB_TEMP_IMAGE_FILENAME);
+        let image_path = temp_dir.join(crate::global_constants::UPLOAD_TEMP_IMAGE_FILENAME);
 
         log::debug!("[IMGBB] Saving image to temp: {:?}", image_path);
 
-        let img = ::image::DynamicImage::ImageRgba8(
-            ::image::RgbaImage::from_raw(buffer.width, buffer.height, buffer.raw_data.clone())
-                .ok_or_else(|| anyhow::anyhow!(global_constants::OCR_RAW_IMAGE_CREATION_FAILED))?,
-        );
-
-        img.save(&image_path)?;
+        buffer.to_dynamic_image()?.save(&image_path)?;
         Ok(image_path)
     }
 
@@ -121,12 +155,26 @@ impl ImgbbImageHostingService {
     }
 
     async fn upload_to_imgbb(&self, image_path: &std::path::Path) -> Result<String> {
-        log::info!("[IMGBB] Uploading image to imgbb");
-
         let image_data = tokio::fs::read(image_path).await?;
         let base64_image = base64::engine::general_purpose::STANDARD.encode(&image_data);
-
         let client = reqwest::Client::new();
+
+        retry_with_backoff(self.config.retry_policy, |attempt| {
+            log::info!(
+                "[IMGBB] Uploading image to imgbb (attempt {}/{})",
+                attempt,
+                self.config.retry_policy.max_attempts
+            );
+            self.send_upload_attempt(&client, base64_image.clone())
+        })
+        .await
+    }
+
+    async fn send_upload_attempt(
+        &self,
+        client: &reqwest::Client,
+        base64_image: String,
+    ) -> Result<String, UploadAttemptError> {
         let form = reqwest::multipart::Form::new()
             .text(self.config.image_field_name.clone(), base64_image)
             .text(
@@ -134,35 +182,85 @@ impl ImgbbImageHostingService {
                 self.config.expiration_seconds.clone(),
             );
 
-        let upload_url = self.build_upload_url()?;
+        let upload_url = self
+            .build_upload_url()
+            .map_err(UploadAttemptError::Permanent)?;
         let response = self
-            .build_upload_request(&client, upload_url, form)?
+            .build_upload_request(client, upload_url, form)
+            .map_err(UploadAttemptError::Permanent)?
             .send()
-            .await?;
+            .await
+            .map_err(|error| UploadAttemptError::Transient(error.into()))?;
 
         let status = response.status();
 
-        let response_text = response.text().await?;
+        let response_text = response
+            .text()
+            .await
+            .map_err(|error| UploadAttemptError::Transient(error.into()))?;
         if !status.is_success() {
-            let upload_error = format!(
-                "{}{} {}",
-                IMGBB_ERROR_UPLOAD_FAILED_PREFIX, status, response_text
-            );
-            anyhow::bail!("{}", upload_error)
+            return Err(UploadAttemptError::from_status(status, &response_text));
         }
 
         log::debug!("[IMGBB] imgbb response: {}", response_text);
 
-        let json: serde_json::Value = serde_json::from_str(&response_text)?;
+        let json: serde_json::Value = serde_json::from_str(&response_text)
+            .map_err(|error| UploadAttemptError::Permanent(error.into()))?;
 
-        let image_url = json["data"]["url"]
-            .as_str()
-            .ok_or_else(|| anyhow::anyhow!("{}", IMGBB_ERROR_URL_EXTRACT_FAILED))?;
+        let image_url = json["data"]["url"].as_str().ok_or_else(|| {
+            UploadAttemptError::Permanent(anyhow::anyhow!("{}", IMGBB_ERROR_URL_EXTRACT_FAILED))
+        })?;
 
         Ok(image_url.to_string())
     }
 }
 
+async fn retry_with_backoff<T, F, Fut>(
+    policy: UploadRetryPolicy,
+    mut attempt_upload: F,
+) -> Result<T>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: std::future::Future<Output = Result<T, UploadAttemptError>>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut backoff = policy.initial_backoff;
+    let mut attempt = 1;
+
+    loop {
+        match attempt_upload(attempt).await {
+            Ok(value) => return Ok(value),
+            Err(UploadAttemptError::Permanent(error)) => {
+                log::error!(
+                    "[IMGBB] Upload attempt {} failed permanently: {}",
+                    attempt,
+                    error
+                );
+                return Err(error);
+            }
+            Err(UploadAttemptError::Transient(error)) if attempt >= max_attempts => {
+                log::error!(
+                    "[IMGBB] Upload failed after {} attempts: {}",
+                    attempt,
+                    error
+                );
+                return Err(error);
+            }
+            Err(UploadAttemptError::Transient(error)) => {
+                log::warn!(
+                    "[IMGBB] Upload attempt {} failed, retrying in {:?}: {}",
+                    attempt,
+                    backoff,
+                    error
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = backoff.saturating_mul(2);
+                attempt += 1;
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl ImageHostingService for ImgbbImageHostingService {
     async fn upload_image(&self, buffer: &CaptureBuffer) -> Result<String> {
@@ -187,6 +285,7 @@ mod tests {
             expiration_seconds: "900".to_string(),
             http_method: ImageUploadHttpMethod::Post,
             image_field_name: "image".to_string(),
+            retry_policy: UploadRetryPolicy::default(),
         }
     }
 
@@ -271,4 +370,72 @@ mod tests {
             .unwrap();
         assert_eq!(request.headers().get("X-API-Key").unwrap(), "test-key");
     }
+
+    fn build_instant_retry_policy() -> UploadRetryPolicy {
+        UploadRetryPolicy {
+            max_attempts: 3,
+            initial_backoff: std::time::Duration::ZERO,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_succeeds_after_two_transient_failures() {
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+
+        let result = retry_with_backoff(build_instant_retry_policy(), |_attempt| {
+            let attempt_number = attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            async move {
+                if attempt_number < 3 {
+                    Err(UploadAttemptError::from_status(
+                        reqwest::StatusCode::BAD_GATEWAY,
+                        "upstream unavailable",
+                    ))
+                } else {
+                    Ok("https://i.ibb.co/test.png".to_string())
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "https://i.ibb.co/test.png");
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_does_not_retry_auth_errors() {
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+
+        let result: Result<String> = retry_with_backoff(build_instant_retry_policy(), |_attempt| {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async {
+                Err(UploadAttemptError::from_status(
+                    reqwest::StatusCode::UNAUTHORIZED,
+                    "invalid key",
+                ))
+            }
+        })
+        .await;
+
+        assert!(result.unwrap_err().to_string().contains("401"));
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_gives_up_after_max_attempts() {
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+
+        let result: Result<String> = retry_with_backoff(build_instant_retry_policy(), |_attempt| {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async {
+                Err(UploadAttemptError::from_status(
+                    reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    "",
+                ))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
 }
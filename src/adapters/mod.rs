@@ -1,10 +1,29 @@
+pub mod audio_feedback;
 pub mod auto_launch;
+mod bing_visual_search_provider;
+mod ensemble_ocr_service;
 mod google_lens_search_provider;
+mod google_translation_service;
 mod imgbb_image_hosting_service;
 pub mod macos_app_behavior;
 pub mod macos_permissions;
+mod model_download;
+mod multipart_image_hosting_service;
+mod neural_ocr_service;
+mod onnx_ocr_service;
+mod rxing_barcode_service;
+pub mod system_appearance;
 mod tesseract_ocr_service;
+mod yandex_image_search_provider;
 
+pub use bing_visual_search_provider::BingVisualSearchProvider;
+pub use ensemble_ocr_service::EnsembleOcrService;
 pub use google_lens_search_provider::GoogleLensSearchProvider;
+pub use google_translation_service::GoogleTranslationService;
 pub use imgbb_image_hosting_service::ImgbbImageHostingService;
+pub use multipart_image_hosting_service::MultipartImageHostingService;
+pub use neural_ocr_service::NeuralOcrService;
+pub use onnx_ocr_service::OnnxOcrService;
+pub use rxing_barcode_service::RxingBarcodeService;
 pub use tesseract_ocr_service::TesseractOcrService;
+pub use yandex_image_search_provider::YandexImageSearchProvider;
@@ -0,0 +1,140 @@
+use anyhow::{Context, Result};
+use futures::channel::mpsc::UnboundedSender;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::core::models::ModelDownloadProgress;
+
+const MODEL_DOWNLOAD_TEMP_EXTENSION: &str = "download";
+const MODEL_DOWNLOAD_CHUNK_BYTES: usize = 64 * 1024;
+
+pub(crate) fn resolve_model_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(crate::global_constants::OCR_MODEL_CACHE_DIR_NAME)
+}
+
+pub(crate) fn prepare_model_cache_dir() -> Result<PathBuf> {
+    let cache_dir = resolve_model_cache_dir();
+    std::fs::create_dir_all(&cache_dir)
+        .with_context(|| format!("Failed to create model cache directory {:?}", cache_dir))?;
+    Ok(cache_dir)
+}
+
+pub(crate) fn ensure_model_downloaded(
+    cache_dir: &Path,
+    filename: &str,
+    url: &str,
+    progress_sender: Option<&UnboundedSender<ModelDownloadProgress>>,
+) -> Result<PathBuf> {
+    let model_path = cache_dir.join(filename);
+    if model_path.exists() {
+        log::debug!("[MODEL_DOWNLOAD] Using cached model {:?}", model_path);
+        return Ok(model_path);
+    }
+
+    log::info!(
+        "[MODEL_DOWNLOAD] Downloading model {} from {}",
+        filename,
+        url
+    );
+
+    let mut response = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to download model {}", filename))?;
+    let total_bytes = response.content_length();
+
+    let download_path = model_path.with_extension(MODEL_DOWNLOAD_TEMP_EXTENSION);
+    let mut download_file = std::fs::File::create(&download_path)
+        .with_context(|| format!("Failed to write model to {:?}", download_path))?;
+    let received_bytes = copy_with_progress(&mut response, &mut download_file, |received_bytes| {
+        if let Some(sender) = progress_sender {
+            let _ = sender.unbounded_send(ModelDownloadProgress {
+                filename: filename.to_string(),
+                received_bytes,
+                total_bytes,
+            });
+        }
+    })
+    .with_context(|| format!("Failed to download model {}", filename))?;
+    drop(download_file);
+
+    std::fs::rename(&download_path, &model_path)
+        .with_context(|| format!("Failed to move model into {:?}", model_path))?;
+
+    log::info!(
+        "[MODEL_DOWNLOAD] Cached model {} ({} bytes)",
+        filename,
+        received_bytes
+    );
+    Ok(model_path)
+}
+
+fn copy_with_progress(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    mut on_progress: impl FnMut(u64),
+) -> std::io::Result<u64> {
+    let mut buffer = vec![0u8; MODEL_DOWNLOAD_CHUNK_BYTES];
+    let mut received_bytes = 0u64;
+
+    loop {
+        let read_bytes = reader.read(&mut buffer)?;
+        if read_bytes == 0 {
+            break;
+        }
+
+        writer.write_all(&buffer[..read_bytes])?;
+        received_bytes += read_bytes as u64;
+        on_progress(received_bytes);
+    }
+
+    writer.flush()?;
+    Ok(received_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_model_cache_dir_uses_app_specific_folder() {
+        let cache_dir = resolve_model_cache_dir();
+
+        assert!(cache_dir.ends_with(crate::global_constants::OCR_MODEL_CACHE_DIR_NAME));
+    }
+
+    #[test]
+    fn test_ensure_model_downloaded_reuses_cached_model() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("test-model-download-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&cache_dir).unwrap();
+        let cached_model_path = cache_dir.join("cached-model.bin");
+        std::fs::write(&cached_model_path, b"cached").unwrap();
+
+        let result =
+            ensure_model_downloaded(&cache_dir, "cached-model.bin", "http://invalid.test", None);
+
+        assert_eq!(result.unwrap(), cached_model_path);
+        std::fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    #[test]
+    fn test_copy_with_progress_reports_cumulative_bytes() {
+        let source = vec![7u8; MODEL_DOWNLOAD_CHUNK_BYTES + 10];
+        let mut destination = Vec::new();
+        let mut reported = Vec::new();
+
+        let copied = copy_with_progress(&mut source.as_slice(), &mut destination, |received| {
+            reported.push(received)
+        })
+        .unwrap();
+
+        assert_eq!(copied, source.len() as u64);
+        assert_eq!(destination, source);
+        assert_eq!(
+            reported,
+            vec![MODEL_DOWNLOAD_CHUNK_BYTES as u64, source.len() as u64]
+        );
+    }
+}
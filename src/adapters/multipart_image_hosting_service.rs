@@ -0,0 +1,134 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Url;
+
+use crate::core::interfaces::adapters::ImageHostingService;
+use crate::core::models::CaptureBuffer;
+use crate::global_constants;
+
+const MULTIPART_UPLOAD_FILENAME: &str = "circle_to_search_image.png";
+const MULTIPART_UPLOAD_MIME_TYPE: &str = "image/png";
+const MULTIPART_ERROR_ENDPOINT_EMPTY: &str = "Image host URL is empty; set it in Settings";
+const MULTIPART_ERROR_ENDPOINT_INVALID_PREFIX: &str = "Image host URL is not a valid http(s) URL: ";
+const MULTIPART_ERROR_FIELD_NAME_EMPTY: &str = "Image host field name is empty";
+const MULTIPART_ERROR_UPLOAD_FAILED_PREFIX: &str = "Image upload failed: ";
+const MULTIPART_ERROR_UNEXPECTED_RESPONSE: &str = "Image host did not return a URL in its response";
+
+pub struct MultipartImageHostingService {
+    endpoint: String,
+    field_name: String,
+}
+
+impl MultipartImageHostingService {
+    pub fn new(endpoint: String, field_name: String) -> Self {
+        Self {
+            endpoint,
+            field_name,
+        }
+    }
+
+    fn build_upload_url(&self) -> Result<Url> {
+        let endpoint = self.endpoint.trim();
+        if endpoint.is_empty() {
+            anyhow::bail!("{}", MULTIPART_ERROR_ENDPOINT_EMPTY)
+        }
+        match Url::parse(endpoint) {
+            Ok(upload_url) if matches!(upload_url.scheme(), "http" | "https") => Ok(upload_url),
+            _ => anyhow::bail!("{}{}", MULTIPART_ERROR_ENDPOINT_INVALID_PREFIX, endpoint),
+        }
+    }
+}
+
+fn parse_uploaded_url(response_text: &str) -> Result<String> {
+    response_text
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with("http://") || line.starts_with("https://"))
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("{}", MULTIPART_ERROR_UNEXPECTED_RESPONSE))
+}
+
+#[async_trait]
+impl ImageHostingService for MultipartImageHostingService {
+    async fn upload_image(&self, buffer: &CaptureBuffer) -> Result<String> {
+        let upload_url = self.build_upload_url()?;
+        if self.field_name.trim().is_empty() {
+            anyhow::bail!("{}", MULTIPART_ERROR_FIELD_NAME_EMPTY)
+        }
+
+        log::info!("[MULTIPART_HOST] Uploading image to {}", upload_url);
+
+        let image_part = reqwest::multipart::Part::bytes(buffer.to_png_bytes()?)
+            .file_name(MULTIPART_UPLOAD_FILENAME)
+            .mime_str(MULTIPART_UPLOAD_MIME_TYPE)?;
+        let form =
+            reqwest::multipart::Form::new().part(self.field_name.trim().to_string(), image_part);
+        let timeout =
+            std::time::Duration::from_secs(global_constants::IMAGE_HOST_UPLOAD_TIMEOUT_SECONDS);
+        let client = reqwest::Client::builder()
+            .user_agent(global_constants::APP_USER_AGENT)
+            .connect_timeout(timeout)
+            .timeout(timeout)
+            .build()?;
+
+        let response = client.post(upload_url).multipart(form).send().await?;
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "{}{} {}",
+                MULTIPART_ERROR_UPLOAD_FAILED_PREFIX,
+                status,
+                response_text.trim()
+            )
+        }
+
+        let image_url = parse_uploaded_url(&response_text)?;
+        log::info!(
+            "[MULTIPART_HOST] Image uploaded successfully: {}",
+            image_url
+        );
+        Ok(image_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uploaded_url_reads_plain_text_response() {
+        let image_url = parse_uploaded_url("https://0x0.st/abc.png\n").unwrap();
+
+        assert_eq!(image_url, "https://0x0.st/abc.png");
+    }
+
+    #[test]
+    fn test_parse_uploaded_url_rejects_response_without_url() {
+        let error = parse_uploaded_url("<html>Forbidden</html>").unwrap_err();
+
+        assert_eq!(error.to_string(), MULTIPART_ERROR_UNEXPECTED_RESPONSE);
+    }
+
+    #[test]
+    fn test_build_upload_url_rejects_non_http_endpoint() {
+        let service =
+            MultipartImageHostingService::new("ftp://example.com".to_string(), "file".to_string());
+
+        let error = service.build_upload_url().unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with(MULTIPART_ERROR_ENDPOINT_INVALID_PREFIX));
+    }
+
+    #[tokio::test]
+    async fn test_upload_image_fails_fast_when_endpoint_is_empty() {
+        let service = MultipartImageHostingService::new("  ".to_string(), "file".to_string());
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+
+        let error = service.upload_image(&buffer).await.unwrap_err();
+
+        assert_eq!(error.to_string(), MULTIPART_ERROR_ENDPOINT_EMPTY);
+    }
+}
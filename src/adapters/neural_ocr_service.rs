@@ -0,0 +1,169 @@
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use futures::channel::mpsc::UnboundedSender;
+use image::DynamicImage;
+use ocrs::{ImageSource, OcrEngine, OcrEngineParams, TextItem};
+use rten::Model;
+
+use super::model_download::{ensure_model_downloaded, prepare_model_cache_dir};
+use crate::core::interfaces::adapters::OcrService;
+use crate::core::models::{
+    DetectedText, DetectedWord, ModelDownloadProgress, OcrLanguage, OcrResult,
+};
+
+const DETECTION_MODEL_FILENAME: &str = "text-detection.rten";
+const LATIN_RECOGNITION_MODEL_FILENAME: &str = "text-recognition.rten";
+const DETECTION_MODEL_URL: &str =
+    "https://ocrs-models.s3-accelerate.amazonaws.com/text-detection.rten";
+const LATIN_RECOGNITION_MODEL_URL: &str =
+    "https://ocrs-models.s3-accelerate.amazonaws.com/text-recognition.rten";
+
+pub struct NeuralOcrService {
+    engine: OcrEngine,
+}
+
+impl NeuralOcrService {
+    pub fn build(
+        language: OcrLanguage,
+        progress_sender: Option<&UnboundedSender<ModelDownloadProgress>>,
+    ) -> Result<Self> {
+        log::info!(
+            "[NEURAL_OCR] Initializing neural OCR service for {}",
+            language
+        );
+
+        let cache_dir = prepare_model_cache_dir()?;
+
+        let detection_model_path = ensure_model_downloaded(
+            &cache_dir,
+            DETECTION_MODEL_FILENAME,
+            DETECTION_MODEL_URL,
+            progress_sender,
+        )?;
+        let (recognition_model_filename, recognition_model_url) =
+            select_recognition_model(language)?;
+        let recognition_model_path = ensure_model_downloaded(
+            &cache_dir,
+            recognition_model_filename,
+            recognition_model_url,
+            progress_sender,
+        )?;
+
+        let detection_model = Model::load_file(&detection_model_path)
+            .with_context(|| format!("Failed to load {:?}", detection_model_path))?;
+        let recognition_model = Model::load_file(&recognition_model_path)
+            .with_context(|| format!("Failed to load {:?}", recognition_model_path))?;
+
+        let engine = OcrEngine::new(OcrEngineParams {
+            detection_model: Some(detection_model),
+            recognition_model: Some(recognition_model),
+            ..Default::default()
+        })
+        .context("Failed to create neural OCR engine")?;
+
+        log::info!("[NEURAL_OCR] Using models from: {:?}", cache_dir);
+
+        Ok(Self { engine })
+    }
+}
+
+fn select_recognition_model(language: OcrLanguage) -> Result<(&'static str, &'static str)> {
+    match language {
+        OcrLanguage::English => Ok((
+            LATIN_RECOGNITION_MODEL_FILENAME,
+            LATIN_RECOGNITION_MODEL_URL,
+        )),
+        unsupported_language => Err(anyhow!(
+            "Neural OCR has no recognition model for {}",
+            unsupported_language
+        )),
+    }
+}
+
+#[async_trait]
+impl OcrService for NeuralOcrService {
+    async fn extract_text_from_image(&self, image: &DynamicImage) -> Result<OcrResult> {
+        log::info!("[NEURAL_OCR] Starting text extraction");
+        log::debug!(
+            "[NEURAL_OCR] Image dimensions: {}x{}",
+            image.width(),
+            image.height()
+        );
+
+        let rgb_image = image.to_rgb8();
+        let image_source = ImageSource::from_bytes(rgb_image.as_raw(), rgb_image.dimensions())
+            .context("Failed to prepare image for neural OCR")?;
+        let ocr_input = self
+            .engine
+            .prepare_input(image_source)
+            .context("Failed to preprocess image for neural OCR")?;
+        let word_rects = self
+            .engine
+            .detect_words(&ocr_input)
+            .context("Failed to detect words")?;
+        let line_rects = self.engine.find_text_lines(&ocr_input, &word_rects);
+        let recognized_lines = self
+            .engine
+            .recognize_text(&ocr_input, &line_rects)
+            .context("Failed to recognize text")?;
+
+        let mut detected_texts = Vec::new();
+        let mut full_text = String::new();
+
+        for line in recognized_lines.iter().flatten() {
+            for word in line.words() {
+                let word_text = word.to_string();
+                let word_text = word_text.trim();
+                if word_text.is_empty() {
+                    continue;
+                }
+
+                let word_bounds = word.bounding_rect();
+                let x = word_bounds.left() as f32;
+                let y = word_bounds.top() as f32;
+                let width = word_bounds.width() as f32;
+                let height = word_bounds.height() as f32;
+
+                full_text.push_str(word_text);
+                full_text.push(' ');
+
+                detected_texts.push(DetectedText::without_confidence(
+                    word_text.to_string(),
+                    x,
+                    y,
+                    width,
+                    height,
+                    vec![DetectedWord::new(
+                        word_text.to_string(),
+                        x,
+                        y,
+                        width,
+                        height,
+                    )],
+                ));
+            }
+        }
+
+        log::info!(
+            "[NEURAL_OCR] Text extraction complete. Found {} words",
+            detected_texts.len()
+        );
+
+        Ok(OcrResult {
+            text_blocks: detected_texts,
+            full_text: full_text.trim().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_recognition_model_rejects_languages_without_a_model() {
+        assert!(select_recognition_model(OcrLanguage::English).is_ok());
+        assert!(select_recognition_model(OcrLanguage::French).is_err());
+        assert!(select_recognition_model(OcrLanguage::Japanese).is_err());
+    }
+}
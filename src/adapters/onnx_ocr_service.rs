@@ -0,0 +1,556 @@
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use futures::channel::mpsc::UnboundedSender;
+use image::imageops::FilterType;
+use image::{DynamicImage, RgbImage};
+use ort::session::Session;
+use ort::value::Tensor;
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::model_download::{ensure_model_downloaded, prepare_model_cache_dir};
+use crate::core::interfaces::adapters::OcrService;
+use crate::core::models::{
+    DetectedText, DetectedWord, ModelDownloadProgress, OcrLanguage, OcrResult,
+};
+
+const DETECTION_MODEL_FILENAME: &str = "ppocr-v4-det.onnx";
+const RECOGNITION_MODEL_FILENAME: &str = "ppocr-v4-en-rec.onnx";
+const RECOGNITION_DICTIONARY_FILENAME: &str = "ppocr-en-dict.txt";
+const DETECTION_MODEL_URL: &str =
+    "https://huggingface.co/SWHL/RapidOCR/resolve/main/PP-OCRv4/ch_PP-OCRv4_det_infer.onnx";
+const RECOGNITION_MODEL_URL: &str =
+    "https://huggingface.co/SWHL/RapidOCR/resolve/main/PP-OCRv4/en_PP-OCRv4_rec_infer.onnx";
+const RECOGNITION_DICTIONARY_URL: &str =
+    "https://raw.githubusercontent.com/PaddlePaddle/PaddleOCR/main/ppocr/utils/en_dict.txt";
+const BGR_CHANNEL_ORDER: [usize; 3] = [2, 1, 0];
+const DETECTION_MAX_SIDE: u32 = 960;
+const DETECTION_SIDE_MULTIPLE: u32 = 32;
+const DETECTION_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+const DETECTION_STD: [f32; 3] = [0.229, 0.224, 0.225];
+const DETECTION_PIXEL_THRESHOLD: f32 = 0.3;
+const DETECTION_BOX_THRESHOLD: f32 = 0.6;
+const DETECTION_UNCLIP_RATIO: f32 = 1.5;
+const DETECTION_MIN_BOX_SIDE: usize = 3;
+const RECOGNITION_HEIGHT: u32 = 48;
+const RECOGNITION_MAX_WIDTH: u32 = 1280;
+const RECOGNITION_MEAN: [f32; 3] = [0.5, 0.5, 0.5];
+const RECOGNITION_STD: [f32; 3] = [0.5, 0.5, 0.5];
+const CTC_BLANK_INDEX: usize = 0;
+
+pub struct OnnxOcrService {
+    detection_session: Mutex<Session>,
+    recognition_session: Mutex<Session>,
+    dictionary: Vec<char>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TextRegion {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct RecognizedChar {
+    character: char,
+    time_step: usize,
+    probability: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct RecognizedWord {
+    content: String,
+    first_time_step: usize,
+    last_time_step: usize,
+    confidence: f32,
+}
+
+impl OnnxOcrService {
+    pub fn build(
+        language: OcrLanguage,
+        progress_sender: Option<&UnboundedSender<ModelDownloadProgress>>,
+    ) -> Result<Self> {
+        log::info!("[ONNX_OCR] Initializing ONNX OCR service for {}", language);
+
+        if language != OcrLanguage::English {
+            return Err(anyhow!(
+                "ONNX OCR has no recognition model for {}",
+                language
+            ));
+        }
+
+        let cache_dir = prepare_model_cache_dir()?;
+        let detection_model_path = ensure_model_downloaded(
+            &cache_dir,
+            DETECTION_MODEL_FILENAME,
+            DETECTION_MODEL_URL,
+            progress_sender,
+        )?;
+        let recognition_model_path = ensure_model_downloaded(
+            &cache_dir,
+            RECOGNITION_MODEL_FILENAME,
+            RECOGNITION_MODEL_URL,
+            progress_sender,
+        )?;
+        let dictionary_path = ensure_model_downloaded(
+            &cache_dir,
+            RECOGNITION_DICTIONARY_FILENAME,
+            RECOGNITION_DICTIONARY_URL,
+            progress_sender,
+        )?;
+
+        let dictionary_text = std::fs::read_to_string(&dictionary_path)
+            .with_context(|| format!("Failed to read {:?}", dictionary_path))?;
+        let dictionary = parse_recognition_dictionary(&dictionary_text);
+
+        log::info!(
+            "[ONNX_OCR] Using models from {:?} with {} dictionary characters",
+            cache_dir,
+            dictionary.len()
+        );
+
+        Ok(Self {
+            detection_session: Mutex::new(load_session(&detection_model_path)?),
+            recognition_session: Mutex::new(load_session(&recognition_model_path)?),
+            dictionary,
+        })
+    }
+
+    fn detect_text_regions(&self, image: &RgbImage) -> Result<Vec<TextRegion>> {
+        let (input_width, input_height) = resize_for_detection(image.width(), image.height());
+        let resized =
+            image::imageops::resize(image, input_width, input_height, FilterType::Triangle);
+        let input = normalize_into_chw(&resized, DETECTION_MEAN, DETECTION_STD);
+
+        let (output_shape, probability_map) = run_session(
+            &self.detection_session,
+            [1, 3, input_height as usize, input_width as usize],
+            input,
+        )
+        .context("Failed to detect text regions")?;
+        let [.., map_height, map_width] = output_shape[..] else {
+            return Err(anyhow!(
+                "Unexpected detection output shape {:?}",
+                output_shape
+            ));
+        };
+
+        let scale_x = image.width() as f32 / map_width as f32;
+        let scale_y = image.height() as f32 / map_height as f32;
+
+        Ok(
+            find_text_regions(&probability_map, map_width as usize, map_height as usize)
+                .into_iter()
+                .map(|region| TextRegion {
+                    x: region.x * scale_x,
+                    y: region.y * scale_y,
+                    width: region.width * scale_x,
+                    height: region.height * scale_y,
+                })
+                .collect(),
+        )
+    }
+
+    fn recognize_region(&self, image: &RgbImage, region: &TextRegion) -> Result<Vec<DetectedText>> {
+        let crop_x = region.x.floor().max(0.0) as u32;
+        let crop_y = region.y.floor().max(0.0) as u32;
+        let crop_width = (region.width.ceil() as u32).min(image.width() - crop_x);
+        let crop_height = (region.height.ceil() as u32).min(image.height() - crop_y);
+        if crop_width == 0 || crop_height == 0 {
+            return Ok(Vec::new());
+        }
+
+        let crop =
+            image::imageops::crop_imm(image, crop_x, crop_y, crop_width, crop_height).to_image();
+        let input_width = ((RECOGNITION_HEIGHT as f32 * crop_width as f32 / crop_height as f32)
+            .round() as u32)
+            .clamp(1, RECOGNITION_MAX_WIDTH);
+        let resized =
+            image::imageops::resize(&crop, input_width, RECOGNITION_HEIGHT, FilterType::Triangle);
+        let input = normalize_into_chw(&resized, RECOGNITION_MEAN, RECOGNITION_STD);
+
+        let (output_shape, probabilities) = run_session(
+            &self.recognition_session,
+            [1, 3, RECOGNITION_HEIGHT as usize, input_width as usize],
+            input,
+        )
+        .context("Failed to recognize text region")?;
+        let [_, time_steps, class_count] = output_shape[..] else {
+            return Err(anyhow!(
+                "Unexpected recognition output shape {:?}",
+                output_shape
+            ));
+        };
+        let time_steps = time_steps as usize;
+
+        let characters = decode_ctc(
+            &probabilities,
+            time_steps,
+            class_count as usize,
+            &self.dictionary,
+        );
+        let time_step_width = crop_width as f32 / time_steps.max(1) as f32;
+
+        Ok(group_into_words(&characters)
+            .into_iter()
+            .map(|word| {
+                let x = crop_x as f32 + word.first_time_step as f32 * time_step_width;
+                let width =
+                    (word.last_time_step + 1 - word.first_time_step) as f32 * time_step_width;
+                let y = crop_y as f32;
+                let height = crop_height as f32;
+                DetectedText::new(
+                    word.content.clone(),
+                    x,
+                    y,
+                    width,
+                    height,
+                    word.confidence,
+                    vec![DetectedWord::new(word.content, x, y, width, height)],
+                )
+            })
+            .collect())
+    }
+}
+
+fn load_session(model_path: &Path) -> Result<Session> {
+    Session::builder()
+        .and_then(|builder| builder.commit_from_file(model_path))
+        .with_context(|| format!("Failed to load ONNX model {:?}", model_path))
+}
+
+fn run_session(
+    session: &Mutex<Session>,
+    input_shape: [usize; 4],
+    input: Vec<f32>,
+) -> Result<(Vec<i64>, Vec<f32>)> {
+    let input_tensor =
+        Tensor::from_array((input_shape, input)).context("Failed to build ONNX input tensor")?;
+    let mut session = session
+        .lock()
+        .map_err(|_| anyhow!("ONNX session lock was poisoned"))?;
+    let outputs = session
+        .run(ort::inputs![input_tensor])
+        .context("Failed to run ONNX model")?;
+    let (output_shape, output) = outputs[0]
+        .try_extract_tensor::<f32>()
+        .context("ONNX model did not return a float tensor")?;
+
+    Ok((output_shape.to_vec(), output.to_vec()))
+}
+
+fn parse_recognition_dictionary(dictionary_text: &str) -> Vec<char> {
+    let mut dictionary: Vec<char> = dictionary_text
+        .lines()
+        .filter_map(|line| line.trim_end_matches('\r').chars().next())
+        .collect();
+    dictionary.push(' ');
+    dictionary
+}
+
+fn resize_for_detection(width: u32, height: u32) -> (u32, u32) {
+    let longest_side = width.max(height).max(1);
+    let scale = (DETECTION_MAX_SIDE as f32 / longest_side as f32).min(1.0);
+    let round_to_multiple = |side: u32| {
+        let scaled = (side as f32 * scale).round() as u32;
+        let multiples = (scaled + DETECTION_SIDE_MULTIPLE / 2) / DETECTION_SIDE_MULTIPLE;
+        multiples.max(1) * DETECTION_SIDE_MULTIPLE
+    };
+
+    (round_to_multiple(width), round_to_multiple(height))
+}
+
+fn normalize_into_chw(image: &RgbImage, mean: [f32; 3], std: [f32; 3]) -> Vec<f32> {
+    let plane_size = (image.width() * image.height()) as usize;
+    let mut tensor_data = vec![0.0f32; plane_size * 3];
+
+    for (pixel_index, pixel) in image.pixels().enumerate() {
+        for (plane, &channel) in BGR_CHANNEL_ORDER.iter().enumerate() {
+            let value = pixel.0[channel] as f32 / 255.0;
+            tensor_data[plane * plane_size + pixel_index] = (value - mean[plane]) / std[plane];
+        }
+    }
+
+    tensor_data
+}
+
+fn find_text_regions(
+    probability_map: &[f32],
+    map_width: usize,
+    map_height: usize,
+) -> Vec<TextRegion> {
+    let mut visited = vec![false; probability_map.len()];
+    let mut regions = Vec::new();
+    let mut pending = Vec::new();
+
+    for (start_index, &start_probability) in probability_map
+        .iter()
+        .enumerate()
+        .take(map_width * map_height)
+    {
+        if visited[start_index] || start_probability <= DETECTION_PIXEL_THRESHOLD {
+            continue;
+        }
+
+        visited[start_index] = true;
+        pending.push(start_index);
+        let (mut min_x, mut min_y) = (map_width, map_height);
+        let (mut max_x, mut max_y) = (0, 0);
+        let mut probability_sum = 0.0;
+        let mut pixel_count = 0usize;
+
+        while let Some(index) = pending.pop() {
+            let (x, y) = (index % map_width, index / map_width);
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+            probability_sum += probability_map[index];
+            pixel_count += 1;
+
+            let neighbours = [
+                x.checked_sub(1).map(|left| y * map_width + left),
+                (x + 1 < map_width).then_some(index + 1),
+                y.checked_sub(1).map(|up| up * map_width + x),
+                (y + 1 < map_height).then_some(index + map_width),
+            ];
+            for neighbour in neighbours.into_iter().flatten() {
+                if !visited[neighbour] && probability_map[neighbour] > DETECTION_PIXEL_THRESHOLD {
+                    visited[neighbour] = true;
+                    pending.push(neighbour);
+                }
+            }
+        }
+
+        let box_width = max_x + 1 - min_x;
+        let box_height = max_y + 1 - min_y;
+        if box_width < DETECTION_MIN_BOX_SIDE || box_height < DETECTION_MIN_BOX_SIDE {
+            continue;
+        }
+        if probability_sum / (pixel_count as f32) < DETECTION_BOX_THRESHOLD {
+            continue;
+        }
+
+        let unclip_distance = (box_width * box_height) as f32 * DETECTION_UNCLIP_RATIO
+            / (2 * (box_width + box_height)) as f32;
+        let left = (min_x as f32 - unclip_distance).max(0.0);
+        let top = (min_y as f32 - unclip_distance).max(0.0);
+        let right = ((max_x + 1) as f32 + unclip_distance).min(map_width as f32);
+        let bottom = ((max_y + 1) as f32 + unclip_distance).min(map_height as f32);
+
+        regions.push(TextRegion {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        });
+    }
+
+    regions.sort_by(|first, second| {
+        first
+            .y
+            .total_cmp(&second.y)
+            .then(first.x.total_cmp(&second.x))
+    });
+    regions
+}
+
+fn decode_ctc(
+    probabilities: &[f32],
+    time_steps: usize,
+    class_count: usize,
+    dictionary: &[char],
+) -> Vec<RecognizedChar> {
+    let mut characters = Vec::new();
+    let mut previous_class = CTC_BLANK_INDEX;
+
+    for (time_step, step_probabilities) in probabilities
+        .chunks_exact(class_count.max(1))
+        .take(time_steps)
+        .enumerate()
+    {
+        let (best_class, &best_probability) = step_probabilities
+            .iter()
+            .enumerate()
+            .max_by(|first, second| first.1.total_cmp(second.1))
+            .unwrap_or((CTC_BLANK_INDEX, &0.0));
+
+        if best_class != CTC_BLANK_INDEX && best_class != previous_class {
+            if let Some(&character) = dictionary.get(best_class - 1) {
+                characters.push(RecognizedChar {
+                    character,
+                    time_step,
+                    probability: best_probability,
+                });
+            }
+        }
+        previous_class = best_class;
+    }
+
+    characters
+}
+
+fn group_into_words(characters: &[RecognizedChar]) -> Vec<RecognizedWord> {
+    characters
+        .split(|recognized| recognized.character.is_whitespace())
+        .filter(|word_characters| !word_characters.is_empty())
+        .map(|word_characters| RecognizedWord {
+            content: word_characters
+                .iter()
+                .map(|recognized| recognized.character)
+                .collect(),
+            first_time_step: word_characters[0].time_step,
+            last_time_step: word_characters[word_characters.len() - 1].time_step,
+            confidence: word_characters
+                .iter()
+                .map(|recognized| recognized.probability)
+                .sum::<f32>()
+                / word_characters.len() as f32,
+        })
+        .collect()
+}
+
+#[async_trait]
+impl OcrService for OnnxOcrService {
+    async fn extract_text_from_image(&self, image: &DynamicImage) -> Result<OcrResult> {
+        log::info!("[ONNX_OCR] Starting text extraction");
+        log::debug!(
+            "[ONNX_OCR] Image dimensions: {}x{}",
+            image.width(),
+            image.height()
+        );
+
+        let rgb_image = image.to_rgb8();
+        let regions = self.detect_text_regions(&rgb_image)?;
+        log::debug!("[ONNX_OCR] Detected {} text regions", regions.len());
+
+        let mut detected_texts = Vec::new();
+        for region in &regions {
+            detected_texts.extend(self.recognize_region(&rgb_image, region)?);
+        }
+
+        let full_text = detected_texts
+            .iter()
+            .map(|detected| detected.content.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        log::info!(
+            "[ONNX_OCR] Text extraction complete. Found {} words",
+            detected_texts.len()
+        );
+
+        Ok(OcrResult {
+            text_blocks: detected_texts,
+            full_text,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recognized(character: char, time_step: usize, probability: f32) -> RecognizedChar {
+        RecognizedChar {
+            character,
+            time_step,
+            probability,
+        }
+    }
+
+    #[test]
+    fn test_parse_recognition_dictionary_appends_space_character() {
+        let dictionary = parse_recognition_dictionary("a\r\nb\nc\n");
+
+        assert_eq!(dictionary, vec!['a', 'b', 'c', ' ']);
+    }
+
+    #[test]
+    fn test_resize_for_detection_limits_longest_side_to_multiples_of_32() {
+        assert_eq!(resize_for_detection(1920, 1080), (960, 544));
+        assert_eq!(resize_for_detection(10, 5), (32, 32));
+    }
+
+    #[test]
+    fn test_normalize_into_chw_orders_planes_as_bgr() {
+        let image = RgbImage::from_pixel(1, 1, image::Rgb([255, 0, 0]));
+
+        let tensor_data = normalize_into_chw(&image, RECOGNITION_MEAN, RECOGNITION_STD);
+
+        assert_eq!(tensor_data, vec![-1.0, -1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_find_text_regions_expands_confident_components() {
+        let (map_width, map_height) = (20, 10);
+        let mut probability_map = vec![0.0f32; map_width * map_height];
+        for y in 4..8 {
+            for x in 6..14 {
+                probability_map[y * map_width + x] = 0.9;
+            }
+        }
+        probability_map[0] = 0.9;
+
+        let regions = find_text_regions(&probability_map, map_width, map_height);
+
+        assert_eq!(regions.len(), 1);
+        let unclip_distance = 8.0 * 4.0 * DETECTION_UNCLIP_RATIO / 24.0;
+        assert_eq!(regions[0].x, 6.0 - unclip_distance);
+        assert_eq!(regions[0].y, 4.0 - unclip_distance);
+        assert_eq!(regions[0].width, 8.0 + 2.0 * unclip_distance);
+    }
+
+    #[test]
+    fn test_find_text_regions_drops_low_scoring_components() {
+        let probability_map = vec![0.4f32; 16];
+
+        assert!(find_text_regions(&probability_map, 4, 4).is_empty());
+    }
+
+    #[test]
+    fn test_decode_ctc_collapses_repeats_and_skips_blanks() {
+        let dictionary = vec!['a', 'b', ' '];
+        let probabilities = vec![
+            0.1, 0.8, 0.05, 0.05, //
+            0.1, 0.7, 0.1, 0.1, //
+            0.9, 0.05, 0.025, 0.025, //
+            0.1, 0.6, 0.2, 0.1, //
+            0.1, 0.1, 0.1, 0.7, //
+            0.1, 0.1, 0.75, 0.05,
+        ];
+
+        let characters = decode_ctc(&probabilities, 6, 4, &dictionary);
+
+        assert_eq!(
+            characters,
+            vec![
+                recognized('a', 0, 0.8),
+                recognized('a', 3, 0.6),
+                recognized(' ', 4, 0.7),
+                recognized('b', 5, 0.75),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_into_words_averages_character_probabilities() {
+        let characters = vec![
+            recognized('h', 0, 0.8),
+            recognized('i', 2, 0.6),
+            recognized(' ', 3, 0.9),
+            recognized('x', 5, 0.5),
+        ];
+
+        let words = group_into_words(&characters);
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].content, "hi");
+        assert_eq!(words[0].first_time_step, 0);
+        assert_eq!(words[0].last_time_step, 2);
+        assert!((words[0].confidence - 0.7).abs() < 1e-6);
+        assert_eq!(words[1].content, "x");
+    }
+}
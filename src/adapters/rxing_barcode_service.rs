@@ -0,0 +1,162 @@
+use anyhow::Result;
+use image::DynamicImage;
+use rxing::{BarcodeFormat, Exceptions, RXingResult};
+
+use crate::core::interfaces::adapters::BarcodeService;
+use crate::core::models::DecodedCode;
+
+#[derive(Default)]
+pub struct RxingBarcodeService;
+
+impl RxingBarcodeService {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+fn describe_barcode_format(format: &BarcodeFormat) -> &'static str {
+    match format {
+        BarcodeFormat::QR_CODE | BarcodeFormat::MICRO_QR_CODE => "QR code",
+        BarcodeFormat::DATA_MATRIX => "Data Matrix",
+        BarcodeFormat::AZTEC => "Aztec",
+        BarcodeFormat::PDF_417 => "PDF417",
+        BarcodeFormat::EAN_13 => "EAN-13",
+        BarcodeFormat::EAN_8 => "EAN-8",
+        BarcodeFormat::UPC_A => "UPC-A",
+        BarcodeFormat::UPC_E => "UPC-E",
+        BarcodeFormat::CODE_128 => "Code 128",
+        BarcodeFormat::CODE_39 => "Code 39",
+        BarcodeFormat::CODE_93 => "Code 93",
+        BarcodeFormat::CODABAR => "Codabar",
+        BarcodeFormat::ITF => "ITF",
+        _ => "Barcode",
+    }
+}
+
+fn to_decoded_code(result: &RXingResult) -> DecodedCode {
+    DecodedCode::with_format(
+        result.getText().to_string(),
+        describe_barcode_format(result.getBarcodeFormat()).to_string(),
+    )
+}
+
+impl BarcodeService for RxingBarcodeService {
+    fn decode(&self, image: &DynamicImage) -> Result<Vec<DecodedCode>> {
+        log::info!(
+            "[BARCODE] Scanning {}x{} image for codes",
+            image.width(),
+            image.height()
+        );
+
+        let luma_image = image.to_luma8();
+        let (width, height) = luma_image.dimensions();
+        let results =
+            match rxing::helpers::detect_multiple_in_luma(luma_image.into_raw(), width, height) {
+                Ok(results) => results,
+                Err(Exceptions::NotFoundException(_)) => Vec::new(),
+                Err(error) => return Err(anyhow::anyhow!("Barcode decoding failed: {}", error)),
+            };
+
+        let mut decoded_codes: Vec<DecodedCode> = Vec::new();
+        for code in results.iter().map(to_decoded_code) {
+            if decoded_codes
+                .iter()
+                .all(|existing| existing.payload != code.payload)
+            {
+                decoded_codes.push(code);
+            }
+        }
+
+        log::info!("[BARCODE] Found {} codes", decoded_codes.len());
+        Ok(decoded_codes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+    use rxing::Writer;
+
+    const MODULE_SIZE_PIXELS: u32 = 6;
+    const QUIET_ZONE_MODULES: u32 = 4;
+
+    fn render_qr_image(payload: &str) -> DynamicImage {
+        let code = qrcode::QrCode::new(payload.as_bytes()).unwrap();
+        let modules_per_side = code.width() as u32;
+        let colors = code.to_colors();
+        let side = (modules_per_side + QUIET_ZONE_MODULES * 2) * MODULE_SIZE_PIXELS;
+
+        let image = GrayImage::from_fn(side, side, |x, y| {
+            let module_x = (x / MODULE_SIZE_PIXELS).checked_sub(QUIET_ZONE_MODULES);
+            let module_y = (y / MODULE_SIZE_PIXELS).checked_sub(QUIET_ZONE_MODULES);
+            match (module_x, module_y) {
+                (Some(mx), Some(my)) if mx < modules_per_side && my < modules_per_side => {
+                    let index = (my * modules_per_side + mx) as usize;
+                    if colors[index] == qrcode::Color::Dark {
+                        Luma([0])
+                    } else {
+                        Luma([255])
+                    }
+                }
+                _ => Luma([255]),
+            }
+        });
+        DynamicImage::ImageLuma8(image)
+    }
+
+    fn render_linear_barcode_image(payload: &str, format: BarcodeFormat) -> DynamicImage {
+        let matrix = rxing::MultiFormatWriter::default()
+            .encode(payload, &format, 400, 120)
+            .unwrap();
+
+        let image = GrayImage::from_fn(matrix.getWidth(), matrix.getHeight(), |x, y| {
+            if matrix.get(x, y) {
+                Luma([0])
+            } else {
+                Luma([255])
+            }
+        });
+        DynamicImage::ImageLuma8(image)
+    }
+
+    #[test]
+    fn test_decode_reads_generated_qr_code() {
+        let service = RxingBarcodeService::new();
+        let image = render_qr_image("https://example.com/circle");
+
+        let codes = service.decode(&image).unwrap();
+
+        assert_eq!(
+            codes,
+            vec![DecodedCode::new("https://example.com/circle".to_string())]
+        );
+        assert!(codes[0].is_url());
+    }
+
+    #[test]
+    fn test_decode_reads_generated_code_128_barcode() {
+        let service = RxingBarcodeService::new();
+        let image = render_linear_barcode_image("CIRCLE-128", BarcodeFormat::CODE_128);
+
+        let codes = service.decode(&image).unwrap();
+
+        assert_eq!(
+            codes,
+            vec![DecodedCode::with_format(
+                "CIRCLE-128".to_string(),
+                "Code 128".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_decode_returns_empty_for_blank_image() {
+        let service = RxingBarcodeService::new();
+        let image = DynamicImage::ImageLuma8(GrayImage::from_pixel(64, 64, Luma([255])));
+
+        let codes = service.decode(&image).unwrap();
+
+        assert!(codes.is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::core::models::ThemeMode;
+
+const LOG_TAG_SYSTEM_APPEARANCE: &str = "[SYSTEM_APPEARANCE]";
+
+pub fn detect_system_theme() -> Option<ThemeMode> {
+    match dark_light::detect() {
+        Ok(dark_light::Mode::Dark) => Some(ThemeMode::Dark),
+        Ok(dark_light::Mode::Light) => Some(ThemeMode::Light),
+        Ok(dark_light::Mode::Unspecified) => {
+            log::debug!(
+                "{} OS did not report a preferred appearance",
+                LOG_TAG_SYSTEM_APPEARANCE
+            );
+            None
+        }
+        Err(detection_error) => {
+            log::debug!(
+                "{} Failed to detect OS appearance: {}",
+                LOG_TAG_SYSTEM_APPEARANCE,
+                detection_error
+            );
+            None
+        }
+    }
+}
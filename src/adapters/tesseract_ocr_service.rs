@@ -1,14 +1,17 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use image::DynamicImage;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tesseract_static::parse::ParsedHocr;
 use tesseract_static::tesseract::Tesseract;
 
 use crate::core::interfaces::adapters::OcrService;
-use crate::core::models::{DetectedText, DetectedWord, OcrResult};
+use crate::core::models::{DetectedText, DetectedWord, OcrLanguage, OcrResult};
 
 const TRAINING_DATA: &[u8] = include_bytes!("../../tessdata/eng.traineddata");
+const TRAINING_DATA_DOWNLOAD_URL_PREFIX: &str =
+    "https://github.com/tesseract-ocr/tessdata_fast/raw/main/";
+const TRAINING_DATA_EXTENSION: &str = "traineddata";
 
 fn get_ocr_replacements() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -164,13 +167,18 @@ fn parse_bounds_from_debug(debug_str: &str) -> (f32, f32, f32, f32) {
 
 pub struct TesseractOcrService {
     tessdata_dir: PathBuf,
+    language_code: String,
 }
 
 impl TesseractOcrService {
-    pub fn build() -> Result<Self> {
-        log::info!("[TESSERACT_OCR] Initializing Tesseract OCR service");
+    pub fn build(language: OcrLanguage) -> Result<Self> {
+        log::info!(
+            "[TESSERACT_OCR] Initializing Tesseract OCR service for {}",
+            language
+        );
 
-        let tessdata_dir = std::env::temp_dir().join("circle-to-search-tessdata");
+        let tessdata_dir =
+            std::env::temp_dir().join(crate::global_constants::TESSDATA_TEMP_DIR_NAME);
         std::fs::create_dir_all(&tessdata_dir)
             .context("Failed to create tessdata directory in temp folder")?;
 
@@ -184,12 +192,65 @@ impl TesseractOcrService {
                 .context("Failed to write eng.traineddata to temp directory")?;
         }
 
-        log::info!("[TESSERACT_OCR] Using tessdata from: {:?}", tessdata_dir);
+        let language_code = match ensure_training_data_available(&tessdata_dir, language) {
+            Ok(()) => language.code().to_string(),
+            Err(download_error) => {
+                log::warn!(
+                    "[TESSERACT_OCR] {} training data unavailable, falling back to English: {:#}",
+                    language,
+                    download_error
+                );
+                OcrLanguage::English.code().to_string()
+            }
+        };
+
+        log::info!(
+            "[TESSERACT_OCR] Using tessdata from: {:?} with language {}",
+            tessdata_dir,
+            language_code
+        );
+
+        Ok(Self {
+            tessdata_dir,
+            language_code,
+        })
+    }
 
-        Ok(Self { tessdata_dir })
+    pub fn uses_language(&self, language: OcrLanguage) -> bool {
+        self.language_code == language.code()
     }
 }
 
+fn ensure_training_data_available(tessdata_dir: &Path, language: OcrLanguage) -> Result<()> {
+    let traineddata_path = tessdata_dir
+        .join(language.code())
+        .with_extension(TRAINING_DATA_EXTENSION);
+    if traineddata_path.exists() {
+        return Ok(());
+    }
+
+    let url = format!(
+        "{}{}.{}",
+        TRAINING_DATA_DOWNLOAD_URL_PREFIX,
+        language.code(),
+        TRAINING_DATA_EXTENSION
+    );
+    log::info!(
+        "[TESSERACT_OCR] Downloading {} training data from {}",
+        language,
+        url
+    );
+
+    let training_data = reqwest::blocking::get(&url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.bytes())
+        .with_context(|| format!("Failed to download {} training data", language))?;
+    std::fs::write(&traineddata_path, &training_data)
+        .with_context(|| format!("Failed to write {:?}", traineddata_path))?;
+
+    Ok(())
+}
+
 #[async_trait]
 impl OcrService for TesseractOcrService {
     async fn extract_text_from_image(&self, image: &DynamicImage) -> Result<OcrResult> {
@@ -215,15 +276,18 @@ impl OcrService for TesseractOcrService {
             bytes_per_line
         );
 
-        let tesseract = Tesseract::new(Some(&self.tessdata_dir.display().to_string()), Some("eng"))
-            .map_err(|e| {
-                log::error!(
-                    "[TESSERACT_OCR] Failed to initialize Tesseract with tessdata: {:?}, error: {:?}",
-                    self.tessdata_dir,
-                    e
-                );
-                anyhow::anyhow!("Failed to initialize Tesseract instance: {:?}", e)
-            })?;
+        let tesseract = Tesseract::new(
+            Some(&self.tessdata_dir.display().to_string()),
+            Some(&self.language_code),
+        )
+        .map_err(|e| {
+            log::error!(
+                "[TESSERACT_OCR] Failed to initialize Tesseract with tessdata: {:?}, error: {:?}",
+                self.tessdata_dir,
+                e
+            );
+            anyhow::anyhow!("Failed to initialize Tesseract instance: {:?}", e)
+        })?;
 
         let mut tesseract = tesseract
             .set_frame(frame_data, width, height, bytes_per_pixel, bytes_per_line)
@@ -330,9 +394,22 @@ mod tests {
 
     #[test]
     fn test_build_creates_service_successfully() {
-        let result = TesseractOcrService::build();
+        let result = TesseractOcrService::build(OcrLanguage::English);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_ensure_training_data_available_reuses_existing_file() {
+        let tessdata_dir =
+            std::env::temp_dir().join(format!("test-tessdata-{}", std::process::id()));
+        std::fs::create_dir_all(&tessdata_dir).unwrap();
+        std::fs::write(tessdata_dir.join("fra.traineddata"), b"cached").unwrap();
+
+        let result = ensure_training_data_available(&tessdata_dir, OcrLanguage::French);
 
         assert!(result.is_ok());
+        std::fs::remove_dir_all(&tessdata_dir).ok();
     }
 
     #[test]
@@ -0,0 +1,8 @@
+use anyhow::Result;
+use image::DynamicImage;
+
+use crate::core::models::DecodedCode;
+
+pub trait BarcodeService: Send + Sync {
+    fn decode(&self, image: &DynamicImage) -> Result<Vec<DecodedCode>>;
+}
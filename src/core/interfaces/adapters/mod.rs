@@ -1,7 +1,11 @@
+mod barcode_service;
 mod image_hosting_service;
 mod ocr_service;
 mod search_provider;
+mod translation_service;
 
+pub use barcode_service::BarcodeService;
 pub use image_hosting_service::ImageHostingService;
 pub use ocr_service::OcrService;
 pub use search_provider::ReverseImageSearchProvider;
+pub use translation_service::TranslationService;
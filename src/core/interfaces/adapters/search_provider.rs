@@ -1,9 +1,13 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::core::models::CaptureBuffer;
+use crate::core::models::{CaptureBuffer, ImageSearchOutcome};
 
 #[async_trait]
 pub trait ReverseImageSearchProvider: Send + Sync {
-    async fn perform_search(&self, buffer: &CaptureBuffer, query: Option<&str>) -> Result<String>;
+    async fn perform_search(
+        &self,
+        buffer: &CaptureBuffer,
+        query: Option<&str>,
+    ) -> Result<ImageSearchOutcome>;
 }
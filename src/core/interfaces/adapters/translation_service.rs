@@ -0,0 +1,7 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TranslationService: Send + Sync {
+    async fn translate(&self, text: &str, target_lang: &str) -> Result<String>;
+}
@@ -1,9 +1,15 @@
 use anyhow::Result;
 
-use crate::core::models::{CaptureBuffer, ScreenRegion, WindowInfo};
+use crate::core::models::{CaptureBuffer, MonitorGeometry, ScreenRegion, WindowInfo};
 
 pub trait ScreenCapturer: Send + Sync {
     fn capture_screen_at_region(&self, region: &ScreenRegion) -> Result<CaptureBuffer>;
     fn list_capturable_windows(&self) -> Result<Vec<WindowInfo>>;
     fn capture_window_by_id(&self, window_id: u32) -> Result<CaptureBuffer>;
+    fn capture_active_window(&self) -> Result<Option<CaptureBuffer>>;
+    fn find_focused_window_id(&self) -> Result<Option<u32>>;
+    fn is_foreground_window_fullscreen(&self) -> Result<bool>;
+    fn get_primary_monitor_origin(&self) -> Result<ScreenRegion>;
+    fn list_monitors(&self) -> Result<Vec<MonitorGeometry>>;
+    fn capture_all_monitors(&self) -> Result<Vec<CaptureBuffer>>;
 }
@@ -0,0 +1,114 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::global_constants;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AnnotationStroke {
+    pub points: Vec<(f32, f32)>,
+    pub color: (f32, f32, f32, f32),
+    pub width: f32,
+    #[serde(default)]
+    pub is_highlighter: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AnnotationStepMarker {
+    pub position: (f32, f32),
+    pub number: u32,
+    pub color: (f32, f32, f32, f32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationProject {
+    pub format_version: u32,
+    pub image_width: u32,
+    pub image_height: u32,
+    pub scale_factor: f64,
+    pub image_png_base64: String,
+    #[serde(default)]
+    pub strokes: Vec<AnnotationStroke>,
+    #[serde(default)]
+    pub step_markers: Vec<AnnotationStepMarker>,
+}
+
+impl AnnotationProject {
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize annotation project")
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let project: Self =
+            serde_json::from_str(json).context("Failed to parse annotation project")?;
+
+        if project.format_version > global_constants::ANNOTATION_PROJECT_FORMAT_VERSION {
+            anyhow::bail!(
+                "Annotation project format version {} is newer than supported version {}",
+                project.format_version,
+                global_constants::ANNOTATION_PROJECT_FORMAT_VERSION
+            );
+        }
+
+        Ok(project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_project() -> AnnotationProject {
+        AnnotationProject {
+            format_version: global_constants::ANNOTATION_PROJECT_FORMAT_VERSION,
+            image_width: 4,
+            image_height: 2,
+            scale_factor: 2.0,
+            image_png_base64: "iVBORw0KGgo=".to_string(),
+            strokes: vec![AnnotationStroke {
+                points: vec![(0.0, 0.0), (3.0, 1.0)],
+                color: (1.0, 0.0, 0.0, 1.0),
+                width: 3.0,
+                is_highlighter: false,
+            }],
+            step_markers: vec![AnnotationStepMarker {
+                position: (2.0, 1.0),
+                number: 1,
+                color: (0.0, 0.5, 1.0, 1.0),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_annotation_project_round_trips_through_json() {
+        let project = create_test_project();
+
+        let restored = AnnotationProject::from_json(&project.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored.image_width, project.image_width);
+        assert_eq!(restored.image_height, project.image_height);
+        assert_eq!(restored.scale_factor, project.scale_factor);
+        assert_eq!(restored.image_png_base64, project.image_png_base64);
+        assert_eq!(restored.strokes, project.strokes);
+        assert_eq!(restored.step_markers, project.step_markers);
+    }
+
+    #[test]
+    fn test_annotation_project_rejects_newer_format_version() {
+        let mut project = create_test_project();
+        project.format_version = global_constants::ANNOTATION_PROJECT_FORMAT_VERSION + 1;
+
+        let result = AnnotationProject::from_json(&project.to_json().unwrap());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_annotation_project_defaults_missing_annotations() {
+        let json = r#"{"format_version":1,"image_width":1,"image_height":1,"scale_factor":1.0,"image_png_base64":""}"#;
+
+        let project = AnnotationProject::from_json(json).unwrap();
+
+        assert!(project.strokes.is_empty());
+        assert!(project.step_markers.is_empty());
+    }
+}
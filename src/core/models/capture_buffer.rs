@@ -1,5 +1,10 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use iced::widget::image;
+use std::hash::{Hash, Hasher};
+
+use crate::global_constants;
+
+const MIN_STITCH_OVERLAP_ROWS: u32 = 8;
 
 #[derive(Clone)]
 pub struct CaptureBuffer {
@@ -47,6 +52,59 @@ impl CaptureBuffer {
         }
     }
 
+    pub fn to_dynamic_image(&self) -> Result<::image::DynamicImage> {
+        let rgba_image =
+            ::image::RgbaImage::from_raw(self.width, self.height, self.raw_data.clone())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("{}", global_constants::OCR_RAW_IMAGE_CREATION_FAILED)
+                })?;
+        Ok(::image::DynamicImage::ImageRgba8(rgba_image))
+    }
+
+    pub fn to_png_bytes(&self) -> Result<Vec<u8>> {
+        let mut png_data = Vec::new();
+        self.to_dynamic_image()?
+            .write_to(
+                &mut std::io::Cursor::new(&mut png_data),
+                ::image::ImageFormat::Png,
+            )
+            .context("Failed to encode PNG")?;
+        Ok(png_data)
+    }
+
+    pub fn effective_scale_factor(&self) -> f64 {
+        if self._scale_factor.is_finite() && self._scale_factor > 0.0 {
+            self._scale_factor
+        } else {
+            1.0
+        }
+    }
+
+    pub fn logical_to_physical_rect(&self, logical_rect: iced::Rectangle) -> iced::Rectangle {
+        let scale = self.effective_scale_factor() as f32;
+
+        iced::Rectangle {
+            x: logical_rect.x * scale,
+            y: logical_rect.y * scale,
+            width: logical_rect.width * scale,
+            height: logical_rect.height * scale,
+        }
+    }
+
+    pub fn crop_to_rect(&self, rect: iced::Rectangle) -> Result<Self> {
+        let left = rect.x.max(0.0).round() as u32;
+        let top = rect.y.max(0.0).round() as u32;
+        let right = (rect.x + rect.width).max(0.0).round() as u32;
+        let bottom = (rect.y + rect.height).max(0.0).round() as u32;
+
+        self.crop_region(
+            left,
+            top,
+            right.saturating_sub(left),
+            bottom.saturating_sub(top),
+        )
+    }
+
     pub fn crop_region(&self, x: u32, y: u32, crop_width: u32, crop_height: u32) -> Result<Self> {
         if crop_width == 0 || crop_height == 0 {
             anyhow::bail!("Crop dimensions must be greater than zero");
@@ -87,6 +145,143 @@ impl CaptureBuffer {
             cropped_data,
         ))
     }
+
+    pub fn rotate_clockwise(&self) -> Result<Self> {
+        self.transform_image(|image| image.rotate90())
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Result<Self> {
+        self.transform_image(|image| image.rotate270())
+    }
+
+    pub fn flip_horizontal(&self) -> Result<Self> {
+        self.transform_image(|image| image.fliph())
+    }
+
+    fn transform_image(
+        &self,
+        transform: impl FnOnce(::image::DynamicImage) -> ::image::DynamicImage,
+    ) -> Result<Self> {
+        let transformed = transform(self.to_dynamic_image()?).to_rgba8();
+        Ok(Self::build_from_raw_data(
+            self._scale_factor,
+            transformed.width(),
+            transformed.height(),
+            transformed.into_raw(),
+        ))
+    }
+
+    pub fn trim_transparent_padding(self) -> Result<Self> {
+        let Some((x, y, trimmed_width, trimmed_height)) = self.find_opaque_bounds() else {
+            return Ok(self);
+        };
+
+        if trimmed_width == self.width && trimmed_height == self.height {
+            return Ok(self);
+        }
+
+        log::debug!(
+            "[CAPTURE_BUFFER] Trimming transparent padding: {}x{} -> {}x{}",
+            self.width,
+            self.height,
+            trimmed_width,
+            trimmed_height
+        );
+
+        self.crop_region(x, y, trimmed_width, trimmed_height)
+    }
+
+    fn find_opaque_bounds(&self) -> Option<(u32, u32, u32, u32)> {
+        let width = self.width.max(1) as usize;
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (usize::MAX, usize::MAX, 0, 0);
+
+        for (pixel_index, pixel) in self.raw_data.chunks_exact(4).enumerate() {
+            if pixel[3] == 0 {
+                continue;
+            }
+
+            let (x, y) = (pixel_index % width, pixel_index / width);
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        if min_x == usize::MAX {
+            return None;
+        }
+
+        Some((
+            min_x as u32,
+            min_y as u32,
+            (max_x - min_x + 1) as u32,
+            (max_y - min_y + 1) as u32,
+        ))
+    }
+
+    pub fn stitch_vertically(frames: &[CaptureBuffer]) -> Result<Self> {
+        let Some(first_frame) = frames.first() else {
+            anyhow::bail!("No frames to stitch");
+        };
+
+        let mut stitched_data = first_frame.raw_data.clone();
+        let mut stitched_height = first_frame.height;
+        let row_bytes = (first_frame.width * 4) as usize;
+
+        for (previous_frame, next_frame) in frames.iter().zip(frames.iter().skip(1)) {
+            if next_frame.width != first_frame.width {
+                anyhow::bail!("Frame widths must match to stitch");
+            }
+
+            let overlap_rows = previous_frame.find_vertical_overlap(next_frame);
+            stitched_data
+                .extend_from_slice(&next_frame.raw_data[overlap_rows as usize * row_bytes..]);
+            stitched_height += next_frame.height - overlap_rows;
+        }
+
+        log::debug!(
+            "[CAPTURE_BUFFER] Stitched {} frames into {}x{}",
+            frames.len(),
+            first_frame.width,
+            stitched_height
+        );
+
+        Ok(Self::build_from_raw_data(
+            first_frame._scale_factor,
+            first_frame.width,
+            stitched_height,
+            stitched_data,
+        ))
+    }
+
+    pub fn find_vertical_overlap(&self, next_frame: &CaptureBuffer) -> u32 {
+        if self.width != next_frame.width {
+            return 0;
+        }
+
+        let previous_rows = self.hash_rows();
+        let next_rows = next_frame.hash_rows();
+        let max_overlap = self.height.min(next_frame.height);
+
+        (MIN_STITCH_OVERLAP_ROWS..max_overlap)
+            .rev()
+            .find(|overlap| {
+                let overlap = *overlap as usize;
+                previous_rows[previous_rows.len() - overlap..] == next_rows[..overlap]
+            })
+            .unwrap_or(0)
+    }
+
+    fn hash_rows(&self) -> Vec<u64> {
+        self.raw_data
+            .chunks_exact((self.width * 4).max(1) as usize)
+            .map(|row| {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                row.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -124,6 +319,25 @@ mod tests {
         assert_eq!(buffer._scale_factor, 1.0);
     }
 
+    #[test]
+    fn test_to_png_bytes_round_trips_valid_buffer() {
+        let buffer = create_test_buffer_with_pattern(6, 4);
+
+        let png_data = buffer.to_png_bytes().unwrap();
+
+        let decoded = ::image::load_from_memory(&png_data).unwrap().to_rgba8();
+        assert_eq!(decoded.dimensions(), (6, 4));
+        assert_eq!(decoded.into_raw(), buffer.raw_data);
+    }
+
+    #[test]
+    fn test_to_png_bytes_rejects_raw_data_that_does_not_match_dimensions() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 12]);
+
+        assert!(buffer.to_dynamic_image().is_err());
+        assert!(buffer.to_png_bytes().is_err());
+    }
+
     #[test]
     fn test_crop_region_with_valid_dimensions_returns_cropped_buffer() {
         let buffer = create_test_buffer_with_pattern(100, 100);
@@ -137,6 +351,23 @@ mod tests {
         assert_eq!(cropped.raw_data.len(), (30 * 40 * 4) as usize);
     }
 
+    #[test]
+    fn test_crop_to_rect_rounds_fractional_edges_to_nearest_pixel() {
+        let buffer = create_test_buffer_with_pattern(100, 100);
+
+        let cropped = buffer
+            .crop_to_rect(iced::Rectangle::new(
+                iced::Point::new(10.6, 20.4),
+                iced::Size::new(29.8, 40.3),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            cropped.raw_data,
+            buffer.crop_region(11, 20, 29, 41).unwrap().raw_data
+        );
+    }
+
     #[test]
     fn test_crop_region_with_zero_width_returns_error() {
         let buffer = create_test_buffer_with_pattern(100, 100);
@@ -199,6 +430,45 @@ mod tests {
         assert_eq!(cropped._scale_factor, 2.5);
     }
 
+    #[test]
+    fn test_logical_to_physical_rect_is_noop_at_scale_one() {
+        let buffer = create_test_buffer_with_pattern(50, 50);
+        let logical_rect =
+            iced::Rectangle::new(iced::Point::new(3.0, 4.0), iced::Size::new(10.0, 12.0));
+
+        assert_eq!(buffer.logical_to_physical_rect(logical_rect), logical_rect);
+    }
+
+    #[test]
+    fn test_crop_logical_selection_at_scale_two_matches_physical_pixels() {
+        let pattern = create_test_buffer_with_pattern(40, 40);
+        let buffer = CaptureBuffer::build_from_raw_data(2.0, 40, 40, pattern.raw_data.clone());
+        let logical_rect =
+            iced::Rectangle::new(iced::Point::new(5.0, 3.0), iced::Size::new(4.0, 2.0));
+
+        let physical_rect = buffer.logical_to_physical_rect(logical_rect);
+        let cropped = buffer
+            .crop_region(
+                physical_rect.x as u32,
+                physical_rect.y as u32,
+                physical_rect.width as u32,
+                physical_rect.height as u32,
+            )
+            .unwrap();
+
+        assert_eq!((cropped.width, cropped.height), (8, 4));
+        assert_eq!(&cropped.raw_data[0..4], &[10, 6, 16, 255]);
+        let last_pixel = cropped.raw_data.len() - 4;
+        assert_eq!(&cropped.raw_data[last_pixel..], &[17, 9, 26, 255]);
+    }
+
+    #[test]
+    fn test_effective_scale_factor_ignores_invalid_values() {
+        let buffer = CaptureBuffer::build_from_raw_data(0.0, 1, 1, vec![0u8; 4]);
+
+        assert_eq!(buffer.effective_scale_factor(), 1.0);
+    }
+
     #[test]
     fn test_crop_full_image_returns_identical_dimensions() {
         let buffer = create_test_buffer_with_pattern(50, 50);
@@ -210,4 +480,107 @@ mod tests {
         assert_eq!(cropped.width, buffer.width);
         assert_eq!(cropped.height, buffer.height);
     }
+
+    #[test]
+    fn test_rotate_clockwise_swaps_dimensions_and_preserves_data_length() {
+        let buffer = create_test_buffer_with_pattern(4, 2);
+
+        let rotated = buffer.rotate_clockwise().unwrap();
+
+        assert_eq!((rotated.width, rotated.height), (2, 4));
+        assert_eq!(rotated.raw_data.len(), buffer.raw_data.len());
+        assert_eq!(&rotated.raw_data[4..8], &buffer.raw_data[0..4]);
+    }
+
+    #[test]
+    fn test_rotate_counter_clockwise_undoes_rotate_clockwise() {
+        let buffer = create_test_buffer_with_pattern(3, 5);
+
+        let restored = buffer
+            .rotate_clockwise()
+            .unwrap()
+            .rotate_counter_clockwise()
+            .unwrap();
+
+        assert_eq!((restored.width, restored.height), (3, 5));
+        assert_eq!(restored.raw_data, buffer.raw_data);
+    }
+
+    #[test]
+    fn test_flip_horizontal_mirrors_each_row() {
+        let buffer = create_test_buffer_with_pattern(3, 1);
+
+        let flipped = buffer.flip_horizontal().unwrap();
+
+        assert_eq!(&flipped.raw_data[0..4], &buffer.raw_data[8..12]);
+        assert_eq!(&flipped.raw_data[8..12], &buffer.raw_data[0..4]);
+    }
+
+    #[test]
+    fn test_trim_transparent_padding_crops_to_opaque_pixels() {
+        let mut raw_data = vec![0u8; (6 * 5 * 4) as usize];
+        for (x, y) in [(2, 1), (4, 3)] {
+            let offset = ((y * 6 + x) * 4) as usize;
+            raw_data[offset..offset + 4].copy_from_slice(&[10, 20, 30, 255]);
+        }
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 6, 5, raw_data);
+
+        let trimmed = buffer.trim_transparent_padding().unwrap();
+
+        assert_eq!(trimmed.width, 3);
+        assert_eq!(trimmed.height, 3);
+        assert_eq!(&trimmed.raw_data[..4], &[10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn test_trim_transparent_padding_keeps_opaque_and_fully_transparent_buffers() {
+        let opaque = create_test_buffer_with_pattern(8, 8);
+        let uniform = CaptureBuffer::build_from_raw_data(1.0, 4, 4, vec![255u8; 64]);
+        let transparent = CaptureBuffer::build_from_raw_data(1.0, 4, 4, vec![0u8; 64]);
+
+        assert_eq!(opaque.trim_transparent_padding().unwrap().width, 8);
+        assert_eq!(uniform.trim_transparent_padding().unwrap().width, 4);
+        assert_eq!(transparent.trim_transparent_padding().unwrap().width, 4);
+    }
+
+    #[test]
+    fn test_stitch_vertically_removes_overlapping_rows() {
+        let source = create_test_buffer_with_pattern(4, 40);
+        let top_frame = source.crop_region(0, 0, 4, 24).unwrap();
+        let bottom_frame = source.crop_region(0, 12, 4, 24).unwrap();
+
+        let stitched = CaptureBuffer::stitch_vertically(&[top_frame, bottom_frame]).unwrap();
+
+        assert_eq!(stitched.height, 36);
+        assert_eq!(
+            stitched.raw_data,
+            source.crop_region(0, 0, 4, 36).unwrap().raw_data
+        );
+    }
+
+    #[test]
+    fn test_stitch_vertically_appends_frames_without_overlap() {
+        let source = create_test_buffer_with_pattern(4, 40);
+        let top_frame = source.crop_region(0, 0, 4, 10).unwrap();
+        let bottom_frame = source.crop_region(0, 20, 4, 10).unwrap();
+
+        let stitched = CaptureBuffer::stitch_vertically(&[top_frame, bottom_frame]).unwrap();
+
+        assert_eq!(stitched.height, 20);
+    }
+
+    #[test]
+    fn test_stitch_vertically_rejects_mismatched_widths() {
+        let narrow_frame = create_test_buffer_with_pattern(4, 10);
+        let wide_frame = create_test_buffer_with_pattern(8, 10);
+
+        let result = CaptureBuffer::stitch_vertically(&[narrow_frame, wide_frame]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_stitch_vertically_with_no_frames_returns_error() {
+        assert!(CaptureBuffer::stitch_vertically(&[]).is_err());
+    }
 }
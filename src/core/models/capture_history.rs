@@ -0,0 +1,226 @@
+use anyhow::Context;
+use iced::widget::image::Handle;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::CaptureBuffer;
+use crate::global_constants;
+
+const HISTORY_IMAGE_EXTENSION: &str = "png";
+const HISTORY_METADATA_EXTENSION: &str = "json";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CaptureHistoryEntry {
+    pub id: String,
+    pub captured_at_unix_ms: u128,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+}
+
+pub struct CaptureHistory {
+    directory: PathBuf,
+    max_entries: usize,
+}
+
+impl CaptureHistory {
+    pub fn new(directory: PathBuf, max_entries: usize) -> Self {
+        Self {
+            directory,
+            max_entries,
+        }
+    }
+
+    pub fn open_default(max_entries: usize) -> anyhow::Result<Self> {
+        let directory = super::UserSettings::get_config_directory()?
+            .join(global_constants::CAPTURE_HISTORY_FOLDER_NAME);
+        Ok(Self::new(directory, max_entries))
+    }
+
+    pub fn push(&self, buffer: &CaptureBuffer) -> anyhow::Result<Option<CaptureHistoryEntry>> {
+        if self.max_entries == 0 {
+            return Ok(None);
+        }
+
+        std::fs::create_dir_all(&self.directory)
+            .with_context(|| format!("Failed to create history directory {:?}", self.directory))?;
+
+        let captured_at_unix_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or_default();
+        let entry = CaptureHistoryEntry {
+            id: format!("{}-{}", captured_at_unix_ms, uuid::Uuid::new_v4().simple()),
+            captured_at_unix_ms,
+            width: buffer.width,
+            height: buffer.height,
+            scale_factor: buffer._scale_factor,
+        };
+
+        let image = buffer
+            .to_dynamic_image()
+            .context("Capture buffer does not match its dimensions")?;
+        image
+            .save(self.image_path(&entry.id))
+            .context("Failed to write history image")?;
+        std::fs::write(
+            self.metadata_path(&entry.id),
+            serde_json::to_string_pretty(&entry)?,
+        )
+        .context("Failed to write history metadata")?;
+
+        self.evict_oldest_entries()?;
+
+        log::info!(
+            "[CAPTURE_HISTORY] Stored {}x{} capture as {}",
+            entry.width,
+            entry.height,
+            entry.id
+        );
+        Ok(Some(entry))
+    }
+
+    pub fn list(&self) -> anyhow::Result<Vec<CaptureHistoryEntry>> {
+        if !self.directory.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for dir_entry in std::fs::read_dir(&self.directory)? {
+            let path = dir_entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(HISTORY_METADATA_EXTENSION) {
+                continue;
+            }
+
+            match read_entry_metadata(&path) {
+                Ok(entry) => entries.push(entry),
+                Err(read_error) => log::warn!(
+                    "[CAPTURE_HISTORY] Skipping unreadable entry {:?}: {:#}",
+                    path,
+                    read_error
+                ),
+            }
+        }
+
+        entries.sort_by(|first, second| second.captured_at_unix_ms.cmp(&first.captured_at_unix_ms));
+        Ok(entries)
+    }
+
+    pub fn load(&self, id: &str) -> anyhow::Result<CaptureBuffer> {
+        let entry = read_entry_metadata(&self.metadata_path(id))?;
+        let image = image::open(self.image_path(id))
+            .with_context(|| format!("Failed to read history image {}", id))?
+            .to_rgba8();
+
+        Ok(CaptureBuffer::build_from_raw_data(
+            entry.scale_factor,
+            image.width(),
+            image.height(),
+            image.into_raw(),
+        ))
+    }
+
+    pub fn load_thumbnail(&self, id: &str) -> anyhow::Result<Handle> {
+        let thumbnail = image::open(self.image_path(id))
+            .with_context(|| format!("Failed to read history image {}", id))?
+            .thumbnail(
+                global_constants::CAPTURE_HISTORY_THUMBNAIL_MAX_SIZE,
+                global_constants::CAPTURE_HISTORY_THUMBNAIL_MAX_SIZE,
+            )
+            .to_rgba8();
+
+        Ok(Handle::from_rgba(
+            thumbnail.width(),
+            thumbnail.height(),
+            thumbnail.into_raw(),
+        ))
+    }
+
+    fn evict_oldest_entries(&self) -> anyhow::Result<()> {
+        for stale_entry in self.list()?.into_iter().skip(self.max_entries) {
+            log::debug!("[CAPTURE_HISTORY] Evicting {}", stale_entry.id);
+            std::fs::remove_file(self.image_path(&stale_entry.id)).ok();
+            std::fs::remove_file(self.metadata_path(&stale_entry.id))?;
+        }
+        Ok(())
+    }
+
+    fn image_path(&self, id: &str) -> PathBuf {
+        self.directory
+            .join(id)
+            .with_extension(HISTORY_IMAGE_EXTENSION)
+    }
+
+    fn metadata_path(&self, id: &str) -> PathBuf {
+        self.directory
+            .join(id)
+            .with_extension(HISTORY_METADATA_EXTENSION)
+    }
+}
+
+fn read_entry_metadata(path: &Path) -> anyhow::Result<CaptureHistoryEntry> {
+    let metadata = std::fs::read_to_string(path)?;
+    serde_json::from_str(&metadata).context("Failed to parse history metadata")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_history(name: &str, max_entries: usize) -> CaptureHistory {
+        let directory = std::env::temp_dir().join(format!(
+            "test-capture-history-{}-{}",
+            name,
+            std::process::id()
+        ));
+        std::fs::remove_dir_all(&directory).ok();
+        CaptureHistory::new(directory, max_entries)
+    }
+
+    fn create_test_buffer(width: u32, height: u32) -> CaptureBuffer {
+        CaptureBuffer::build_from_raw_data(
+            2.0,
+            width,
+            height,
+            vec![128u8; (width * height * 4) as usize],
+        )
+    }
+
+    #[test]
+    fn test_push_evicts_oldest_entries_beyond_limit() {
+        let history = create_test_history("eviction", 3);
+
+        let first_entry = history.push(&create_test_buffer(2, 2)).unwrap().unwrap();
+        for _ in 0..4 {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            history.push(&create_test_buffer(2, 2)).unwrap();
+        }
+
+        let entries = history.list().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries.iter().all(|entry| entry.id != first_entry.id));
+        std::fs::remove_dir_all(&history.directory).ok();
+    }
+
+    #[test]
+    fn test_load_round_trips_image_dimensions() {
+        let history = create_test_history("round-trip", 5);
+
+        let entry = history.push(&create_test_buffer(7, 3)).unwrap().unwrap();
+        let restored = history.load(&entry.id).unwrap();
+
+        assert_eq!(restored.width, 7);
+        assert_eq!(restored.height, 3);
+        assert_eq!(restored._scale_factor, 2.0);
+        std::fs::remove_dir_all(&history.directory).ok();
+    }
+
+    #[test]
+    fn test_push_is_disabled_when_limit_is_zero() {
+        let history = create_test_history("disabled", 0);
+
+        assert!(history.push(&create_test_buffer(2, 2)).unwrap().is_none());
+        assert!(history.list().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+const URL_SCHEMES: [&str; 2] = ["http", "https"];
+const DEFAULT_CODE_FORMAT: &str = "QR code";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCode {
+    pub payload: String,
+    pub format: String,
+}
+
+impl DecodedCode {
+    pub fn new(payload: String) -> Self {
+        Self::with_format(payload, DEFAULT_CODE_FORMAT.to_string())
+    }
+
+    pub fn with_format(payload: String, format: String) -> Self {
+        Self { payload, format }
+    }
+
+    pub fn safe_url(&self) -> Option<String> {
+        let candidate = self.payload.trim();
+        if candidate
+            .chars()
+            .any(|character| character.is_whitespace() || character.is_control())
+        {
+            return None;
+        }
+
+        let parsed = url::Url::parse(candidate).ok()?;
+        let has_web_scheme = URL_SCHEMES.contains(&parsed.scheme());
+        let has_host = parsed.host_str().is_some_and(|host| !host.is_empty());
+        (has_web_scheme && has_host).then(|| parsed.to_string())
+    }
+
+    pub fn is_url(&self) -> bool {
+        self.safe_url().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_url_detects_http_payloads() {
+        assert!(DecodedCode::new("https://example.com".to_string()).is_url());
+        assert!(DecodedCode::new("HTTP://EXAMPLE.COM".to_string()).is_url());
+        assert!(!DecodedCode::new("WIFI:S:home;T:WPA;P:secret;;".to_string()).is_url());
+    }
+
+    #[test]
+    fn test_safe_url_rejects_payloads_that_could_smuggle_arguments() {
+        assert_eq!(
+            DecodedCode::new("https://example.com --new-window file:///etc".to_string()).safe_url(),
+            None
+        );
+        assert_eq!(
+            DecodedCode::new("https://example.com\n--incognito".to_string()).safe_url(),
+            None
+        );
+        assert_eq!(
+            DecodedCode::new("javascript:alert(1)".to_string()).safe_url(),
+            None
+        );
+        assert_eq!(DecodedCode::new("https://".to_string()).safe_url(), None);
+    }
+
+    #[test]
+    fn test_safe_url_normalizes_web_links() {
+        assert_eq!(
+            DecodedCode::new("  HTTPS://Example.com/path?q=1  ".to_string()).safe_url(),
+            Some("https://example.com/path?q=1".to_string())
+        );
+    }
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSearchOutcome {
+    pub search_url: String,
+    pub hosted_image_url: String,
+}
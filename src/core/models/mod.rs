@@ -1,11 +1,29 @@
+mod annotation_project;
 mod capture_buffer;
+mod capture_history;
+mod decoded_code;
+mod image_search_outcome;
+mod model_download_progress;
+mod monitor_geometry;
 mod ocr;
+mod ocr_preprocess;
 mod screen_region;
 pub mod user_settings;
 mod window_info;
 
+pub use annotation_project::{AnnotationProject, AnnotationStepMarker, AnnotationStroke};
 pub use capture_buffer::CaptureBuffer;
+pub use capture_history::{CaptureHistory, CaptureHistoryEntry};
+pub use decoded_code::DecodedCode;
+pub use image_search_outcome::ImageSearchOutcome;
+pub use model_download_progress::ModelDownloadProgress;
+pub use monitor_geometry::{select_monitor_for_point, MonitorGeometry};
 pub use ocr::{DetectedText, DetectedWord, OcrResult};
+pub use ocr_preprocess::OcrPreprocess;
 pub use screen_region::ScreenRegion;
-pub use user_settings::{ImageHostingAuthMode, ImageUploadHttpMethod, ThemeMode, UserSettings};
+pub use user_settings::{
+    CaptureMonitor, CaptureTrigger, ImageHost, ImageHostingAuthMode, ImageSaveFormat,
+    ImageUploadHttpMethod, LogLevel, OcrEngine, OcrLanguage, RecentRegion, SearchEngine, ThemeMode,
+    UserSettings, WindowGeometry,
+};
 pub use window_info::WindowInfo;
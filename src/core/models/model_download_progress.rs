@@ -0,0 +1,54 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelDownloadProgress {
+    pub filename: String,
+    pub received_bytes: u64,
+    pub total_bytes: Option<u64>,
+}
+
+impl ModelDownloadProgress {
+    pub fn percent(&self) -> Option<u8> {
+        let total_bytes = self.total_bytes.filter(|total| *total > 0)?;
+        let percent = self.received_bytes.saturating_mul(100) / total_bytes;
+
+        Some(percent.min(100) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(received_bytes: u64, total_bytes: Option<u64>) -> ModelDownloadProgress {
+        ModelDownloadProgress {
+            filename: "text-detection.rten".to_string(),
+            received_bytes,
+            total_bytes,
+        }
+    }
+
+    #[test]
+    fn test_percent_accumulates_chunks_against_content_length() {
+        let total_bytes = Some(1_000);
+        let chunk_sizes = [250u64, 150, 200, 400];
+        let mut received_bytes = 0;
+        let mut percents = Vec::new();
+
+        for chunk_size in chunk_sizes {
+            received_bytes += chunk_size;
+            percents.push(progress(received_bytes, total_bytes).percent());
+        }
+
+        assert_eq!(percents, vec![Some(25), Some(40), Some(60), Some(100)]);
+    }
+
+    #[test]
+    fn test_percent_is_indeterminate_without_content_length() {
+        assert_eq!(progress(4_096, None).percent(), None);
+        assert_eq!(progress(4_096, Some(0)).percent(), None);
+    }
+
+    #[test]
+    fn test_percent_caps_at_one_hundred() {
+        assert_eq!(progress(1_500, Some(1_000)).percent(), Some(100));
+    }
+}
@@ -0,0 +1,179 @@
+const MONITOR_BOUNDS_ARE_PHYSICAL: bool = cfg!(not(target_os = "macos"));
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f32,
+}
+
+impl MonitorGeometry {
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        let right = self.x as i64 + self.width as i64;
+        let bottom = self.y as i64 + self.height as i64;
+
+        x >= self.x && (x as i64) < right && y >= self.y && (y as i64) < bottom
+    }
+
+    fn distance_squared_to_point(&self, x: i32, y: i32) -> i64 {
+        let right = self.x as i64 + self.width as i64 - 1;
+        let bottom = self.y as i64 + self.height as i64 - 1;
+        let dx = (x as i64).clamp(self.x as i64, right.max(self.x as i64)) - x as i64;
+        let dy = (y as i64).clamp(self.y as i64, bottom.max(self.y as i64)) - y as i64;
+
+        dx * dx + dy * dy
+    }
+
+    pub fn overlay_origin(&self, monitors: &[MonitorGeometry]) -> (f32, f32) {
+        self.overlay_origin_for_bounds(monitors, MONITOR_BOUNDS_ARE_PHYSICAL)
+    }
+
+    pub fn overlay_size(&self) -> (f32, f32) {
+        self.overlay_size_for_bounds(MONITOR_BOUNDS_ARE_PHYSICAL)
+    }
+
+    fn overlay_origin_for_bounds(
+        &self,
+        monitors: &[MonitorGeometry],
+        bounds_are_physical: bool,
+    ) -> (f32, f32) {
+        let reference_monitor = select_monitor_for_point(monitors, 0, 0).unwrap_or(*self);
+        let scale = reference_monitor.effective_scale_factor(bounds_are_physical);
+        (self.x as f32 / scale, self.y as f32 / scale)
+    }
+
+    fn overlay_size_for_bounds(&self, bounds_are_physical: bool) -> (f32, f32) {
+        let scale = self.effective_scale_factor(bounds_are_physical);
+        (self.width as f32 / scale, self.height as f32 / scale)
+    }
+
+    fn effective_scale_factor(&self, bounds_are_physical: bool) -> f32 {
+        if bounds_are_physical && self.scale_factor > 0.0 {
+            self.scale_factor
+        } else {
+            1.0
+        }
+    }
+}
+
+pub fn select_monitor_for_point(
+    monitors: &[MonitorGeometry],
+    x: i32,
+    y: i32,
+) -> Option<MonitorGeometry> {
+    monitors
+        .iter()
+        .find(|monitor| monitor.contains_point(x, y))
+        .or_else(|| {
+            monitors
+                .iter()
+                .min_by_key(|monitor| monitor.distance_squared_to_point(x, y))
+        })
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(x: i32, y: i32, width: u32, height: u32, scale_factor: f32) -> MonitorGeometry {
+        MonitorGeometry {
+            x,
+            y,
+            width,
+            height,
+            scale_factor,
+        }
+    }
+
+    #[test]
+    fn test_select_monitor_for_point_handles_negative_coordinates() {
+        let monitors = [
+            monitor(0, 0, 1920, 1080, 1.0),
+            monitor(-2560, -360, 2560, 1440, 1.0),
+            monitor(0, -1080, 1920, 1080, 1.0),
+        ];
+
+        assert_eq!(
+            select_monitor_for_point(&monitors, -1, 0),
+            Some(monitors[1])
+        );
+        assert_eq!(
+            select_monitor_for_point(&monitors, 100, -1),
+            Some(monitors[2])
+        );
+        assert_eq!(
+            select_monitor_for_point(&monitors, 1919, 1079),
+            Some(monitors[0])
+        );
+    }
+
+    #[test]
+    fn test_select_monitor_for_point_treats_right_edge_as_next_monitor() {
+        let monitors = [
+            monitor(0, 0, 1920, 1080, 1.0),
+            monitor(1920, 0, 3840, 2160, 2.0),
+        ];
+
+        assert_eq!(
+            select_monitor_for_point(&monitors, 1920, 500),
+            Some(monitors[1])
+        );
+    }
+
+    #[test]
+    fn test_select_monitor_for_point_falls_back_to_nearest_monitor() {
+        let monitors = [
+            monitor(0, 0, 1920, 1080, 1.0),
+            monitor(1920, 200, 1920, 1080, 1.0),
+        ];
+
+        assert_eq!(
+            select_monitor_for_point(&monitors, 2500, 100),
+            Some(monitors[1])
+        );
+        assert_eq!(select_monitor_for_point(&[], 0, 0), None);
+    }
+
+    #[test]
+    fn test_overlay_origin_uses_primary_scale_on_mixed_dpi_layout() {
+        let primary = monitor(0, 0, 1920, 1080, 1.0);
+        let secondary = monitor(1920, 0, 3840, 2160, 2.0);
+        let monitors = [primary, secondary];
+
+        assert_eq!(
+            secondary.overlay_origin_for_bounds(&monitors, true),
+            (1920.0, 0.0)
+        );
+        assert_eq!(secondary.overlay_size_for_bounds(true), (1920.0, 1080.0));
+        assert_eq!(primary.overlay_size_for_bounds(true), (1920.0, 1080.0));
+    }
+
+    #[test]
+    fn test_overlay_origin_divides_by_high_dpi_primary_scale() {
+        let primary = monitor(0, 0, 3840, 2160, 2.0);
+        let secondary = monitor(3840, -200, 1920, 1080, 1.0);
+        let monitors = [primary, secondary];
+
+        assert_eq!(
+            secondary.overlay_origin_for_bounds(&monitors, true),
+            (1920.0, -100.0)
+        );
+        assert_eq!(secondary.overlay_size_for_bounds(true), (1920.0, 1080.0));
+    }
+
+    #[test]
+    fn test_overlay_bounds_pass_through_when_monitor_bounds_are_logical() {
+        let primary = monitor(0, 0, 1920, 1080, 1.0);
+        let secondary = monitor(1920, 0, 1920, 1080, 2.0);
+        let monitors = [primary, secondary];
+
+        assert_eq!(
+            secondary.overlay_origin_for_bounds(&monitors, false),
+            (1920.0, 0.0)
+        );
+        assert_eq!(secondary.overlay_size_for_bounds(false), (1920.0, 1080.0));
+    }
+}
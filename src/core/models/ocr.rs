@@ -1,9 +1,15 @@
+use anyhow::Context;
 use iced::Rectangle;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 
-#[derive(Debug, Clone)]
+const DUPLICATE_BLOCK_IOU_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize)]
 #[allow(dead_code)]
 pub struct DetectedWord {
     pub content: String,
+    #[serde(serialize_with = "serialize_bounds")]
     pub bounds: Rectangle,
 }
 
@@ -21,12 +27,13 @@ impl DetectedWord {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 #[allow(dead_code)]
 pub struct DetectedText {
     pub content: String,
+    #[serde(serialize_with = "serialize_bounds")]
     pub bounds: Rectangle,
-    pub confidence: f32,
+    pub confidence: Option<f32>,
     pub words: Vec<DetectedWord>,
 }
 
@@ -48,10 +55,36 @@ impl DetectedText {
                 width,
                 height,
             },
-            confidence,
+            confidence: Some(confidence),
+            words,
+        }
+    }
+
+    pub fn without_confidence(
+        content: String,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        words: Vec<DetectedWord>,
+    ) -> Self {
+        Self {
+            content,
+            bounds: Rectangle {
+                x,
+                y,
+                width,
+                height,
+            },
+            confidence: None,
             words,
         }
     }
+
+    fn meets_confidence(&self, min_confidence: f32) -> bool {
+        self.confidence
+            .is_none_or(|confidence| confidence >= min_confidence)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +94,129 @@ pub struct OcrResult {
     pub full_text: String,
 }
 
+impl OcrResult {
+    pub fn to_layout_json(&self) -> anyhow::Result<String> {
+        serde_json::to_string_pretty(&self.text_blocks).context("Failed to serialize text layout")
+    }
+
+    pub fn retain_confident_blocks(self, min_confidence: f32) -> OcrResult {
+        let original_block_count = self.text_blocks.len();
+        let text_blocks: Vec<DetectedText> = self
+            .text_blocks
+            .into_iter()
+            .filter(|block| block.meets_confidence(min_confidence))
+            .collect();
+        let full_text = if text_blocks.len() == original_block_count {
+            self.full_text
+        } else {
+            compose_full_text(&text_blocks)
+        };
+
+        OcrResult {
+            text_blocks,
+            full_text,
+        }
+    }
+
+    pub fn merge(results: Vec<OcrResult>) -> OcrResult {
+        let mut merged_blocks: Vec<DetectedText> = Vec::new();
+
+        for block in results.into_iter().flat_map(|result| result.text_blocks) {
+            let duplicate_index = merged_blocks.iter().position(|existing| {
+                calculate_intersection_over_union(&existing.bounds, &block.bounds)
+                    >= DUPLICATE_BLOCK_IOU_THRESHOLD
+            });
+
+            match duplicate_index {
+                Some(index) if block.confidence > merged_blocks[index].confidence => {
+                    merged_blocks[index] = block;
+                }
+                Some(_) => {}
+                None => merged_blocks.push(block),
+            }
+        }
+
+        merged_blocks.sort_by(|first, second| {
+            first
+                .bounds
+                .y
+                .total_cmp(&second.bounds.y)
+                .then(first.bounds.x.total_cmp(&second.bounds.x))
+        });
+
+        let full_text = compose_full_text(&merged_blocks);
+
+        OcrResult {
+            text_blocks: merged_blocks,
+            full_text,
+        }
+    }
+}
+
+fn compose_full_text(blocks: &[DetectedText]) -> String {
+    let mut lines: Vec<(f32, f32, Vec<&DetectedText>)> = Vec::new();
+
+    for block in blocks {
+        let center_y = block.bounds.y + block.bounds.height / 2.0;
+        match lines
+            .iter_mut()
+            .find(|(top, bottom, _)| center_y >= *top && center_y <= *bottom)
+        {
+            Some((top, bottom, line_blocks)) => {
+                *top = top.min(block.bounds.y);
+                *bottom = bottom.max(block.bounds.y + block.bounds.height);
+                line_blocks.push(block);
+            }
+            None => lines.push((
+                block.bounds.y,
+                block.bounds.y + block.bounds.height,
+                vec![block],
+            )),
+        }
+    }
+
+    lines.sort_by(|first, second| first.0.total_cmp(&second.0));
+    lines
+        .into_iter()
+        .map(|(_, _, mut line_blocks)| {
+            line_blocks.sort_by(|first, second| first.bounds.x.total_cmp(&second.bounds.x));
+            line_blocks
+                .iter()
+                .map(|block| block.content.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn serialize_bounds<S: Serializer>(bounds: &Rectangle, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Bounds", 4)?;
+    state.serialize_field("x", &bounds.x)?;
+    state.serialize_field("y", &bounds.y)?;
+    state.serialize_field("width", &bounds.width)?;
+    state.serialize_field("height", &bounds.height)?;
+    state.end()
+}
+
+fn calculate_intersection_over_union(first: &Rectangle, second: &Rectangle) -> f32 {
+    let intersection_width =
+        (first.x + first.width).min(second.x + second.width) - first.x.max(second.x);
+    let intersection_height =
+        (first.y + first.height).min(second.y + second.height) - first.y.max(second.y);
+    if intersection_width <= 0.0 || intersection_height <= 0.0 {
+        return 0.0;
+    }
+
+    let intersection_area = intersection_width * intersection_height;
+    let union_area = first.width * first.height + second.width * second.height - intersection_area;
+    if union_area <= 0.0 {
+        return 0.0;
+    }
+
+    intersection_area / union_area
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,7 +254,7 @@ mod tests {
         assert_eq!(text.bounds.y, 0.0);
         assert_eq!(text.bounds.width, 51.0);
         assert_eq!(text.bounds.height, 10.0);
-        assert_eq!(text.confidence, 0.95);
+        assert_eq!(text.confidence, Some(0.95));
         assert_eq!(text.words.len(), 2);
     }
 
@@ -109,4 +265,255 @@ mod tests {
         assert_eq!(text.words.len(), 0);
         assert_eq!(text.content, "Test");
     }
+
+    #[test]
+    fn test_to_layout_json_includes_text_bounds_and_confidence() {
+        let result = build_result(vec![DetectedText::new(
+            "Hello".to_string(),
+            10.0,
+            20.0,
+            50.0,
+            12.0,
+            0.5,
+            vec![DetectedWord::new(
+                "Hello".to_string(),
+                10.0,
+                20.0,
+                50.0,
+                12.0,
+            )],
+        )]);
+
+        let layout: serde_json::Value =
+            serde_json::from_str(&result.to_layout_json().unwrap()).unwrap();
+
+        assert_eq!(layout[0]["content"], "Hello");
+        assert_eq!(layout[0]["confidence"], 0.5);
+        assert_eq!(layout[0]["bounds"]["x"], 10.0);
+        assert_eq!(layout[0]["bounds"]["height"], 12.0);
+        assert_eq!(layout[0]["words"][0]["bounds"]["width"], 50.0);
+    }
+
+    #[test]
+    fn test_to_layout_json_reports_missing_confidence_as_null() {
+        let result = build_result(vec![DetectedText::without_confidence(
+            "Hello".to_string(),
+            10.0,
+            20.0,
+            50.0,
+            12.0,
+            vec![],
+        )]);
+
+        let layout: serde_json::Value =
+            serde_json::from_str(&result.to_layout_json().unwrap()).unwrap();
+
+        assert!(layout[0]["confidence"].is_null());
+    }
+
+    #[test]
+    fn test_retain_confident_blocks_keeps_line_breaks_between_remaining_lines() {
+        let result = build_result(vec![
+            DetectedText::new("first".to_string(), 0.0, 0.0, 30.0, 10.0, 0.9, vec![]),
+            DetectedText::new("line".to_string(), 40.0, 1.0, 30.0, 10.0, 0.8, vec![]),
+            DetectedText::new("noise".to_string(), 80.0, 0.0, 30.0, 10.0, 0.1, vec![]),
+            DetectedText::new("second".to_string(), 0.0, 20.0, 30.0, 10.0, 0.9, vec![]),
+            DetectedText::new("line".to_string(), 40.0, 20.0, 30.0, 10.0, 0.9, vec![]),
+        ]);
+
+        let retained = result.retain_confident_blocks(0.5);
+
+        assert_eq!(retained.full_text, "first line\nsecond line");
+    }
+
+    #[test]
+    fn test_retain_confident_blocks_keeps_engine_text_when_nothing_is_dropped() {
+        let mut result = build_result(vec![DetectedText::new(
+            "kept".to_string(),
+            0.0,
+            0.0,
+            30.0,
+            10.0,
+            0.9,
+            vec![],
+        )]);
+        result.full_text = "kept\n".to_string();
+
+        let retained = result.retain_confident_blocks(0.5);
+
+        assert_eq!(retained.full_text, "kept\n");
+    }
+
+    #[test]
+    fn test_retain_confident_blocks_keeps_blocks_without_confidence() {
+        let result = build_result(vec![
+            DetectedText::new("low".to_string(), 0.0, 0.0, 30.0, 10.0, 0.2, vec![]),
+            DetectedText::without_confidence("unscored".to_string(), 40.0, 0.0, 30.0, 10.0, vec![]),
+        ]);
+
+        let retained = result.retain_confident_blocks(0.5);
+
+        assert_eq!(retained.text_blocks.len(), 1);
+        assert_eq!(retained.full_text, "unscored");
+    }
+
+    #[test]
+    fn test_merge_prefers_scored_block_over_unscored_duplicate() {
+        let neural_engine = build_result(vec![DetectedText::without_confidence(
+            "He1lo".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            vec![],
+        )]);
+        let tesseract_engine = build_result(vec![DetectedText::new(
+            "Hello".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.3,
+            vec![],
+        )]);
+
+        let merged = OcrResult::merge(vec![neural_engine, tesseract_engine]);
+
+        assert_eq!(merged.text_blocks.len(), 1);
+        assert_eq!(merged.text_blocks[0].content, "Hello");
+    }
+
+    fn build_result(blocks: Vec<DetectedText>) -> OcrResult {
+        OcrResult {
+            full_text: String::new(),
+            text_blocks: blocks,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_higher_confidence_text_for_overlapping_boxes() {
+        let first_engine = build_result(vec![DetectedText::new(
+            "He1lo".to_string(),
+            10.0,
+            10.0,
+            50.0,
+            20.0,
+            0.6,
+            vec![],
+        )]);
+        let second_engine = build_result(vec![DetectedText::new(
+            "Hello".to_string(),
+            12.0,
+            11.0,
+            50.0,
+            20.0,
+            0.9,
+            vec![],
+        )]);
+
+        let merged = OcrResult::merge(vec![first_engine, second_engine]);
+
+        assert_eq!(merged.text_blocks.len(), 1);
+        assert_eq!(merged.text_blocks[0].content, "Hello");
+        assert_eq!(merged.full_text, "Hello");
+    }
+
+    #[test]
+    fn test_merge_keeps_first_block_when_duplicate_has_lower_confidence() {
+        let first_engine = build_result(vec![DetectedText::new(
+            "Hello".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.95,
+            vec![],
+        )]);
+        let second_engine = build_result(vec![DetectedText::new(
+            "Hell0".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.4,
+            vec![],
+        )]);
+
+        let merged = OcrResult::merge(vec![first_engine, second_engine]);
+
+        assert_eq!(merged.text_blocks.len(), 1);
+        assert_eq!(merged.text_blocks[0].content, "Hello");
+    }
+
+    #[test]
+    fn test_merge_keeps_blocks_that_do_not_overlap_enough() {
+        let first_engine = build_result(vec![DetectedText::new(
+            "Hello".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.9,
+            vec![],
+        )]);
+        let second_engine = build_result(vec![
+            DetectedText::new("World".to_string(), 30.0, 0.0, 40.0, 10.0, 0.9, vec![]),
+            DetectedText::new("Below".to_string(), 0.0, 50.0, 40.0, 10.0, 0.9, vec![]),
+        ]);
+
+        let merged = OcrResult::merge(vec![first_engine, second_engine]);
+
+        assert_eq!(merged.text_blocks.len(), 3);
+        assert_eq!(merged.full_text, "Hello World\nBelow");
+    }
+
+    #[test]
+    fn test_merge_orders_blocks_from_later_engines_by_position() {
+        let first_engine = build_result(vec![DetectedText::new(
+            "Below".to_string(),
+            0.0,
+            50.0,
+            40.0,
+            10.0,
+            0.9,
+            vec![],
+        )]);
+        let second_engine = build_result(vec![DetectedText::new(
+            "Above".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.9,
+            vec![],
+        )]);
+
+        let merged = OcrResult::merge(vec![first_engine, second_engine]);
+
+        assert_eq!(merged.full_text, "Above\nBelow");
+    }
+
+    #[test]
+    fn test_intersection_over_union_of_identical_boxes_is_one() {
+        let bounds = Rectangle {
+            x: 5.0,
+            y: 5.0,
+            width: 10.0,
+            height: 10.0,
+        };
+
+        assert_eq!(calculate_intersection_over_union(&bounds, &bounds), 1.0);
+    }
+
+    #[test]
+    fn test_intersection_over_union_of_degenerate_boxes_is_zero() {
+        let empty = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+
+        assert_eq!(calculate_intersection_over_union(&empty, &empty), 0.0);
+    }
 }
@@ -0,0 +1,102 @@
+use super::CaptureBuffer;
+
+const CHANNEL_MIDPOINT: f32 = 128.0;
+const THRESHOLD_LEVEL: u8 = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OcrPreprocess {
+    pub brightness: i16,
+    pub contrast: f32,
+    pub threshold: bool,
+}
+
+impl Default for OcrPreprocess {
+    fn default() -> Self {
+        Self {
+            brightness: 0,
+            contrast: 1.0,
+            threshold: false,
+        }
+    }
+}
+
+impl OcrPreprocess {
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn apply(&self, buffer: &CaptureBuffer) -> CaptureBuffer {
+        if self.is_identity() {
+            return buffer.clone();
+        }
+
+        let mut adjusted_data = buffer.raw_data.clone();
+        for pixel in adjusted_data.chunks_exact_mut(4) {
+            let red = adjust_channel(pixel[0], self.brightness, self.contrast);
+            let green = adjust_channel(pixel[1], self.brightness, self.contrast);
+            let blue = adjust_channel(pixel[2], self.brightness, self.contrast);
+
+            if self.threshold {
+                let luma =
+                    ((red as u32 * 299 + green as u32 * 587 + blue as u32 * 114) / 1000) as u8;
+                let level = if luma >= THRESHOLD_LEVEL { 255 } else { 0 };
+                pixel[..3].fill(level);
+            } else {
+                pixel[0] = red;
+                pixel[1] = green;
+                pixel[2] = blue;
+            }
+        }
+
+        CaptureBuffer::build_from_raw_data(
+            buffer._scale_factor,
+            buffer.width,
+            buffer.height,
+            adjusted_data,
+        )
+    }
+}
+
+pub fn adjust_channel(value: u8, brightness: i16, contrast: f32) -> u8 {
+    let adjusted =
+        (value as f32 - CHANNEL_MIDPOINT) * contrast + CHANNEL_MIDPOINT + brightness as f32;
+    adjusted.round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjust_channel_clamps_brightness_into_channel_range() {
+        assert_eq!(adjust_channel(250, 50, 1.0), 255);
+        assert_eq!(adjust_channel(5, -50, 1.0), 0);
+        assert_eq!(adjust_channel(100, 20, 1.0), 120);
+    }
+
+    #[test]
+    fn test_adjust_channel_clamps_high_contrast() {
+        assert_eq!(adjust_channel(250, 0, 3.0), 255);
+        assert_eq!(adjust_channel(10, 0, 3.0), 0);
+        assert_eq!(adjust_channel(128, 0, 3.0), 128);
+    }
+
+    #[test]
+    fn test_apply_threshold_produces_black_and_white_pixels_and_keeps_alpha() {
+        let buffer = CaptureBuffer::build_from_raw_data(
+            1.0,
+            2,
+            1,
+            vec![40, 40, 40, 200, 200, 200, 200, 255],
+        );
+        let preprocess = OcrPreprocess {
+            threshold: true,
+            ..OcrPreprocess::default()
+        };
+
+        let adjusted = preprocess.apply(&buffer);
+
+        assert_eq!(adjusted.raw_data, vec![0, 0, 0, 200, 255, 255, 255, 255]);
+        assert_eq!(buffer.raw_data[0], 40);
+    }
+}
@@ -18,6 +18,21 @@ impl ScreenRegion {
         }
     }
 
+    pub fn centered_bounds_within(
+        &self,
+        width: u32,
+        height: u32,
+        bounds_width: u32,
+        bounds_height: u32,
+    ) -> (u32, u32, u32, u32) {
+        let clamped_width = width.min(bounds_width);
+        let clamped_height = height.min(bounds_height);
+        let left = center_axis_within(self.x_position, clamped_width, bounds_width);
+        let top = center_axis_within(self.y_position, clamped_height, bounds_height);
+
+        (left, top, clamped_width, clamped_height)
+    }
+
     #[allow(dead_code)]
     pub fn default_origin() -> Self {
         log::debug!("[SCREEN_REGION] using default origin (0, 0)");
@@ -29,6 +44,13 @@ impl ScreenRegion {
     }
 }
 
+fn center_axis_within(center: i32, size: u32, bounds: u32) -> u32 {
+    let start = center as i64 - (size / 2) as i64;
+    let max_start = (bounds - size) as i64;
+
+    start.clamp(0, max_start) as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,6 +71,41 @@ mod tests {
         assert_eq!(region.y_position, -100);
     }
 
+    #[test]
+    fn test_centered_bounds_within_keeps_region_centered_on_cursor() {
+        let region = ScreenRegion::at_coordinates(960, 540);
+
+        assert_eq!(
+            region.centered_bounds_within(400, 300, 1920, 1080),
+            (760, 390, 400, 300)
+        );
+    }
+
+    #[test]
+    fn test_centered_bounds_within_shifts_region_inward_near_corners() {
+        let top_left = ScreenRegion::at_coordinates(10, 5);
+        let bottom_right = ScreenRegion::at_coordinates(1915, 1079);
+
+        assert_eq!(
+            top_left.centered_bounds_within(400, 300, 1920, 1080),
+            (0, 0, 400, 300)
+        );
+        assert_eq!(
+            bottom_right.centered_bounds_within(400, 300, 1920, 1080),
+            (1520, 780, 400, 300)
+        );
+    }
+
+    #[test]
+    fn test_centered_bounds_within_clamps_size_to_bounds() {
+        let region = ScreenRegion::at_coordinates(-40, 2000);
+
+        assert_eq!(
+            region.centered_bounds_within(2500, 600, 1920, 1080),
+            (0, 480, 1920, 600)
+        );
+    }
+
     #[test]
     fn test_default_origin_creates_region_at_zero() {
         let region = ScreenRegion::default_origin();
@@ -4,12 +4,14 @@ use std::fs;
 use std::path::PathBuf;
 use uuid::Uuid;
 
+use super::MonitorGeometry;
 use crate::global_constants;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ThemeMode {
     Dark,
     Light,
+    System,
 }
 
 impl fmt::Display for ThemeMode {
@@ -17,13 +19,14 @@ impl fmt::Display for ThemeMode {
         match self {
             ThemeMode::Dark => write!(f, "Dark"),
             ThemeMode::Light => write!(f, "Light"),
+            ThemeMode::System => write!(f, "System"),
         }
     }
 }
 
 impl Default for ThemeMode {
     fn default() -> Self {
-        ThemeMode::Dark
+        ThemeMode::System
     }
 }
 
@@ -71,6 +74,346 @@ impl Default for ImageUploadHttpMethod {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ImageHost {
+    Imgbb,
+    ZeroXZero,
+    CustomMultipart,
+}
+
+impl fmt::Display for ImageHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageHost::Imgbb => write!(f, "imgbb"),
+            ImageHost::ZeroXZero => write!(f, "0x0.st"),
+            ImageHost::CustomMultipart => write!(f, "Custom (multipart upload)"),
+        }
+    }
+}
+
+impl Default for ImageHost {
+    fn default() -> Self {
+        ImageHost::Imgbb
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum OcrEngine {
+    Tesseract,
+    Neural,
+    Onnx,
+    Ensemble,
+    Auto,
+}
+
+impl fmt::Display for OcrEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrEngine::Tesseract => write!(f, "Tesseract"),
+            OcrEngine::Neural => write!(f, "Neural (ocrs)"),
+            OcrEngine::Onnx => write!(f, "ONNX (PaddleOCR)"),
+            OcrEngine::Ensemble => write!(f, "Ensemble (best of both)"),
+            OcrEngine::Auto => write!(f, "Auto (whichever is available)"),
+        }
+    }
+}
+
+impl OcrEngine {
+    pub fn supported_languages(&self) -> Vec<OcrLanguage> {
+        match self {
+            OcrEngine::Neural | OcrEngine::Onnx => vec![OcrLanguage::English],
+            OcrEngine::Tesseract | OcrEngine::Ensemble | OcrEngine::Auto => {
+                OcrLanguage::ALL.to_vec()
+            }
+        }
+    }
+
+    pub fn supports_language_override(&self) -> bool {
+        self.supported_languages().len() > 1
+    }
+}
+
+impl Default for OcrEngine {
+    fn default() -> Self {
+        OcrEngine::Tesseract
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum SearchEngine {
+    GoogleLens,
+    BingVisualSearch,
+    Yandex,
+}
+
+impl fmt::Display for SearchEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchEngine::GoogleLens => write!(f, "Google Lens"),
+            SearchEngine::BingVisualSearch => write!(f, "Bing Visual Search"),
+            SearchEngine::Yandex => write!(f, "Yandex Images"),
+        }
+    }
+}
+
+impl Default for SearchEngine {
+    fn default() -> Self {
+        SearchEngine::GoogleLens
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OcrLanguage {
+    English,
+    French,
+    German,
+    Spanish,
+    ChineseSimplified,
+    Japanese,
+    Korean,
+}
+
+impl OcrLanguage {
+    pub const ALL: [OcrLanguage; 7] = [
+        OcrLanguage::English,
+        OcrLanguage::French,
+        OcrLanguage::German,
+        OcrLanguage::Spanish,
+        OcrLanguage::ChineseSimplified,
+        OcrLanguage::Japanese,
+        OcrLanguage::Korean,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            OcrLanguage::English => "eng",
+            OcrLanguage::French => "fra",
+            OcrLanguage::German => "deu",
+            OcrLanguage::Spanish => "spa",
+            OcrLanguage::ChineseSimplified => "chi_sim",
+            OcrLanguage::Japanese => "jpn",
+            OcrLanguage::Korean => "kor",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code.trim())
+    }
+}
+
+impl fmt::Display for OcrLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrLanguage::English => write!(f, "English"),
+            OcrLanguage::French => write!(f, "French"),
+            OcrLanguage::German => write!(f, "German"),
+            OcrLanguage::Spanish => write!(f, "Spanish"),
+            OcrLanguage::ChineseSimplified => write!(f, "Chinese (Simplified)"),
+            OcrLanguage::Japanese => write!(f, "Japanese"),
+            OcrLanguage::Korean => write!(f, "Korean"),
+        }
+    }
+}
+
+impl Default for OcrLanguage {
+    fn default() -> Self {
+        OcrLanguage::English
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CaptureTrigger {
+    Keyboard,
+    MiddleClickHold,
+    TrayOnly,
+}
+
+impl fmt::Display for CaptureTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureTrigger::Keyboard => write!(f, "Keyboard shortcut"),
+            CaptureTrigger::MiddleClickHold => write!(f, "Middle-click and hold"),
+            CaptureTrigger::TrayOnly => write!(f, "Tray menu only"),
+        }
+    }
+}
+
+impl Default for CaptureTrigger {
+    fn default() -> Self {
+        CaptureTrigger::Keyboard
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CaptureMonitor {
+    UnderCursor,
+    Primary,
+}
+
+impl fmt::Display for CaptureMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureMonitor::UnderCursor => write!(f, "Monitor under cursor"),
+            CaptureMonitor::Primary => write!(f, "Always primary monitor"),
+        }
+    }
+}
+
+impl Default for CaptureMonitor {
+    fn default() -> Self {
+        CaptureMonitor::UnderCursor
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+
+    pub fn to_level_filter(self) -> log::LevelFilter {
+        match self {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogLevel::Error => write!(f, "Errors only"),
+            LogLevel::Warn => write!(f, "Warnings"),
+            LogLevel::Info => write!(f, "Info"),
+            LogLevel::Debug => write!(f, "Debug"),
+            LogLevel::Trace => write!(f, "Trace"),
+        }
+    }
+}
+
+impl Default for LogLevel {
+    fn default() -> Self {
+        LogLevel::Info
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ImageSaveFormat {
+    Png,
+    Jpeg,
+    WebP,
+}
+
+impl ImageSaveFormat {
+    pub const ALL: [ImageSaveFormat; 3] = [
+        ImageSaveFormat::Png,
+        ImageSaveFormat::Jpeg,
+        ImageSaveFormat::WebP,
+    ];
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            ImageSaveFormat::Png => "png",
+            ImageSaveFormat::Jpeg => "jpg",
+            ImageSaveFormat::WebP => "webp",
+        }
+    }
+}
+
+impl fmt::Display for ImageSaveFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageSaveFormat::Png => write!(f, "PNG"),
+            ImageSaveFormat::Jpeg => write!(f, "JPEG"),
+            ImageSaveFormat::WebP => write!(f, "WebP"),
+        }
+    }
+}
+
+impl Default for ImageSaveFormat {
+    fn default() -> Self {
+        ImageSaveFormat::Png
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecentRegion {
+    pub monitor_x: i32,
+    pub monitor_y: i32,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl fmt::Display for RecentRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} × {} at ({}, {})",
+            self.width, self.height, self.x, self.y
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct WindowGeometry {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl WindowGeometry {
+    pub fn is_visible_on_monitors(&self, monitors: &[MonitorGeometry]) -> bool {
+        monitors
+            .iter()
+            .any(|monitor| self.is_visible_on_monitor(monitor, monitors))
+    }
+
+    pub fn is_visible_on_monitor(
+        &self,
+        monitor: &MonitorGeometry,
+        monitors: &[MonitorGeometry],
+    ) -> bool {
+        if !(self.width > 0.0 && self.height > 0.0) {
+            return false;
+        }
+
+        let min_visible_width = self
+            .width
+            .min(global_constants::WINDOW_GEOMETRY_MIN_VISIBLE_PX);
+        let min_visible_height = self
+            .height
+            .min(global_constants::WINDOW_GEOMETRY_MIN_VISIBLE_PX);
+        let (monitor_x, monitor_y) = monitor.overlay_origin(monitors);
+        let (monitor_width, monitor_height) = monitor.overlay_size();
+        let overlap_width =
+            (self.x + self.width).min(monitor_x + monitor_width) - self.x.max(monitor_x);
+        let overlap_height =
+            (self.y + self.height).min(monitor_y + monitor_height) - self.y.max(monitor_y);
+
+        overlap_width >= min_visible_width && overlap_height >= min_visible_height
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserSettings {
     pub image_search_url_template: String,
@@ -106,6 +449,106 @@ pub struct UserSettings {
     pub install_id: Option<String>,
     #[serde(default = "UserSettings::default_screenshot_save_location")]
     pub screenshot_save_location: String,
+    #[serde(default = "UserSettings::default_confirm_quit_with_unsaved_annotations")]
+    pub confirm_quit_with_unsaved_annotations: bool,
+    #[serde(default = "UserSettings::default_capture_delay_ms")]
+    pub capture_delay_ms: u32,
+    #[serde(default = "UserSettings::default_scroll_capture_delay_ms")]
+    pub scroll_capture_delay_ms: u32,
+    #[serde(default = "UserSettings::default_enable_type_out_text")]
+    pub enable_type_out_text: bool,
+    #[serde(default = "UserSettings::default_auto_run_ocr")]
+    pub auto_run_ocr: bool,
+    #[serde(default)]
+    pub ocr_engine: OcrEngine,
+    #[serde(default = "UserSettings::default_quit_on_main_window_close")]
+    pub quit_on_main_window_close: bool,
+    #[serde(default)]
+    pub close_to_tray_notice_shown: bool,
+    #[serde(default)]
+    pub capture_trigger: CaptureTrigger,
+    #[serde(default)]
+    pub capture_monitor: CaptureMonitor,
+    #[serde(default = "UserSettings::default_suspend_hotkey_in_fullscreen")]
+    pub suspend_hotkey_in_fullscreen: bool,
+    #[serde(default = "UserSettings::default_ocr_timeout_secs")]
+    pub ocr_timeout_secs: u32,
+    #[serde(default)]
+    pub recent_regions: Vec<RecentRegion>,
+    #[serde(default = "UserSettings::default_auto_close_after_action")]
+    pub auto_close_after_action: bool,
+    #[serde(default = "UserSettings::default_draw_mode_shortcut")]
+    pub draw_mode_shortcut: String,
+    #[serde(default = "UserSettings::default_save_image_shortcut")]
+    pub save_image_shortcut: String,
+    #[serde(default = "UserSettings::default_copy_image_shortcut")]
+    pub copy_image_shortcut: String,
+    #[serde(default = "UserSettings::default_open_search_in_private_window")]
+    pub open_search_in_private_window: bool,
+    #[serde(default = "UserSettings::default_auto_save_captures")]
+    pub auto_save_captures: bool,
+    #[serde(default = "UserSettings::default_auto_save_location")]
+    pub auto_save_location: String,
+    #[serde(default = "UserSettings::default_include_annotations_in_search")]
+    pub include_annotations_in_search: bool,
+    #[serde(default = "UserSettings::default_show_help_hint")]
+    pub show_help_hint: bool,
+    #[serde(default = "UserSettings::default_play_capture_sound")]
+    pub play_capture_sound: bool,
+    #[serde(default = "UserSettings::default_play_success_sound")]
+    pub play_success_sound: bool,
+    #[serde(default = "UserSettings::default_play_failure_sound")]
+    pub play_failure_sound: bool,
+    #[serde(default = "UserSettings::default_show_capture_flash")]
+    pub show_capture_flash: bool,
+    #[serde(default = "UserSettings::default_language")]
+    pub language: String,
+    #[serde(default)]
+    pub search_engine: SearchEngine,
+    #[serde(default = "UserSettings::default_max_history_entries")]
+    pub max_history_entries: u32,
+    #[serde(default = "UserSettings::default_min_ocr_confidence")]
+    pub min_ocr_confidence: f32,
+    #[serde(default = "UserSettings::default_draw_width")]
+    pub draw_width: f32,
+    #[serde(default = "UserSettings::default_translation_target_language")]
+    pub translation_target_language: String,
+    #[serde(default)]
+    pub translation_api_key: String,
+    #[serde(default)]
+    pub pin_result_window_on_top: bool,
+    #[serde(default)]
+    pub show_magnifier: bool,
+    #[serde(default = "UserSettings::default_text_search_url_template")]
+    pub text_search_url_template: String,
+    #[serde(default)]
+    pub image_host: ImageHost,
+    #[serde(default)]
+    pub custom_image_host_url: String,
+    #[serde(default = "UserSettings::default_custom_image_host_field_name")]
+    pub custom_image_host_field_name: String,
+    #[serde(default)]
+    pub capture_region_width: u32,
+    #[serde(default)]
+    pub capture_region_height: u32,
+    #[serde(default)]
+    pub main_window_geometry: Option<WindowGeometry>,
+    #[serde(default)]
+    pub ocr_window_geometry: Option<WindowGeometry>,
+    #[serde(default = "UserSettings::default_minimize_on_capture")]
+    pub minimize_on_capture: bool,
+    #[serde(default)]
+    pub save_format: ImageSaveFormat,
+    #[serde(default = "UserSettings::default_jpeg_quality")]
+    pub jpeg_quality: u8,
+    #[serde(default)]
+    pub log_level: LogLevel,
+    #[serde(default)]
+    pub recent_draw_colors: Vec<(f32, f32, f32)>,
+    #[serde(default = "UserSettings::default_auto_copy_on_ocr")]
+    pub auto_copy_on_ocr: bool,
+    #[serde(default = "UserSettings::default_active_window_hotkey")]
+    pub active_window_hotkey: String,
 }
 
 impl Default for UserSettings {
@@ -126,6 +569,57 @@ impl Default for UserSettings {
             launch_at_login: false,
             install_id: None,
             screenshot_save_location: Self::default_screenshot_save_location(),
+            confirm_quit_with_unsaved_annotations:
+                Self::default_confirm_quit_with_unsaved_annotations(),
+            capture_delay_ms: Self::default_capture_delay_ms(),
+            scroll_capture_delay_ms: Self::default_scroll_capture_delay_ms(),
+            enable_type_out_text: Self::default_enable_type_out_text(),
+            auto_run_ocr: Self::default_auto_run_ocr(),
+            ocr_engine: OcrEngine::default(),
+            quit_on_main_window_close: Self::default_quit_on_main_window_close(),
+            close_to_tray_notice_shown: false,
+            capture_trigger: CaptureTrigger::default(),
+            capture_monitor: CaptureMonitor::default(),
+            suspend_hotkey_in_fullscreen: Self::default_suspend_hotkey_in_fullscreen(),
+            ocr_timeout_secs: Self::default_ocr_timeout_secs(),
+            recent_regions: Vec::new(),
+            auto_close_after_action: Self::default_auto_close_after_action(),
+            draw_mode_shortcut: Self::default_draw_mode_shortcut(),
+            save_image_shortcut: Self::default_save_image_shortcut(),
+            copy_image_shortcut: Self::default_copy_image_shortcut(),
+            open_search_in_private_window: Self::default_open_search_in_private_window(),
+            auto_save_captures: Self::default_auto_save_captures(),
+            auto_save_location: Self::default_auto_save_location(),
+            include_annotations_in_search: Self::default_include_annotations_in_search(),
+            show_help_hint: Self::default_show_help_hint(),
+            play_capture_sound: Self::default_play_capture_sound(),
+            play_success_sound: Self::default_play_success_sound(),
+            play_failure_sound: Self::default_play_failure_sound(),
+            show_capture_flash: Self::default_show_capture_flash(),
+            language: Self::default_language(),
+            search_engine: SearchEngine::default(),
+            max_history_entries: Self::default_max_history_entries(),
+            min_ocr_confidence: Self::default_min_ocr_confidence(),
+            draw_width: Self::default_draw_width(),
+            translation_target_language: Self::default_translation_target_language(),
+            translation_api_key: String::new(),
+            pin_result_window_on_top: false,
+            show_magnifier: false,
+            text_search_url_template: Self::default_text_search_url_template(),
+            image_host: ImageHost::default(),
+            custom_image_host_url: String::new(),
+            custom_image_host_field_name: Self::default_custom_image_host_field_name(),
+            capture_region_width: 0,
+            capture_region_height: 0,
+            main_window_geometry: None,
+            ocr_window_geometry: None,
+            minimize_on_capture: Self::default_minimize_on_capture(),
+            save_format: ImageSaveFormat::default(),
+            jpeg_quality: Self::default_jpeg_quality(),
+            log_level: LogLevel::default(),
+            recent_draw_colors: Vec::new(),
+            auto_copy_on_ocr: Self::default_auto_copy_on_ocr(),
+            active_window_hotkey: Self::default_active_window_hotkey(),
         }
     }
 }
@@ -157,12 +651,144 @@ impl UserSettings {
     }
 
     pub fn default_screenshot_save_location() -> String {
-        dirs::download_dir()
+        dirs::picture_dir()
+            .or_else(dirs::download_dir)
             .unwrap_or_else(|| dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")))
             .to_string_lossy()
             .to_string()
     }
 
+    pub fn default_auto_save_location() -> String {
+        PathBuf::from(Self::default_screenshot_save_location())
+            .join(global_constants::AUTO_SAVE_FOLDER_NAME)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    pub fn default_confirm_quit_with_unsaved_annotations() -> bool {
+        true
+    }
+
+    pub fn default_capture_delay_ms() -> u32 {
+        global_constants::DEFAULT_CAPTURE_DELAY_MS
+    }
+
+    pub fn default_scroll_capture_delay_ms() -> u32 {
+        global_constants::DEFAULT_SCROLL_CAPTURE_DELAY_MS
+    }
+
+    pub fn default_ocr_timeout_secs() -> u32 {
+        global_constants::DEFAULT_OCR_TIMEOUT_SECS
+    }
+
+    pub fn default_enable_type_out_text() -> bool {
+        false
+    }
+
+    pub fn default_auto_run_ocr() -> bool {
+        false
+    }
+
+    pub fn default_quit_on_main_window_close() -> bool {
+        false
+    }
+
+    pub fn default_suspend_hotkey_in_fullscreen() -> bool {
+        false
+    }
+
+    pub fn default_auto_close_after_action() -> bool {
+        false
+    }
+
+    pub fn default_minimize_on_capture() -> bool {
+        true
+    }
+
+    pub fn default_jpeg_quality() -> u8 {
+        global_constants::DEFAULT_JPEG_QUALITY
+    }
+
+    pub fn default_draw_mode_shortcut() -> String {
+        global_constants::DEFAULT_DRAW_MODE_SHORTCUT.to_string()
+    }
+
+    pub fn default_save_image_shortcut() -> String {
+        global_constants::DEFAULT_SAVE_IMAGE_SHORTCUT.to_string()
+    }
+
+    pub fn default_copy_image_shortcut() -> String {
+        global_constants::DEFAULT_COPY_IMAGE_SHORTCUT.to_string()
+    }
+
+    pub fn default_open_search_in_private_window() -> bool {
+        false
+    }
+
+    pub fn default_auto_save_captures() -> bool {
+        false
+    }
+
+    pub fn default_include_annotations_in_search() -> bool {
+        false
+    }
+
+    pub fn default_show_help_hint() -> bool {
+        true
+    }
+
+    pub fn default_play_capture_sound() -> bool {
+        false
+    }
+
+    pub fn default_play_success_sound() -> bool {
+        false
+    }
+
+    pub fn default_play_failure_sound() -> bool {
+        false
+    }
+
+    pub fn default_show_capture_flash() -> bool {
+        false
+    }
+
+    pub fn default_language() -> String {
+        OcrLanguage::default().code().to_string()
+    }
+
+    pub fn default_max_history_entries() -> u32 {
+        global_constants::DEFAULT_MAX_HISTORY_ENTRIES
+    }
+
+    pub fn default_min_ocr_confidence() -> f32 {
+        global_constants::DEFAULT_MIN_OCR_CONFIDENCE
+    }
+
+    pub fn default_draw_width() -> f32 {
+        global_constants::DEFAULT_DRAW_WIDTH
+    }
+
+    pub fn default_translation_target_language() -> String {
+        global_constants::DEFAULT_TRANSLATION_TARGET_LANGUAGE.to_string()
+    }
+
+    pub fn default_text_search_url_template() -> String {
+        global_constants::DEFAULT_TEXT_SEARCH_URL.to_string()
+    }
+
+    pub fn default_custom_image_host_field_name() -> String {
+        global_constants::DEFAULT_CUSTOM_IMAGE_HOST_FIELD_NAME.to_string()
+    }
+
+    pub fn default_auto_copy_on_ocr() -> bool {
+        false
+    }
+
+    pub fn default_active_window_hotkey() -> String {
+        global_constants::DEFAULT_ACTIVE_WINDOW_HOTKEY.to_string()
+    }
+
     pub fn load() -> anyhow::Result<Self> {
         let current_install_id = Self::get_or_create_install_id();
         let settings_path = Self::get_settings_file_path()?;
@@ -213,6 +839,10 @@ impl UserSettings {
     }
 
     fn get_settings_file_path() -> anyhow::Result<PathBuf> {
+        Ok(Self::get_config_directory()?.join(global_constants::SETTINGS_FILE_NAME))
+    }
+
+    pub fn get_config_directory() -> anyhow::Result<PathBuf> {
         let config_dir = if cfg!(target_os = "macos") {
             dirs::config_dir()
                 .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?
@@ -227,7 +857,7 @@ impl UserSettings {
                 .join("circle-to-search-pc")
         };
 
-        Ok(config_dir.join(global_constants::SETTINGS_FILE_NAME))
+        Ok(config_dir)
     }
 
     fn get_or_create_install_id() -> Option<String> {
@@ -319,9 +949,9 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_theme_mode_default_is_dark() {
+    fn test_theme_mode_default_follows_system() {
         let default_theme = ThemeMode::default();
-        assert_eq!(default_theme, ThemeMode::Dark);
+        assert_eq!(default_theme, ThemeMode::System);
     }
 
     #[test]
@@ -338,6 +968,45 @@ mod tests {
         assert_eq!(theme, ThemeMode::Light);
     }
 
+    #[test]
+    fn test_ocr_engine_auto_deserialization() {
+        let json = "\"Auto\"";
+        let engine: OcrEngine = serde_json::from_str(json).unwrap();
+        assert_eq!(engine, OcrEngine::Auto);
+    }
+
+    #[test]
+    fn test_ocr_language_from_code_round_trips_supported_codes() {
+        for language in OcrLanguage::ALL {
+            assert_eq!(OcrLanguage::from_code(language.code()), Some(language));
+        }
+        assert_eq!(OcrLanguage::from_code("klingon"), None);
+    }
+
+    #[test]
+    fn test_model_engines_only_offer_english() {
+        assert_eq!(
+            OcrEngine::Neural.supported_languages(),
+            vec![OcrLanguage::English]
+        );
+        assert_eq!(
+            OcrEngine::Onnx.supported_languages(),
+            vec![OcrLanguage::English]
+        );
+        assert_eq!(
+            OcrEngine::Tesseract.supported_languages(),
+            OcrLanguage::ALL.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_only_multi_language_engines_support_language_override() {
+        assert!(!OcrEngine::Neural.supports_language_override());
+        assert!(!OcrEngine::Onnx.supports_language_override());
+        assert!(OcrEngine::Tesseract.supports_language_override());
+        assert!(OcrEngine::Ensemble.supports_language_override());
+    }
+
     #[test]
     fn test_user_settings_default_values() {
         let settings = UserSettings::default();
@@ -370,10 +1039,85 @@ mod tests {
             settings.capture_hotkey,
             global_constants::DEFAULT_CAPTURE_HOTKEY
         );
-        assert_eq!(settings.theme_mode, ThemeMode::Dark);
+        assert_eq!(settings.theme_mode, ThemeMode::System);
         assert!(settings.run_in_system_tray);
         assert!(!settings.onboarding_complete);
         assert!(!settings.launch_at_login);
+        assert!(settings.confirm_quit_with_unsaved_annotations);
+        assert_eq!(
+            settings.capture_delay_ms,
+            global_constants::DEFAULT_CAPTURE_DELAY_MS
+        );
+        assert!(!settings.enable_type_out_text);
+        assert!(!settings.auto_run_ocr);
+        assert_eq!(settings.ocr_engine, OcrEngine::Tesseract);
+        assert_eq!(settings.capture_trigger, CaptureTrigger::Keyboard);
+        assert_eq!(settings.capture_monitor, CaptureMonitor::UnderCursor);
+        assert!(!settings.suspend_hotkey_in_fullscreen);
+        assert_eq!(
+            settings.ocr_timeout_secs,
+            global_constants::DEFAULT_OCR_TIMEOUT_SECS
+        );
+        assert!(settings.recent_regions.is_empty());
+        assert!(!settings.auto_close_after_action);
+        assert_eq!(settings.draw_mode_shortcut, "D");
+        assert_eq!(settings.save_image_shortcut, "Ctrl+S");
+        assert_eq!(settings.copy_image_shortcut, "Ctrl+D");
+        assert!(!settings.open_search_in_private_window);
+        assert!(!settings.auto_save_captures);
+        assert!(!settings.include_annotations_in_search);
+        assert!(settings.show_help_hint);
+        assert!(!settings.play_capture_sound);
+        assert!(!settings.play_success_sound);
+        assert!(!settings.play_failure_sound);
+        assert!(!settings.show_capture_flash);
+        assert_eq!(settings.language, "eng");
+        assert_eq!(settings.search_engine, SearchEngine::GoogleLens);
+        assert_eq!(
+            settings.max_history_entries,
+            global_constants::DEFAULT_MAX_HISTORY_ENTRIES
+        );
+        assert_eq!(
+            settings.min_ocr_confidence,
+            global_constants::DEFAULT_MIN_OCR_CONFIDENCE
+        );
+        assert_eq!(settings.draw_width, global_constants::DEFAULT_DRAW_WIDTH);
+        assert_eq!(
+            settings.translation_target_language,
+            global_constants::DEFAULT_TRANSLATION_TARGET_LANGUAGE
+        );
+        assert!(settings.translation_api_key.is_empty());
+        assert!(settings
+            .auto_save_location
+            .ends_with(global_constants::AUTO_SAVE_FOLDER_NAME));
+        assert!(!settings.quit_on_main_window_close);
+        assert!(!settings.pin_result_window_on_top);
+        assert!(!settings.show_magnifier);
+        assert_eq!(
+            settings.text_search_url_template,
+            global_constants::DEFAULT_TEXT_SEARCH_URL
+        );
+        assert_eq!(settings.image_host, ImageHost::Imgbb);
+        assert!(settings.custom_image_host_url.is_empty());
+        assert_eq!(
+            settings.custom_image_host_field_name,
+            global_constants::DEFAULT_CUSTOM_IMAGE_HOST_FIELD_NAME
+        );
+        assert_eq!(settings.capture_region_width, 0);
+        assert_eq!(settings.capture_region_height, 0);
+        assert_eq!(settings.main_window_geometry, None);
+        assert_eq!(settings.ocr_window_geometry, None);
+        assert!(settings.minimize_on_capture);
+        assert_eq!(settings.save_format, ImageSaveFormat::Png);
+        assert_eq!(
+            settings.jpeg_quality,
+            global_constants::DEFAULT_JPEG_QUALITY
+        );
+        assert_eq!(settings.log_level, LogLevel::Info);
+        assert_eq!(settings.log_level.to_level_filter(), log::LevelFilter::Info);
+        assert!(settings.recent_draw_colors.is_empty());
+        assert!(!settings.auto_copy_on_ocr);
+        assert!(!settings.close_to_tray_notice_shown);
     }
 
     #[test]
@@ -394,6 +1138,56 @@ mod tests {
             launch_at_login: true,
             install_id: Some("test-id".to_string()),
             screenshot_save_location: "/tmp/screenshots".to_string(),
+            confirm_quit_with_unsaved_annotations: true,
+            capture_delay_ms: 0,
+            enable_type_out_text: false,
+            auto_run_ocr: false,
+            quit_on_main_window_close: false,
+            close_to_tray_notice_shown: false,
+            scroll_capture_delay_ms: 0,
+            ocr_engine: OcrEngine::Neural,
+            capture_trigger: CaptureTrigger::MiddleClickHold,
+            capture_monitor: CaptureMonitor::Primary,
+            suspend_hotkey_in_fullscreen: true,
+            ocr_timeout_secs: 30,
+            recent_regions: Vec::new(),
+            auto_close_after_action: false,
+            draw_mode_shortcut: "D".to_string(),
+            save_image_shortcut: "Ctrl+S".to_string(),
+            copy_image_shortcut: "Ctrl+D".to_string(),
+            open_search_in_private_window: false,
+            auto_save_captures: false,
+            auto_save_location: "/tmp/auto-saved".to_string(),
+            include_annotations_in_search: false,
+            show_help_hint: true,
+            play_capture_sound: false,
+            play_success_sound: false,
+            play_failure_sound: false,
+            show_capture_flash: true,
+            language: "fra".to_string(),
+            search_engine: SearchEngine::Yandex,
+            max_history_entries: 10,
+            min_ocr_confidence: 0.4,
+            draw_width: 5.0,
+            translation_target_language: "es".to_string(),
+            translation_api_key: "cloud-key".to_string(),
+            pin_result_window_on_top: true,
+            show_magnifier: true,
+            text_search_url_template: "https://duckduckgo.com/?q={}".to_string(),
+            image_host: ImageHost::CustomMultipart,
+            custom_image_host_url: "https://example.com/upload".to_string(),
+            custom_image_host_field_name: "upload".to_string(),
+            capture_region_width: 640,
+            capture_region_height: 480,
+            main_window_geometry: None,
+            ocr_window_geometry: None,
+            minimize_on_capture: true,
+            save_format: ImageSaveFormat::Jpeg,
+            jpeg_quality: 80,
+            log_level: LogLevel::Debug,
+            recent_draw_colors: vec![(0.5, 0.25, 1.0)],
+            auto_copy_on_ocr: true,
+            active_window_hotkey: "Ctrl+Alt+W".to_string(),
         };
 
         let serialized = serde_json::to_string(&settings).unwrap();
@@ -431,6 +1225,18 @@ mod tests {
             settings.onboarding_complete
         );
         assert_eq!(deserialized.launch_at_login, settings.launch_at_login);
+        assert_eq!(deserialized.ocr_engine, settings.ocr_engine);
+        assert_eq!(deserialized.capture_trigger, settings.capture_trigger);
+        assert_eq!(deserialized.capture_monitor, settings.capture_monitor);
+        assert_eq!(deserialized.save_format, settings.save_format);
+        assert_eq!(deserialized.jpeg_quality, settings.jpeg_quality);
+        assert_eq!(deserialized.log_level, settings.log_level);
+        assert_eq!(deserialized.recent_draw_colors, settings.recent_draw_colors);
+        assert_eq!(deserialized.auto_copy_on_ocr, settings.auto_copy_on_ocr);
+        assert_eq!(
+            deserialized.active_window_hotkey,
+            settings.active_window_hotkey
+        );
     }
 
     #[test]
@@ -463,6 +1269,7 @@ mod tests {
             settings.image_hosting_expiration_seconds,
             global_constants::IMGBB_EXPIRATION_SECONDS
         );
+        assert!(settings.confirm_quit_with_unsaved_annotations);
     }
 
     #[test]
@@ -486,6 +1293,56 @@ mod tests {
             launch_at_login: true,
             install_id: Some("test-roundtrip-id".to_string()),
             screenshot_save_location: "/tmp/test-screenshots".to_string(),
+            confirm_quit_with_unsaved_annotations: true,
+            capture_delay_ms: 0,
+            enable_type_out_text: false,
+            auto_run_ocr: false,
+            quit_on_main_window_close: false,
+            close_to_tray_notice_shown: false,
+            scroll_capture_delay_ms: 0,
+            ocr_engine: OcrEngine::Neural,
+            capture_trigger: CaptureTrigger::MiddleClickHold,
+            capture_monitor: CaptureMonitor::Primary,
+            suspend_hotkey_in_fullscreen: true,
+            ocr_timeout_secs: 30,
+            recent_regions: Vec::new(),
+            auto_close_after_action: false,
+            draw_mode_shortcut: "D".to_string(),
+            save_image_shortcut: "Ctrl+S".to_string(),
+            copy_image_shortcut: "Ctrl+D".to_string(),
+            open_search_in_private_window: false,
+            auto_save_captures: false,
+            auto_save_location: "/tmp/auto-saved".to_string(),
+            include_annotations_in_search: false,
+            show_help_hint: true,
+            play_capture_sound: false,
+            play_success_sound: false,
+            play_failure_sound: false,
+            show_capture_flash: true,
+            language: "fra".to_string(),
+            search_engine: SearchEngine::Yandex,
+            max_history_entries: 10,
+            min_ocr_confidence: 0.4,
+            draw_width: 5.0,
+            translation_target_language: "es".to_string(),
+            translation_api_key: "cloud-key".to_string(),
+            pin_result_window_on_top: true,
+            show_magnifier: true,
+            text_search_url_template: "https://duckduckgo.com/?q={}".to_string(),
+            image_host: ImageHost::CustomMultipart,
+            custom_image_host_url: "https://example.com/upload".to_string(),
+            custom_image_host_field_name: "upload".to_string(),
+            capture_region_width: 640,
+            capture_region_height: 480,
+            main_window_geometry: None,
+            ocr_window_geometry: None,
+            minimize_on_capture: true,
+            save_format: ImageSaveFormat::Jpeg,
+            jpeg_quality: 80,
+            log_level: LogLevel::Debug,
+            recent_draw_colors: vec![(0.5, 0.25, 1.0)],
+            auto_copy_on_ocr: false,
+            active_window_hotkey: "Alt+Shift+W".to_string(),
         };
 
         let test_file = temp_dir.join("test_settings.json");
@@ -536,7 +1393,123 @@ mod tests {
             loaded_settings.launch_at_login,
             original_settings.launch_at_login
         );
+        assert_eq!(
+            loaded_settings.recent_draw_colors,
+            original_settings.recent_draw_colors
+        );
 
         std::fs::remove_dir_all(&temp_dir).ok();
     }
+
+    fn create_test_monitor(x: i32, y: i32, width: u32, height: u32) -> MonitorGeometry {
+        MonitorGeometry {
+            x,
+            y,
+            width,
+            height,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_window_geometry_on_connected_monitor_is_visible() {
+        let monitors = vec![create_test_monitor(0, 0, 1920, 1080)];
+        let geometry = WindowGeometry {
+            x: 200.0,
+            y: 100.0,
+            width: 700.0,
+            height: 800.0,
+        };
+
+        assert!(geometry.is_visible_on_monitors(&monitors));
+    }
+
+    #[test]
+    fn test_window_geometry_on_high_dpi_secondary_monitor_is_visible() {
+        let monitors = vec![
+            create_test_monitor(0, 0, 1920, 1080),
+            MonitorGeometry {
+                x: 1920,
+                y: 0,
+                width: 3840,
+                height: 2160,
+                scale_factor: 2.0,
+            },
+        ];
+        let geometry = WindowGeometry {
+            x: 3000.0,
+            y: 100.0,
+            width: 400.0,
+            height: 300.0,
+        };
+
+        assert!(geometry.is_visible_on_monitors(&monitors));
+    }
+
+    #[test]
+    fn test_window_geometry_on_disconnected_monitor_is_rejected() {
+        let monitors = vec![create_test_monitor(0, 0, 1920, 1080)];
+        let geometry = WindowGeometry {
+            x: 2200.0,
+            y: 100.0,
+            width: 700.0,
+            height: 800.0,
+        };
+
+        assert!(!geometry.is_visible_on_monitors(&monitors));
+    }
+
+    #[test]
+    fn test_window_geometry_on_secondary_monitor_is_visible() {
+        let monitors = vec![
+            create_test_monitor(0, 0, 1920, 1080),
+            create_test_monitor(-1280, 0, 1280, 1024),
+        ];
+        let geometry = WindowGeometry {
+            x: -1000.0,
+            y: 50.0,
+            width: 700.0,
+            height: 800.0,
+        };
+
+        assert!(geometry.is_visible_on_monitors(&monitors));
+    }
+
+    #[test]
+    fn test_window_geometry_barely_overlapping_monitor_is_rejected() {
+        let monitors = vec![create_test_monitor(0, 0, 1920, 1080)];
+        let geometry = WindowGeometry {
+            x: 1900.0,
+            y: 100.0,
+            width: 700.0,
+            height: 800.0,
+        };
+
+        assert!(!geometry.is_visible_on_monitors(&monitors));
+    }
+
+    #[test]
+    fn test_window_geometry_without_monitors_is_rejected() {
+        let geometry = WindowGeometry {
+            x: 0.0,
+            y: 0.0,
+            width: 700.0,
+            height: 800.0,
+        };
+
+        assert!(!geometry.is_visible_on_monitors(&[]));
+    }
+
+    #[test]
+    fn test_window_geometry_with_empty_size_is_rejected() {
+        let monitors = vec![create_test_monitor(0, 0, 1920, 1080)];
+        let geometry = WindowGeometry {
+            x: 100.0,
+            y: 100.0,
+            width: 0.0,
+            height: 800.0,
+        };
+
+        assert!(!geometry.is_visible_on_monitors(&monitors));
+    }
 }
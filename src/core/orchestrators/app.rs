@@ -3,15 +3,15 @@ use std::sync::Arc;
 use iced::window::Id;
 use iced::{Element, Task};
 
-use crate::adapters::{
-    macos_app_behavior, GoogleLensSearchProvider, ImgbbImageHostingService, TesseractOcrService,
-};
+use crate::adapters::macos_app_behavior;
+use crate::adapters::system_appearance;
+use crate::adapters::RxingBarcodeService;
 use crate::core::interfaces::adapters::OcrService;
-use crate::core::models::{OcrResult, UserSettings};
+use crate::core::models::{OcrResult, ThemeMode, UserSettings};
 use crate::core::orchestrators::app_orchestrator::{AppOrchestrator, OrchestratorMessage};
 use crate::ports::{
-    GlobalKeyboardEvent, GlobalKeyboardListener, SystemMousePositionProvider, SystemTray,
-    XcapScreenCapturer,
+    GlobalKeyboardEvent, GlobalKeyboardListener, HotkeyCombo, SystemMousePositionProvider,
+    SystemTray, XcapScreenCapturer,
 };
 
 struct DummyOcrService;
@@ -32,6 +32,8 @@ pub struct CircleApp {
     orchestrator: AppOrchestrator,
     _tray: Option<SystemTray>,
     keyboard_listener_enabled: bool,
+    capture_hotkey: HotkeyCombo,
+    active_window_hotkey: HotkeyCombo,
 }
 
 fn check_all_permissions_granted() -> bool {
@@ -71,19 +73,30 @@ impl CircleApp {
         });
 
         let onboarding_complete = settings.onboarding_complete;
+        let ocr_engine = settings.ocr_engine.clone();
+        let ocr_language = settings.language.clone();
+        let capture_hotkey = HotkeyCombo::parse(&settings.capture_hotkey).unwrap_or_else(|e| {
+            log::warn!("[APP] Invalid capture hotkey: {}, using default", e);
+            HotkeyCombo::default()
+        });
+        let active_window_hotkey = HotkeyCombo::parse(&settings.active_window_hotkey)
+            .or_else(|e| {
+                log::warn!("[APP] Invalid active window hotkey: {}, using default", e);
+                HotkeyCombo::parse(crate::global_constants::DEFAULT_ACTIVE_WINDOW_HOTKEY)
+            })
+            .unwrap_or_default();
 
-        let image_hosting_service =
-            Arc::new(ImgbbImageHostingService::from_user_settings(&settings));
-        let reverse_image_search_provider = Arc::new(GoogleLensSearchProvider::new(
-            image_hosting_service,
-            settings.image_search_url_template.clone(),
-        ));
+        let reverse_image_search_provider =
+            AppOrchestrator::build_reverse_image_search_provider(&settings);
+        let translation_service = AppOrchestrator::build_translation_service(&settings);
 
         let orchestrator = AppOrchestrator::build(
             Arc::new(XcapScreenCapturer::initialize()),
             Arc::new(SystemMousePositionProvider::initialize()),
             Arc::new(DummyOcrService),
+            Arc::new(RxingBarcodeService::new()),
             reverse_image_search_provider,
+            translation_service,
             settings,
         );
 
@@ -99,21 +112,11 @@ impl CircleApp {
         };
 
         let mut tasks = vec![
+            Task::done(OrchestratorMessage::SystemThemeChanged(
+                system_appearance::detect_system_theme(),
+            )),
             Task::done(OrchestratorMessage::CreateHiddenWindow),
-            Task::future(async {
-                match TesseractOcrService::build() {
-                    Ok(service) => {
-                        log::info!("[APP] Tesseract OCR service initialized successfully");
-                        OrchestratorMessage::OcrServiceReady(
-                            Arc::new(service) as Arc<dyn OcrService>
-                        )
-                    }
-                    Err(e) => {
-                        log::error!("[APP] Failed to initialize Tesseract OCR service: {}", e);
-                        OrchestratorMessage::OcrServiceFailed(e.to_string())
-                    }
-                }
-            }),
+            orchestrator.build_ocr_service_task(ocr_engine, ocr_language),
         ];
 
         let all_permissions_granted = check_all_permissions_granted();
@@ -135,6 +138,8 @@ impl CircleApp {
                 orchestrator,
                 _tray: tray,
                 keyboard_listener_enabled,
+                capture_hotkey,
+                active_window_hotkey,
             },
             Task::batch(tasks),
         )
@@ -146,9 +151,31 @@ impl CircleApp {
             self.keyboard_listener_enabled = true;
             return Task::none();
         }
+        if let OrchestratorMessage::RecentCapturesChanged(captures) = &message {
+            if let Some(tray) = &mut self._tray {
+                if let Err(e) = tray.set_recent_captures(captures) {
+                    log::error!("[APP] Failed to rebuild recent captures menu: {}", e);
+                }
+            }
+        }
+        if let OrchestratorMessage::HotkeyEnabledChanged(enabled) = &message {
+            GlobalKeyboardListener::set_capture_hotkey_enabled(*enabled);
+            if let Some(tray) = &self._tray {
+                tray.set_hotkey_enabled(*enabled);
+            }
+        }
+        if matches!(message, OrchestratorMessage::InitializationComplete) {
+            if let Some(tray) = &self._tray {
+                tray.set_ready();
+            }
+        }
         self.orchestrator.update(message)
     }
 
+    pub fn window_title(&self, window_id: Id) -> String {
+        self.orchestrator.get_window_title(window_id)
+    }
+
     pub fn render_view(&self, window_id: Id) -> Element<'_, OrchestratorMessage> {
         self.orchestrator.render_view(window_id)
     }
@@ -164,6 +191,18 @@ impl CircleApp {
                 iced::Event::Window(window::Event::Focused) => {
                     Some(OrchestratorMessage::WindowFocused(id))
                 }
+                iced::Event::Window(window::Event::Opened { position, size }) => Some(
+                    OrchestratorMessage::WindowGeometryChanged(id, position, Some(size)),
+                ),
+                iced::Event::Window(window::Event::Moved(position)) => Some(
+                    OrchestratorMessage::WindowGeometryChanged(id, Some(position), None),
+                ),
+                iced::Event::Window(window::Event::Resized(size)) => Some(
+                    OrchestratorMessage::WindowGeometryChanged(id, None, Some(size)),
+                ),
+                iced::Event::Window(window::Event::FileDropped(path)) => {
+                    Some(OrchestratorMessage::FileDropped(id, path))
+                }
                 _ => None,
             }),
             iced::Subscription::run(|| {
@@ -192,16 +231,75 @@ impl CircleApp {
             }),
         ];
 
+        if self.orchestrator.is_following_system_theme() {
+            subscriptions.push(iced::Subscription::run(|| {
+                iced::stream::channel(
+                    1,
+                    |mut output: futures::channel::mpsc::Sender<OrchestratorMessage>| async move {
+                        let mut last_theme: Option<Option<ThemeMode>> = None;
+                        loop {
+                            let Ok(detected_theme) =
+                                tokio::task::spawn_blocking(system_appearance::detect_system_theme)
+                                    .await
+                            else {
+                                log::warn!("[APP] OS appearance detection task failed");
+                                tokio::time::sleep(std::time::Duration::from_secs(
+                                    crate::global_constants::SYSTEM_THEME_POLL_INTERVAL_SECS,
+                                ))
+                                .await;
+                                continue;
+                            };
+                            if last_theme.as_ref() != Some(&detected_theme) {
+                                last_theme = Some(detected_theme.clone());
+                                let _ = output.try_send(OrchestratorMessage::SystemThemeChanged(
+                                    detected_theme,
+                                ));
+                            }
+                            tokio::time::sleep(std::time::Duration::from_secs(
+                                crate::global_constants::SYSTEM_THEME_POLL_INTERVAL_SECS,
+                            ))
+                            .await;
+                        }
+                    },
+                )
+            }));
+        }
+
+        if self.orchestrator.is_recording_hotkey() {
+            subscriptions.push(iced::event::listen_with(
+                |event, _status, _id| match event {
+                    iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                        key,
+                        modifiers,
+                        ..
+                    }) => Some(OrchestratorMessage::HotkeyKeyPressed(key, modifiers)),
+                    _ => None,
+                },
+            ));
+        }
+
         if self.keyboard_listener_enabled {
             subscriptions.push(
-                iced::Subscription::run(GlobalKeyboardListener::create_event_stream).map(|event| {
-                    match event {
-                        GlobalKeyboardEvent::CaptureHotkeyPressed => {
-                            OrchestratorMessage::Keyboard(GlobalKeyboardEvent::CaptureHotkeyPressed)
-                        }
-                        GlobalKeyboardEvent::EscapePressed => {
-                            OrchestratorMessage::Keyboard(GlobalKeyboardEvent::EscapePressed)
-                        }
+                iced::Subscription::run_with(
+                    (self.capture_hotkey, self.active_window_hotkey),
+                    |(hotkey, active_window_hotkey)| {
+                        GlobalKeyboardListener::create_event_stream(*hotkey, *active_window_hotkey)
+                    },
+                )
+                .map(|event| match event {
+                    GlobalKeyboardEvent::CaptureHotkeyPressed => {
+                        OrchestratorMessage::Keyboard(GlobalKeyboardEvent::CaptureHotkeyPressed)
+                    }
+                    GlobalKeyboardEvent::ActiveWindowHotkeyPressed => {
+                        OrchestratorMessage::Keyboard(
+                            GlobalKeyboardEvent::ActiveWindowHotkeyPressed,
+                        )
+                    }
+                    GlobalKeyboardEvent::MiddleClickHeld => {
+                        OrchestratorMessage::Keyboard(GlobalKeyboardEvent::MiddleClickHeld)
+                    }
+                    GlobalKeyboardEvent::EscapePressed => {
+                        OrchestratorMessage::Keyboard(GlobalKeyboardEvent::EscapePressed)
                     }
                 }),
             );
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use iced::widget::{container, text, Space};
@@ -6,27 +7,40 @@ use iced::window::{self, Id};
 use iced::{Element, Point, Rectangle, Size, Task};
 use mouse_position::mouse_position::Mouse;
 
-use crate::core::interfaces::adapters::{OcrService, ReverseImageSearchProvider};
+use crate::core::interfaces::adapters::{
+    BarcodeService, OcrService, ReverseImageSearchProvider, TranslationService,
+};
 use crate::core::interfaces::ports::{MousePositionProvider, ScreenCapturer};
 use crate::core::models::{
-    CaptureBuffer, ImageHostingAuthMode, ImageUploadHttpMethod, OcrResult, ScreenRegion, ThemeMode,
-    UserSettings, WindowInfo,
+    select_monitor_for_point, CaptureBuffer, CaptureMonitor, CaptureTrigger, DecodedCode,
+    ImageHost, ImageHostingAuthMode, ImageUploadHttpMethod, MonitorGeometry, OcrEngine,
+    OcrLanguage, OcrResult, ScreenRegion, ThemeMode, UserSettings, WindowInfo,
 };
 use crate::global_constants;
-use crate::ports::{GlobalKeyboardEvent, TrayEvent};
+use crate::ports::{GlobalKeyboardEvent, RecentCaptureMenuItem, TrayEvent};
 use crate::presentation::app_theme;
+use crate::presentation::{CaptureHistoryMessage, CaptureHistoryView};
 use crate::presentation::{CaptureView, CaptureViewMessage, OnboardingMessage, OnboardingView};
 use crate::presentation::{WindowPickerMessage, WindowPickerView};
 
 const CORRELATION_ID_STARTUP: &str = "startup";
 const CORRELATION_ID_ORCHESTRATOR_PREFIX: &str = "orchestrator-";
 
+mod all_monitors_capture;
+mod annotation_project;
 mod capture;
+mod capture_history;
+mod clipboard_capture;
+mod dropped_file;
 mod image_actions;
 mod interactive_ocr;
+mod main_window_toast;
 mod onboarding;
+mod recent_regions;
+mod search_progress;
 mod settings;
 mod ui;
+mod window_geometry;
 mod window_lifecycle;
 mod window_picker;
 
@@ -38,6 +52,16 @@ pub enum AppWindow {
     Onboarding(OnboardingView),
     Hidden,
     WindowPicker(WindowPickerView),
+    CaptureHistory(CaptureHistoryView),
+    QuitConfirmation,
+    TrayNotice,
+    SearchProgress(String, ToastKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToastKind {
+    Error,
+    Info,
 }
 
 #[derive(Debug, Clone)]
@@ -48,21 +72,49 @@ pub enum SettingsEditState {
 
 pub struct AppOrchestrator {
     screen_capturer: Arc<dyn ScreenCapturer>,
-    #[allow(dead_code)]
     mouse_provider: Arc<dyn MousePositionProvider>,
     ocr_service: Arc<dyn OcrService>,
+    ocr_language_services: HashMap<String, Arc<dyn OcrService>>,
+    ocr_builds_in_flight: Arc<AtomicUsize>,
+    barcode_service: Arc<dyn BarcodeService>,
     reverse_image_search_provider: Arc<dyn ReverseImageSearchProvider>,
+    translation_service: Arc<dyn TranslationService>,
     windows: HashMap<Id, AppWindow>,
     main_window_id: Option<Id>,
     onboarding_window_id: Option<Id>,
     hidden_window_id: Option<Id>,
     window_picker_window_id: Option<Id>,
+    capture_history_window_id: Option<Id>,
+    quit_confirmation_window_id: Option<Id>,
+    tray_notice_window_id: Option<Id>,
     status: String,
     settings: UserSettings,
     settings_window_id: Option<Id>,
     settings_edit_state: SettingsEditState,
     pending_draw_strokes: Option<Vec<crate::presentation::DrawStroke>>,
+    is_reopening_history_capture: bool,
+    is_pending_crop_masked: bool,
+    capture_overlay_monitor_origin: Option<(i32, i32)>,
+    recrop_overlay: Option<(Id, Id)>,
+    is_initialized: bool,
+    pending_tray_events: Vec<TrayEvent>,
+    tray_foreground_window_id: Option<u32>,
+    last_capture: Option<(u64, std::time::Instant, Id)>,
+    pending_project_annotations: Option<(
+        Vec<crate::core::models::AnnotationStroke>,
+        Vec<crate::core::models::AnnotationStepMarker>,
+    )>,
     current_correlation_id: String,
+    ocr_task_handles: HashMap<Id, iced::task::Handle>,
+    search_task_handles: HashMap<Id, iced::task::Handle>,
+    is_recording_hotkey: bool,
+    system_theme: Option<ThemeMode>,
+    window_geometries: HashMap<Id, window_geometry::TrackedWindowGeometry>,
+    is_main_window_minimized_for_capture: bool,
+    is_hotkey_enabled: bool,
+    main_window_toast: Option<(String, ToastKind)>,
+    main_window_toast_generation: u64,
+    audio_feedback: crate::adapters::audio_feedback::AudioFeedbackPlayer,
 }
 
 #[derive(Clone)]
@@ -70,27 +122,45 @@ pub enum OrchestratorMessage {
     #[allow(dead_code)]
     OpenMainWindow,
     CreateHiddenWindow,
+    InitializationComplete,
     CaptureScreen,
     PerformCapture,
+    MainWindowMinimizedForCapture,
     OpenCaptureOverlay(i32, i32, CaptureBuffer),
     CaptureError(String),
     CaptureOverlayMessage(Id, CaptureViewMessage),
     ConfirmSelection(Id),
+    SearchSelection(Id),
+    StartScrollCapture(Id),
+    CaptureRecentRegion(usize),
+    RecentRegionCaptured(Result<(CaptureBuffer, crate::core::models::RecentRegion), String>),
+    ScrollCaptureFinished(Result<CaptureBuffer, String>),
     ShowCroppedImage(CaptureBuffer, Rectangle),
     ProcessOcr(Id, CaptureBuffer),
     OcrComplete(Id, Result<OcrResult, String>),
+    BarcodesDecoded(Id, Vec<DecodedCode>),
+    ImageSearchCompleted(Id, String),
     OcrServiceReady(Arc<dyn OcrService>),
+    OcrServiceFallbackReady(Arc<dyn OcrService>, OcrEngine),
+    OcrModelDownloadProgress(crate::core::models::ModelDownloadProgress),
     OcrServiceFailed(String),
+    OcrTrainingDataUnavailable(OcrLanguage),
+    OcrLanguageServiceReady(Id, String, Result<Arc<dyn OcrService>, String>),
     InteractiveOcrMessage(Id, crate::presentation::InteractiveOcrMessage),
     PerformImageSearch(Id, CaptureBuffer, Option<String>),
+    PerformTextSearch(Id, String),
     SpinnerTick,
-    #[allow(dead_code)]
     CloseWindow(Id),
     WindowClosed(Id),
     WindowFocused(Id),
+    WindowGeometryChanged(Id, Option<Point>, Option<Size>),
+    CancelOcr(Id),
+    CancelSearch(Id),
     Keyboard(GlobalKeyboardEvent),
     OpenSettings,
     UpdateSearchUrl(String),
+    ResetSearchUrl,
+    UpdateTextSearchUrl(String),
     UpdateImageHostingProviderUrl(String),
     UpdateImageHostingAuthMode(ImageHostingAuthMode),
     UpdateImageHostingPublicKeyName(String),
@@ -98,9 +168,57 @@ pub enum OrchestratorMessage {
     UpdateImageHostingExpirationSeconds(String),
     UpdateImageHostingHttpMethod(ImageUploadHttpMethod),
     UpdateImageHostingImageFieldName(String),
+    UpdateImageHost(ImageHost),
+    UpdateCustomImageHostUrl(String),
+    UpdateCustomImageHostFieldName(String),
     UpdateHotkey(String),
+    RecordingHotkey(bool),
+    HotkeyKeyPressed(iced::keyboard::Key, iced::keyboard::Modifiers),
+    UpdateDrawModeShortcut(String),
+    UpdateSaveImageShortcut(String),
+    UpdateCopyImageShortcut(String),
     UpdateTheme(ThemeMode),
+    UpdateOcrEngine(OcrEngine),
+    UpdateOcrLanguage(OcrLanguage),
+    UpdateSearchEngine(crate::core::models::SearchEngine),
+    UpdateCaptureTrigger(CaptureTrigger),
+    UpdateCaptureMonitor(CaptureMonitor),
     UpdateSystemTrayMode(bool),
+    UpdateConfirmQuitWithUnsavedAnnotations(bool),
+    UpdateCaptureDelayMs(u32),
+    UpdateScrollCaptureDelayMs(u32),
+    UpdateCaptureRegionWidth(u32),
+    UpdateCaptureRegionHeight(u32),
+    UpdateMaxHistoryEntries(u32),
+    UpdateOcrTimeoutSecs(u32),
+    UpdateSaveFormat(crate::core::models::ImageSaveFormat),
+    UpdateJpegQuality(u8),
+    UpdateLogLevel(crate::core::models::LogLevel),
+    ClearCache,
+    CacheCleared(u64),
+    UpdateMinOcrConfidence(f32),
+    UpdateEnableTypeOutText(bool),
+    TypeOutTextFinished(Result<(), String>),
+    UpdateAutoRunOcr(bool),
+    UpdateQuitOnMainWindowClose(bool),
+    UpdateSuspendHotkeyInFullscreen(bool),
+    UpdateAutoCloseAfterAction(bool),
+    UpdateMinimizeOnCapture(bool),
+    UpdatePinResultWindowOnTop(bool),
+    UpdateShowMagnifier(bool),
+    UpdateOpenSearchInPrivateWindow(bool),
+    UpdateAutoSaveCaptures(bool),
+    UpdateScreenshotSaveLocation(String),
+    UpdateAutoSaveLocation(String),
+    UpdateTranslationTargetLanguage(String),
+    UpdateTranslationApiKey(String),
+    UpdateIncludeAnnotationsInSearch(bool),
+    UpdateShowHelpHint(bool),
+    UpdatePlayCaptureSound(bool),
+    UpdatePlaySuccessSound(bool),
+    UpdatePlayFailureSound(bool),
+    UpdateShowCaptureFlash(bool),
+    UpdateAutoCopyOnOcr(bool),
     SaveSettings,
     RestartApp,
     TrayEvent(TrayEvent),
@@ -109,8 +227,20 @@ pub enum OrchestratorMessage {
     OpenOnboarding,
     OnboardingMsg(Id, OnboardingMessage),
     EnableKeyboardListener,
+    RecentCapturesChanged(Vec<RecentCaptureMenuItem>),
+    HotkeyEnabledChanged(bool),
+    SystemThemeChanged(Option<ThemeMode>),
     CopyImageToClipboard(Id, CaptureBuffer, Vec<crate::presentation::DrawStroke>),
     SaveImageToFile(Id, CaptureBuffer, Vec<crate::presentation::DrawStroke>),
+    OpenAnnotationProject,
+    AnnotationProjectFileChosen(Option<std::path::PathBuf>),
+    AnnotationProjectLoaded(
+        Result<(CaptureBuffer, crate::core::models::AnnotationProject), String>,
+    ),
+    OpenCaptureHistory,
+    CaptureHistoryMsg(Id, CaptureHistoryMessage),
+    CaptureHistoryLoaded(Id, Vec<crate::presentation::CaptureHistoryItem>),
+    CaptureHistoryEntryLoaded(Result<CaptureBuffer, String>),
     #[allow(dead_code)]
     OpenWindowPicker,
     WindowPickerMsg(Id, WindowPickerMessage),
@@ -120,6 +250,20 @@ pub enum OrchestratorMessage {
     CaptureSelectedWindow(u32),
     WindowCaptureComplete(CaptureBuffer),
     WindowCaptureError(String),
+    CaptureActiveWindow,
+    TrayForegroundWindowRecorded(Option<u32>),
+    FullscreenCheckCompleted(CaptureTrigger, Result<bool, String>),
+    ActiveWindowCaptureFallback(CaptureBuffer),
+    CaptureAllMonitors,
+    AllMonitorsCaptured(Result<Vec<CaptureBuffer>, String>),
+    OcrClipboardImage,
+    ClipboardImageRead(Result<CaptureBuffer, String>),
+    FileDropped(Id, std::path::PathBuf),
+    DroppedFileLoaded(Result<CaptureBuffer, String>),
+    ConfirmQuit,
+    CancelQuit,
+    DismissTrayNotice,
+    HideMainWindowToast(u64),
 }
 
 impl std::fmt::Debug for OrchestratorMessage {
@@ -127,6 +271,7 @@ impl std::fmt::Debug for OrchestratorMessage {
         match self {
             OrchestratorMessage::OpenMainWindow => write!(f, "OpenMainWindow"),
             OrchestratorMessage::CreateHiddenWindow => write!(f, "CreateHiddenWindow"),
+            OrchestratorMessage::InitializationComplete => write!(f, "InitializationComplete"),
             OrchestratorMessage::CaptureScreen => write!(f, "CaptureScreen"),
             OrchestratorMessage::PerformCapture => write!(f, "PerformCapture"),
             OrchestratorMessage::OpenCaptureOverlay(x, y, _) => {
@@ -137,6 +282,19 @@ impl std::fmt::Debug for OrchestratorMessage {
                 write!(f, "CaptureOverlayMessage({:?})", id)
             }
             OrchestratorMessage::ConfirmSelection(id) => write!(f, "ConfirmSelection({:?})", id),
+            OrchestratorMessage::SearchSelection(id) => write!(f, "SearchSelection({:?})", id),
+            OrchestratorMessage::StartScrollCapture(id) => {
+                write!(f, "StartScrollCapture({:?})", id)
+            }
+            OrchestratorMessage::CaptureRecentRegion(index) => {
+                write!(f, "CaptureRecentRegion({})", index)
+            }
+            OrchestratorMessage::RecentRegionCaptured(result) => {
+                write!(f, "RecentRegionCaptured(success={})", result.is_ok())
+            }
+            OrchestratorMessage::ScrollCaptureFinished(result) => {
+                write!(f, "ScrollCaptureFinished(success={})", result.is_ok())
+            }
             OrchestratorMessage::ShowCroppedImage(_, rect) => {
                 write!(f, "ShowCroppedImage({:?})", rect)
             }
@@ -144,21 +302,55 @@ impl std::fmt::Debug for OrchestratorMessage {
             OrchestratorMessage::OcrComplete(id, result) => {
                 write!(f, "OcrComplete({:?}, {:?})", id, result.is_ok())
             }
+            OrchestratorMessage::BarcodesDecoded(id, codes) => {
+                write!(f, "BarcodesDecoded({:?}, {})", id, codes.len())
+            }
+            OrchestratorMessage::ImageSearchCompleted(id, _) => {
+                write!(f, "ImageSearchCompleted({:?})", id)
+            }
             OrchestratorMessage::OcrServiceReady(_) => write!(f, "OcrServiceReady"),
+            OrchestratorMessage::OcrLanguageServiceReady(id, language_code, result) => write!(
+                f,
+                "OcrLanguageServiceReady({:?}, {}, {})",
+                id,
+                language_code,
+                if result.is_ok() { "Ok" } else { "Err" }
+            ),
+            OrchestratorMessage::OcrServiceFallbackReady(_, engine) => {
+                write!(f, "OcrServiceFallbackReady({})", engine)
+            }
+            OrchestratorMessage::OcrModelDownloadProgress(progress) => {
+                write!(f, "OcrModelDownloadProgress({:?})", progress.percent())
+            }
             OrchestratorMessage::OcrServiceFailed(e) => write!(f, "OcrServiceFailed({})", e),
+            OrchestratorMessage::OcrTrainingDataUnavailable(language) => {
+                write!(f, "OcrTrainingDataUnavailable({})", language.code())
+            }
             OrchestratorMessage::InteractiveOcrMessage(id, _) => {
                 write!(f, "InteractiveOcrMessage({:?})", id)
             }
             OrchestratorMessage::PerformImageSearch(id, _, query) => {
                 write!(f, "PerformImageSearch({:?}, query={:?})", id, query)
             }
+            OrchestratorMessage::PerformTextSearch(id, query) => {
+                write!(f, "PerformTextSearch({:?}, query={:?})", id, query)
+            }
             OrchestratorMessage::SpinnerTick => write!(f, "SpinnerTick"),
             OrchestratorMessage::CloseWindow(id) => write!(f, "CloseWindow({:?})", id),
             OrchestratorMessage::WindowClosed(id) => write!(f, "WindowClosed({:?})", id),
             OrchestratorMessage::WindowFocused(id) => write!(f, "WindowFocused({:?})", id),
+            OrchestratorMessage::WindowGeometryChanged(id, position, size) => write!(
+                f,
+                "WindowGeometryChanged({:?}, {:?}, {:?})",
+                id, position, size
+            ),
+            OrchestratorMessage::CancelOcr(id) => write!(f, "CancelOcr({:?})", id),
+            OrchestratorMessage::CancelSearch(id) => write!(f, "CancelSearch({:?})", id),
             OrchestratorMessage::Keyboard(event) => write!(f, "Keyboard({:?})", event),
             OrchestratorMessage::OpenSettings => write!(f, "OpenSettings"),
             OrchestratorMessage::UpdateSearchUrl(_) => write!(f, "UpdateSearchUrl"),
+            OrchestratorMessage::ResetSearchUrl => write!(f, "ResetSearchUrl"),
+            OrchestratorMessage::UpdateTextSearchUrl(_) => write!(f, "UpdateTextSearchUrl"),
             OrchestratorMessage::UpdateImageHostingProviderUrl(_) => {
                 write!(f, "UpdateImageHostingProviderUrl")
             }
@@ -180,9 +372,153 @@ impl std::fmt::Debug for OrchestratorMessage {
             OrchestratorMessage::UpdateImageHostingImageFieldName(_) => {
                 write!(f, "UpdateImageHostingImageFieldName")
             }
+            OrchestratorMessage::UpdateImageHost(image_host) => {
+                write!(f, "UpdateImageHost({})", image_host)
+            }
+            OrchestratorMessage::UpdateCustomImageHostUrl(_) => {
+                write!(f, "UpdateCustomImageHostUrl")
+            }
+            OrchestratorMessage::UpdateCustomImageHostFieldName(_) => {
+                write!(f, "UpdateCustomImageHostFieldName")
+            }
             OrchestratorMessage::UpdateHotkey(_) => write!(f, "UpdateHotkey"),
+            OrchestratorMessage::RecordingHotkey(is_recording) => {
+                write!(f, "RecordingHotkey({})", is_recording)
+            }
+            OrchestratorMessage::HotkeyKeyPressed(key, modifiers) => {
+                write!(f, "HotkeyKeyPressed({:?}, {:?})", key, modifiers)
+            }
+            OrchestratorMessage::UpdateDrawModeShortcut(_) => write!(f, "UpdateDrawModeShortcut"),
+            OrchestratorMessage::UpdateSaveImageShortcut(_) => {
+                write!(f, "UpdateSaveImageShortcut")
+            }
+            OrchestratorMessage::UpdateCopyImageShortcut(_) => {
+                write!(f, "UpdateCopyImageShortcut")
+            }
             OrchestratorMessage::UpdateTheme(_) => write!(f, "UpdateTheme"),
+            OrchestratorMessage::UpdateCaptureTrigger(trigger) => {
+                write!(f, "UpdateCaptureTrigger({})", trigger)
+            }
+            OrchestratorMessage::UpdateCaptureMonitor(monitor) => {
+                write!(f, "UpdateCaptureMonitor({})", monitor)
+            }
+            OrchestratorMessage::UpdateOcrLanguage(language) => {
+                write!(f, "UpdateOcrLanguage({})", language.code())
+            }
+            OrchestratorMessage::UpdateSearchEngine(engine) => {
+                write!(f, "UpdateSearchEngine({})", engine)
+            }
+            OrchestratorMessage::UpdateOcrEngine(engine) => {
+                write!(f, "UpdateOcrEngine({})", engine)
+            }
             OrchestratorMessage::UpdateSystemTrayMode(_) => write!(f, "UpdateSystemTrayMode"),
+            OrchestratorMessage::UpdateConfirmQuitWithUnsavedAnnotations(_) => {
+                write!(f, "UpdateConfirmQuitWithUnsavedAnnotations")
+            }
+            OrchestratorMessage::UpdateCaptureDelayMs(delay_ms) => {
+                write!(f, "UpdateCaptureDelayMs({})", delay_ms)
+            }
+            OrchestratorMessage::UpdateScrollCaptureDelayMs(delay_ms) => {
+                write!(f, "UpdateScrollCaptureDelayMs({})", delay_ms)
+            }
+            OrchestratorMessage::UpdateCaptureRegionWidth(width) => {
+                write!(f, "UpdateCaptureRegionWidth({})", width)
+            }
+            OrchestratorMessage::UpdateCaptureRegionHeight(height) => {
+                write!(f, "UpdateCaptureRegionHeight({})", height)
+            }
+            OrchestratorMessage::UpdateMaxHistoryEntries(max_entries) => {
+                write!(f, "UpdateMaxHistoryEntries({})", max_entries)
+            }
+            OrchestratorMessage::UpdateMinOcrConfidence(min_confidence) => {
+                write!(f, "UpdateMinOcrConfidence({:.2})", min_confidence)
+            }
+            OrchestratorMessage::UpdateOcrTimeoutSecs(timeout_secs) => {
+                write!(f, "UpdateOcrTimeoutSecs({})", timeout_secs)
+            }
+            OrchestratorMessage::UpdateSaveFormat(save_format) => {
+                write!(f, "UpdateSaveFormat({})", save_format)
+            }
+            OrchestratorMessage::UpdateJpegQuality(jpeg_quality) => {
+                write!(f, "UpdateJpegQuality({})", jpeg_quality)
+            }
+            OrchestratorMessage::UpdateLogLevel(log_level) => {
+                write!(f, "UpdateLogLevel({})", log_level)
+            }
+            OrchestratorMessage::ClearCache => write!(f, "ClearCache"),
+            OrchestratorMessage::HideMainWindowToast(generation) => {
+                write!(f, "HideMainWindowToast({})", generation)
+            }
+            OrchestratorMessage::CacheCleared(freed_bytes) => {
+                write!(f, "CacheCleared({})", freed_bytes)
+            }
+            OrchestratorMessage::UpdateEnableTypeOutText(enabled) => {
+                write!(f, "UpdateEnableTypeOutText({})", enabled)
+            }
+            OrchestratorMessage::TypeOutTextFinished(result) => {
+                write!(f, "TypeOutTextFinished(success={})", result.is_ok())
+            }
+            OrchestratorMessage::UpdateAutoRunOcr(enabled) => {
+                write!(f, "UpdateAutoRunOcr({})", enabled)
+            }
+            OrchestratorMessage::UpdateQuitOnMainWindowClose(enabled) => {
+                write!(f, "UpdateQuitOnMainWindowClose({})", enabled)
+            }
+            OrchestratorMessage::UpdateSuspendHotkeyInFullscreen(enabled) => {
+                write!(f, "UpdateSuspendHotkeyInFullscreen({})", enabled)
+            }
+            OrchestratorMessage::UpdateAutoCloseAfterAction(enabled) => {
+                write!(f, "UpdateAutoCloseAfterAction({})", enabled)
+            }
+            OrchestratorMessage::UpdateMinimizeOnCapture(enabled) => {
+                write!(f, "UpdateMinimizeOnCapture({})", enabled)
+            }
+            OrchestratorMessage::MainWindowMinimizedForCapture => {
+                write!(f, "MainWindowMinimizedForCapture")
+            }
+            OrchestratorMessage::UpdatePinResultWindowOnTop(enabled) => {
+                write!(f, "UpdatePinResultWindowOnTop({})", enabled)
+            }
+            OrchestratorMessage::UpdateShowMagnifier(enabled) => {
+                write!(f, "UpdateShowMagnifier({})", enabled)
+            }
+            OrchestratorMessage::UpdateOpenSearchInPrivateWindow(enabled) => {
+                write!(f, "UpdateOpenSearchInPrivateWindow({})", enabled)
+            }
+            OrchestratorMessage::UpdateAutoSaveCaptures(enabled) => {
+                write!(f, "UpdateAutoSaveCaptures({})", enabled)
+            }
+            OrchestratorMessage::UpdateScreenshotSaveLocation(_) => {
+                write!(f, "UpdateScreenshotSaveLocation")
+            }
+            OrchestratorMessage::UpdateAutoSaveLocation(_) => write!(f, "UpdateAutoSaveLocation"),
+            OrchestratorMessage::UpdateTranslationTargetLanguage(_) => {
+                write!(f, "UpdateTranslationTargetLanguage")
+            }
+            OrchestratorMessage::UpdateTranslationApiKey(_) => {
+                write!(f, "UpdateTranslationApiKey")
+            }
+            OrchestratorMessage::UpdateIncludeAnnotationsInSearch(enabled) => {
+                write!(f, "UpdateIncludeAnnotationsInSearch({})", enabled)
+            }
+            OrchestratorMessage::UpdateShowHelpHint(enabled) => {
+                write!(f, "UpdateShowHelpHint({})", enabled)
+            }
+            OrchestratorMessage::UpdatePlayCaptureSound(enabled) => {
+                write!(f, "UpdatePlayCaptureSound({})", enabled)
+            }
+            OrchestratorMessage::UpdatePlaySuccessSound(enabled) => {
+                write!(f, "UpdatePlaySuccessSound({})", enabled)
+            }
+            OrchestratorMessage::UpdatePlayFailureSound(enabled) => {
+                write!(f, "UpdatePlayFailureSound({})", enabled)
+            }
+            OrchestratorMessage::UpdateShowCaptureFlash(enabled) => {
+                write!(f, "UpdateShowCaptureFlash({})", enabled)
+            }
+            OrchestratorMessage::UpdateAutoCopyOnOcr(enabled) => {
+                write!(f, "UpdateAutoCopyOnOcr({})", enabled)
+            }
             OrchestratorMessage::SaveSettings => write!(f, "SaveSettings"),
             OrchestratorMessage::RestartApp => write!(f, "RestartApp"),
             OrchestratorMessage::TrayEvent(event) => write!(f, "TrayEvent({:?})", event),
@@ -190,12 +526,43 @@ impl std::fmt::Debug for OrchestratorMessage {
             OrchestratorMessage::OpenOnboarding => write!(f, "OpenOnboarding"),
             OrchestratorMessage::OnboardingMsg(id, _) => write!(f, "OnboardingMsg({:?})", id),
             OrchestratorMessage::EnableKeyboardListener => write!(f, "EnableKeyboardListener"),
+            OrchestratorMessage::RecentCapturesChanged(captures) => {
+                write!(f, "RecentCapturesChanged({} captures)", captures.len())
+            }
+            OrchestratorMessage::HotkeyEnabledChanged(enabled) => {
+                write!(f, "HotkeyEnabledChanged({})", enabled)
+            }
+            OrchestratorMessage::SystemThemeChanged(detected_theme) => {
+                write!(f, "SystemThemeChanged({:?})", detected_theme)
+            }
             OrchestratorMessage::CopyImageToClipboard(id, _, _) => {
                 write!(f, "CopyImageToClipboard({:?})", id)
             }
             OrchestratorMessage::SaveImageToFile(id, _, _) => {
                 write!(f, "SaveImageToFile({:?})", id)
             }
+            OrchestratorMessage::OpenAnnotationProject => write!(f, "OpenAnnotationProject"),
+            OrchestratorMessage::AnnotationProjectFileChosen(path) => {
+                write!(f, "AnnotationProjectFileChosen({:?})", path)
+            }
+            OrchestratorMessage::AnnotationProjectLoaded(result) => {
+                write!(f, "AnnotationProjectLoaded(success={})", result.is_ok())
+            }
+            OrchestratorMessage::OpenCaptureHistory => write!(f, "OpenCaptureHistory"),
+            OrchestratorMessage::CaptureHistoryMsg(id, _) => {
+                write!(f, "CaptureHistoryMsg({:?})", id)
+            }
+            OrchestratorMessage::CaptureHistoryLoaded(id, entries) => {
+                write!(
+                    f,
+                    "CaptureHistoryLoaded({:?}, {} entries)",
+                    id,
+                    entries.len()
+                )
+            }
+            OrchestratorMessage::CaptureHistoryEntryLoaded(result) => {
+                write!(f, "CaptureHistoryEntryLoaded(success={})", result.is_ok())
+            }
             OrchestratorMessage::OpenWindowPicker => write!(f, "OpenWindowPicker"),
             OrchestratorMessage::WindowPickerMsg(id, _) => {
                 write!(f, "WindowPickerMsg({:?})", id)
@@ -215,6 +582,46 @@ impl std::fmt::Debug for OrchestratorMessage {
             OrchestratorMessage::WindowCaptureError(e) => {
                 write!(f, "WindowCaptureError({})", e)
             }
+            OrchestratorMessage::CaptureActiveWindow => write!(f, "CaptureActiveWindow"),
+            OrchestratorMessage::CaptureAllMonitors => write!(f, "CaptureAllMonitors"),
+            OrchestratorMessage::OcrClipboardImage => write!(f, "OcrClipboardImage"),
+            OrchestratorMessage::ClipboardImageRead(result) => match result {
+                Ok(capture_buffer) => write!(
+                    f,
+                    "ClipboardImageRead(Ok({}x{}))",
+                    capture_buffer.width, capture_buffer.height
+                ),
+                Err(error) => write!(f, "ClipboardImageRead(Err({}))", error),
+            },
+            OrchestratorMessage::FileDropped(id, path) => {
+                write!(f, "FileDropped({:?}, {})", id, path.display())
+            }
+            OrchestratorMessage::DroppedFileLoaded(result) => match result {
+                Ok(capture_buffer) => write!(
+                    f,
+                    "DroppedFileLoaded(Ok({}x{}))",
+                    capture_buffer.width, capture_buffer.height
+                ),
+                Err(error) => write!(f, "DroppedFileLoaded(Err({}))", error),
+            },
+            OrchestratorMessage::AllMonitorsCaptured(result) => match result {
+                Ok(capture_buffers) => {
+                    write!(f, "AllMonitorsCaptured(Ok({}))", capture_buffers.len())
+                }
+                Err(error) => write!(f, "AllMonitorsCaptured(Err({}))", error),
+            },
+            OrchestratorMessage::TrayForegroundWindowRecorded(window_id) => {
+                write!(f, "TrayForegroundWindowRecorded({:?})", window_id)
+            }
+            OrchestratorMessage::FullscreenCheckCompleted(source, result) => {
+                write!(f, "FullscreenCheckCompleted({}, {:?})", source, result)
+            }
+            OrchestratorMessage::ActiveWindowCaptureFallback(_) => {
+                write!(f, "ActiveWindowCaptureFallback")
+            }
+            OrchestratorMessage::ConfirmQuit => write!(f, "ConfirmQuit"),
+            OrchestratorMessage::CancelQuit => write!(f, "CancelQuit"),
+            OrchestratorMessage::DismissTrayNotice => write!(f, "DismissTrayNotice"),
         }
     }
 }
@@ -224,25 +631,53 @@ impl AppOrchestrator {
         screen_capturer: Arc<dyn ScreenCapturer>,
         mouse_provider: Arc<dyn MousePositionProvider>,
         ocr_service: Arc<dyn OcrService>,
+        barcode_service: Arc<dyn BarcodeService>,
         reverse_image_search_provider: Arc<dyn ReverseImageSearchProvider>,
+        translation_service: Arc<dyn TranslationService>,
         settings: UserSettings,
     ) -> Self {
         Self {
             screen_capturer,
             mouse_provider,
             ocr_service,
+            ocr_language_services: HashMap::new(),
+            ocr_builds_in_flight: Arc::new(AtomicUsize::new(0)),
+            barcode_service,
             reverse_image_search_provider,
+            translation_service,
             windows: HashMap::new(),
             main_window_id: None,
             onboarding_window_id: None,
             hidden_window_id: None,
             window_picker_window_id: None,
+            capture_history_window_id: None,
+            quit_confirmation_window_id: None,
+            tray_notice_window_id: None,
             status: global_constants::STATUS_INITIALIZING.to_string(),
             settings,
             settings_window_id: None,
             settings_edit_state: SettingsEditState::Closed,
             pending_draw_strokes: None,
+            is_reopening_history_capture: false,
+            is_pending_crop_masked: false,
+            capture_overlay_monitor_origin: None,
+            recrop_overlay: None,
+            is_initialized: false,
+            pending_tray_events: Vec::new(),
+            tray_foreground_window_id: None,
+            last_capture: None,
+            pending_project_annotations: None,
             current_correlation_id: CORRELATION_ID_STARTUP.to_string(),
+            ocr_task_handles: HashMap::new(),
+            search_task_handles: HashMap::new(),
+            is_recording_hotkey: false,
+            system_theme: None,
+            window_geometries: HashMap::new(),
+            is_main_window_minimized_for_capture: false,
+            is_hotkey_enabled: true,
+            main_window_toast: None,
+            main_window_toast_generation: 0,
+            audio_feedback: crate::adapters::audio_feedback::AudioFeedbackPlayer::default(),
         }
     }
 
@@ -265,6 +700,7 @@ impl AppOrchestrator {
 
     pub(super) fn discard_settings_edit(&mut self) {
         self.settings_edit_state = SettingsEditState::Closed;
+        self.is_recording_hotkey = false;
     }
 
     pub(super) fn get_settings_for_rendering(&self) -> &UserSettings {
@@ -288,12 +724,33 @@ impl AppOrchestrator {
     }
 
     pub(super) fn take_settings_draft(&mut self) -> Option<UserSettings> {
+        self.is_recording_hotkey = false;
         match std::mem::replace(&mut self.settings_edit_state, SettingsEditState::Closed) {
             SettingsEditState::Closed => None,
             SettingsEditState::Editing(settings) => Some(settings),
         }
     }
 
+    pub(super) fn play_feedback_sound(
+        &self,
+        sound: crate::adapters::audio_feedback::FeedbackSound,
+    ) {
+        let is_enabled = match sound {
+            crate::adapters::audio_feedback::FeedbackSound::Capture => {
+                self.settings.play_capture_sound
+            }
+            crate::adapters::audio_feedback::FeedbackSound::Success => {
+                self.settings.play_success_sound
+            }
+            crate::adapters::audio_feedback::FeedbackSound::Failure => {
+                self.settings.play_failure_sound
+            }
+        };
+        if is_enabled {
+            self.audio_feedback.play(sound);
+        }
+    }
+
     pub(super) fn log_info_event(&self, event: &str, details: serde_json::Value) {
         log::info!(
             "{}",
@@ -368,6 +825,18 @@ impl AppOrchestrator {
         );
     }
 
+    pub(super) fn get_app_theme(&self) -> iced::Theme {
+        app_theme::get_theme(&self.settings.theme_mode, self.system_theme.as_ref())
+    }
+
+    pub fn is_following_system_theme(&self) -> bool {
+        self.get_settings_for_rendering().theme_mode == ThemeMode::System
+    }
+
+    pub fn is_recording_hotkey(&self) -> bool {
+        self.is_recording_hotkey
+    }
+
     #[allow(dead_code)]
     pub fn is_any_window_searching(&self) -> bool {
         for window in self.windows.values() {
@@ -398,11 +867,38 @@ impl AppOrchestrator {
         self.windows.insert(id, AppWindow::Hidden);
 
         task.discard()
+            .chain(Task::done(OrchestratorMessage::InitializationComplete))
     }
 
-    #[allow(dead_code)]
-    pub fn get_window_title(&self, _window: Id) -> String {
-        global_constants::APPLICATION_TITLE.to_string()
+    pub fn get_window_title(&self, window: Id) -> String {
+        let window_label = match self.windows.get(&window) {
+            Some(AppWindow::CaptureOverlay(_)) => Some(global_constants::WINDOW_TITLE_CAPTURE),
+            Some(AppWindow::InteractiveOcr(_)) => Some(global_constants::WINDOW_TITLE_RESULT),
+            Some(AppWindow::Settings) => Some(global_constants::WINDOW_TITLE_SETTINGS),
+            Some(AppWindow::Onboarding(_)) => Some(global_constants::WINDOW_TITLE_ONBOARDING),
+            Some(AppWindow::WindowPicker(_)) => Some(global_constants::WINDOW_TITLE_WINDOW_PICKER),
+            Some(AppWindow::CaptureHistory(_)) => {
+                Some(global_constants::WINDOW_TITLE_CAPTURE_HISTORY)
+            }
+            Some(AppWindow::QuitConfirmation) => {
+                Some(global_constants::WINDOW_TITLE_QUIT_CONFIRMATION)
+            }
+            Some(AppWindow::TrayNotice) => Some(global_constants::WINDOW_TITLE_TRAY_NOTICE),
+            Some(AppWindow::SearchProgress(..)) => {
+                Some(global_constants::WINDOW_TITLE_SEARCH_PROGRESS)
+            }
+            Some(AppWindow::Main) | Some(AppWindow::Hidden) | None => None,
+        };
+
+        match window_label {
+            Some(label) => format!(
+                "{}{}{}",
+                global_constants::APPLICATION_TITLE,
+                global_constants::WINDOW_TITLE_SEPARATOR,
+                label
+            ),
+            None => global_constants::APPLICATION_TITLE.to_string(),
+        }
     }
 
     pub fn update(&mut self, message: OrchestratorMessage) -> Task<OrchestratorMessage> {
@@ -421,6 +917,9 @@ impl AppOrchestrator {
             OrchestratorMessage::CreateHiddenWindow => {
                 return self.create_hidden_window();
             }
+            OrchestratorMessage::InitializationComplete => {
+                return self.handle_initialization_complete();
+            }
             OrchestratorMessage::CaptureScreen => {
                 return self.handle_capture_screen();
             }
@@ -435,7 +934,15 @@ impl AppOrchestrator {
             }
             OrchestratorMessage::Keyboard(GlobalKeyboardEvent::CaptureHotkeyPressed) => {
                 log::info!("[ORCHESTRATOR] Capture hotkey pressed (Alt+Shift+S)");
-                return self.update(OrchestratorMessage::CaptureScreen);
+                return self.handle_global_capture_trigger(CaptureTrigger::Keyboard);
+            }
+            OrchestratorMessage::Keyboard(GlobalKeyboardEvent::ActiveWindowHotkeyPressed) => {
+                log::info!("[ORCHESTRATOR] Active window hotkey pressed");
+                return self.handle_active_window_hotkey();
+            }
+            OrchestratorMessage::Keyboard(GlobalKeyboardEvent::MiddleClickHeld) => {
+                log::info!("[ORCHESTRATOR] Middle-click hold detected");
+                return self.handle_global_capture_trigger(CaptureTrigger::MiddleClickHold);
             }
             OrchestratorMessage::Keyboard(GlobalKeyboardEvent::EscapePressed) => {
                 return self.handle_escape_pressed();
@@ -446,6 +953,21 @@ impl AppOrchestrator {
             OrchestratorMessage::ConfirmSelection(overlay_id) => {
                 return self.handle_confirm_selection(overlay_id);
             }
+            OrchestratorMessage::SearchSelection(overlay_id) => {
+                return self.handle_search_selection(overlay_id);
+            }
+            OrchestratorMessage::StartScrollCapture(overlay_id) => {
+                return self.handle_start_scroll_capture(overlay_id);
+            }
+            OrchestratorMessage::CaptureRecentRegion(region_index) => {
+                return self.handle_capture_recent_region(region_index);
+            }
+            OrchestratorMessage::RecentRegionCaptured(capture_result) => {
+                return self.handle_recent_region_captured(capture_result);
+            }
+            OrchestratorMessage::ScrollCaptureFinished(result) => {
+                return self.handle_scroll_capture_finished(result);
+            }
             OrchestratorMessage::ShowCroppedImage(capture_buffer, selection_rect) => {
                 return self.handle_show_cropped_image(capture_buffer, selection_rect);
             }
@@ -455,18 +977,51 @@ impl AppOrchestrator {
             OrchestratorMessage::OcrComplete(window_id, result) => {
                 return self.handle_ocr_complete(window_id, result);
             }
+            OrchestratorMessage::BarcodesDecoded(window_id, codes) => {
+                return self.handle_barcodes_decoded(window_id, codes);
+            }
+            OrchestratorMessage::ImageSearchCompleted(window_id, hosted_image_url) => {
+                return self.handle_image_search_completed(window_id, hosted_image_url);
+            }
             OrchestratorMessage::OcrServiceReady(service) => {
                 return self.handle_ocr_service_ready(service);
             }
+            OrchestratorMessage::OcrServiceFallbackReady(service, fallback_engine) => {
+                return self.handle_ocr_service_fallback_ready(service, fallback_engine);
+            }
+            OrchestratorMessage::OcrModelDownloadProgress(progress) => {
+                return self.handle_ocr_model_download_progress(progress);
+            }
             OrchestratorMessage::OcrServiceFailed(error) => {
                 return self.handle_ocr_service_failed(error);
             }
+            OrchestratorMessage::OcrTrainingDataUnavailable(language) => {
+                return self.handle_ocr_training_data_unavailable(language);
+            }
+            OrchestratorMessage::OcrLanguageServiceReady(window_id, language_code, result) => {
+                return self.handle_ocr_language_service_ready(window_id, language_code, result);
+            }
             OrchestratorMessage::InteractiveOcrMessage(window_id, ocr_msg) => {
                 return self.handle_interactive_ocr_message(window_id, ocr_msg);
             }
+            OrchestratorMessage::CancelOcr(window_id) => {
+                return self.handle_interactive_ocr_message(
+                    window_id,
+                    crate::presentation::InteractiveOcrMessage::CancelOcr,
+                );
+            }
+            OrchestratorMessage::CancelSearch(window_id) => {
+                return self.handle_interactive_ocr_message(
+                    window_id,
+                    crate::presentation::InteractiveOcrMessage::CancelSearch,
+                );
+            }
             OrchestratorMessage::PerformImageSearch(window_id, buffer, query) => {
                 return self.handle_perform_image_search(window_id, buffer, query);
             }
+            OrchestratorMessage::PerformTextSearch(window_id, query) => {
+                return self.handle_perform_text_search(window_id, query);
+            }
             OrchestratorMessage::SpinnerTick => {
                 for (_window_id, window) in &mut self.windows {
                     if let AppWindow::InteractiveOcr(view) = window {
@@ -487,6 +1042,9 @@ impl AppOrchestrator {
             OrchestratorMessage::WindowFocused(id) => {
                 return self.handle_window_focused(id);
             }
+            OrchestratorMessage::WindowGeometryChanged(id, position, size) => {
+                return self.handle_window_geometry_changed(id, position, size);
+            }
             OrchestratorMessage::OpenSettings => {
                 return self.handle_open_settings();
             }
@@ -495,6 +1053,17 @@ impl AppOrchestrator {
                     settings.image_search_url_template = url;
                 });
             }
+            OrchestratorMessage::ResetSearchUrl => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.image_search_url_template =
+                        global_constants::DEFAULT_IMAGE_SEARCH_URL.to_string();
+                });
+            }
+            OrchestratorMessage::UpdateTextSearchUrl(url) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.text_search_url_template = url;
+                });
+            }
             OrchestratorMessage::UpdateImageHostingProviderUrl(provider_url) => {
                 let _ = self.update_settings_draft(|settings| {
                     settings.image_hosting_provider_url = provider_url;
@@ -533,16 +1102,82 @@ impl AppOrchestrator {
                     settings.image_hosting_image_field_name = image_field_name;
                 });
             }
+            OrchestratorMessage::UpdateImageHost(image_host) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.image_host = image_host;
+                });
+            }
+            OrchestratorMessage::UpdateCustomImageHostUrl(host_url) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.custom_image_host_url = host_url;
+                });
+            }
+            OrchestratorMessage::UpdateCustomImageHostFieldName(field_name) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.custom_image_host_field_name = field_name;
+                });
+            }
             OrchestratorMessage::UpdateHotkey(hotkey) => {
                 let _ = self.update_settings_draft(|settings| {
                     settings.capture_hotkey = hotkey;
                 });
             }
+            OrchestratorMessage::RecordingHotkey(is_recording) => {
+                self.handle_recording_hotkey(is_recording);
+            }
+            OrchestratorMessage::HotkeyKeyPressed(key, modifiers) => {
+                self.handle_hotkey_key_pressed(key, modifiers);
+            }
+            OrchestratorMessage::UpdateDrawModeShortcut(shortcut) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.draw_mode_shortcut = shortcut;
+                });
+            }
+            OrchestratorMessage::UpdateSaveImageShortcut(shortcut) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.save_image_shortcut = shortcut;
+                });
+            }
+            OrchestratorMessage::UpdateCopyImageShortcut(shortcut) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.copy_image_shortcut = shortcut;
+                });
+            }
             OrchestratorMessage::UpdateTheme(theme) => {
                 let _ = self.update_settings_draft(|settings| {
                     settings.theme_mode = theme;
                 });
             }
+            OrchestratorMessage::UpdateOcrEngine(engine) => {
+                let _ = self.update_settings_draft(|settings| {
+                    let is_language_supported = OcrLanguage::from_code(&settings.language)
+                        .is_some_and(|language| engine.supported_languages().contains(&language));
+                    if !is_language_supported {
+                        settings.language = OcrLanguage::default().code().to_string();
+                    }
+                    settings.ocr_engine = engine;
+                });
+            }
+            OrchestratorMessage::UpdateSearchEngine(engine) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.search_engine = engine;
+                });
+            }
+            OrchestratorMessage::UpdateOcrLanguage(language) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.language = language.code().to_string();
+                });
+            }
+            OrchestratorMessage::UpdateCaptureTrigger(trigger) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.capture_trigger = trigger;
+                });
+            }
+            OrchestratorMessage::UpdateCaptureMonitor(monitor) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.capture_monitor = monitor;
+                });
+            }
             OrchestratorMessage::UpdateSystemTrayMode(enabled) => {
                 self.settings.run_in_system_tray = enabled;
                 if let Err(save_error) = self.settings.save() {
@@ -557,6 +1192,192 @@ impl AppOrchestrator {
                     return self.handle_open_main_window();
                 }
             }
+            OrchestratorMessage::UpdateConfirmQuitWithUnsavedAnnotations(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.confirm_quit_with_unsaved_annotations = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateCaptureDelayMs(delay_ms) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.capture_delay_ms =
+                        delay_ms.min(global_constants::MAX_CAPTURE_DELAY_MS);
+                });
+            }
+            OrchestratorMessage::UpdateCaptureRegionWidth(width) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.capture_region_width =
+                        width.min(global_constants::MAX_CAPTURE_REGION_SIZE);
+                });
+            }
+            OrchestratorMessage::UpdateCaptureRegionHeight(height) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.capture_region_height =
+                        height.min(global_constants::MAX_CAPTURE_REGION_SIZE);
+                });
+            }
+            OrchestratorMessage::UpdateScrollCaptureDelayMs(delay_ms) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.scroll_capture_delay_ms =
+                        delay_ms.min(global_constants::MAX_SCROLL_CAPTURE_DELAY_MS);
+                });
+            }
+            OrchestratorMessage::UpdateMinOcrConfidence(min_confidence) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.min_ocr_confidence = min_confidence.clamp(0.0, 1.0);
+                });
+            }
+            OrchestratorMessage::UpdateMaxHistoryEntries(max_entries) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.max_history_entries =
+                        max_entries.min(global_constants::MAX_HISTORY_ENTRIES_LIMIT);
+                });
+            }
+            OrchestratorMessage::UpdateOcrTimeoutSecs(timeout_secs) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.ocr_timeout_secs = timeout_secs.clamp(
+                        global_constants::MIN_OCR_TIMEOUT_SECS,
+                        global_constants::MAX_OCR_TIMEOUT_SECS,
+                    );
+                });
+            }
+            OrchestratorMessage::UpdateSaveFormat(save_format) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.save_format = save_format;
+                });
+            }
+            OrchestratorMessage::UpdateJpegQuality(jpeg_quality) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.jpeg_quality = jpeg_quality.clamp(
+                        global_constants::MIN_JPEG_QUALITY,
+                        global_constants::MAX_JPEG_QUALITY,
+                    );
+                });
+            }
+            OrchestratorMessage::UpdateLogLevel(log_level) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.log_level = log_level;
+                });
+            }
+            OrchestratorMessage::ClearCache => {
+                return self.handle_clear_cache();
+            }
+            OrchestratorMessage::CacheCleared(freed_bytes) => {
+                return self.handle_cache_cleared(freed_bytes);
+            }
+            OrchestratorMessage::HideMainWindowToast(generation) => {
+                self.handle_hide_main_window_toast(generation);
+            }
+            OrchestratorMessage::UpdateEnableTypeOutText(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.enable_type_out_text = enabled;
+                });
+            }
+            OrchestratorMessage::TypeOutTextFinished(result) => {
+                return self.handle_type_out_text_finished(result);
+            }
+            OrchestratorMessage::UpdateAutoRunOcr(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.auto_run_ocr = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateQuitOnMainWindowClose(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.quit_on_main_window_close = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateSuspendHotkeyInFullscreen(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.suspend_hotkey_in_fullscreen = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateAutoCloseAfterAction(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.auto_close_after_action = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateMinimizeOnCapture(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.minimize_on_capture = enabled;
+                });
+            }
+            OrchestratorMessage::MainWindowMinimizedForCapture => {
+                self.is_main_window_minimized_for_capture = true;
+            }
+            OrchestratorMessage::UpdatePinResultWindowOnTop(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.pin_result_window_on_top = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateShowMagnifier(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.show_magnifier = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateOpenSearchInPrivateWindow(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.open_search_in_private_window = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateAutoSaveCaptures(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.auto_save_captures = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateScreenshotSaveLocation(location) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.screenshot_save_location = location;
+                });
+            }
+            OrchestratorMessage::UpdateAutoSaveLocation(location) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.auto_save_location = location;
+                });
+            }
+            OrchestratorMessage::UpdateTranslationTargetLanguage(language_code) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.translation_target_language = language_code.trim().to_string();
+                });
+            }
+            OrchestratorMessage::UpdateTranslationApiKey(api_key) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.translation_api_key = api_key.trim().to_string();
+                });
+            }
+            OrchestratorMessage::UpdateIncludeAnnotationsInSearch(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.include_annotations_in_search = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateShowHelpHint(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.show_help_hint = enabled;
+                });
+            }
+            OrchestratorMessage::UpdatePlayCaptureSound(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.play_capture_sound = enabled;
+                });
+            }
+            OrchestratorMessage::UpdatePlaySuccessSound(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.play_success_sound = enabled;
+                });
+            }
+            OrchestratorMessage::UpdatePlayFailureSound(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.play_failure_sound = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateShowCaptureFlash(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.show_capture_flash = enabled;
+                });
+            }
+            OrchestratorMessage::UpdateAutoCopyOnOcr(enabled) => {
+                let _ = self.update_settings_draft(|settings| {
+                    settings.auto_copy_on_ocr = enabled;
+                });
+            }
             OrchestratorMessage::SaveSettings => {
                 return self.handle_save_settings();
             }
@@ -578,12 +1399,52 @@ impl AppOrchestrator {
             OrchestratorMessage::EnableKeyboardListener => {
                 log::debug!("[ORCHESTRATOR] EnableKeyboardListener handled at app level");
             }
+            OrchestratorMessage::SystemThemeChanged(detected_theme) => {
+                self.log_info_event(
+                    "system_theme_changed",
+                    serde_json::json!({"theme": format!("{:?}", detected_theme)}),
+                );
+                self.system_theme = detected_theme;
+            }
+            OrchestratorMessage::RecentCapturesChanged(captures) => {
+                log::debug!(
+                    "[ORCHESTRATOR] RecentCapturesChanged({}) handled at app level",
+                    captures.len()
+                );
+            }
+            OrchestratorMessage::HotkeyEnabledChanged(enabled) => {
+                log::debug!(
+                    "[ORCHESTRATOR] HotkeyEnabledChanged({}) handled at app level",
+                    enabled
+                );
+            }
             OrchestratorMessage::CopyImageToClipboard(window_id, buffer, draw_strokes) => {
                 return self.handle_copy_image_to_clipboard(window_id, buffer, draw_strokes);
             }
             OrchestratorMessage::SaveImageToFile(window_id, buffer, draw_strokes) => {
                 return self.handle_save_image_to_file(window_id, buffer, draw_strokes);
             }
+            OrchestratorMessage::OpenAnnotationProject => {
+                return self.handle_open_annotation_project();
+            }
+            OrchestratorMessage::AnnotationProjectFileChosen(project_path) => {
+                return self.handle_annotation_project_file_chosen(project_path);
+            }
+            OrchestratorMessage::AnnotationProjectLoaded(load_result) => {
+                return self.handle_annotation_project_loaded(load_result);
+            }
+            OrchestratorMessage::OpenCaptureHistory => {
+                return self.handle_open_capture_history();
+            }
+            OrchestratorMessage::CaptureHistoryMsg(window_id, msg) => {
+                return self.handle_capture_history_message(window_id, msg);
+            }
+            OrchestratorMessage::CaptureHistoryLoaded(window_id, entries) => {
+                self.handle_capture_history_loaded(window_id, entries);
+            }
+            OrchestratorMessage::CaptureHistoryEntryLoaded(load_result) => {
+                return self.handle_capture_history_entry_loaded(load_result);
+            }
             OrchestratorMessage::OpenWindowPicker => {
                 return self.handle_open_window_picker();
             }
@@ -602,6 +1463,36 @@ impl AppOrchestrator {
             OrchestratorMessage::WindowCaptureComplete(capture_buffer) => {
                 return self.handle_window_capture_complete(capture_buffer);
             }
+            OrchestratorMessage::CaptureActiveWindow => {
+                return self.handle_capture_active_window(None);
+            }
+            OrchestratorMessage::TrayForegroundWindowRecorded(window_id) => {
+                self.handle_tray_foreground_window_recorded(window_id);
+            }
+            OrchestratorMessage::FullscreenCheckCompleted(source, result) => {
+                return self.handle_fullscreen_check_completed(source, result);
+            }
+            OrchestratorMessage::ActiveWindowCaptureFallback(capture_buffer) => {
+                return self.handle_active_window_capture_fallback(capture_buffer);
+            }
+            OrchestratorMessage::CaptureAllMonitors => {
+                return self.handle_capture_all_monitors();
+            }
+            OrchestratorMessage::AllMonitorsCaptured(capture_result) => {
+                return self.handle_all_monitors_captured(capture_result);
+            }
+            OrchestratorMessage::OcrClipboardImage => {
+                return self.handle_ocr_clipboard_image();
+            }
+            OrchestratorMessage::ClipboardImageRead(read_result) => {
+                return self.handle_clipboard_image_read(read_result);
+            }
+            OrchestratorMessage::FileDropped(window_id, path) => {
+                return self.handle_file_dropped(window_id, path);
+            }
+            OrchestratorMessage::DroppedFileLoaded(load_result) => {
+                return self.handle_dropped_file_loaded(load_result);
+            }
             OrchestratorMessage::WindowCaptureError(error_msg) => {
                 self.log_error_event(
                     "window_capture_failed",
@@ -610,6 +1501,17 @@ impl AppOrchestrator {
                     }),
                 );
             }
+            OrchestratorMessage::ConfirmQuit => {
+                self.log_info_event("quit_confirmed", serde_json::json!({}));
+                self.remember_open_window_geometries();
+                return iced::exit();
+            }
+            OrchestratorMessage::CancelQuit => {
+                return self.handle_cancel_quit();
+            }
+            OrchestratorMessage::DismissTrayNotice => {
+                return self.handle_dismiss_tray_notice();
+            }
         }
 
         self.log_info_event(
@@ -638,6 +1540,14 @@ impl AppOrchestrator {
             Some(AppWindow::WindowPicker(picker_view)) => picker_view
                 .render_ui()
                 .map(move |msg| OrchestratorMessage::WindowPickerMsg(window_id, msg)),
+            Some(AppWindow::CaptureHistory(history_view)) => history_view
+                .render_ui()
+                .map(move |msg| OrchestratorMessage::CaptureHistoryMsg(window_id, msg)),
+            Some(AppWindow::QuitConfirmation) => self.render_quit_confirmation_window(),
+            Some(AppWindow::TrayNotice) => self.render_tray_notice_window(),
+            Some(AppWindow::SearchProgress(message, kind)) => {
+                self.render_search_progress_window(window_id, message, *kind)
+            }
             None => text(global_constants::UI_GENERIC_LOADING).into(),
         }
     }
@@ -0,0 +1,81 @@
+use super::*;
+
+impl AppOrchestrator {
+    pub(super) fn handle_capture_all_monitors(&mut self) -> Task<OrchestratorMessage> {
+        self.log_info_event("all_monitors_capture_started", serde_json::json!({}));
+        self.status = global_constants::STATUS_CAPTURING_ALL_MONITORS.to_string();
+
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let capture_result =
+                tokio::task::spawn_blocking(move || screen_capturer.capture_all_monitors())
+                    .await
+                    .map_err(|join_error| join_error.to_string())
+                    .and_then(|result| result.map_err(|capture_error| capture_error.to_string()));
+            match &capture_result {
+                Ok(capture_buffers) => AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id,
+                    "all_monitors_capture_completed",
+                    serde_json::json!({"captured_monitors": capture_buffers.len()}),
+                ),
+                Err(capture_error) => AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id,
+                    "all_monitors_capture_failed",
+                    serde_json::json!({"error": capture_error}),
+                ),
+            }
+            OrchestratorMessage::AllMonitorsCaptured(capture_result)
+        })
+    }
+
+    pub(super) fn handle_all_monitors_captured(
+        &mut self,
+        capture_result: Result<Vec<CaptureBuffer>, String>,
+    ) -> Task<OrchestratorMessage> {
+        let capture_buffers = match capture_result {
+            Ok(capture_buffers) if !capture_buffers.is_empty() => capture_buffers,
+            Ok(_) => {
+                return self.handle_capture_error(
+                    global_constants::CAPTURE_ERROR_NO_MONITORS_CAPTURED.to_string(),
+                )
+            }
+            Err(capture_error) => return self.handle_capture_error(capture_error),
+        };
+
+        let monitor_count = capture_buffers.len();
+        let tasks = capture_buffers
+            .into_iter()
+            .map(|capture_buffer| {
+                self.last_capture = None;
+                self.open_capture_result_with_ocr(capture_buffer)
+            })
+            .collect::<Vec<_>>();
+        self.status = format!(
+            "{}{}",
+            global_constants::STATUS_ALL_MONITORS_CAPTURED_PREFIX,
+            monitor_count
+        );
+
+        Task::batch(tasks)
+    }
+
+    pub(super) fn open_capture_result_with_ocr(
+        &mut self,
+        capture_buffer: CaptureBuffer,
+    ) -> Task<OrchestratorMessage> {
+        let (opened_window_id, task) = self.open_window_capture(capture_buffer);
+
+        match opened_window_id {
+            Some(window_id) if !self.settings.auto_run_ocr => Task::batch(vec![
+                task,
+                Task::done(OrchestratorMessage::InteractiveOcrMessage(
+                    window_id,
+                    crate::presentation::InteractiveOcrMessage::StartOcr,
+                )),
+            ]),
+            _ => task,
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use super::*;
+use crate::core::models::{AnnotationProject, AnnotationStepMarker, AnnotationStroke};
+
+impl AppOrchestrator {
+    pub(super) fn handle_save_annotation_project(
+        &mut self,
+        window_id: Id,
+    ) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let buffer = view.get_capture_buffer().clone();
+        let (strokes, step_markers) = view.get_project_annotations();
+
+        self.log_info_event(
+            "annotation_project_save_started",
+            serde_json::json!({
+                "strokes": strokes.len(),
+                "step_markers": step_markers.len(),
+            }),
+        );
+
+        let save_location = self.settings.screenshot_save_location.clone();
+        let correlation_id = self.current_correlation_id();
+
+        Task::batch(vec![
+            Task::done(OrchestratorMessage::InteractiveOcrMessage(
+                window_id,
+                crate::presentation::InteractiveOcrMessage::SaveImageSaving,
+            )),
+            Task::future(async move {
+                let save_result = build_annotation_project(&buffer, strokes, step_markers)
+                    .and_then(|project| project.to_json().map_err(|error| error.to_string()))
+                    .and_then(|project_json| {
+                        crate::infrastructure::utils::save_annotation_project_to_file(
+                            &project_json,
+                            &save_location,
+                        )
+                    });
+
+                match save_result {
+                    Ok(path) => {
+                        AppOrchestrator::log_info_event_for_correlation(
+                            correlation_id,
+                            "annotation_project_save_succeeded",
+                            serde_json::json!({"path": path}),
+                        );
+                        OrchestratorMessage::InteractiveOcrMessage(
+                            window_id,
+                            crate::presentation::InteractiveOcrMessage::SaveSuccess(path),
+                        )
+                    }
+                    Err(save_error) => {
+                        AppOrchestrator::log_error_event_for_correlation(
+                            correlation_id,
+                            "annotation_project_save_failed",
+                            serde_json::json!({"error": save_error}),
+                        );
+                        OrchestratorMessage::InteractiveOcrMessage(
+                            window_id,
+                            crate::presentation::InteractiveOcrMessage::SaveFailed(save_error),
+                        )
+                    }
+                }
+            }),
+            Task::future(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(2600)).await;
+                OrchestratorMessage::InteractiveOcrMessage(
+                    window_id,
+                    crate::presentation::InteractiveOcrMessage::HideToast,
+                )
+            }),
+        ])
+    }
+
+    pub(super) fn handle_open_annotation_project(&mut self) -> Task<OrchestratorMessage> {
+        self.log_info_event("annotation_project_open_requested", serde_json::json!({}));
+
+        let save_location = self.settings.screenshot_save_location.clone();
+
+        Task::future(async move {
+            let chosen_file = rfd::AsyncFileDialog::new()
+                .set_title(global_constants::ANNOTATION_PROJECT_DIALOG_TITLE)
+                .add_filter(
+                    global_constants::ANNOTATION_PROJECT_FILTER_NAME,
+                    &[global_constants::ANNOTATION_PROJECT_FILE_EXTENSION],
+                )
+                .set_directory(save_location)
+                .pick_file()
+                .await;
+
+            OrchestratorMessage::AnnotationProjectFileChosen(
+                chosen_file.map(|file| file.path().to_path_buf()),
+            )
+        })
+    }
+
+    pub(super) fn handle_annotation_project_file_chosen(
+        &mut self,
+        project_path: Option<std::path::PathBuf>,
+    ) -> Task<OrchestratorMessage> {
+        let Some(project_path) = project_path else {
+            self.log_info_event("annotation_project_open_cancelled", serde_json::json!({}));
+            return Task::none();
+        };
+
+        self.log_info_event(
+            "annotation_project_loading",
+            serde_json::json!({"path": project_path.to_string_lossy()}),
+        );
+        self.status = global_constants::STATUS_LOADING_ANNOTATION_PROJECT.to_string();
+
+        Task::future(async move {
+            let load_result = tokio::task::spawn_blocking(move || {
+                let project_json =
+                    std::fs::read_to_string(&project_path).map_err(|error| error.to_string())?;
+                let project = AnnotationProject::from_json(&project_json)
+                    .map_err(|error| format!("{:#}", error))?;
+                let buffer = restore_capture_buffer_from_project(&project)?;
+                Ok((buffer, project))
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            OrchestratorMessage::AnnotationProjectLoaded(load_result)
+        })
+    }
+
+    pub(super) fn handle_annotation_project_loaded(
+        &mut self,
+        load_result: Result<(CaptureBuffer, AnnotationProject), String>,
+    ) -> Task<OrchestratorMessage> {
+        match load_result {
+            Ok((buffer, project)) => {
+                self.log_info_event(
+                    "annotation_project_loaded",
+                    serde_json::json!({
+                        "width": buffer.width,
+                        "height": buffer.height,
+                        "strokes": project.strokes.len(),
+                        "step_markers": project.step_markers.len(),
+                    }),
+                );
+                self.pending_project_annotations = Some((project.strokes, project.step_markers));
+
+                let full_rect = Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(buffer.width as f32, buffer.height as f32),
+                );
+                self.handle_show_cropped_image(buffer, full_rect)
+            }
+            Err(load_error) => {
+                self.log_error_event(
+                    "annotation_project_load_failed",
+                    serde_json::json!({"error": load_error}),
+                );
+                self.status = format!(
+                    "{}{}",
+                    global_constants::STATUS_ANNOTATION_PROJECT_LOAD_FAILED_PREFIX,
+                    load_error
+                );
+                Task::none()
+            }
+        }
+    }
+}
+
+fn build_annotation_project(
+    buffer: &CaptureBuffer,
+    strokes: Vec<AnnotationStroke>,
+    step_markers: Vec<AnnotationStepMarker>,
+) -> Result<AnnotationProject, String> {
+    let image_png_base64 = crate::infrastructure::utils::encode_rgba_as_png_base64(
+        &buffer.raw_data,
+        buffer.width,
+        buffer.height,
+    )?;
+
+    Ok(AnnotationProject {
+        format_version: global_constants::ANNOTATION_PROJECT_FORMAT_VERSION,
+        image_width: buffer.width,
+        image_height: buffer.height,
+        scale_factor: buffer._scale_factor,
+        image_png_base64,
+        strokes,
+        step_markers,
+    })
+}
+
+fn restore_capture_buffer_from_project(
+    project: &AnnotationProject,
+) -> Result<CaptureBuffer, String> {
+    let (rgba_data, width, height) =
+        crate::infrastructure::utils::decode_png_base64_to_rgba(&project.image_png_base64)?;
+
+    if width != project.image_width || height != project.image_height {
+        return Err(format!(
+            "Project image is {}x{} but the project expects {}x{}",
+            width, height, project.image_width, project.image_height
+        ));
+    }
+
+    Ok(CaptureBuffer::build_from_raw_data(
+        project.scale_factor,
+        width,
+        height,
+        rgba_data,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotation_project_restores_original_capture_buffer() {
+        let raw_data = vec![
+            255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 255, 255, 255,
+        ];
+        let buffer = CaptureBuffer::build_from_raw_data(2.0, 2, 2, raw_data.clone());
+        let strokes = vec![AnnotationStroke {
+            points: vec![(0.0, 0.0), (1.0, 1.0)],
+            color: (1.0, 0.0, 0.0, 1.0),
+            width: 3.0,
+            is_highlighter: false,
+        }];
+
+        let project = build_annotation_project(&buffer, strokes.clone(), Vec::new()).unwrap();
+        let restored = restore_capture_buffer_from_project(&project).unwrap();
+
+        assert_eq!(project.strokes, strokes);
+        assert_eq!(restored.width, 2);
+        assert_eq!(restored.height, 2);
+        assert_eq!(restored._scale_factor, 2.0);
+        assert_eq!(restored.raw_data, raw_data);
+    }
+
+    #[test]
+    fn test_restore_capture_buffer_rejects_mismatched_dimensions() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 1, 1, vec![0, 0, 0, 255]);
+        let mut project = build_annotation_project(&buffer, Vec::new(), Vec::new()).unwrap();
+        project.image_width = 5;
+
+        assert!(restore_capture_buffer_from_project(&project).is_err());
+    }
+}
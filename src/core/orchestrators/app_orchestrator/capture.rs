@@ -1,6 +1,101 @@
+use super::window_geometry::cascaded_window_position;
 use super::*;
 
 impl AppOrchestrator {
+    pub(super) fn handle_global_capture_trigger(
+        &mut self,
+        source: CaptureTrigger,
+    ) -> Task<OrchestratorMessage> {
+        if self.is_recording_hotkey {
+            self.log_info_event(
+                "capture_trigger_ignored_while_recording_hotkey",
+                serde_json::json!({"source": source.to_string()}),
+            );
+            return Task::none();
+        }
+
+        if !self.is_hotkey_enabled {
+            self.log_info_event(
+                "capture_trigger_ignored_while_hotkey_paused",
+                serde_json::json!({"source": source.to_string()}),
+            );
+            return Task::none();
+        }
+
+        if !is_capture_trigger_enabled(&self.settings.capture_trigger, &source) {
+            self.log_info_event(
+                "capture_trigger_ignored",
+                serde_json::json!({
+                    "source": source.to_string(),
+                    "configured_trigger": self.settings.capture_trigger.to_string(),
+                }),
+            );
+            return Task::none();
+        }
+
+        if !self.settings.suspend_hotkey_in_fullscreen {
+            return self.handle_capture_screen();
+        }
+
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+        Task::future(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                screen_capturer.is_foreground_window_fullscreen()
+            })
+            .await
+            .map_err(|join_error| join_error.to_string())
+            .and_then(|result| result.map_err(|error| error.to_string()));
+            OrchestratorMessage::FullscreenCheckCompleted(source, result)
+        })
+    }
+
+    pub(super) fn handle_fullscreen_check_completed(
+        &mut self,
+        source: CaptureTrigger,
+        result: Result<bool, String>,
+    ) -> Task<OrchestratorMessage> {
+        match result {
+            Ok(true) => {
+                self.log_info_event(
+                    "capture_trigger_suspended_for_fullscreen_app",
+                    serde_json::json!({"source": source.to_string()}),
+                );
+                self.status = global_constants::STATUS_CAPTURE_SUSPENDED_FOR_FULLSCREEN.to_string();
+                return self.show_main_window_toast(
+                    global_constants::STATUS_CAPTURE_SUSPENDED_FOR_FULLSCREEN.to_string(),
+                    ToastKind::Info,
+                );
+            }
+            Ok(false) => {}
+            Err(detection_error) => {
+                self.log_error_event(
+                    "fullscreen_detection_failed",
+                    serde_json::json!({"error": detection_error}),
+                );
+            }
+        }
+
+        self.handle_capture_screen()
+    }
+
+    pub(super) fn handle_toggle_hotkey(&mut self) -> Task<OrchestratorMessage> {
+        self.is_hotkey_enabled = !self.is_hotkey_enabled;
+        self.log_info_event(
+            "capture_hotkey_toggled",
+            serde_json::json!({"enabled": self.is_hotkey_enabled}),
+        );
+        self.status = if self.is_hotkey_enabled {
+            global_constants::STATUS_HOTKEY_RESUMED
+        } else {
+            global_constants::STATUS_HOTKEY_PAUSED
+        }
+        .to_string();
+
+        Task::done(OrchestratorMessage::HotkeyEnabledChanged(
+            self.is_hotkey_enabled,
+        ))
+    }
+
     pub(super) fn handle_capture_screen(&mut self) -> Task<OrchestratorMessage> {
         self.log_info_event(
             "capture_screen_started",
@@ -10,33 +105,75 @@ impl AppOrchestrator {
         );
         self.status = global_constants::STATUS_PREPARING_CAPTURE.to_string();
 
-        let main_window_id = self.main_window_id;
+        let main_window_id = self.main_window_in_capture_path(self.resolve_capture_point());
+        let capture_delay_ms =
+            resolve_capture_delay_ms(main_window_id.is_some(), self.settings.capture_delay_ms);
 
         self.log_info_event(
             "capture_screen_minimizing_window",
             serde_json::json!({
-                "has_main_window": main_window_id.is_some(),
+                "has_main_window": self.main_window_id.is_some(),
+                "main_window_in_capture_path": main_window_id.is_some(),
+                "minimize_on_capture": self.settings.minimize_on_capture,
+                "capture_delay_ms": capture_delay_ms,
             }),
         );
-        Task::batch(vec![
-            if let Some(id) = main_window_id {
-                window::minimize(id, true)
-            } else {
-                Task::none()
-            },
-            Task::future(async {
-                tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+        let capture_task = if capture_delay_ms == 0 {
+            Task::done(OrchestratorMessage::PerformCapture)
+        } else {
+            Task::future(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(capture_delay_ms as u64)).await;
                 OrchestratorMessage::PerformCapture
-            }),
+            })
+        };
+
+        Task::batch(vec![
+            self.minimize_main_window_for_capture(main_window_id),
+            capture_task,
         ])
     }
 
+    fn resolve_capture_point(&self) -> Option<(i32, i32)> {
+        let capture_region = match self.settings.capture_monitor {
+            CaptureMonitor::UnderCursor => self.mouse_provider.get_current_mouse_position().ok(),
+            CaptureMonitor::Primary => self.screen_capturer.get_primary_monitor_origin().ok(),
+        }?;
+
+        Some((capture_region.x_position, capture_region.y_position))
+    }
+
+    pub(super) fn minimize_main_window_for_capture(
+        &self,
+        main_window_id: Option<Id>,
+    ) -> Task<OrchestratorMessage> {
+        let Some(id) =
+            resolve_main_window_to_minimize(main_window_id, self.settings.minimize_on_capture)
+        else {
+            return Task::none();
+        };
+
+        window::is_minimized(id).then(move |was_minimized| {
+            if was_minimized == Some(true) {
+                return Task::none();
+            }
+            window::minimize(id, true).chain(Task::done(
+                OrchestratorMessage::MainWindowMinimizedForCapture,
+            ))
+        })
+    }
+
     pub(super) fn handle_perform_capture(&mut self) -> Task<OrchestratorMessage> {
         self.log_info_event("screen_capture_performing", serde_json::json!({}));
         self.status = global_constants::STATUS_CAPTURING_SCREEN.to_string();
 
         let screen_capturer = Arc::clone(&self.screen_capturer);
         let correlation_id = self.current_correlation_id();
+        let capture_monitor = self.settings.capture_monitor.clone();
+        let capture_region_size = (
+            self.settings.capture_region_width,
+            self.settings.capture_region_height,
+        );
 
         Task::future(async move {
             let (mouse_x, mouse_y) = match Mouse::get_mouse_position() {
@@ -58,7 +195,12 @@ impl AppOrchestrator {
                 }
             };
 
-            let region = ScreenRegion::at_coordinates(mouse_x, mouse_y);
+            let region = resolve_capture_region(
+                &capture_monitor,
+                ScreenRegion::at_coordinates(mouse_x, mouse_y),
+                || screen_capturer.get_primary_monitor_origin(),
+            );
+            let (capture_x, capture_y) = (region.x_position, region.y_position);
 
             match screen_capturer.capture_screen_at_region(&region) {
                 Ok(capture_buffer) => {
@@ -70,7 +212,48 @@ impl AppOrchestrator {
                             "height": capture_buffer.height,
                         }),
                     );
-                    OrchestratorMessage::OpenCaptureOverlay(mouse_x, mouse_y, capture_buffer)
+                    let (region_width, region_height) = capture_region_size;
+                    if region_width == 0 || region_height == 0 {
+                        return OrchestratorMessage::OpenCaptureOverlay(
+                            capture_x,
+                            capture_y,
+                            capture_buffer,
+                        );
+                    }
+
+                    let monitor = screen_capturer.list_monitors().ok().and_then(|monitors| {
+                        select_monitor_for_point(&monitors, capture_x, capture_y)
+                    });
+                    let Some(monitor) = monitor else {
+                        AppOrchestrator::log_error_event_for_correlation(
+                            correlation_id.clone(),
+                            "capture_region_monitor_unavailable",
+                            serde_json::json!({"fallback": "selection_overlay"}),
+                        );
+                        return OrchestratorMessage::OpenCaptureOverlay(
+                            capture_x,
+                            capture_y,
+                            capture_buffer,
+                        );
+                    };
+
+                    let selection_rect = resolve_cursor_centered_selection(
+                        &monitor,
+                        (mouse_x, mouse_y),
+                        (capture_buffer.width, capture_buffer.height),
+                        capture_region_size,
+                    );
+                    AppOrchestrator::log_info_event_for_correlation(
+                        correlation_id.clone(),
+                        "capture_region_resolved",
+                        serde_json::json!({
+                            "x": selection_rect.x,
+                            "y": selection_rect.y,
+                            "width": selection_rect.width,
+                            "height": selection_rect.height,
+                        }),
+                    );
+                    OrchestratorMessage::ShowCroppedImage(capture_buffer, selection_rect)
                 }
                 Err(capture_error) => {
                     AppOrchestrator::log_error_event_for_correlation(
@@ -99,24 +282,35 @@ impl AppOrchestrator {
             "capture_overlay_opening",
             serde_json::json!({"mouse_x": mouse_x, "mouse_y": mouse_y}),
         );
-        match xcap::Monitor::from_point(mouse_x, mouse_y) {
-            Ok(monitor) => {
-                self.log_info_event("capture_overlay_monitor_found", serde_json::json!({}));
+        let monitor = self.screen_capturer.list_monitors().and_then(|monitors| {
+            select_monitor_for_point(&monitors, mouse_x, mouse_y)
+                .map(|monitor| (monitor, monitor.overlay_origin(&monitors)))
+                .ok_or_else(|| anyhow::anyhow!("no monitor found near ({}, {})", mouse_x, mouse_y))
+        });
+        match monitor {
+            Ok((monitor, (overlay_x, overlay_y))) => {
+                self.log_info_event(
+                    "capture_overlay_monitor_found",
+                    serde_json::json!({
+                        "monitor_x": monitor.x,
+                        "monitor_y": monitor.y,
+                        "monitor_width": monitor.width,
+                        "monitor_height": monitor.height,
+                        "scale_factor": monitor.scale_factor,
+                    }),
+                );
+                self.capture_overlay_monitor_origin = Some((monitor.x, monitor.y));
+                let (overlay_width, overlay_height) = monitor.overlay_size();
                 let (id, task) = window::open(window::Settings {
-                    position: window::Position::Specific(Point::new(
-                        monitor.x().unwrap_or(0) as f32,
-                        monitor.y().unwrap_or(0) as f32,
-                    )),
-                    size: Size::new(
-                        monitor.width().unwrap_or(1920) as f32,
-                        monitor.height().unwrap_or(1080) as f32,
-                    ),
+                    position: window::Position::Specific(Point::new(overlay_x, overlay_y)),
+                    size: Size::new(overlay_width, overlay_height),
                     transparent: true,
                     decorations: false,
                     ..Default::default()
                 });
 
-                let capture_view = CaptureView::build_with_capture_buffer(capture_buffer);
+                let mut capture_view = CaptureView::build_with_capture_buffer(capture_buffer);
+                capture_view.set_magnifier_enabled(self.settings.show_magnifier);
                 self.windows
                     .insert(id, AppWindow::CaptureOverlay(capture_view));
                 self.status = global_constants::STATUS_OVERLAY_READY.to_string();
@@ -149,9 +343,10 @@ impl AppOrchestrator {
         );
 
         let user_friendly_message = build_capture_error_message(&error_msg);
-        self.status = user_friendly_message;
+        self.status = user_friendly_message.clone();
+        self.play_feedback_sound(crate::adapters::audio_feedback::FeedbackSound::Failure);
 
-        Task::none()
+        self.show_main_window_toast(user_friendly_message, ToastKind::Error)
     }
 
     pub(super) fn handle_escape_pressed(&mut self) -> Task<OrchestratorMessage> {
@@ -188,6 +383,14 @@ impl AppOrchestrator {
             return self.update(OrchestratorMessage::ConfirmSelection(window_id));
         }
 
+        if let CaptureViewMessage::SearchSelection = capture_msg {
+            return self.update(OrchestratorMessage::SearchSelection(window_id));
+        }
+
+        if let CaptureViewMessage::ScrollCaptureSelection = capture_msg {
+            return self.update(OrchestratorMessage::StartScrollCapture(window_id));
+        }
+
         if let CaptureViewMessage::SelectWindow = capture_msg {
             self.log_info_event(
                 "window_selection_requested_from_overlay",
@@ -221,20 +424,41 @@ impl AppOrchestrator {
             return window::close(overlay_id);
         };
 
-        let Some(selection_rect) = capture_view.get_selected_region() else {
+        let selected_region = capture_view
+            .get_selected_region()
+            .filter(|rect| rect.width >= 1.0 && rect.height >= 1.0);
+        let capture_buffer = capture_view.get_capture_buffer().clone();
+        let is_freeform_selection = capture_view.is_freeform_selection();
+        let recrop_source = self.take_recrop_source(overlay_id);
+
+        let Some(selection_rect) = selected_region else {
             self.log_error_event("confirm_selection_no_region", serde_json::json!({}));
-            return window::close(overlay_id);
+            return match recrop_source {
+                Some(source_window_id) => Task::batch(vec![
+                    window::close(overlay_id),
+                    self.restore_recrop_source(source_window_id),
+                ]),
+                None => window::close(overlay_id),
+            };
         };
 
         self.log_info_event(
             "selection_confirmed",
             serde_json::json!({"rect": format!("{:?}", selection_rect)}),
         );
-        let capture_buffer = capture_view.get_capture_buffer().clone();
 
+        let replace_source_task = match recrop_source {
+            Some(source_window_id) => self.replace_recrop_source(source_window_id),
+            None => {
+                self.remember_recent_region(selection_rect);
+                Task::none()
+            }
+        };
+        self.is_pending_crop_masked = is_freeform_selection;
         self.status = global_constants::STATUS_PROCESSING_SELECTION.to_string();
         Task::batch(vec![
             window::close(overlay_id),
+            replace_source_task,
             Task::done(OrchestratorMessage::ShowCroppedImage(
                 capture_buffer,
                 selection_rect,
@@ -242,22 +466,244 @@ impl AppOrchestrator {
         ])
     }
 
+    pub(super) fn handle_search_selection(&mut self, overlay_id: Id) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "selection_search_requested",
+            serde_json::json!({"overlay_id": format!("{:?}", overlay_id)}),
+        );
+
+        let Some(AppWindow::CaptureOverlay(capture_view)) = self.windows.get(&overlay_id) else {
+            self.log_error_event("search_selection_overlay_not_found", serde_json::json!({}));
+            return Task::none();
+        };
+
+        let Some(selection_rect) = capture_view.get_selected_region() else {
+            self.log_info_event("search_selection_no_region", serde_json::json!({}));
+            return Task::none();
+        };
+
+        let cropped_buffer = capture_view
+            .get_capture_buffer()
+            .crop_to_rect(selection_rect);
+
+        match cropped_buffer {
+            Ok(buffer) => {
+                self.log_info_event(
+                    "selection_search_starting",
+                    serde_json::json!({"width": buffer.width, "height": buffer.height}),
+                );
+                self.status = global_constants::STATUS_SEARCHING_SELECTION.to_string();
+                let (progress_id, progress_task) = self.open_search_progress_window();
+                Task::batch(vec![
+                    window::close(overlay_id),
+                    progress_task,
+                    Task::done(OrchestratorMessage::PerformImageSearch(
+                        progress_id,
+                        buffer,
+                        None,
+                    )),
+                ])
+            }
+            Err(crop_error) => {
+                self.log_error_event(
+                    "image_crop_failed",
+                    serde_json::json!({"error": crop_error.to_string()}),
+                );
+                self.status = format!(
+                    "{}{}",
+                    global_constants::CAPTURE_ERROR_CROP_PREFIX,
+                    crop_error
+                );
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn handle_start_scroll_capture(
+        &mut self,
+        overlay_id: Id,
+    ) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "scroll_capture_requested",
+            serde_json::json!({"overlay_id": format!("{:?}", overlay_id)}),
+        );
+
+        let Some(AppWindow::CaptureOverlay(capture_view)) = self.windows.get(&overlay_id) else {
+            self.log_error_event("scroll_capture_overlay_not_found", serde_json::json!({}));
+            return Task::none();
+        };
+
+        let Some(selection_rect) = capture_view.get_selected_region() else {
+            self.log_info_event("scroll_capture_no_region", serde_json::json!({}));
+            return Task::none();
+        };
+
+        let first_frame = match capture_view
+            .get_capture_buffer()
+            .crop_to_rect(selection_rect)
+        {
+            Ok(buffer) => buffer,
+            Err(crop_error) => {
+                self.log_error_event(
+                    "image_crop_failed",
+                    serde_json::json!({"error": crop_error.to_string()}),
+                );
+                self.status = format!(
+                    "{}{}",
+                    global_constants::CAPTURE_ERROR_CROP_PREFIX,
+                    crop_error
+                );
+                return Task::none();
+            }
+        };
+
+        let (region, pointer_position) = match self.capture_overlay_monitor_origin {
+            Some((monitor_x, monitor_y)) => (
+                ScreenRegion::at_coordinates(monitor_x, monitor_y),
+                Some(resolve_scroll_pointer_position(
+                    (monitor_x, monitor_y),
+                    selection_rect,
+                    capture_view.get_capture_buffer().effective_scale_factor(),
+                )),
+            ),
+            None => {
+                self.log_error_event(
+                    "scroll_capture_monitor_origin_unknown",
+                    serde_json::json!({}),
+                );
+                let region = match Mouse::get_mouse_position() {
+                    Mouse::Position { x, y } => ScreenRegion::at_coordinates(x, y),
+                    Mouse::Error => ScreenRegion::at_coordinates(0, 0),
+                };
+                (region, None)
+            }
+        };
+        self.log_info_event(
+            "scroll_capture_target_resolved",
+            serde_json::json!({
+                "monitor_x": region.x_position,
+                "monitor_y": region.y_position,
+                "pointer_position": pointer_position,
+            }),
+        );
+
+        self.status = global_constants::STATUS_SCROLL_CAPTURING.to_string();
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+        let scroll_delay = std::time::Duration::from_millis(
+            self.settings
+                .scroll_capture_delay_ms
+                .min(global_constants::MAX_SCROLL_CAPTURE_DELAY_MS) as u64,
+        );
+        let correlation_id = self.current_correlation_id();
+
+        Task::batch(vec![
+            window::close(overlay_id),
+            Task::future(async move {
+                tokio::time::sleep(scroll_delay).await;
+
+                let frames = tokio::task::spawn_blocking(move || {
+                    collect_scroll_frames(
+                        first_frame,
+                        global_constants::SCROLL_CAPTURE_MAX_FRAMES,
+                        || {
+                            crate::infrastructure::utils::scroll_mouse_wheel(
+                                pointer_position,
+                                global_constants::SCROLL_CAPTURE_LINES_PER_STEP,
+                            )?;
+                            std::thread::sleep(scroll_delay);
+                            screen_capturer
+                                .capture_screen_at_region(&region)
+                                .and_then(|screen| screen.crop_to_rect(selection_rect))
+                                .map_err(|capture_error| capture_error.to_string())
+                        },
+                    )
+                })
+                .await
+                .unwrap_or_else(|join_error| Err(join_error.to_string()));
+                let frames = match frames {
+                    Ok(frames) => frames,
+                    Err(scroll_error) => {
+                        return OrchestratorMessage::ScrollCaptureFinished(Err(scroll_error));
+                    }
+                };
+
+                AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id,
+                    "scroll_capture_frames_collected",
+                    serde_json::json!({"frames": frames.len()}),
+                );
+
+                OrchestratorMessage::ScrollCaptureFinished(
+                    CaptureBuffer::stitch_vertically(&frames)
+                        .map_err(|stitch_error| stitch_error.to_string()),
+                )
+            }),
+        ])
+    }
+
+    pub(super) fn handle_scroll_capture_finished(
+        &mut self,
+        result: Result<CaptureBuffer, String>,
+    ) -> Task<OrchestratorMessage> {
+        match result {
+            Ok(buffer) => {
+                self.log_info_event(
+                    "scroll_capture_completed",
+                    serde_json::json!({"width": buffer.width, "height": buffer.height}),
+                );
+                let full_rect = Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(buffer.width as f32, buffer.height as f32),
+                );
+                Task::done(OrchestratorMessage::ShowCroppedImage(buffer, full_rect))
+            }
+            Err(scroll_error) => {
+                self.log_error_event(
+                    "scroll_capture_failed",
+                    serde_json::json!({"error": scroll_error}),
+                );
+                self.status = format!(
+                    "{}{}",
+                    global_constants::STATUS_SCROLL_CAPTURE_FAILED_PREFIX,
+                    scroll_error
+                );
+                Task::none()
+            }
+        }
+    }
+
     pub(super) fn handle_show_cropped_image(
         &mut self,
         capture_buffer: CaptureBuffer,
         selection_rect: Rectangle,
     ) -> Task<OrchestratorMessage> {
+        let (_, task) = self.open_cropped_image_window(capture_buffer, selection_rect);
+        task
+    }
+
+    pub(super) fn open_cropped_image_window(
+        &mut self,
+        capture_buffer: CaptureBuffer,
+        selection_rect: Rectangle,
+    ) -> (Option<Id>, Task<OrchestratorMessage>) {
         self.log_info_event(
             "cropped_image_showing",
             serde_json::json!({"rect": format!("{:?}", selection_rect)}),
         );
 
-        let cropped_buffer = capture_buffer.crop_region(
-            selection_rect.x as u32,
-            selection_rect.y as u32,
-            selection_rect.width as u32,
-            selection_rect.height as u32,
-        );
+        let is_history_reopen = std::mem::take(&mut self.is_reopening_history_capture);
+        let is_masked_crop = std::mem::take(&mut self.is_pending_crop_masked);
+        let has_pending_annotations =
+            self.pending_draw_strokes.is_some() || self.pending_project_annotations.is_some();
+        let cropped_buffer = capture_buffer
+            .crop_to_rect(selection_rect)
+            .and_then(|buffer| {
+                if is_masked_crop && !has_pending_annotations {
+                    buffer.trim_transparent_padding()
+                } else {
+                    Ok(buffer)
+                }
+            });
 
         match cropped_buffer {
             Ok(buffer) => {
@@ -266,13 +712,34 @@ impl AppOrchestrator {
                     serde_json::json!({"width": buffer.width, "height": buffer.height}),
                 );
 
+                let capture_fingerprint = compute_capture_fingerprint(&buffer);
+                if let Some(existing_window_id) = self.find_duplicate_capture(capture_fingerprint) {
+                    self.log_info_event(
+                        "duplicate_capture_skipped",
+                        serde_json::json!({"window_id": format!("{:?}", existing_window_id)}),
+                    );
+                    self.pending_draw_strokes = None;
+                    self.pending_project_annotations = None;
+                    self.status = global_constants::STATUS_DUPLICATE_CAPTURE_SKIPPED.to_string();
+                    return (None, window::gain_focus(existing_window_id));
+                }
+
+                self.play_feedback_sound(crate::adapters::audio_feedback::FeedbackSound::Capture);
+                let restored_geometry =
+                    self.restorable_window_geometry(self.settings.ocr_window_geometry);
+                let open_result_window_count = self
+                    .windows
+                    .values()
+                    .filter(|window| matches!(window, AppWindow::InteractiveOcr(_)))
+                    .count();
                 let (id, task) = window::open(window::Settings {
                     size: Size::new(
                         (buffer.width as f32).min(1200.0),
                         (buffer.height as f32).min(800.0),
                     ),
-                    position: window::Position::Centered,
+                    position: cascaded_window_position(restored_geometry, open_result_window_count),
                     resizable: true,
+                    level: result_window_level(self.settings.pin_result_window_on_top),
                     ..Default::default()
                 });
 
@@ -281,14 +748,82 @@ impl AppOrchestrator {
                     self.settings.theme_mode.clone(),
                 );
 
+                view.set_type_out_text_enabled(self.settings.enable_type_out_text);
+                view.set_help_hint_enabled(self.settings.show_help_hint);
+                view.set_ocr_language(&self.settings.language);
+                view.set_available_ocr_languages(self.settings.ocr_engine.supported_languages());
+                view.set_min_ocr_confidence(self.settings.min_ocr_confidence);
+                view.set_draw_width(self.settings.draw_width);
+                view.set_recent_draw_colors(&self.settings.recent_draw_colors);
+                view.set_always_on_top(self.settings.pin_result_window_on_top);
+                view.set_shortcuts(crate::presentation::ResultWindowShortcuts::from_bindings(
+                    &self.settings.draw_mode_shortcut,
+                    &self.settings.save_image_shortcut,
+                    &self.settings.copy_image_shortcut,
+                ));
+
                 if let Some(strokes) = self.pending_draw_strokes.take() {
                     view.set_draw_strokes(strokes);
                 }
 
+                if let Some((strokes, step_markers)) = self.pending_project_annotations.take() {
+                    view.restore_project_annotations(strokes, step_markers);
+                }
+
+                let flash_task = if self.settings.show_capture_flash {
+                    view.show_capture_flash();
+                    Task::future(async move {
+                        tokio::time::sleep(tokio::time::Duration::from_millis(
+                            global_constants::CAPTURE_FLASH_DURATION_MS,
+                        ))
+                        .await;
+                        OrchestratorMessage::InteractiveOcrMessage(
+                            id,
+                            crate::presentation::InteractiveOcrMessage::CaptureFlashFinished,
+                        )
+                    })
+                } else {
+                    Task::none()
+                };
+                let auto_save_task = self.auto_save_capture(&buffer);
+                let history_task = if is_history_reopen {
+                    Task::none()
+                } else {
+                    self.record_capture_history(&buffer)
+                };
                 self.windows.insert(id, AppWindow::InteractiveOcr(view));
+                self.last_capture = Some((capture_fingerprint, std::time::Instant::now(), id));
                 self.status = global_constants::STATUS_READY_SIMPLE.to_string();
 
-                return task.discard();
+                if self.settings.auto_run_ocr {
+                    self.log_info_event(
+                        "ocr_auto_run_scheduled",
+                        serde_json::json!({"window_id": format!("{:?}", id)}),
+                    );
+                    return (
+                        Some(id),
+                        Task::batch(vec![
+                            task.discard(),
+                            flash_task,
+                            auto_save_task,
+                            history_task,
+                            Task::done(OrchestratorMessage::InteractiveOcrMessage(
+                                id,
+                                crate::presentation::InteractiveOcrMessage::StartOcr,
+                            )),
+                        ]),
+                    );
+                }
+
+                return (
+                    Some(id),
+                    Task::batch(vec![
+                        task.discard(),
+                        flash_task,
+                        auto_save_task,
+                        history_task,
+                    ]),
+                );
             }
             Err(crop_error) => {
                 self.log_error_event(
@@ -302,7 +837,133 @@ impl AppOrchestrator {
                 );
             }
         }
-        Task::none()
+        (None, Task::none())
+    }
+
+    fn find_duplicate_capture(&self, capture_fingerprint: u64) -> Option<Id> {
+        let (last_fingerprint, captured_at, window_id) = self.last_capture?;
+        let is_recent = captured_at.elapsed()
+            < std::time::Duration::from_millis(global_constants::DUPLICATE_CAPTURE_WINDOW_MS);
+
+        (last_fingerprint == capture_fingerprint
+            && is_recent
+            && self.windows.contains_key(&window_id))
+        .then_some(window_id)
+    }
+}
+
+pub(super) fn result_window_level(always_on_top: bool) -> window::Level {
+    if always_on_top {
+        window::Level::AlwaysOnTop
+    } else {
+        window::Level::Normal
+    }
+}
+
+fn compute_capture_fingerprint(buffer: &CaptureBuffer) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    buffer.width.hash(&mut hasher);
+    buffer.height.hash(&mut hasher);
+    buffer.raw_data.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn resolve_capture_region(
+    capture_monitor: &CaptureMonitor,
+    cursor_region: ScreenRegion,
+    get_primary_monitor_origin: impl FnOnce() -> anyhow::Result<ScreenRegion>,
+) -> ScreenRegion {
+    if *capture_monitor == CaptureMonitor::UnderCursor {
+        return cursor_region;
+    }
+
+    get_primary_monitor_origin().unwrap_or_else(|primary_error| {
+        log::warn!(
+            "[CAPTURE] Primary monitor unavailable, using monitor under cursor: {:#}",
+            primary_error
+        );
+        cursor_region
+    })
+}
+
+fn resolve_cursor_centered_selection(
+    monitor: &MonitorGeometry,
+    (mouse_x, mouse_y): (i32, i32),
+    (buffer_width, buffer_height): (u32, u32),
+    (region_width, region_height): (u32, u32),
+) -> Rectangle {
+    let to_buffer_axis = |offset: i32, monitor_size: u32, buffer_size: u32| {
+        if monitor_size == 0 {
+            return offset;
+        }
+        (offset as i64 * buffer_size as i64 / monitor_size as i64) as i32
+    };
+    let cursor = ScreenRegion::at_coordinates(
+        to_buffer_axis(mouse_x - monitor.x, monitor.width, buffer_width),
+        to_buffer_axis(mouse_y - monitor.y, monitor.height, buffer_height),
+    );
+    let region_width = to_buffer_axis(region_width as i32, monitor.width, buffer_width) as u32;
+    let region_height = to_buffer_axis(region_height as i32, monitor.height, buffer_height) as u32;
+    let (left, top, width, height) =
+        cursor.centered_bounds_within(region_width, region_height, buffer_width, buffer_height);
+
+    Rectangle::new(
+        Point::new(left as f32, top as f32),
+        Size::new(width as f32, height as f32),
+    )
+}
+
+fn resolve_scroll_pointer_position(
+    monitor_origin: (i32, i32),
+    selection_rect: Rectangle,
+    scale_factor: f64,
+) -> (i32, i32) {
+    let center = selection_rect.center();
+    (
+        monitor_origin.0 + (center.x as f64 / scale_factor).round() as i32,
+        monitor_origin.1 + (center.y as f64 / scale_factor).round() as i32,
+    )
+}
+
+fn collect_scroll_frames(
+    first_frame: CaptureBuffer,
+    max_frames: usize,
+    mut scroll_and_capture: impl FnMut() -> Result<CaptureBuffer, String>,
+) -> Result<Vec<CaptureBuffer>, String> {
+    let mut frames = vec![first_frame];
+
+    while frames.len() < max_frames {
+        let next_frame = scroll_and_capture()?;
+        let reached_end = frames
+            .last()
+            .is_some_and(|previous| previous.raw_data == next_frame.raw_data);
+        if reached_end {
+            break;
+        }
+        frames.push(next_frame);
+    }
+
+    Ok(frames)
+}
+
+fn is_capture_trigger_enabled(configured: &CaptureTrigger, source: &CaptureTrigger) -> bool {
+    *configured != CaptureTrigger::TrayOnly && configured == source
+}
+
+fn resolve_main_window_to_minimize(
+    main_window_id: Option<Id>,
+    minimize_on_capture: bool,
+) -> Option<Id> {
+    main_window_id.filter(|_| minimize_on_capture)
+}
+
+pub(super) fn resolve_capture_delay_ms(has_main_window: bool, configured_delay_ms: u32) -> u32 {
+    if has_main_window {
+        configured_delay_ms.min(global_constants::MAX_CAPTURE_DELAY_MS)
+    } else {
+        0
     }
 }
 
@@ -322,6 +983,10 @@ pub(super) fn build_capture_error_message(error_msg: &str) -> String {
 pub(super) fn build_capture_error_message_for_platform(error_msg: &str, platform: &str) -> String {
     let error_lower = error_msg.to_lowercase();
 
+    if error_lower.contains(global_constants::CAPTURE_ERROR_KEYWORD_PROTECTED) {
+        return global_constants::CAPTURE_ERROR_DISPLAY_PROTECTED.to_string();
+    }
+
     match platform {
         global_constants::CAPTURE_PLATFORM_LINUX => {
             let is_permission_error = error_lower
@@ -370,6 +1035,175 @@ pub(super) fn build_capture_error_message_for_platform(error_msg: &str, platform
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_capture_trigger_enabled_matches_configured_trigger() {
+        assert!(is_capture_trigger_enabled(
+            &CaptureTrigger::Keyboard,
+            &CaptureTrigger::Keyboard
+        ));
+        assert!(is_capture_trigger_enabled(
+            &CaptureTrigger::MiddleClickHold,
+            &CaptureTrigger::MiddleClickHold
+        ));
+        assert!(!is_capture_trigger_enabled(
+            &CaptureTrigger::MiddleClickHold,
+            &CaptureTrigger::Keyboard
+        ));
+    }
+
+    #[test]
+    fn test_resolve_cursor_centered_selection_shifts_inward_near_monitor_corner() {
+        let monitor = MonitorGeometry {
+            x: 1920,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            scale_factor: 1.0,
+        };
+
+        let selection =
+            resolve_cursor_centered_selection(&monitor, (3830, 1075), (1920, 1080), (400, 300));
+
+        assert_eq!(
+            selection,
+            Rectangle::new(Point::new(1520.0, 780.0), Size::new(400.0, 300.0))
+        );
+    }
+
+    #[test]
+    fn test_resolve_cursor_centered_selection_scales_logical_region_into_buffer_pixels() {
+        let monitor = MonitorGeometry {
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            scale_factor: 2.0,
+        };
+
+        let selection =
+            resolve_cursor_centered_selection(&monitor, (960, 540), (3840, 2160), (400, 300));
+
+        assert_eq!(
+            selection,
+            Rectangle::new(Point::new(1520.0, 780.0), Size::new(800.0, 600.0))
+        );
+    }
+
+    #[test]
+    fn test_resolve_capture_region_uses_primary_monitor_when_selected() {
+        let region = resolve_capture_region(
+            &CaptureMonitor::Primary,
+            ScreenRegion::at_coordinates(2500, 300),
+            || Ok(ScreenRegion::at_coordinates(0, 0)),
+        );
+
+        assert_eq!((region.x_position, region.y_position), (0, 0));
+    }
+
+    #[test]
+    fn test_resolve_capture_region_falls_back_to_cursor_monitor() {
+        let under_cursor = resolve_capture_region(
+            &CaptureMonitor::UnderCursor,
+            ScreenRegion::at_coordinates(2500, 300),
+            || Ok(ScreenRegion::at_coordinates(0, 0)),
+        );
+        let primary_failed = resolve_capture_region(
+            &CaptureMonitor::Primary,
+            ScreenRegion::at_coordinates(2500, 300),
+            || Err(anyhow::anyhow!("no monitors")),
+        );
+
+        assert_eq!(
+            (under_cursor.x_position, under_cursor.y_position),
+            (2500, 300)
+        );
+        assert_eq!(
+            (primary_failed.x_position, primary_failed.y_position),
+            (2500, 300)
+        );
+    }
+
+    #[test]
+    fn test_compute_capture_fingerprint_detects_identical_buffers() {
+        let first = CaptureBuffer::build_from_raw_data(1.0, 2, 1, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        let same = CaptureBuffer::build_from_raw_data(1.0, 2, 1, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        let different = CaptureBuffer::build_from_raw_data(1.0, 2, 1, vec![1, 2, 3, 4, 5, 6, 7, 9]);
+
+        assert_eq!(
+            compute_capture_fingerprint(&first),
+            compute_capture_fingerprint(&same)
+        );
+        assert_ne!(
+            compute_capture_fingerprint(&first),
+            compute_capture_fingerprint(&different)
+        );
+    }
+
+    #[test]
+    fn test_is_capture_trigger_enabled_ignores_global_triggers_in_tray_only_mode() {
+        assert!(!is_capture_trigger_enabled(
+            &CaptureTrigger::TrayOnly,
+            &CaptureTrigger::Keyboard
+        ));
+        assert!(!is_capture_trigger_enabled(
+            &CaptureTrigger::TrayOnly,
+            &CaptureTrigger::MiddleClickHold
+        ));
+    }
+
+    #[test]
+    fn test_resolve_main_window_to_minimize_skips_minimize_when_setting_is_off() {
+        let main_window_id = Id::unique();
+
+        assert_eq!(
+            resolve_main_window_to_minimize(Some(main_window_id), false),
+            None
+        );
+        assert_eq!(
+            resolve_main_window_to_minimize(Some(main_window_id), true),
+            Some(main_window_id)
+        );
+        assert_eq!(resolve_main_window_to_minimize(None, true), None);
+    }
+
+    #[test]
+    fn test_resolve_capture_delay_ms_skips_delay_without_main_window() {
+        assert_eq!(resolve_capture_delay_ms(false, 200), 0);
+    }
+
+    #[test]
+    fn test_resolve_capture_delay_ms_uses_configured_delay_with_main_window() {
+        assert_eq!(resolve_capture_delay_ms(true, 0), 0);
+        assert_eq!(resolve_capture_delay_ms(true, 120), 120);
+        assert_eq!(
+            resolve_capture_delay_ms(true, u32::MAX),
+            global_constants::MAX_CAPTURE_DELAY_MS
+        );
+    }
+
+    #[test]
+    fn test_build_capture_error_message_protected_display_error() {
+        let error = format!(
+            "{}: Unable to capture Monitor",
+            global_constants::CAPTURE_ERROR_DISPLAY_PROTECTED
+        );
+        let result = build_capture_error_message_for_platform(&error, "windows");
+        assert_eq!(result, global_constants::CAPTURE_ERROR_DISPLAY_PROTECTED);
+    }
+
+    #[test]
+    fn test_build_capture_error_message_keeps_platform_guidance_for_unprotected_failures() {
+        let error = format!(
+            "{}: PipeWire portal request was denied",
+            global_constants::ERROR_CONTEXT_CAPTURE_MONITOR
+        );
+
+        let result = build_capture_error_message_for_platform(&error, "linux");
+
+        assert_ne!(result, global_constants::CAPTURE_ERROR_DISPLAY_PROTECTED);
+        assert!(result.contains("xdg-desktop-portal"));
+    }
+
     #[test]
     fn test_build_capture_error_message_linux_permission_error() {
         let error = "Access denied to screen capture";
@@ -448,4 +1282,64 @@ mod tests {
         assert!(result.contains("Capture failed"));
         assert!(result.contains("Try closing other instances"));
     }
+
+    fn build_scroll_source(height: u32) -> CaptureBuffer {
+        let raw_data = (0..4 * height)
+            .flat_map(|index| [(index % 4) as u8, (index / 4) as u8, 9, 255])
+            .collect();
+        CaptureBuffer::build_from_raw_data(1.0, 4, height, raw_data)
+    }
+
+    #[test]
+    fn test_collect_scroll_frames_stops_on_unchanged_frame_and_stitches() {
+        let source = build_scroll_source(40);
+        let mut scripted_frames = [12, 16, 16, 16]
+            .into_iter()
+            .map(|top| source.crop_region(0, top, 4, 24).unwrap());
+        let mut capture_count = 0;
+
+        let frames = collect_scroll_frames(source.crop_region(0, 0, 4, 24).unwrap(), 12, || {
+            capture_count += 1;
+            scripted_frames.next().ok_or_else(|| "ran out".to_string())
+        })
+        .unwrap();
+
+        assert_eq!(capture_count, 3);
+        assert_eq!(frames.len(), 3);
+        let stitched = CaptureBuffer::stitch_vertically(&frames).unwrap();
+        assert_eq!(stitched.height, 40);
+        assert_eq!(stitched.raw_data, source.raw_data);
+    }
+
+    #[test]
+    fn test_collect_scroll_frames_respects_max_frames_and_propagates_errors() {
+        let source = build_scroll_source(60);
+        let mut next_top = 0;
+
+        let frames = collect_scroll_frames(source.crop_region(0, 0, 4, 20).unwrap(), 3, || {
+            next_top += 10;
+            source
+                .crop_region(0, next_top, 4, 20)
+                .map_err(|error| error.to_string())
+        })
+        .unwrap();
+        assert_eq!(frames.len(), 3);
+
+        let result = collect_scroll_frames(source.clone(), 3, || Err("scroll failed".to_string()));
+        assert_eq!(result.unwrap_err(), "scroll failed");
+    }
+
+    #[test]
+    fn test_resolve_scroll_pointer_position_targets_selection_center_on_monitor() {
+        let selection = Rectangle::new(Point::new(200.0, 100.0), Size::new(400.0, 300.0));
+
+        assert_eq!(
+            resolve_scroll_pointer_position((1920, -40), selection, 2.0),
+            (1920 + 200, -40 + 125)
+        );
+        assert_eq!(
+            resolve_scroll_pointer_position((0, 0), selection, 1.0),
+            (400, 250)
+        );
+    }
 }
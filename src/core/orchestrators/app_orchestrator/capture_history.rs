@@ -0,0 +1,285 @@
+use super::*;
+use crate::core::models::{CaptureHistory, CaptureHistoryEntry};
+use crate::presentation::{format_capture_age, CaptureHistoryItem};
+
+impl AppOrchestrator {
+    pub(super) fn record_capture_history(
+        &self,
+        buffer: &CaptureBuffer,
+    ) -> Task<OrchestratorMessage> {
+        if self.settings.max_history_entries == 0 {
+            return Task::none();
+        }
+
+        let buffer = buffer.clone();
+        let max_entries = self.settings.max_history_entries as usize;
+        let correlation_id = self.current_correlation_id();
+        let refresh_task = self.refresh_recent_captures();
+
+        Task::future(async move {
+            let record_result = tokio::task::spawn_blocking(move || {
+                CaptureHistory::open_default(max_entries)
+                    .and_then(|history| history.push(&buffer))
+                    .map_err(|error| format!("{:#}", error))
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            match record_result {
+                Ok(entry) => AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id,
+                    "capture_history_recorded",
+                    serde_json::json!({"id": entry.map(|entry| entry.id)}),
+                ),
+                Err(record_error) => AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id,
+                    "capture_history_record_failed",
+                    serde_json::json!({"error": record_error}),
+                ),
+            }
+        })
+        .discard()
+        .chain(refresh_task)
+    }
+
+    pub(super) fn refresh_recent_captures(&self) -> Task<OrchestratorMessage> {
+        let max_entries = self.settings.max_history_entries as usize;
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let entries = tokio::task::spawn_blocking(move || {
+                CaptureHistory::open_default(max_entries)
+                    .and_then(|history| history.list())
+                    .map_err(|error| format!("{:#}", error))
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()))
+            .unwrap_or_else(|list_error| {
+                AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id,
+                    "recent_captures_load_failed",
+                    serde_json::json!({"error": list_error}),
+                );
+                vec![]
+            });
+
+            let now_unix_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis())
+                .unwrap_or_default();
+            OrchestratorMessage::RecentCapturesChanged(build_recent_capture_menu_items(
+                &entries,
+                now_unix_ms,
+            ))
+        })
+    }
+
+    pub(super) fn handle_open_capture_history(&mut self) -> Task<OrchestratorMessage> {
+        self.log_info_event("capture_history_open_requested", serde_json::json!({}));
+
+        if let Some(id) = self.capture_history_window_id {
+            log::debug!("[ORCHESTRATOR] Capture history already open, bringing to front");
+            return window::gain_focus(id);
+        }
+
+        let (id, open_task) = window::open(window::Settings {
+            size: Size::new(420.0, 600.0),
+            position: window::Position::Centered,
+            visible: true,
+            resizable: true,
+            decorations: true,
+            ..Default::default()
+        });
+
+        self.capture_history_window_id = Some(id);
+        self.windows
+            .insert(id, AppWindow::CaptureHistory(CaptureHistoryView::build()));
+
+        let max_entries = self.settings.max_history_entries as usize;
+        let correlation_id = self.current_correlation_id();
+
+        Task::batch(vec![
+            open_task.discard(),
+            Task::future(async move {
+                let entries =
+                    tokio::task::spawn_blocking(move || load_capture_history_items(max_entries))
+                        .await
+                        .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+                match entries {
+                    Ok(entries) => {
+                        AppOrchestrator::log_info_event_for_correlation(
+                            correlation_id,
+                            "capture_history_loaded",
+                            serde_json::json!({"entry_count": entries.len()}),
+                        );
+                        OrchestratorMessage::CaptureHistoryLoaded(id, entries)
+                    }
+                    Err(load_error) => {
+                        AppOrchestrator::log_error_event_for_correlation(
+                            correlation_id,
+                            "capture_history_load_failed",
+                            serde_json::json!({"error": load_error}),
+                        );
+                        OrchestratorMessage::CaptureHistoryLoaded(id, vec![])
+                    }
+                }
+            }),
+        ])
+    }
+
+    pub(super) fn handle_capture_history_loaded(
+        &mut self,
+        window_id: Id,
+        entries: Vec<CaptureHistoryItem>,
+    ) {
+        if let Some(AppWindow::CaptureHistory(view)) = self.windows.get_mut(&window_id) {
+            let now_unix_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis())
+                .unwrap_or_default();
+            view.set_entries(entries, now_unix_ms);
+        }
+    }
+
+    pub(super) fn handle_capture_history_message(
+        &mut self,
+        window_id: Id,
+        msg: CaptureHistoryMessage,
+    ) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "capture_history_message_received",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "message": format!("{:?}", msg),
+            }),
+        );
+
+        match msg {
+            CaptureHistoryMessage::Reopen(entry_id) => self.reopen_capture_history_entry(entry_id),
+            CaptureHistoryMessage::Close => {
+                self.capture_history_window_id = None;
+                window::close(window_id)
+            }
+        }
+    }
+
+    pub(super) fn reopen_capture_history_entry(
+        &self,
+        entry_id: String,
+    ) -> Task<OrchestratorMessage> {
+        let max_entries = self.settings.max_history_entries as usize;
+        Task::future(async move {
+            let load_result = tokio::task::spawn_blocking(move || {
+                CaptureHistory::open_default(max_entries)
+                    .and_then(|history| history.load(&entry_id))
+                    .map_err(|error| format!("{:#}", error))
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            OrchestratorMessage::CaptureHistoryEntryLoaded(load_result)
+        })
+    }
+
+    pub(super) fn handle_capture_history_entry_loaded(
+        &mut self,
+        load_result: Result<CaptureBuffer, String>,
+    ) -> Task<OrchestratorMessage> {
+        match load_result {
+            Ok(buffer) => {
+                self.log_info_event(
+                    "capture_history_entry_reopened",
+                    serde_json::json!({"width": buffer.width, "height": buffer.height}),
+                );
+                self.is_reopening_history_capture = true;
+
+                let full_rect = Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(buffer.width as f32, buffer.height as f32),
+                );
+                self.handle_show_cropped_image(buffer, full_rect)
+            }
+            Err(load_error) => {
+                self.log_error_event(
+                    "capture_history_entry_load_failed",
+                    serde_json::json!({"error": load_error}),
+                );
+                self.status = format!(
+                    "{}{}",
+                    global_constants::STATUS_CAPTURE_HISTORY_LOAD_FAILED_PREFIX,
+                    load_error
+                );
+                Task::none()
+            }
+        }
+    }
+}
+
+fn load_capture_history_items(max_entries: usize) -> Result<Vec<CaptureHistoryItem>, String> {
+    let history =
+        CaptureHistory::open_default(max_entries).map_err(|error| format!("{:#}", error))?;
+    let entries = history.list().map_err(|error| format!("{:#}", error))?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| {
+            let thumbnail = history.load_thumbnail(&entry.id).ok();
+            (entry, thumbnail)
+        })
+        .collect())
+}
+
+fn build_recent_capture_menu_items(
+    entries: &[CaptureHistoryEntry],
+    now_unix_ms: u128,
+) -> Vec<RecentCaptureMenuItem> {
+    entries
+        .iter()
+        .take(global_constants::TRAY_RECENT_CAPTURES_LIMIT)
+        .map(|entry| RecentCaptureMenuItem {
+            entry_id: entry.id.clone(),
+            label: format!(
+                "{}×{} · {}",
+                entry.width,
+                entry.height,
+                format_capture_age(now_unix_ms, entry.captured_at_unix_ms)
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, captured_at_unix_ms: u128) -> CaptureHistoryEntry {
+        CaptureHistoryEntry {
+            id: id.to_string(),
+            captured_at_unix_ms,
+            width: 800,
+            height: 600,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_build_recent_capture_menu_items_labels_and_limits_entries() {
+        let now = 10 * 60_000;
+        let entries = (0..8)
+            .map(|index| entry(&index.to_string(), now - index as u128 * 60_000))
+            .collect::<Vec<_>>();
+
+        let items = build_recent_capture_menu_items(&entries, now);
+
+        assert_eq!(items.len(), global_constants::TRAY_RECENT_CAPTURES_LIMIT);
+        assert_eq!(items[0].entry_id, "0");
+        assert_eq!(items[0].label, "800×600 · Just now");
+        assert_eq!(items[2].label, "800×600 · 2 min ago");
+    }
+
+    #[test]
+    fn test_build_recent_capture_menu_items_is_empty_without_history() {
+        assert!(build_recent_capture_menu_items(&[], 0).is_empty());
+    }
+}
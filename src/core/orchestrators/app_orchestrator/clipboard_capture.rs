@@ -0,0 +1,49 @@
+use super::*;
+
+impl AppOrchestrator {
+    pub(super) fn handle_ocr_clipboard_image(&mut self) -> Task<OrchestratorMessage> {
+        self.log_info_event("clipboard_image_read_started", serde_json::json!({}));
+        self.status = global_constants::STATUS_READING_CLIPBOARD_IMAGE.to_string();
+
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let read_result = tokio::task::spawn_blocking(
+                crate::infrastructure::utils::read_image_from_clipboard,
+            )
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            match &read_result {
+                Ok(capture_buffer) => AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id,
+                    "clipboard_image_read_completed",
+                    serde_json::json!({
+                        "width": capture_buffer.width,
+                        "height": capture_buffer.height,
+                    }),
+                ),
+                Err(read_error) => AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id,
+                    "clipboard_image_read_failed",
+                    serde_json::json!({"error": read_error}),
+                ),
+            }
+            OrchestratorMessage::ClipboardImageRead(read_result)
+        })
+    }
+
+    pub(super) fn handle_clipboard_image_read(
+        &mut self,
+        read_result: Result<CaptureBuffer, String>,
+    ) -> Task<OrchestratorMessage> {
+        match read_result {
+            Ok(capture_buffer) => self.open_capture_result_with_ocr(capture_buffer),
+            Err(read_error) => {
+                self.status = read_error;
+                self.play_feedback_sound(crate::adapters::audio_feedback::FeedbackSound::Failure);
+                Task::none()
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+impl AppOrchestrator {
+    pub(super) fn handle_file_dropped(
+        &mut self,
+        window_id: Id,
+        path: std::path::PathBuf,
+    ) -> Task<OrchestratorMessage> {
+        if self.main_window_id != Some(window_id) {
+            self.log_info_event(
+                "dropped_file_ignored",
+                serde_json::json!({
+                    "window_id": format!("{:?}", window_id),
+                    "path": path.display().to_string(),
+                }),
+            );
+            return Task::none();
+        }
+
+        self.log_info_event(
+            "dropped_file_load_started",
+            serde_json::json!({"path": path.display().to_string()}),
+        );
+        self.status = global_constants::STATUS_LOADING_DROPPED_IMAGE.to_string();
+
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let load_result = tokio::task::spawn_blocking(move || {
+                crate::infrastructure::utils::load_image_file_as_capture_buffer(&path)
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            match &load_result {
+                Ok(capture_buffer) => AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id,
+                    "dropped_file_load_completed",
+                    serde_json::json!({
+                        "width": capture_buffer.width,
+                        "height": capture_buffer.height,
+                    }),
+                ),
+                Err(load_error) => AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id,
+                    "dropped_file_load_failed",
+                    serde_json::json!({"error": load_error}),
+                ),
+            }
+            OrchestratorMessage::DroppedFileLoaded(load_result)
+        })
+    }
+
+    pub(super) fn handle_dropped_file_loaded(
+        &mut self,
+        load_result: Result<CaptureBuffer, String>,
+    ) -> Task<OrchestratorMessage> {
+        match load_result {
+            Ok(capture_buffer) => self.open_capture_result_with_ocr(capture_buffer),
+            Err(load_error) => {
+                self.status = load_error;
+                self.play_feedback_sound(crate::adapters::audio_feedback::FeedbackSound::Failure);
+                Task::none()
+            }
+        }
+    }
+}
@@ -94,6 +94,32 @@ impl AppOrchestrator {
         }
     }
 
+    pub(super) fn build_region_buffer(
+        buffer: &CaptureBuffer,
+        draw_strokes: &[crate::presentation::DrawStroke],
+        region: Rectangle,
+    ) -> anyhow::Result<CaptureBuffer> {
+        let composited_data = Self::build_clipboard_image_data(buffer, draw_strokes);
+        let composited_buffer = CaptureBuffer::build_from_raw_data(
+            buffer._scale_factor,
+            buffer.width,
+            buffer.height,
+            composited_data,
+        );
+
+        let region_x = region.x.max(0.0).floor() as u32;
+        let region_y = region.y.max(0.0).floor() as u32;
+        let region_width = (region.x + region.width).ceil().max(0.0) as u32;
+        let region_height = (region.y + region.height).ceil().max(0.0) as u32;
+
+        composited_buffer.crop_region(
+            region_x,
+            region_y,
+            region_width.saturating_sub(region_x),
+            region_height.saturating_sub(region_y),
+        )
+    }
+
     fn copy_image_to_clipboard_message(
         window_id: Id,
         buffer: &CaptureBuffer,
@@ -144,6 +170,8 @@ impl AppOrchestrator {
         );
 
         let save_location = self.settings.screenshot_save_location.clone();
+        let save_format = self.settings.save_format;
+        let jpeg_quality = self.settings.jpeg_quality;
         let correlation_id = self.current_correlation_id();
 
         Task::batch(vec![
@@ -166,6 +194,8 @@ impl AppOrchestrator {
                     &buffer,
                     &rgba_data,
                     &save_location,
+                    save_format,
+                    jpeg_quality,
                     correlation_id,
                 )
             }),
@@ -179,24 +209,78 @@ impl AppOrchestrator {
         ])
     }
 
+    pub(super) fn auto_save_capture(&self, buffer: &CaptureBuffer) -> Task<OrchestratorMessage> {
+        if !self.settings.auto_save_captures {
+            return Task::none();
+        }
+
+        let buffer = buffer.clone();
+        let save_location = self.settings.auto_save_location.clone();
+        let save_format = self.settings.save_format;
+        let jpeg_quality = self.settings.jpeg_quality;
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let save_result = tokio::task::spawn_blocking(move || {
+                crate::infrastructure::utils::save_image_to_file(
+                    &buffer.raw_data,
+                    buffer.width,
+                    buffer.height,
+                    buffer._scale_factor,
+                    &save_location,
+                    save_format,
+                    jpeg_quality,
+                )
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            match save_result {
+                Ok(path) => AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id,
+                    "capture_auto_saved",
+                    serde_json::json!({"path": path}),
+                ),
+                Err(save_error) => AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id,
+                    "capture_auto_save_failed",
+                    serde_json::json!({"error": save_error}),
+                ),
+            }
+        })
+        .discard()
+    }
+
     fn save_image_to_file_message(
         window_id: Id,
         buffer: &CaptureBuffer,
         rgba_data: &[u8],
         save_location: &str,
+        save_format: crate::core::models::ImageSaveFormat,
+        jpeg_quality: u8,
         correlation_id: String,
     ) -> OrchestratorMessage {
         match crate::infrastructure::utils::save_image_to_file(
             rgba_data,
             buffer.width,
             buffer.height,
+            buffer._scale_factor,
             save_location,
+            save_format,
+            jpeg_quality,
         ) {
             Ok(path) => {
                 AppOrchestrator::log_info_event_for_correlation(
                     correlation_id,
                     "save_image_to_file_succeeded",
-                    serde_json::json!({"path": path}),
+                    serde_json::json!({
+                        "path": path,
+                        "dimensions": crate::infrastructure::utils::format_dimensions_with_points(
+                            buffer.width,
+                            buffer.height,
+                            buffer._scale_factor,
+                        ),
+                    }),
                 );
                 OrchestratorMessage::InteractiveOcrMessage(
                     window_id,
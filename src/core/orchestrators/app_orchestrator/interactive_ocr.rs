@@ -1,4 +1,8 @@
+use super::capture::result_window_level;
 use super::*;
+use crate::adapters::audio_feedback::FeedbackSound;
+use crate::adapters::{EnsembleOcrService, NeuralOcrService, OnnxOcrService, TesseractOcrService};
+use crate::core::models::ModelDownloadProgress;
 
 impl AppOrchestrator {
     pub(super) fn handle_interactive_ocr_message(
@@ -14,58 +18,267 @@ impl AppOrchestrator {
             }),
         );
 
+        let mut copy_succeeded = false;
         if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) {
             view.update(ocr_msg.clone());
+            copy_succeeded = view.has_copy_succeeded();
+        }
+
+        if let crate::presentation::InteractiveOcrMessage::SearchFailed(error) = &ocr_msg {
+            self.search_task_handles.remove(&window_id);
+            if let Some(AppWindow::SearchProgress(..)) = self.windows.get(&window_id) {
+                return self.finish_search_progress(window_id, error.clone(), ToastKind::Error);
+            }
+        }
+
+        if let Some(sound) = feedback_sound_for_message(&ocr_msg, copy_succeeded) {
+            self.play_feedback_sound(sound);
+        }
+
+        if self.settings.auto_close_after_action
+            && should_auto_close_after_action(&ocr_msg, copy_succeeded)
+        {
+            self.log_info_event(
+                "interactive_ocr_auto_close_scheduled",
+                serde_json::json!({"window_id": format!("{:?}", window_id)}),
+            );
+            return Task::future(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    global_constants::AUTO_CLOSE_AFTER_ACTION_DELAY_MS,
+                ))
+                .await;
+                OrchestratorMessage::CloseWindow(window_id)
+            });
         }
 
         match ocr_msg {
             crate::presentation::InteractiveOcrMessage::Close => window::close(window_id),
             crate::presentation::InteractiveOcrMessage::SearchSelected => {
-                self.start_selected_image_search(window_id)
+                self.start_search(window_id, false)
+            }
+            crate::presentation::InteractiveOcrMessage::SearchTextOnly => {
+                self.start_search(window_id, true)
+            }
+            crate::presentation::InteractiveOcrMessage::CopySelected
+            | crate::presentation::InteractiveOcrMessage::CopyAllText
+            | crate::presentation::InteractiveOcrMessage::CopyDecodedCode(_)
+            | crate::presentation::InteractiveOcrMessage::CopyImageUrl
+            | crate::presentation::InteractiveOcrMessage::CopyTextLayoutJson
+            | crate::presentation::InteractiveOcrMessage::CopyAsMarkdown => {
+                Task::future(async move {
+                    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                    OrchestratorMessage::InteractiveOcrMessage(
+                        window_id,
+                        crate::presentation::InteractiveOcrMessage::HideToast,
+                    )
+                })
+            }
+            crate::presentation::InteractiveOcrMessage::ToggleAlwaysOnTop => {
+                self.apply_result_window_level(window_id)
+            }
+            crate::presentation::InteractiveOcrMessage::TranslateSelected => {
+                self.start_translate_selected(window_id)
+            }
+            crate::presentation::InteractiveOcrMessage::TypeSelectedText => {
+                self.start_type_out_text(window_id)
             }
-            crate::presentation::InteractiveOcrMessage::CopySelected => Task::future(async move {
-                tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-                OrchestratorMessage::InteractiveOcrMessage(
-                    window_id,
-                    crate::presentation::InteractiveOcrMessage::HideToast,
-                )
-            }),
             crate::presentation::InteractiveOcrMessage::CopyImageToClipboard => {
                 self.start_copy_image(window_id)
             }
+            crate::presentation::InteractiveOcrMessage::CopySelectedRegionToClipboard => {
+                self.start_copy_selected_region(window_id)
+            }
             crate::presentation::InteractiveOcrMessage::SaveImageToFile => {
                 self.start_save_image(window_id)
             }
+            crate::presentation::InteractiveOcrMessage::SaveProject => {
+                self.handle_save_annotation_project(window_id)
+            }
+            crate::presentation::InteractiveOcrMessage::ExportTextToFile => {
+                self.start_export_text(window_id)
+            }
+            crate::presentation::InteractiveOcrMessage::OpenDecodedCode(code_index) => {
+                self.open_decoded_code(window_id, code_index)
+            }
             crate::presentation::InteractiveOcrMessage::Recrop => self.start_recrop(window_id),
             crate::presentation::InteractiveOcrMessage::StartOcr => {
                 self.start_ocr_processing(window_id)
             }
             crate::presentation::InteractiveOcrMessage::CancelOcr => {
-                log::info!(
-                    "[ORCHESTRATOR] OCR cancelled by user for window {:?}",
-                    window_id
-                );
-                window::close(window_id)
+                self.abort_ocr_task(window_id);
+                Task::none()
+            }
+            crate::presentation::InteractiveOcrMessage::CancelSearch => {
+                self.abort_search_task(window_id);
+                Task::none()
             }
-            crate::presentation::InteractiveOcrMessage::RetryOcr => {
+            crate::presentation::InteractiveOcrMessage::RetryOcr
+            | crate::presentation::InteractiveOcrMessage::RotateLeft
+            | crate::presentation::InteractiveOcrMessage::RotateRight
+            | crate::presentation::InteractiveOcrMessage::FlipHorizontal
+            | crate::presentation::InteractiveOcrMessage::SetOcrPreprocess(_) => {
                 self.start_ocr_processing(window_id)
             }
+            crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage(language_code) => {
+                self.start_ocr_with_language(window_id, language_code)
+            }
+            crate::presentation::InteractiveOcrMessage::DismissHelpHintPermanently => {
+                self.disable_help_hint();
+                Task::none()
+            }
+            crate::presentation::InteractiveOcrMessage::SetDrawWidth(width) => {
+                self.remember_draw_width(width);
+                Task::none()
+            }
+            crate::presentation::InteractiveOcrMessage::ApplyPickedColor => {
+                self.remember_recent_draw_colors(window_id);
+                Task::none()
+            }
             _ => Task::none(),
         }
     }
 
-    fn start_selected_image_search(&self, window_id: Id) -> Task<OrchestratorMessage> {
+    fn disable_help_hint(&mut self) {
+        self.log_info_event("help_hint_disabled", serde_json::json!({}));
+        self.settings.show_help_hint = false;
+        let _ = self.update_settings_draft(|settings| {
+            settings.show_help_hint = false;
+        });
+
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "help_hint_setting_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+    }
+
+    fn remember_draw_width(&mut self, width: f32) {
+        self.log_info_event("draw_width_changed", serde_json::json!({"width": width}));
+        self.settings.draw_width = width;
+        let _ = self.update_settings_draft(|settings| {
+            settings.draw_width = width;
+        });
+
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "draw_width_setting_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+    }
+
+    fn remember_recent_draw_colors(&mut self, window_id: Id) {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return;
+        };
+
+        let recent_draw_colors = view.get_recent_draw_colors();
+        self.log_info_event(
+            "recent_draw_colors_changed",
+            serde_json::json!({"count": recent_draw_colors.len()}),
+        );
+        self.settings.recent_draw_colors = recent_draw_colors.clone();
+        let _ = self.update_settings_draft(|settings| {
+            settings.recent_draw_colors = recent_draw_colors;
+        });
+
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "recent_draw_colors_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+    }
+
+    fn start_type_out_text(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        if !self.settings.enable_type_out_text {
+            self.log_info_event("type_out_text_disabled", serde_json::json!({}));
+            return Task::none();
+        }
+
+        if !crate::adapters::macos_permissions::macos::check_input_monitoring_permission() {
+            self.log_error_event("type_out_text_permission_missing", serde_json::json!({}));
+            self.status = global_constants::STATUS_TYPING_TEXT_PERMISSION_REQUIRED.to_string();
+            return Task::none();
+        }
+
         let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
             return Task::none();
         };
-        let buffer = view.get_capture_buffer().clone();
-        let query = view.get_search_query().to_string();
-        let query_option = if query.is_empty() { None } else { Some(query) };
-        Task::done(OrchestratorMessage::PerformImageSearch(
-            window_id,
-            buffer,
-            query_option,
-        ))
+        let selected_text = view.get_selected_text();
+        if selected_text.is_empty() {
+            return Task::none();
+        }
+
+        self.log_info_event(
+            "type_out_text_started",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "characters": selected_text.chars().count(),
+            }),
+        );
+        self.status = global_constants::STATUS_TYPING_TEXT.to_string();
+
+        Task::batch(vec![
+            window::minimize(window_id, true),
+            Task::future(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    global_constants::TYPE_OUT_TEXT_FOCUS_DELAY_MS,
+                ))
+                .await;
+                let typing_result = tokio::task::spawn_blocking(move || {
+                    crate::infrastructure::utils::type_text_as_keyboard_input(&selected_text)
+                })
+                .await
+                .unwrap_or_else(|join_error| Err(join_error.to_string()));
+                OrchestratorMessage::TypeOutTextFinished(typing_result)
+            }),
+        ])
+    }
+
+    pub(super) fn handle_type_out_text_finished(
+        &mut self,
+        result: Result<(), String>,
+    ) -> Task<OrchestratorMessage> {
+        match result {
+            Ok(()) => {
+                self.log_info_event("type_out_text_completed", serde_json::json!({}));
+                self.status = global_constants::STATUS_TYPING_TEXT_DONE.to_string();
+            }
+            Err(error) => {
+                self.log_error_event("type_out_text_failed", serde_json::json!({"error": error}));
+                self.status = format!(
+                    "{}{}",
+                    global_constants::STATUS_TYPING_TEXT_FAILED_PREFIX,
+                    error
+                );
+            }
+        }
+        Task::none()
+    }
+
+    fn start_search(&self, window_id: Id, text_only: bool) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+
+        match resolve_search_request(text_only, view.get_search_query()) {
+            Some(SearchRequest::Image(query)) => {
+                let buffer = build_search_buffer(
+                    view.get_capture_buffer(),
+                    &view.get_draw_strokes(),
+                    self.settings.include_annotations_in_search,
+                );
+                Task::done(OrchestratorMessage::PerformImageSearch(
+                    window_id, buffer, query,
+                ))
+            }
+            Some(SearchRequest::Text(query)) => {
+                Task::done(OrchestratorMessage::PerformTextSearch(window_id, query))
+            }
+            None => Task::none(),
+        }
     }
 
     fn start_copy_image(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
@@ -73,7 +286,7 @@ impl AppOrchestrator {
             return Task::none();
         };
         let buffer = view.get_capture_buffer().clone();
-        let draw_strokes = view.get_draw_strokes();
+        let draw_strokes = view.get_export_draw_strokes();
         self.update(OrchestratorMessage::CopyImageToClipboard(
             window_id,
             buffer,
@@ -81,12 +294,43 @@ impl AppOrchestrator {
         ))
     }
 
+    fn start_copy_selected_region(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let Some(region) = view.get_selected_region() else {
+            return Task::none();
+        };
+        let buffer = view.get_capture_buffer().clone();
+        let draw_strokes = view.get_export_draw_strokes();
+
+        match Self::build_region_buffer(&buffer, &draw_strokes, region) {
+            Ok(region_buffer) => self.update(OrchestratorMessage::CopyImageToClipboard(
+                window_id,
+                region_buffer,
+                Vec::new(),
+            )),
+            Err(crop_error) => {
+                self.log_error_event(
+                    "region_crop_failed",
+                    serde_json::json!({"error": crop_error.to_string()}),
+                );
+                Task::done(OrchestratorMessage::InteractiveOcrMessage(
+                    window_id,
+                    crate::presentation::InteractiveOcrMessage::CopyImageFailed(
+                        crop_error.to_string(),
+                    ),
+                ))
+            }
+        }
+    }
+
     fn start_save_image(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
         let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
             return Task::none();
         };
         let buffer = view.get_capture_buffer().clone();
-        let draw_strokes = view.get_draw_strokes();
+        let draw_strokes = view.get_export_draw_strokes();
         self.update(OrchestratorMessage::SaveImageToFile(
             window_id,
             buffer,
@@ -94,202 +338,691 @@ impl AppOrchestrator {
         ))
     }
 
-    fn start_recrop(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
-        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
-            return Task::none();
-        };
-        self.pending_draw_strokes = Some(view.get_draw_strokes());
-        Task::batch(vec![
-            window::close(window_id),
-            self.update(OrchestratorMessage::CaptureScreen),
-        ])
-    }
-
-    fn start_ocr_processing(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
-        self.log_info_event(
-            "ocr_processing_requested",
-            serde_json::json!({
-                "window_id": format!("{:?}", window_id),
-            }),
-        );
-
+    fn start_export_text(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
         let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
             return Task::none();
         };
-        let buffer = view.get_capture_buffer().clone();
-        self.status = global_constants::STATUS_PROCESSING_OCR.to_string();
-        Task::done(OrchestratorMessage::ProcessOcr(window_id, buffer))
-    }
+        let all_text = view.get_all_text();
 
-    pub(super) fn handle_perform_image_search(
-        &mut self,
-        window_id: Id,
-        buffer: CaptureBuffer,
-        query: Option<String>,
-    ) -> Task<OrchestratorMessage> {
         self.log_info_event(
-            "image_search_started",
-            serde_json::json!({
-                "window_id": format!("{:?}", window_id),
-                "has_query": query.as_ref().map(|q| !q.is_empty()).unwrap_or(false),
-            }),
+            "export_text_started",
+            serde_json::json!({"characters": all_text.chars().count()}),
         );
 
-        let search_provider = Arc::clone(&self.reverse_image_search_provider);
+        let save_location = self.settings.screenshot_save_location.clone();
         let correlation_id = self.current_correlation_id();
 
         Task::batch(vec![
-            Task::done(OrchestratorMessage::InteractiveOcrMessage(
-                window_id,
-                crate::presentation::InteractiveOcrMessage::SearchUploading,
-            )),
             Task::future(async move {
-                let search_future = search_provider.perform_search(&buffer, query.as_deref());
-                let timeout_duration =
-                    std::time::Duration::from_secs(global_constants::IMAGE_SEARCH_TIMEOUT_SECONDS);
+                let export_result = tokio::task::spawn_blocking(move || {
+                    crate::infrastructure::utils::save_text_to_file(&all_text, &save_location)
+                })
+                .await
+                .unwrap_or_else(|join_error| Err(join_error.to_string()));
 
-                match tokio::time::timeout(timeout_duration, search_future).await {
-                    Ok(Ok(_search_url)) => {
+                match export_result {
+                    Ok(path) => {
                         AppOrchestrator::log_info_event_for_correlation(
-                            correlation_id.clone(),
-                            "image_search_completed",
-                            serde_json::json!({
-                                "window_id": format!("{:?}", window_id),
-                            }),
-                        );
-                        OrchestratorMessage::InteractiveOcrMessage(
-                            window_id,
-                            crate::presentation::InteractiveOcrMessage::SearchCompleted,
-                        )
-                    }
-                    Ok(Err(error)) => {
-                        AppOrchestrator::log_error_event_for_correlation(
-                            correlation_id.clone(),
-                            "image_search_failed",
-                            serde_json::json!({
-                                "window_id": format!("{:?}", window_id),
-                                "error": error.to_string(),
-                            }),
+                            correlation_id,
+                            "export_text_succeeded",
+                            serde_json::json!({"path": path}),
                         );
                         OrchestratorMessage::InteractiveOcrMessage(
                             window_id,
-                            crate::presentation::InteractiveOcrMessage::SearchFailed(format!(
-                                "{}{}",
-                                error,
-                                global_constants::IMAGE_SEARCH_FAILURE_SUFFIX
-                            )),
+                            crate::presentation::InteractiveOcrMessage::ExportTextSuccess(path),
                         )
                     }
-                    Err(_) => {
+                    Err(export_error) => {
                         AppOrchestrator::log_error_event_for_correlation(
-                            correlation_id.clone(),
-                            "image_search_timeout",
-                            serde_json::json!({
-                                "window_id": format!("{:?}", window_id),
-                                "timeout_seconds": global_constants::IMAGE_SEARCH_TIMEOUT_SECONDS,
-                            }),
+                            correlation_id,
+                            "export_text_failed",
+                            serde_json::json!({"error": export_error}),
                         );
                         OrchestratorMessage::InteractiveOcrMessage(
                             window_id,
-                            crate::presentation::InteractiveOcrMessage::SearchFailed(
-                                global_constants::IMAGE_SEARCH_TIMEOUT_MESSAGE.to_string(),
-                            ),
+                            crate::presentation::InteractiveOcrMessage::SaveFailed(export_error),
                         )
                     }
                 }
             }),
+            Task::future(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(2600)).await;
+                OrchestratorMessage::InteractiveOcrMessage(
+                    window_id,
+                    crate::presentation::InteractiveOcrMessage::HideToast,
+                )
+            }),
         ])
     }
 
-    pub(super) fn handle_process_ocr(
+    fn start_recrop(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let capture_buffer = view.get_capture_buffer().clone();
+
+        self.log_info_event(
+            "recrop_started",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "width": capture_buffer.width,
+                "height": capture_buffer.height,
+            }),
+        );
+
+        let (overlay_id, open_task) = window::open(window::Settings {
+            position: window::Position::Centered,
+            maximized: true,
+            transparent: true,
+            decorations: false,
+            ..Default::default()
+        });
+
+        let mut capture_view = CaptureView::build_with_capture_buffer(capture_buffer);
+        capture_view.set_magnifier_enabled(self.settings.show_magnifier);
+        self.windows
+            .insert(overlay_id, AppWindow::CaptureOverlay(capture_view));
+        self.recrop_overlay = Some((overlay_id, window_id));
+        self.status = global_constants::STATUS_OVERLAY_READY.to_string();
+
+        Task::batch(vec![
+            window::minimize(window_id, true),
+            open_task.discard().chain(window::gain_focus(overlay_id)),
+        ])
+    }
+
+    pub(super) fn take_recrop_source(&mut self, overlay_id: Id) -> Option<Id> {
+        match self.recrop_overlay.take() {
+            Some((recrop_id, source_window_id)) if recrop_id == overlay_id => {
+                Some(source_window_id)
+            }
+            other => {
+                self.recrop_overlay = other;
+                None
+            }
+        }
+    }
+
+    pub(super) fn restore_recrop_source(
         &mut self,
-        window_id: Id,
-        buffer: CaptureBuffer,
+        source_window_id: Id,
     ) -> Task<OrchestratorMessage> {
         self.log_info_event(
-            "ocr_processing_started",
+            "recrop_kept_original_image",
+            serde_json::json!({"window_id": format!("{:?}", source_window_id)}),
+        );
+        if !self.windows.contains_key(&source_window_id) {
+            return Task::none();
+        }
+
+        window::minimize(source_window_id, false).chain(window::gain_focus(source_window_id))
+    }
+
+    pub(super) fn replace_recrop_source(
+        &mut self,
+        source_window_id: Id,
+    ) -> Task<OrchestratorMessage> {
+        if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&source_window_id) {
+            self.pending_draw_strokes = Some(view.get_draw_strokes());
+        }
+
+        window::close(source_window_id)
+    }
+
+    fn apply_result_window_level(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let always_on_top = view.is_always_on_top();
+        self.log_info_event(
+            "result_window_level_changed",
             serde_json::json!({
                 "window_id": format!("{:?}", window_id),
-                "buffer_width": buffer.width,
-                "buffer_height": buffer.height,
+                "always_on_top": always_on_top,
             }),
         );
+        window::set_level(window_id, result_window_level(always_on_top))
+    }
 
-        let ocr_service = self.ocr_service.clone();
-        let width = buffer.width;
-        let height = buffer.height;
-        let correlation_id = self.current_correlation_id();
-
-        Task::future(async move {
-            AppOrchestrator::log_info_event_for_correlation(
-                correlation_id.clone(),
-                "ocr_image_converting",
-                serde_json::json!({"width": width, "height": height}),
-            );
+    fn start_translate_selected(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let selected_text = view.get_selected_text();
+        if selected_text.trim().is_empty() {
+            return Task::none();
+        }
 
-            let raw_image = image::RgbaImage::from_raw(width, height, buffer.raw_data.clone());
+        let target_lang = self.settings.translation_target_language.clone();
+        self.log_info_event(
+            "translation_started",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "target_lang": target_lang,
+                "characters": selected_text.chars().count(),
+            }),
+        );
 
-            match raw_image {
-                None => {
-                    AppOrchestrator::log_error_event_for_correlation(
-                        correlation_id.clone(),
-                        "ocr_image_conversion_failed",
-                        serde_json::json!({"width": width, "height": height}),
+        let translation_service = Arc::clone(&self.translation_service);
+        let correlation_id = self.current_correlation_id();
+        Task::future(async move {
+            let timeout_duration =
+                std::time::Duration::from_secs(global_constants::TRANSLATION_TIMEOUT_SECONDS);
+            match translate_with_timeout(
+                translation_service,
+                selected_text,
+                target_lang,
+                timeout_duration,
+            )
+            .await
+            {
+                Ok(translated_text) => {
+                    AppOrchestrator::log_info_event_for_correlation(
+                        correlation_id,
+                        "translation_completed",
+                        serde_json::json!({"window_id": format!("{:?}", window_id)}),
                     );
-                    OrchestratorMessage::OcrComplete(
+                    OrchestratorMessage::InteractiveOcrMessage(
                         window_id,
-                        Err(global_constants::OCR_RAW_IMAGE_CREATION_FAILED.to_string()),
+                        crate::presentation::InteractiveOcrMessage::TranslationCompleted(
+                            translated_text,
+                        ),
                     )
                 }
-                Some(rgba_image) => {
-                    let dynamic_image = image::DynamicImage::ImageRgba8(rgba_image);
-                    AppOrchestrator::log_info_event_for_correlation(
-                        correlation_id.clone(),
-                        "ocr_running",
-                        serde_json::json!({}),
+                Err(translation_error) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id,
+                        "translation_failed",
+                        serde_json::json!({
+                            "window_id": format!("{:?}", window_id),
+                            "error": translation_error,
+                        }),
                     );
-                    match ocr_service.extract_text_from_image(&dynamic_image).await {
-                        Ok(result) => {
-                            AppOrchestrator::log_info_event_for_correlation(
-                                correlation_id.clone(),
-                                "ocr_completed",
-                                serde_json::json!({"text_blocks": result.text_blocks.len()}),
-                            );
-                            OrchestratorMessage::OcrComplete(window_id, Ok(result))
-                        }
-                        Err(ocr_error) => {
-                            AppOrchestrator::log_error_event_for_correlation(
-                                correlation_id.clone(),
-                                "ocr_failed",
-                                serde_json::json!({"error": ocr_error.to_string()}),
-                            );
-                            OrchestratorMessage::OcrComplete(window_id, Err(ocr_error.to_string()))
-                        }
-                    }
+                    OrchestratorMessage::InteractiveOcrMessage(
+                        window_id,
+                        crate::presentation::InteractiveOcrMessage::TranslationFailed(
+                            translation_error,
+                        ),
+                    )
                 }
             }
         })
     }
 
-    pub(super) fn handle_ocr_complete(
+    fn start_ocr_processing(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "ocr_processing_requested",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+            }),
+        );
+
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let buffer = view.get_ocr_preprocess().apply(view.get_capture_buffer());
+        self.status = global_constants::STATUS_PROCESSING_OCR.to_string();
+        Task::done(OrchestratorMessage::ProcessOcr(window_id, buffer))
+    }
+
+    fn start_ocr_with_language(
         &mut self,
         window_id: Id,
-        result: Result<OcrResult, String>,
+        language_code: String,
     ) -> Task<OrchestratorMessage> {
-        match result {
-            Ok(ocr_result) => {
-                self.log_info_event(
-                    "ocr_complete",
-                    serde_json::json!({
-                        "window_id": format!("{:?}", window_id),
-                        "text_blocks": ocr_result.text_blocks.len(),
-                    }),
-                );
+        if language_code == self.settings.language
+            || self.ocr_language_services.contains_key(&language_code)
+        {
+            return self.start_ocr_processing(window_id);
+        }
 
-                if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) {
+        if !self.settings.ocr_engine.supports_language_override() {
+            self.log_info_event(
+                "ocr_language_override_skipped",
+                serde_json::json!({
+                    "window_id": format!("{:?}", window_id),
+                    "language": language_code,
+                    "engine": self.settings.ocr_engine.to_string(),
+                }),
+            );
+            return self.start_ocr_processing(window_id);
+        }
+
+        self.log_info_event(
+            "ocr_language_override_loading",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "language": language_code,
+            }),
+        );
+        self.status = format!(
+            "{}{}",
+            global_constants::STATUS_LOADING_OCR_LANGUAGE_PREFIX,
+            language_code
+        );
+
+        let requested_language = language_code.clone();
+        self.run_ocr_service_build(
+            self.settings.ocr_engine.clone(),
+            language_code,
+            move |build_result| {
+                OrchestratorMessage::OcrLanguageServiceReady(
+                    window_id,
+                    requested_language,
+                    build_result
+                        .map(|built| built.service)
+                        .map_err(|build_error| build_error.to_string()),
+                )
+            },
+        )
+    }
+
+    pub(super) fn handle_ocr_language_service_ready(
+        &mut self,
+        window_id: Id,
+        language_code: String,
+        build_result: Result<Arc<dyn OcrService>, String>,
+    ) -> Task<OrchestratorMessage> {
+        match build_result {
+            Ok(service) => {
+                self.log_info_event(
+                    "ocr_language_override_ready",
+                    serde_json::json!({"language": language_code}),
+                );
+                let is_current_language = matches!(
+                    self.windows.get(&window_id),
+                    Some(AppWindow::InteractiveOcr(view)) if view.get_ocr_language() == language_code
+                );
+                self.ocr_language_services
+                    .insert(language_code.clone(), service);
+
+                if !is_current_language {
+                    self.log_info_event(
+                        "ocr_language_override_stale",
+                        serde_json::json!({
+                            "window_id": format!("{:?}", window_id),
+                            "language": language_code,
+                        }),
+                    );
+                    return Task::none();
+                }
+
+                self.start_ocr_processing(window_id)
+            }
+            Err(build_error) => {
+                self.log_error_event(
+                    "ocr_language_override_failed",
+                    serde_json::json!({"language": language_code, "error": build_error}),
+                );
+                self.status = format!(
+                    "{}{}",
+                    global_constants::OCR_INITIALIZATION_FAILED_PREFIX,
+                    build_error
+                );
+                if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) {
+                    view.set_ocr_failed(build_error);
+                }
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn ocr_service_for_window(&self, window_id: Id) -> Arc<dyn OcrService> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return self.ocr_service.clone();
+        };
+
+        self.ocr_language_services
+            .get(view.get_ocr_language())
+            .cloned()
+            .unwrap_or_else(|| self.ocr_service.clone())
+    }
+
+    pub(super) fn handle_perform_image_search(
+        &mut self,
+        window_id: Id,
+        buffer: CaptureBuffer,
+        query: Option<String>,
+    ) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "image_search_started",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "has_query": query.as_ref().map(|q| !q.is_empty()).unwrap_or(false),
+            }),
+        );
+
+        let search_provider = Arc::clone(&self.reverse_image_search_provider);
+        let correlation_id = self.current_correlation_id();
+
+        let (search_task, abort_handle) = Task::future(async move {
+            let search_future = search_provider.perform_search(&buffer, query.as_deref());
+            let timeout_duration =
+                std::time::Duration::from_secs(global_constants::IMAGE_SEARCH_TIMEOUT_SECONDS);
+
+            match tokio::time::timeout(timeout_duration, search_future).await {
+                Ok(Ok(search_outcome)) => {
+                    AppOrchestrator::log_info_event_for_correlation(
+                        correlation_id.clone(),
+                        "image_search_completed",
+                        serde_json::json!({
+                            "window_id": format!("{:?}", window_id),
+                        }),
+                    );
+                    OrchestratorMessage::ImageSearchCompleted(
+                        window_id,
+                        search_outcome.hosted_image_url,
+                    )
+                }
+                Ok(Err(error)) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id.clone(),
+                        "image_search_failed",
+                        serde_json::json!({
+                            "window_id": format!("{:?}", window_id),
+                            "error": error.to_string(),
+                        }),
+                    );
+                    OrchestratorMessage::InteractiveOcrMessage(
+                        window_id,
+                        crate::presentation::InteractiveOcrMessage::SearchFailed(format!(
+                            "{}{}",
+                            error,
+                            global_constants::IMAGE_SEARCH_FAILURE_SUFFIX
+                        )),
+                    )
+                }
+                Err(_) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id.clone(),
+                        "image_search_timeout",
+                        serde_json::json!({
+                            "window_id": format!("{:?}", window_id),
+                            "timeout_seconds": global_constants::IMAGE_SEARCH_TIMEOUT_SECONDS,
+                        }),
+                    );
+                    OrchestratorMessage::InteractiveOcrMessage(
+                        window_id,
+                        crate::presentation::InteractiveOcrMessage::SearchFailed(
+                            global_constants::IMAGE_SEARCH_TIMEOUT_MESSAGE.to_string(),
+                        ),
+                    )
+                }
+            }
+        })
+        .abortable();
+        if let Some(previous_handle) = self.search_task_handles.insert(window_id, abort_handle) {
+            previous_handle.abort();
+        }
+
+        Task::batch(vec![
+            Task::done(OrchestratorMessage::InteractiveOcrMessage(
+                window_id,
+                crate::presentation::InteractiveOcrMessage::SearchUploading,
+            )),
+            search_task,
+        ])
+    }
+
+    pub(super) fn handle_image_search_completed(
+        &mut self,
+        window_id: Id,
+        hosted_image_url: String,
+    ) -> Task<OrchestratorMessage> {
+        self.search_task_handles.remove(&window_id);
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) else {
+            return self.finish_search_progress(
+                window_id,
+                global_constants::STATUS_SELECTION_SEARCH_OPENED.to_string(),
+                ToastKind::Info,
+            );
+        };
+        view.set_hosted_image_url(hosted_image_url);
+        self.update(OrchestratorMessage::InteractiveOcrMessage(
+            window_id,
+            crate::presentation::InteractiveOcrMessage::SearchCompleted,
+        ))
+    }
+
+    pub(super) fn handle_perform_text_search(
+        &mut self,
+        window_id: Id,
+        query: String,
+    ) -> Task<OrchestratorMessage> {
+        let Some(search_url) =
+            build_text_search_url(&self.settings.text_search_url_template, &query)
+        else {
+            return Task::none();
+        };
+        self.log_info_event(
+            "text_search_started",
+            serde_json::json!({"window_id": format!("{:?}", window_id)}),
+        );
+
+        let open_in_private_window = self.settings.open_search_in_private_window;
+        let correlation_id = self.current_correlation_id();
+        Task::future(async move {
+            match crate::infrastructure::utils::open_search_url(&search_url, open_in_private_window)
+            {
+                Ok(()) => {
+                    AppOrchestrator::log_info_event_for_correlation(
+                        correlation_id,
+                        "text_search_completed",
+                        serde_json::json!({"window_id": format!("{:?}", window_id)}),
+                    );
+                    OrchestratorMessage::InteractiveOcrMessage(
+                        window_id,
+                        crate::presentation::InteractiveOcrMessage::SearchCompleted,
+                    )
+                }
+                Err(open_error) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id,
+                        "text_search_failed",
+                        serde_json::json!({
+                            "window_id": format!("{:?}", window_id),
+                            "error": open_error,
+                        }),
+                    );
+                    OrchestratorMessage::InteractiveOcrMessage(
+                        window_id,
+                        crate::presentation::InteractiveOcrMessage::SearchFailed(open_error),
+                    )
+                }
+            }
+        })
+    }
+
+    pub(super) fn handle_process_ocr(
+        &mut self,
+        window_id: Id,
+        buffer: CaptureBuffer,
+    ) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "ocr_processing_started",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "buffer_width": buffer.width,
+                "buffer_height": buffer.height,
+            }),
+        );
+
+        let barcode_task = self.build_barcode_decode_task(window_id, &buffer);
+        let ocr_service = self.ocr_service_for_window(window_id);
+        let correlation_id = self.current_correlation_id();
+        let ocr_timeout = std::time::Duration::from_secs(
+            self.settings
+                .ocr_timeout_secs
+                .clamp(
+                    global_constants::MIN_OCR_TIMEOUT_SECS,
+                    global_constants::MAX_OCR_TIMEOUT_SECS,
+                )
+                .into(),
+        );
+
+        let ocr_task = Task::future(async move {
+            let result =
+                recognize_capture_buffer(ocr_service, buffer, ocr_timeout, correlation_id).await;
+            OrchestratorMessage::OcrComplete(window_id, result)
+        });
+
+        let (abortable_task, abort_handle) = Task::batch([ocr_task, barcode_task]).abortable();
+        if let Some(previous_handle) = self.ocr_task_handles.insert(window_id, abort_handle) {
+            previous_handle.abort();
+        }
+        abortable_task
+    }
+
+    fn abort_ocr_task(&mut self, window_id: Id) {
+        let Some(abort_handle) = self.ocr_task_handles.remove(&window_id) else {
+            return;
+        };
+
+        abort_handle.abort();
+        self.log_info_event(
+            "ocr_cancelled",
+            serde_json::json!({"window_id": format!("{:?}", window_id)}),
+        );
+        self.status = global_constants::STATUS_OCR_CANCELLED.to_string();
+    }
+
+    fn abort_search_task(&mut self, window_id: Id) {
+        let Some(abort_handle) = self.search_task_handles.remove(&window_id) else {
+            return;
+        };
+
+        abort_handle.abort();
+        self.log_info_event(
+            "image_search_cancelled",
+            serde_json::json!({"window_id": format!("{:?}", window_id)}),
+        );
+        self.status = global_constants::STATUS_SEARCH_CANCELLED.to_string();
+    }
+
+    pub(super) fn abort_window_tasks(&mut self, window_id: Id) {
+        if let Some(abort_handle) = self.ocr_task_handles.remove(&window_id) {
+            abort_handle.abort();
+        }
+        if let Some(abort_handle) = self.search_task_handles.remove(&window_id) {
+            abort_handle.abort();
+        }
+    }
+
+    fn build_barcode_decode_task(
+        &self,
+        window_id: Id,
+        buffer: &CaptureBuffer,
+    ) -> Task<OrchestratorMessage> {
+        let barcode_service = self.barcode_service.clone();
+        let buffer = buffer.clone();
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let decode_result = tokio::task::spawn_blocking(move || {
+                let dynamic_image = buffer
+                    .to_dynamic_image()
+                    .map_err(|conversion_error| conversion_error.to_string())?;
+                barcode_service
+                    .decode(&dynamic_image)
+                    .map_err(|decode_error| decode_error.to_string())
+            })
+            .await
+            .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            match decode_result {
+                Ok(codes) => {
+                    AppOrchestrator::log_info_event_for_correlation(
+                        correlation_id,
+                        "barcode_decode_completed",
+                        serde_json::json!({"codes": codes.len()}),
+                    );
+                    OrchestratorMessage::BarcodesDecoded(window_id, codes)
+                }
+                Err(decode_error) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id,
+                        "barcode_decode_failed",
+                        serde_json::json!({"error": decode_error}),
+                    );
+                    OrchestratorMessage::BarcodesDecoded(window_id, Vec::new())
+                }
+            }
+        })
+    }
+
+    fn open_decoded_code(&mut self, window_id: Id, code_index: usize) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get(&window_id) else {
+            return Task::none();
+        };
+        let Some(url) = view
+            .get_decoded_code(code_index)
+            .and_then(|code| code.safe_url())
+        else {
+            self.log_error_event(
+                "decoded_code_open_rejected",
+                serde_json::json!({"code_index": code_index}),
+            );
+            return Task::none();
+        };
+
+        let open_in_private_window = self.settings.open_search_in_private_window;
+        match crate::infrastructure::utils::open_search_url(&url, open_in_private_window) {
+            Ok(()) => self.log_info_event("decoded_code_opened", serde_json::json!({"url": url})),
+            Err(open_error) => self.log_error_event(
+                "decoded_code_open_failed",
+                serde_json::json!({"url": url, "error": open_error}),
+            ),
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_barcodes_decoded(
+        &mut self,
+        window_id: Id,
+        codes: Vec<DecodedCode>,
+    ) -> Task<OrchestratorMessage> {
+        if codes.is_empty() {
+            return Task::none();
+        }
+
+        self.log_info_event(
+            "barcodes_decoded",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "codes": codes.len(),
+            }),
+        );
+
+        if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) {
+            view.set_decoded_codes(codes);
+        }
+        Task::none()
+    }
+
+    pub(super) fn handle_ocr_complete(
+        &mut self,
+        window_id: Id,
+        result: Result<OcrResult, String>,
+    ) -> Task<OrchestratorMessage> {
+        self.ocr_task_handles.remove(&window_id);
+        match result {
+            Ok(ocr_result) => {
+                self.log_info_event(
+                    "ocr_complete",
+                    serde_json::json!({
+                        "window_id": format!("{:?}", window_id),
+                        "text_blocks": ocr_result.text_blocks.len(),
+                    }),
+                );
+
+                if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) {
                     view.set_ocr_result(ocr_result);
                     self.status = global_constants::STATUS_OCR_COMPLETE.to_string();
                 }
+
+                if self.settings.auto_copy_on_ocr {
+                    return self.auto_copy_ocr_text(window_id);
+                }
             }
             Err(ocr_error) => {
                 self.log_error_event(
@@ -299,7 +1032,11 @@ impl AppOrchestrator {
                         "error": ocr_error,
                     }),
                 );
-                self.status = global_constants::STATUS_READY.to_string();
+                self.status = format!(
+                    "{}{}",
+                    global_constants::STATUS_OCR_FAILED_PREFIX,
+                    ocr_error
+                );
                 if let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) {
                     view.set_ocr_failed(ocr_error);
                 }
@@ -308,16 +1045,185 @@ impl AppOrchestrator {
         Task::none()
     }
 
+    fn auto_copy_ocr_text(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
+        let Some(AppWindow::InteractiveOcr(view)) = self.windows.get_mut(&window_id) else {
+            return Task::none();
+        };
+        if view.has_auto_copied() {
+            return Task::none();
+        }
+        view.mark_auto_copied();
+
+        let recognized_length = view.get_all_text().trim().chars().count();
+        if recognized_length < global_constants::AUTO_COPY_OCR_MIN_TEXT_LENGTH {
+            self.log_info_event(
+                "ocr_auto_copy_skipped",
+                serde_json::json!({
+                    "window_id": format!("{:?}", window_id),
+                    "text_length": recognized_length,
+                }),
+            );
+            return Task::none();
+        }
+
+        view.update(crate::presentation::InteractiveOcrMessage::CopyAllText);
+        let copy_succeeded = view.has_copy_succeeded();
+        self.log_info_event(
+            "ocr_auto_copy_finished",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "text_length": recognized_length,
+                "succeeded": copy_succeeded,
+            }),
+        );
+        self.status = if copy_succeeded {
+            global_constants::STATUS_OCR_TEXT_AUTO_COPIED.to_string()
+        } else {
+            global_constants::STATUS_OCR_AUTO_COPY_FAILED.to_string()
+        };
+
+        Task::future(async move {
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+            OrchestratorMessage::InteractiveOcrMessage(
+                window_id,
+                crate::presentation::InteractiveOcrMessage::HideToast,
+            )
+        })
+    }
+
+    pub fn build_ocr_service_task(
+        &self,
+        engine: OcrEngine,
+        language_code: String,
+    ) -> Task<OrchestratorMessage> {
+        let requested_engine = engine.clone();
+        self.run_ocr_service_build(engine, language_code, move |build_result| {
+            build_ocr_service_message(&requested_engine, build_result)
+        })
+    }
+
+    fn run_ocr_service_build<F>(
+        &self,
+        engine: OcrEngine,
+        language_code: String,
+        finish: F,
+    ) -> Task<OrchestratorMessage>
+    where
+        F: FnOnce(anyhow::Result<BuiltOcrService>) -> OrchestratorMessage + Send + 'static,
+    {
+        let in_flight_guard = OcrBuildInFlightGuard::track(&self.ocr_builds_in_flight);
+        Task::run(
+            iced::stream::channel(
+                10,
+                |mut output: futures::channel::mpsc::Sender<OrchestratorMessage>| async move {
+                    use futures::{SinkExt, StreamExt};
+
+                    let _in_flight_guard = in_flight_guard;
+                    let language = resolve_ocr_language(&language_code);
+                    let (progress_sender, mut progress_receiver) =
+                        futures::channel::mpsc::unbounded();
+                    let build_handle = tokio::task::spawn_blocking(move || {
+                        build_ocr_service(&engine, language, &progress_sender)
+                    });
+
+                    while let Some(progress) = progress_receiver.next().await {
+                        let _ = output
+                            .send(OrchestratorMessage::OcrModelDownloadProgress(progress))
+                            .await;
+                    }
+
+                    let build_result = build_handle
+                        .await
+                        .unwrap_or_else(|join_error| Err(anyhow::anyhow!(join_error.to_string())));
+                    let unavailable_language = build_result
+                        .as_ref()
+                        .ok()
+                        .and_then(|built| built.unavailable_language);
+                    let _ = output.send(finish(build_result)).await;
+                    if let Some(language) = unavailable_language {
+                        let _ = output
+                            .send(OrchestratorMessage::OcrTrainingDataUnavailable(language))
+                            .await;
+                    }
+                },
+            ),
+            std::convert::identity,
+        )
+    }
+
+    pub(super) fn handle_ocr_model_download_progress(
+        &mut self,
+        progress: ModelDownloadProgress,
+    ) -> Task<OrchestratorMessage> {
+        log::debug!(
+            "[ORCHESTRATOR] OCR model {} download: {} of {:?} bytes",
+            progress.filename,
+            progress.received_bytes,
+            progress.total_bytes
+        );
+        self.status = build_model_download_status(&progress);
+        Task::none()
+    }
+
     pub(super) fn handle_ocr_service_ready(
         &mut self,
         service: Arc<dyn OcrService>,
     ) -> Task<OrchestratorMessage> {
-        self.log_info_event("ocr_service_ready", serde_json::json!({}));
+        self.log_info_event(
+            "ocr_service_ready",
+            serde_json::json!({"language": self.settings.language}),
+        );
         self.ocr_service = service;
-        self.status = global_constants::STATUS_READY.to_string();
+        self.ocr_language_services.clear();
+        self.status = if OcrLanguage::from_code(&self.settings.language).is_some() {
+            global_constants::STATUS_READY.to_string()
+        } else {
+            format!(
+                "{}{}",
+                global_constants::STATUS_OCR_LANGUAGE_UNSUPPORTED_PREFIX,
+                self.settings.language
+            )
+        };
         Task::none()
     }
 
+    pub(super) fn handle_ocr_service_fallback_ready(
+        &mut self,
+        service: Arc<dyn OcrService>,
+        fallback_engine: OcrEngine,
+    ) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "ocr_service_fallback_selected",
+            serde_json::json!({
+                "requested_engine": self.settings.ocr_engine.to_string(),
+                "fallback_engine": fallback_engine.to_string(),
+            }),
+        );
+        let task = self.handle_ocr_service_ready(service);
+        self.status = format!(
+            "{}{}",
+            global_constants::STATUS_OCR_ENGINE_FALLBACK_PREFIX,
+            fallback_engine
+        );
+        task
+    }
+
+    pub(super) fn handle_ocr_training_data_unavailable(
+        &mut self,
+        language: OcrLanguage,
+    ) -> Task<OrchestratorMessage> {
+        self.log_error_event(
+            "ocr_training_data_unavailable",
+            serde_json::json!({"language": language.code()}),
+        );
+        self.status = format!(
+            "{}{}",
+            global_constants::STATUS_OCR_TRAINING_DATA_UNAVAILABLE_PREFIX,
+            language
+        );
+        self.show_main_window_toast(self.status.clone(), ToastKind::Error)
+    }
+
     pub(super) fn handle_ocr_service_failed(&mut self, error: String) -> Task<OrchestratorMessage> {
         self.log_error_event(
             "ocr_service_initialization_failed",
@@ -328,6 +1234,680 @@ impl AppOrchestrator {
             global_constants::OCR_INITIALIZATION_FAILED_PREFIX,
             error
         );
-        Task::none()
+        self.show_main_window_toast(self.status.clone(), ToastKind::Error)
+    }
+}
+
+fn resolve_ocr_language(language_code: &str) -> OcrLanguage {
+    OcrLanguage::from_code(language_code).unwrap_or_else(|| {
+        log::warn!(
+            "[ORCHESTRATOR] Unsupported OCR language '{}', falling back to English",
+            language_code
+        );
+        OcrLanguage::default()
+    })
+}
+
+struct OcrBuildInFlightGuard(Arc<AtomicUsize>);
+
+impl OcrBuildInFlightGuard {
+    fn track(counter: &Arc<AtomicUsize>) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(Arc::clone(counter))
+    }
+}
+
+impl Drop for OcrBuildInFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn build_ocr_service_message(
+    requested_engine: &OcrEngine,
+    build_result: anyhow::Result<BuiltOcrService>,
+) -> OrchestratorMessage {
+    match build_result.map(|built| (built.service, built.fallback_engine)) {
+        Ok((service, Some(fallback_engine))) => {
+            log::warn!(
+                "[ORCHESTRATOR] {} OCR unavailable, using {} instead",
+                requested_engine,
+                fallback_engine
+            );
+            OrchestratorMessage::OcrServiceFallbackReady(service, fallback_engine)
+        }
+        Ok((service, None)) => {
+            log::info!(
+                "[ORCHESTRATOR] {} OCR service initialized",
+                requested_engine
+            );
+            OrchestratorMessage::OcrServiceReady(service)
+        }
+        Err(build_error) => {
+            log::error!(
+                "[ORCHESTRATOR] Failed to initialize {} OCR service: {}",
+                requested_engine,
+                build_error
+            );
+            OrchestratorMessage::OcrServiceFailed(build_error.to_string())
+        }
+    }
+}
+
+pub(super) fn build_model_download_status(progress: &ModelDownloadProgress) -> String {
+    match progress.percent() {
+        Some(percent) => format!(
+            "{}{}%",
+            global_constants::STATUS_DOWNLOADING_OCR_MODELS_PREFIX,
+            percent
+        ),
+        None => {
+            let frames = global_constants::STATUS_DOWNLOAD_SPINNER_FRAMES;
+            let frame_index = (progress.received_bytes
+                / global_constants::STATUS_DOWNLOAD_SPINNER_BYTES_PER_FRAME)
+                as usize
+                % frames.len();
+            format!(
+                "{}{}",
+                global_constants::STATUS_DOWNLOADING_OCR_MODELS_PREFIX,
+                frames[frame_index]
+            )
+        }
+    }
+}
+
+pub(super) struct BuiltOcrService {
+    service: Arc<dyn OcrService>,
+    fallback_engine: Option<OcrEngine>,
+    unavailable_language: Option<OcrLanguage>,
+}
+
+fn build_ocr_service(
+    engine: &OcrEngine,
+    language: OcrLanguage,
+    progress_sender: &futures::channel::mpsc::UnboundedSender<ModelDownloadProgress>,
+) -> anyhow::Result<BuiltOcrService> {
+    let build_tesseract = move || {
+        TesseractOcrService::build(language).map(|service| {
+            let unavailable_language = (!service.uses_language(language)).then_some(language);
+            (
+                Arc::new(service) as Arc<dyn OcrService>,
+                unavailable_language,
+            )
+        })
+    };
+    let build_neural = move || {
+        NeuralOcrService::build(language, Some(progress_sender))
+            .map(|service| (Arc::new(service) as Arc<dyn OcrService>, None))
+    };
+    let build_onnx = move || {
+        OnnxOcrService::build(language, Some(progress_sender))
+            .map(|service| (Arc::new(service) as Arc<dyn OcrService>, None))
+    };
+
+    let ((service, unavailable_language), fallback_engine) = match engine {
+        OcrEngine::Tesseract => build_with_fallback(
+            OcrEngine::Tesseract,
+            build_tesseract,
+            OcrEngine::Neural,
+            build_neural,
+        )?,
+        OcrEngine::Neural => build_with_fallback(
+            OcrEngine::Neural,
+            build_neural,
+            OcrEngine::Tesseract,
+            build_tesseract,
+        )?,
+        OcrEngine::Onnx => build_with_fallback(
+            OcrEngine::Onnx,
+            build_onnx,
+            OcrEngine::Tesseract,
+            build_tesseract,
+        )?,
+        OcrEngine::Auto => {
+            let (built_member, _) = build_with_fallback(
+                OcrEngine::Tesseract,
+                build_tesseract,
+                OcrEngine::Neural,
+                build_neural,
+            )?;
+            (built_member, None)
+        }
+        OcrEngine::Ensemble => {
+            let member_results = [
+                (OcrEngine::Tesseract, build_tesseract()),
+                (OcrEngine::Neural, build_neural()),
+            ];
+
+            let mut engines = Vec::new();
+            let mut unavailable_language = None;
+            for (member_engine, member_result) in member_results {
+                match member_result {
+                    Ok((service, member_unavailable_language)) => {
+                        engines.push(service);
+                        unavailable_language = unavailable_language.or(member_unavailable_language);
+                    }
+                    Err(member_error) => log::error!(
+                        "[ORCHESTRATOR] {} OCR unavailable for ensemble: {}",
+                        member_engine,
+                        member_error
+                    ),
+                }
+            }
+
+            if engines.is_empty() {
+                return Err(anyhow::anyhow!("No OCR engines available for ensemble"));
+            }
+
+            (
+                (
+                    Arc::new(EnsembleOcrService::new(engines)) as Arc<dyn OcrService>,
+                    unavailable_language,
+                ),
+                None,
+            )
+        }
+    };
+
+    Ok(BuiltOcrService {
+        service,
+        fallback_engine,
+        unavailable_language,
+    })
+}
+
+fn build_with_fallback<T>(
+    primary_engine: OcrEngine,
+    build_primary: impl FnOnce() -> anyhow::Result<T>,
+    fallback_engine: OcrEngine,
+    build_fallback: impl FnOnce() -> anyhow::Result<T>,
+) -> anyhow::Result<(T, Option<OcrEngine>)> {
+    let primary_error = match build_primary() {
+        Ok(service) => return Ok((service, None)),
+        Err(primary_error) => primary_error,
+    };
+
+    log::warn!(
+        "[ORCHESTRATOR] {} OCR unavailable, trying {}: {}",
+        primary_engine,
+        fallback_engine,
+        primary_error
+    );
+
+    build_fallback()
+        .map(|service| (service, Some(fallback_engine.clone())))
+        .map_err(|fallback_error| {
+            anyhow::anyhow!(
+                "{}: {}; {}: {}",
+                primary_engine,
+                primary_error,
+                fallback_engine,
+                fallback_error
+            )
+        })
+}
+
+fn feedback_sound_for_message(
+    ocr_msg: &crate::presentation::InteractiveOcrMessage,
+    copy_succeeded: bool,
+) -> Option<FeedbackSound> {
+    match ocr_msg {
+        crate::presentation::InteractiveOcrMessage::CopySelected
+        | crate::presentation::InteractiveOcrMessage::CopyAllText
+        | crate::presentation::InteractiveOcrMessage::CopyDecodedCode(_)
+        | crate::presentation::InteractiveOcrMessage::CopyImageUrl
+        | crate::presentation::InteractiveOcrMessage::CopyTextLayoutJson
+        | crate::presentation::InteractiveOcrMessage::CopyAsMarkdown
+            if copy_succeeded =>
+        {
+            Some(FeedbackSound::Success)
+        }
+        crate::presentation::InteractiveOcrMessage::CopyImageSuccess
+        | crate::presentation::InteractiveOcrMessage::SaveSuccess(_)
+        | crate::presentation::InteractiveOcrMessage::ExportTextSuccess(_)
+        | crate::presentation::InteractiveOcrMessage::SearchCompleted => {
+            Some(FeedbackSound::Success)
+        }
+        crate::presentation::InteractiveOcrMessage::CopyImageFailed(_)
+        | crate::presentation::InteractiveOcrMessage::SaveFailed(_)
+        | crate::presentation::InteractiveOcrMessage::SearchFailed(_)
+        | crate::presentation::InteractiveOcrMessage::OcrFailed(_) => Some(FeedbackSound::Failure),
+        _ => None,
+    }
+}
+
+fn should_auto_close_after_action(
+    ocr_msg: &crate::presentation::InteractiveOcrMessage,
+    copy_succeeded: bool,
+) -> bool {
+    match ocr_msg {
+        crate::presentation::InteractiveOcrMessage::CopySelected
+        | crate::presentation::InteractiveOcrMessage::CopyAllText
+        | crate::presentation::InteractiveOcrMessage::CopyAsMarkdown => copy_succeeded,
+        crate::presentation::InteractiveOcrMessage::SearchCompleted
+        | crate::presentation::InteractiveOcrMessage::SaveSuccess(_) => true,
+        _ => false,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SearchRequest {
+    Image(Option<String>),
+    Text(String),
+}
+
+fn resolve_search_request(text_only: bool, query: &str) -> Option<SearchRequest> {
+    if text_only {
+        let query = query.trim();
+        return (!query.is_empty()).then(|| SearchRequest::Text(query.to_string()));
+    }
+
+    Some(SearchRequest::Image(
+        (!query.is_empty()).then(|| query.to_string()),
+    ))
+}
+
+fn build_search_buffer(
+    buffer: &CaptureBuffer,
+    draw_strokes: &[crate::presentation::DrawStroke],
+    include_annotations: bool,
+) -> CaptureBuffer {
+    if !include_annotations || draw_strokes.is_empty() {
+        return buffer.clone();
+    }
+
+    CaptureBuffer::build_from_raw_data(
+        buffer._scale_factor,
+        buffer.width,
+        buffer.height,
+        AppOrchestrator::build_clipboard_image_data(buffer, draw_strokes),
+    )
+}
+
+fn build_text_search_url(template: &str, query: &str) -> Option<String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    Some(template.replace("{}", &urlencoding::encode(query)))
+}
+
+async fn recognize_capture_buffer(
+    ocr_service: Arc<dyn OcrService>,
+    buffer: CaptureBuffer,
+    ocr_timeout: std::time::Duration,
+    correlation_id: String,
+) -> Result<OcrResult, String> {
+    let (width, height) = (buffer.width, buffer.height);
+    AppOrchestrator::log_info_event_for_correlation(
+        correlation_id.clone(),
+        "ocr_image_converting",
+        serde_json::json!({"width": width, "height": height}),
+    );
+
+    let dynamic_image = buffer.to_dynamic_image().map_err(|conversion_error| {
+        AppOrchestrator::log_error_event_for_correlation(
+            correlation_id.clone(),
+            "ocr_image_conversion_failed",
+            serde_json::json!({
+                "width": width,
+                "height": height,
+                "raw_bytes": buffer.raw_data.len(),
+            }),
+        );
+        conversion_error.to_string()
+    })?;
+
+    AppOrchestrator::log_info_event_for_correlation(
+        correlation_id.clone(),
+        "ocr_running",
+        serde_json::json!({"timeout_secs": ocr_timeout.as_secs()}),
+    );
+    match extract_text_with_timeout(ocr_service, dynamic_image, ocr_timeout).await {
+        Ok(result) => {
+            AppOrchestrator::log_info_event_for_correlation(
+                correlation_id,
+                "ocr_completed",
+                serde_json::json!({"text_blocks": result.text_blocks.len()}),
+            );
+            Ok(result)
+        }
+        Err(ocr_error) => {
+            AppOrchestrator::log_error_event_for_correlation(
+                correlation_id,
+                "ocr_failed",
+                serde_json::json!({"error": ocr_error}),
+            );
+            Err(ocr_error)
+        }
+    }
+}
+
+async fn extract_text_with_timeout(
+    ocr_service: Arc<dyn OcrService>,
+    image: image::DynamicImage,
+    timeout: std::time::Duration,
+) -> Result<OcrResult, String> {
+    let ocr_task = tokio::task::spawn_blocking(move || {
+        futures::executor::block_on(ocr_service.extract_text_from_image(&image))
+            .map_err(|ocr_error| ocr_error.to_string())
+    });
+
+    match tokio::time::timeout(timeout, ocr_task).await {
+        Ok(join_result) => join_result.unwrap_or_else(|join_error| Err(join_error.to_string())),
+        Err(_) => {
+            log::warn!(
+                "[ORCHESTRATOR] OCR did not finish within {}s",
+                timeout.as_secs_f32()
+            );
+            Err(global_constants::OCR_TIMED_OUT.to_string())
+        }
+    }
+}
+
+async fn translate_with_timeout(
+    translation_service: Arc<dyn TranslationService>,
+    text: String,
+    target_lang: String,
+    timeout: std::time::Duration,
+) -> Result<String, String> {
+    let translate_future = translation_service.translate(&text, &target_lang);
+
+    match tokio::time::timeout(timeout, translate_future).await {
+        Ok(translate_result) => translate_result.map_err(|error| error.to_string()),
+        Err(_) => {
+            log::warn!(
+                "[ORCHESTRATOR] Translation did not finish within {}s",
+                timeout.as_secs_f32()
+            );
+            Err(global_constants::TRANSLATION_TIMEOUT_MESSAGE.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SlowOcrService;
+
+    #[async_trait::async_trait]
+    impl OcrService for SlowOcrService {
+        async fn extract_text_from_image(
+            &self,
+            _image: &image::DynamicImage,
+        ) -> anyhow::Result<OcrResult> {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            Ok(OcrResult {
+                text_blocks: vec![],
+                full_text: "late".to_string(),
+            })
+        }
+    }
+
+    struct SlowTranslationService;
+
+    #[async_trait::async_trait]
+    impl TranslationService for SlowTranslationService {
+        async fn translate(&self, text: &str, target_lang: &str) -> anyhow::Result<String> {
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            Ok(format!("{}:{}", target_lang, text))
+        }
+    }
+
+    #[test]
+    fn test_build_with_fallback_selects_fallback_when_primary_fails() {
+        let (service, fallback_engine) = build_with_fallback(
+            OcrEngine::Tesseract,
+            || Err(anyhow::anyhow!("tesseract not installed")),
+            OcrEngine::Neural,
+            || Ok("neural"),
+        )
+        .unwrap();
+
+        assert_eq!(service, "neural");
+        assert_eq!(fallback_engine, Some(OcrEngine::Neural));
+    }
+
+    #[test]
+    fn test_build_with_fallback_keeps_primary_and_reports_both_failures() {
+        let primary = build_with_fallback(
+            OcrEngine::Tesseract,
+            || Ok("tesseract"),
+            OcrEngine::Neural,
+            || -> anyhow::Result<&str> { panic!("fallback should not be built") },
+        )
+        .unwrap();
+        let both_failed: anyhow::Result<(&str, Option<OcrEngine>)> = build_with_fallback(
+            OcrEngine::Tesseract,
+            || Err(anyhow::anyhow!("tesseract not installed")),
+            OcrEngine::Neural,
+            || Err(anyhow::anyhow!("model download failed")),
+        );
+
+        assert_eq!(primary, ("tesseract", None));
+        let error = both_failed.unwrap_err().to_string();
+        assert!(error.contains("tesseract not installed"));
+        assert!(error.contains("model download failed"));
+    }
+
+    #[test]
+    fn test_build_model_download_status_shows_percent_or_spinner() {
+        let known_length = ModelDownloadProgress {
+            filename: "text-detection.rten".to_string(),
+            received_bytes: 400,
+            total_bytes: Some(1_000),
+        };
+        let unknown_length = ModelDownloadProgress {
+            total_bytes: None,
+            ..known_length.clone()
+        };
+
+        assert_eq!(
+            build_model_download_status(&known_length),
+            format!(
+                "{}40%",
+                global_constants::STATUS_DOWNLOADING_OCR_MODELS_PREFIX
+            )
+        );
+        assert!(global_constants::STATUS_DOWNLOAD_SPINNER_FRAMES
+            .iter()
+            .any(|frame| build_model_download_status(&unknown_length).ends_with(frame)));
+    }
+
+    fn create_search_test_stroke() -> crate::presentation::DrawStroke {
+        crate::presentation::DrawStroke {
+            points: vec![Point::new(0.0, 0.0), Point::new(3.0, 3.0)],
+            color: iced::Color::from_rgb(1.0, 0.0, 0.0),
+            width: 2.0,
+            tool: crate::presentation::DrawTool::Pen,
+        }
+    }
+
+    #[test]
+    fn test_build_search_buffer_flattens_drawings_when_enabled() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 4, 4, vec![255u8; 64]);
+
+        let search_buffer = build_search_buffer(&buffer, &[create_search_test_stroke()], true);
+
+        assert_eq!(search_buffer.width, buffer.width);
+        assert_eq!(search_buffer.height, buffer.height);
+        assert_ne!(search_buffer.raw_data, buffer.raw_data);
+    }
+
+    #[test]
+    fn test_build_search_buffer_keeps_raw_image_when_disabled() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 4, 4, vec![255u8; 64]);
+
+        let search_buffer = build_search_buffer(&buffer, &[create_search_test_stroke()], false);
+
+        assert_eq!(search_buffer.raw_data, buffer.raw_data);
+    }
+
+    #[test]
+    fn test_feedback_sound_for_message_distinguishes_outcomes() {
+        use crate::presentation::InteractiveOcrMessage;
+
+        assert_eq!(
+            feedback_sound_for_message(&InteractiveOcrMessage::CopySelected, true),
+            Some(FeedbackSound::Success)
+        );
+        assert_eq!(
+            feedback_sound_for_message(&InteractiveOcrMessage::CopySelected, false),
+            None
+        );
+        assert_eq!(
+            feedback_sound_for_message(
+                &InteractiveOcrMessage::SaveFailed("disk full".to_string()),
+                false
+            ),
+            Some(FeedbackSound::Failure)
+        );
+        assert_eq!(
+            feedback_sound_for_message(&InteractiveOcrMessage::SelectAll, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_build_text_search_url_encodes_query_into_template() {
+        let search_url = build_text_search_url(
+            global_constants::DEFAULT_TEXT_SEARCH_URL,
+            "  rust iced & canvas ",
+        );
+
+        assert_eq!(
+            search_url.as_deref(),
+            Some("https://www.google.com/search?q=rust%20iced%20%26%20canvas")
+        );
+    }
+
+    #[test]
+    fn test_text_only_search_resolves_to_text_request_without_image() {
+        assert_eq!(
+            resolve_search_request(true, "  iced canvas "),
+            Some(SearchRequest::Text("iced canvas".to_string()))
+        );
+        assert_eq!(resolve_search_request(true, "   "), None);
+    }
+
+    #[test]
+    fn test_image_search_resolves_to_image_request_with_optional_query() {
+        assert_eq!(
+            resolve_search_request(false, "iced canvas"),
+            Some(SearchRequest::Image(Some("iced canvas".to_string())))
+        );
+        assert_eq!(
+            resolve_search_request(false, ""),
+            Some(SearchRequest::Image(None))
+        );
+    }
+
+    #[test]
+    fn test_build_text_search_url_rejects_blank_query() {
+        assert!(build_text_search_url(global_constants::DEFAULT_TEXT_SEARCH_URL, "   ").is_none());
+    }
+
+    #[test]
+    fn test_should_auto_close_after_action_for_completed_actions() {
+        use crate::presentation::InteractiveOcrMessage;
+
+        assert!(should_auto_close_after_action(
+            &InteractiveOcrMessage::CopySelected,
+            true
+        ));
+        assert!(should_auto_close_after_action(
+            &InteractiveOcrMessage::SearchCompleted,
+            false
+        ));
+        assert!(should_auto_close_after_action(
+            &InteractiveOcrMessage::SaveSuccess("/tmp/shot.png".to_string()),
+            false
+        ));
+    }
+
+    #[test]
+    fn test_should_auto_close_after_action_ignores_failed_copy_and_other_messages() {
+        use crate::presentation::InteractiveOcrMessage;
+
+        assert!(!should_auto_close_after_action(
+            &InteractiveOcrMessage::CopySelected,
+            false
+        ));
+        assert!(!should_auto_close_after_action(
+            &InteractiveOcrMessage::SelectAll,
+            true
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_extract_text_with_timeout_reports_timeout_for_hung_ocr() {
+        let result = extract_text_with_timeout(
+            Arc::new(SlowOcrService),
+            image::DynamicImage::new_rgb8(8, 8),
+            std::time::Duration::from_millis(20),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), global_constants::OCR_TIMED_OUT);
+    }
+
+    #[tokio::test]
+    async fn test_extract_text_with_timeout_returns_result_within_limit() {
+        let result = extract_text_with_timeout(
+            Arc::new(SlowOcrService),
+            image::DynamicImage::new_rgb8(8, 8),
+            std::time::Duration::from_secs(5),
+        )
+        .await;
+
+        assert_eq!(result.unwrap().full_text, "late");
+    }
+
+    #[tokio::test]
+    async fn test_translate_with_timeout_reports_timeout() {
+        let result = translate_with_timeout(
+            Arc::new(SlowTranslationService),
+            "hola".to_string(),
+            "en".to_string(),
+            std::time::Duration::from_millis(50),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            global_constants::TRANSLATION_TIMEOUT_MESSAGE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_translate_with_timeout_returns_translation_within_limit() {
+        let result = translate_with_timeout(
+            Arc::new(SlowTranslationService),
+            "hola".to_string(),
+            "en".to_string(),
+            std::time::Duration::from_secs(5),
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "en:hola");
+    }
+
+    #[tokio::test]
+    async fn test_recognize_capture_buffer_reports_mismatched_buffer_without_panicking() {
+        let malformed_buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 12]);
+
+        let result = recognize_capture_buffer(
+            Arc::new(SlowOcrService),
+            malformed_buffer,
+            std::time::Duration::from_secs(5),
+            "test".to_string(),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            global_constants::OCR_RAW_IMAGE_CREATION_FAILED
+        );
     }
 }
@@ -0,0 +1,37 @@
+use super::*;
+
+impl AppOrchestrator {
+    pub(super) fn show_main_window_toast(
+        &mut self,
+        message: String,
+        kind: ToastKind,
+    ) -> Task<OrchestratorMessage> {
+        self.main_window_toast_generation = self.main_window_toast_generation.wrapping_add(1);
+        let generation = self.main_window_toast_generation;
+        self.log_info_event(
+            "main_window_toast_shown",
+            serde_json::json!({
+                "message": message,
+                "kind": format!("{:?}", kind),
+                "generation": generation,
+            }),
+        );
+        self.main_window_toast = Some((message, kind));
+
+        Task::future(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(
+                global_constants::MAIN_WINDOW_TOAST_DURATION_MS,
+            ))
+            .await;
+            OrchestratorMessage::HideMainWindowToast(generation)
+        })
+    }
+
+    pub(super) fn handle_hide_main_window_toast(&mut self, generation: u64) {
+        if generation != self.main_window_toast_generation {
+            return;
+        }
+
+        self.main_window_toast = None;
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::adapters::{auto_launch, macos_permissions};
+use crate::presentation::{CaptureTestStatus, ConnectivityStatus};
 
 impl AppOrchestrator {
     pub(super) fn handle_open_onboarding(&mut self) -> Task<OrchestratorMessage> {
@@ -33,7 +34,19 @@ impl AppOrchestrator {
             serde_json::json!({"window_id": format!("{:?}", id)}),
         );
 
-        task.discard()
+        Task::batch(vec![task.discard(), self.check_onboarding_connectivity(id)])
+    }
+
+    fn check_onboarding_connectivity(&self, window_id: Id) -> Task<OrchestratorMessage> {
+        let probe_url = self.settings.image_hosting_provider_url.clone();
+
+        Task::future(async move {
+            let is_online = is_network_reachable(&probe_url).await;
+            OrchestratorMessage::OnboardingMsg(
+                window_id,
+                OnboardingMessage::ConnectivityChecked(is_online),
+            )
+        })
     }
 
     pub(super) fn handle_onboarding_message(
@@ -75,14 +88,86 @@ impl AppOrchestrator {
             OnboardingMessage::FinishOnboarding => {
                 return self.handle_finish_onboarding(window_id);
             }
+            OnboardingMessage::ConnectivityChecked(is_online) => {
+                self.log_info_event(
+                    "onboarding_connectivity_checked",
+                    serde_json::json!({"online": is_online}),
+                );
+            }
+            OnboardingMessage::CaptureTestFinished(ref result) => match result {
+                Ok(()) => {
+                    self.log_info_event("onboarding_capture_test_passed", serde_json::json!({}))
+                }
+                Err(capture_error) => self.log_error_event(
+                    "onboarding_capture_test_failed",
+                    serde_json::json!({"error": capture_error}),
+                ),
+            },
             _ => {}
         }
 
-        if let Some(AppWindow::Onboarding(view)) = self.windows.get_mut(&window_id) {
-            view.handle_message(message);
+        let follow_up_task = match message {
+            OnboardingMessage::RetryConnectivityCheck => {
+                self.check_onboarding_connectivity(window_id)
+            }
+            OnboardingMessage::ContinueOffline => self.switch_to_offline_ocr_engine(),
+            _ => Task::none(),
+        };
+
+        let should_run_capture_test = match self.windows.get_mut(&window_id) {
+            Some(AppWindow::Onboarding(view)) => {
+                let was_running = *view.capture_test_status() == CaptureTestStatus::Running;
+                view.handle_message(message);
+                !was_running && *view.capture_test_status() == CaptureTestStatus::Running
+            }
+            _ => false,
+        };
+
+        if should_run_capture_test {
+            return Task::batch(vec![
+                follow_up_task,
+                self.run_onboarding_capture_test(window_id),
+            ]);
         }
 
-        Task::none()
+        follow_up_task
+    }
+
+    fn run_onboarding_capture_test(&self, window_id: Id) -> Task<OrchestratorMessage> {
+        self.log_info_event("onboarding_capture_test_started", serde_json::json!({}));
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+
+        Task::future(async move {
+            let result =
+                tokio::task::spawn_blocking(move || run_capture_test(screen_capturer.as_ref()))
+                    .await
+                    .unwrap_or_else(|join_error| Err(join_error.to_string()));
+
+            OrchestratorMessage::OnboardingMsg(
+                window_id,
+                OnboardingMessage::CaptureTestFinished(result),
+            )
+        })
+    }
+
+    fn switch_to_offline_ocr_engine(&mut self) -> Task<OrchestratorMessage> {
+        if self.settings.ocr_engine == OcrEngine::Tesseract {
+            return Task::none();
+        }
+
+        self.log_info_event(
+            "onboarding_offline_ocr_engine_selected",
+            serde_json::json!({"previous_engine": self.settings.ocr_engine.to_string()}),
+        );
+        self.settings.ocr_engine = OcrEngine::Tesseract;
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "onboarding_settings_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+
+        self.build_ocr_service_task(OcrEngine::Tesseract, self.settings.language.clone())
     }
 
     fn refresh_onboarding_permissions(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
@@ -109,9 +194,12 @@ impl AppOrchestrator {
     fn handle_finish_onboarding(&mut self, window_id: Id) -> Task<OrchestratorMessage> {
         self.log_info_event("onboarding_finishing", serde_json::json!({}));
 
-        let launch_at_login = match self.windows.get(&window_id) {
-            Some(AppWindow::Onboarding(view)) => view.is_launch_at_login_enabled(),
-            _ => false,
+        let (launch_at_login, finished_offline) = match self.windows.get(&window_id) {
+            Some(AppWindow::Onboarding(view)) => (
+                view.is_launch_at_login_enabled(),
+                view.connectivity_status() == ConnectivityStatus::Offline,
+            ),
+            _ => (false, false),
         };
 
         self.settings.onboarding_complete = true;
@@ -127,7 +215,10 @@ impl AppOrchestrator {
         auto_launch::set_launch_at_login(launch_at_login);
         self.log_info_event(
             "onboarding_finished",
-            serde_json::json!({"launch_at_login": launch_at_login}),
+            serde_json::json!({
+                "launch_at_login": launch_at_login,
+                "offline": finished_offline,
+            }),
         );
 
         self.windows.remove(&window_id);
@@ -140,3 +231,45 @@ impl AppOrchestrator {
         ])
     }
 }
+
+pub(super) fn run_capture_test(screen_capturer: &dyn ScreenCapturer) -> Result<(), String> {
+    let region = screen_capturer
+        .get_primary_monitor_origin()
+        .unwrap_or_else(|_| ScreenRegion::at_coordinates(0, 0));
+    let capture_buffer = screen_capturer
+        .capture_screen_at_region(&region)
+        .map_err(|capture_error| format!("{:#}", capture_error))?;
+
+    if capture_buffer.width == 0 || capture_buffer.height == 0 || capture_buffer.raw_data.is_empty()
+    {
+        return Err(global_constants::ONBOARDING_CAPTURE_TEST_EMPTY_ERROR.to_string());
+    }
+
+    Ok(())
+}
+
+async fn is_network_reachable(probe_url: &str) -> bool {
+    let client = match reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(
+            global_constants::CONNECTIVITY_CHECK_TIMEOUT_SECS,
+        ))
+        .build()
+    {
+        Ok(client) => client,
+        Err(build_error) => {
+            log::warn!(
+                "[ONBOARDING] Failed to build connectivity client: {}",
+                build_error
+            );
+            return false;
+        }
+    };
+
+    match client.head(probe_url).send().await {
+        Ok(_) => true,
+        Err(request_error) => {
+            log::warn!("[ONBOARDING] Connectivity check failed: {}", request_error);
+            false
+        }
+    }
+}
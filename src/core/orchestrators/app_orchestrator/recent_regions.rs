@@ -0,0 +1,211 @@
+use super::capture::resolve_capture_delay_ms;
+use super::*;
+use crate::core::models::RecentRegion;
+
+impl AppOrchestrator {
+    pub(super) fn remember_recent_region(&mut self, selection_rect: Rectangle) {
+        let Some((monitor_x, monitor_y)) = self.capture_overlay_monitor_origin else {
+            return;
+        };
+
+        let region = RecentRegion {
+            monitor_x,
+            monitor_y,
+            x: selection_rect.x.max(0.0) as u32,
+            y: selection_rect.y.max(0.0) as u32,
+            width: selection_rect.width.max(0.0) as u32,
+            height: selection_rect.height.max(0.0) as u32,
+        };
+        self.store_recent_region(region);
+    }
+
+    fn store_recent_region(&mut self, region: RecentRegion) {
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        self.log_info_event(
+            "recent_region_remembered",
+            serde_json::json!({"region": region.to_string()}),
+        );
+
+        self.settings.recent_regions = push_recent_region(
+            &self.settings.recent_regions,
+            region.clone(),
+            global_constants::RECENT_REGIONS_LIMIT,
+        );
+        let _ = self.update_settings_draft(|settings| {
+            settings.recent_regions = push_recent_region(
+                &settings.recent_regions,
+                region,
+                global_constants::RECENT_REGIONS_LIMIT,
+            );
+        });
+
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "recent_region_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+    }
+
+    pub(super) fn handle_capture_recent_region(
+        &mut self,
+        region_index: usize,
+    ) -> Task<OrchestratorMessage> {
+        let Some(region) = self.settings.recent_regions.get(region_index).cloned() else {
+            self.log_error_event(
+                "recent_region_not_found",
+                serde_json::json!({"index": region_index}),
+            );
+            return Task::none();
+        };
+
+        self.log_info_event(
+            "recent_region_capture_started",
+            serde_json::json!({"region": region.to_string()}),
+        );
+        self.status = global_constants::STATUS_CAPTURING_SCREEN.to_string();
+
+        let main_window_id =
+            self.main_window_in_capture_path(Some((region.monitor_x, region.monitor_y)));
+        let capture_delay_ms =
+            resolve_capture_delay_ms(main_window_id.is_some(), self.settings.capture_delay_ms);
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+
+        let capture_task = Task::future(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(capture_delay_ms as u64)).await;
+            let monitor_region = ScreenRegion::at_coordinates(region.monitor_x, region.monitor_y);
+            let capture_result = screen_capturer
+                .capture_screen_at_region(&monitor_region)
+                .map(|capture_buffer| (capture_buffer, region))
+                .map_err(|capture_error| capture_error.to_string());
+            OrchestratorMessage::RecentRegionCaptured(capture_result)
+        });
+
+        Task::batch(vec![
+            self.minimize_main_window_for_capture(main_window_id),
+            capture_task,
+        ])
+    }
+
+    pub(super) fn handle_recent_region_captured(
+        &mut self,
+        capture_result: Result<(CaptureBuffer, RecentRegion), String>,
+    ) -> Task<OrchestratorMessage> {
+        let (capture_buffer, region) = match capture_result {
+            Ok(captured) => captured,
+            Err(capture_error) => return self.handle_capture_error(capture_error),
+        };
+
+        let Some(selection_rect) =
+            clamp_recent_region_to_bounds(&region, capture_buffer.width, capture_buffer.height)
+        else {
+            self.log_error_event(
+                "recent_region_outside_screen",
+                serde_json::json!({"region": region.to_string()}),
+            );
+            self.status = global_constants::STATUS_RECENT_REGION_OUTSIDE_SCREEN.to_string();
+            return Task::none();
+        };
+
+        self.store_recent_region(region);
+        self.handle_show_cropped_image(capture_buffer, selection_rect)
+    }
+}
+
+fn push_recent_region(
+    recent_regions: &[RecentRegion],
+    region: RecentRegion,
+    limit: usize,
+) -> Vec<RecentRegion> {
+    let mut updated_regions = vec![region.clone()];
+    updated_regions.extend(
+        recent_regions
+            .iter()
+            .filter(|existing| **existing != region)
+            .cloned(),
+    );
+    updated_regions.truncate(limit);
+    updated_regions
+}
+
+fn clamp_recent_region_to_bounds(
+    region: &RecentRegion,
+    bounds_width: u32,
+    bounds_height: u32,
+) -> Option<Rectangle> {
+    if region.x >= bounds_width || region.y >= bounds_height {
+        return None;
+    }
+
+    let width = region.width.min(bounds_width - region.x);
+    let height = region.height.min(bounds_height - region.y);
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    Some(Rectangle::new(
+        Point::new(region.x as f32, region.y as f32),
+        Size::new(width as f32, height as f32),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_region(x: u32, y: u32, width: u32, height: u32) -> RecentRegion {
+        RecentRegion {
+            monitor_x: 0,
+            monitor_y: 0,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_push_recent_region_moves_existing_region_to_front() {
+        let regions = vec![build_region(0, 0, 10, 10), build_region(5, 5, 20, 20)];
+
+        let updated = push_recent_region(&regions, build_region(5, 5, 20, 20), 5);
+
+        assert_eq!(
+            updated,
+            vec![build_region(5, 5, 20, 20), build_region(0, 0, 10, 10)]
+        );
+    }
+
+    #[test]
+    fn test_push_recent_region_drops_oldest_beyond_limit() {
+        let regions = vec![build_region(0, 0, 10, 10), build_region(1, 1, 10, 10)];
+
+        let updated = push_recent_region(&regions, build_region(2, 2, 10, 10), 2);
+
+        assert_eq!(
+            updated,
+            vec![build_region(2, 2, 10, 10), build_region(0, 0, 10, 10)]
+        );
+    }
+
+    #[test]
+    fn test_clamp_recent_region_to_bounds_trims_overflow() {
+        let rect =
+            clamp_recent_region_to_bounds(&build_region(1800, 1000, 400, 200), 1920, 1080).unwrap();
+
+        assert_eq!(rect.x, 1800.0);
+        assert_eq!(rect.y, 1000.0);
+        assert_eq!(rect.width, 120.0);
+        assert_eq!(rect.height, 80.0);
+    }
+
+    #[test]
+    fn test_clamp_recent_region_to_bounds_rejects_region_off_screen() {
+        assert!(
+            clamp_recent_region_to_bounds(&build_region(2000, 10, 50, 50), 1920, 1080).is_none()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+impl AppOrchestrator {
+    pub(super) fn open_search_progress_window(&mut self) -> (Id, Task<OrchestratorMessage>) {
+        let (id, task) = window::open(window::Settings {
+            size: Size::new(
+                global_constants::SEARCH_PROGRESS_WINDOW_WIDTH,
+                global_constants::SEARCH_PROGRESS_WINDOW_HEIGHT,
+            ),
+            position: window::Position::Centered,
+            resizable: false,
+            level: window::Level::AlwaysOnTop,
+            ..Default::default()
+        });
+
+        self.windows.insert(
+            id,
+            AppWindow::SearchProgress(
+                global_constants::STATUS_SEARCHING_SELECTION.to_string(),
+                ToastKind::Info,
+            ),
+        );
+        self.log_info_event(
+            "search_progress_window_created",
+            serde_json::json!({"window_id": format!("{:?}", id)}),
+        );
+
+        (id, task.discard())
+    }
+
+    pub(super) fn finish_search_progress(
+        &mut self,
+        window_id: Id,
+        message: String,
+        kind: ToastKind,
+    ) -> Task<OrchestratorMessage> {
+        if !matches!(
+            self.windows.get(&window_id),
+            Some(AppWindow::SearchProgress(..))
+        ) {
+            return Task::none();
+        }
+
+        self.log_info_event(
+            "search_progress_finished",
+            serde_json::json!({
+                "window_id": format!("{:?}", window_id),
+                "kind": format!("{:?}", kind),
+            }),
+        );
+        self.status = message.clone();
+        self.windows
+            .insert(window_id, AppWindow::SearchProgress(message, kind));
+
+        if kind == ToastKind::Error {
+            return Task::none();
+        }
+
+        Task::future(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(
+                global_constants::SEARCH_PROGRESS_AUTO_CLOSE_MS,
+            ))
+            .await;
+            OrchestratorMessage::CloseWindow(window_id)
+        })
+    }
+}
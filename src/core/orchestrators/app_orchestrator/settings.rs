@@ -1,5 +1,10 @@
 use super::*;
-use crate::adapters::{GoogleLensSearchProvider, ImgbbImageHostingService};
+use crate::adapters::{
+    BingVisualSearchProvider, GoogleLensSearchProvider, GoogleTranslationService,
+    ImgbbImageHostingService, MultipartImageHostingService, YandexImageSearchProvider,
+};
+use crate::core::interfaces::adapters::ImageHostingService;
+use crate::core::models::SearchEngine;
 
 impl AppOrchestrator {
     pub(super) fn handle_open_settings(&mut self) -> Task<OrchestratorMessage> {
@@ -31,6 +36,89 @@ impl AppOrchestrator {
         task.discard()
     }
 
+    pub(super) fn handle_recording_hotkey(&mut self, is_recording: bool) {
+        if matches!(self.settings_edit_state, SettingsEditState::Closed) {
+            return;
+        }
+
+        self.log_info_event(
+            "settings_hotkey_recording_toggled",
+            serde_json::json!({"is_recording": is_recording}),
+        );
+        self.is_recording_hotkey = is_recording;
+    }
+
+    pub(super) fn handle_hotkey_key_pressed(
+        &mut self,
+        key: iced::keyboard::Key,
+        modifiers: iced::keyboard::Modifiers,
+    ) {
+        if !self.is_recording_hotkey {
+            return;
+        }
+
+        if key == iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape) {
+            self.log_info_event("settings_hotkey_recording_cancelled", serde_json::json!({}));
+            self.is_recording_hotkey = false;
+            return;
+        }
+
+        let Some(binding) = crate::ports::format_key_press_binding(&key, modifiers) else {
+            log::debug!(
+                "[ORCHESTRATOR] Waiting for a non-modifier key while recording hotkey: {:?}",
+                key
+            );
+            return;
+        };
+
+        self.log_info_event(
+            "settings_hotkey_recorded",
+            serde_json::json!({"hotkey": binding}),
+        );
+        let _ = self.update_settings_draft(|settings| {
+            settings.capture_hotkey = binding;
+        });
+        self.is_recording_hotkey = false;
+    }
+
+    pub(super) fn validate_settings(settings: &UserSettings) -> Result<(), String> {
+        crate::ports::HotkeyCombo::parse(&settings.capture_hotkey)?;
+        crate::ports::HotkeyCombo::parse(&settings.active_window_hotkey)?;
+        Self::validate_image_search_url_template(&settings.image_search_url_template)?;
+        Self::validate_image_hosting_settings(settings)
+    }
+
+    pub(super) fn validate_image_search_url_template(template: &str) -> Result<(), String> {
+        let placeholder_count = template
+            .matches(global_constants::IMAGE_SEARCH_URL_PLACEHOLDER)
+            .count();
+
+        match placeholder_count {
+            0 => {
+                return Err(
+                    global_constants::IMAGE_SEARCH_URL_VALIDATION_MISSING_PLACEHOLDER.to_string(),
+                )
+            }
+            1 => {}
+            count => {
+                return Err(format!(
+                    "{}{}",
+                    global_constants::IMAGE_SEARCH_URL_VALIDATION_MULTIPLE_PLACEHOLDERS_PREFIX,
+                    count
+                ))
+            }
+        }
+
+        let sample_url = template.replace(
+            global_constants::IMAGE_SEARCH_URL_PLACEHOLDER,
+            "placeholder",
+        );
+        match reqwest::Url::parse(&sample_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => Ok(()),
+            _ => Err(global_constants::IMAGE_SEARCH_URL_VALIDATION_INVALID.to_string()),
+        }
+    }
+
     pub(super) fn validate_image_hosting_settings(settings: &UserSettings) -> Result<(), String> {
         if settings.image_hosting_provider_url.trim().is_empty() {
             return Err(global_constants::IMAGE_HOSTING_VALIDATION_URL_EMPTY.to_string());
@@ -68,7 +156,11 @@ impl AppOrchestrator {
         previous_settings: &UserSettings,
         next_settings: &UserSettings,
     ) -> bool {
-        next_settings.image_search_url_template != previous_settings.image_search_url_template
+        next_settings.search_engine != previous_settings.search_engine
+            || next_settings.image_search_url_template
+                != previous_settings.image_search_url_template
+            || next_settings.open_search_in_private_window
+                != previous_settings.open_search_in_private_window
             || next_settings.image_hosting_provider_url
                 != previous_settings.image_hosting_provider_url
             || next_settings.image_hosting_auth_mode != previous_settings.image_hosting_auth_mode
@@ -82,9 +174,18 @@ impl AppOrchestrator {
                 != previous_settings.image_hosting_http_method
             || next_settings.image_hosting_image_field_name
                 != previous_settings.image_hosting_image_field_name
+            || next_settings.image_host != previous_settings.image_host
+            || next_settings.custom_image_host_url != previous_settings.custom_image_host_url
+            || next_settings.custom_image_host_field_name
+                != previous_settings.custom_image_host_field_name
     }
 
     pub(super) fn handle_save_settings(&mut self) -> Task<OrchestratorMessage> {
+        let _ = self.update_settings_draft(|settings| {
+            settings.image_search_url_template =
+                settings.image_search_url_template.trim().to_string();
+        });
+
         let settings_for_validation = match &self.settings_edit_state {
             SettingsEditState::Editing(settings) => settings,
             SettingsEditState::Closed => {
@@ -94,9 +195,7 @@ impl AppOrchestrator {
             }
         };
 
-        if let Err(validation_error) =
-            Self::validate_image_hosting_settings(settings_for_validation)
-        {
+        if let Err(validation_error) = Self::validate_settings(settings_for_validation) {
             self.log_error_event(
                 "settings_validation_failed",
                 serde_json::json!({"error": validation_error}),
@@ -106,13 +205,18 @@ impl AppOrchestrator {
                 global_constants::STATUS_SETTINGS_SAVE_FAILED_PREFIX,
                 validation_error
             );
-            return Task::none();
+            return self.show_main_window_toast(self.status.clone(), ToastKind::Error);
         }
 
         if let Some(next_settings) = self.take_settings_draft() {
-            let hotkey_changed = next_settings.capture_hotkey != self.settings.capture_hotkey;
+            let hotkey_changed = next_settings.capture_hotkey != self.settings.capture_hotkey
+                || next_settings.active_window_hotkey != self.settings.active_window_hotkey;
             let search_provider_changed =
                 Self::should_rebuild_search_provider(&self.settings, &next_settings);
+            let translation_api_key_changed =
+                next_settings.translation_api_key != self.settings.translation_api_key;
+            let ocr_engine_changed = next_settings.ocr_engine != self.settings.ocr_engine
+                || next_settings.language != self.settings.language;
 
             self.settings = next_settings.clone();
 
@@ -126,6 +230,7 @@ impl AppOrchestrator {
                     global_constants::STATUS_SETTINGS_SAVE_FAILED_PREFIX,
                     save_error
                 );
+                return self.show_main_window_toast(self.status.clone(), ToastKind::Error);
             } else {
                 self.log_info_event("settings_saved", serde_json::json!({}));
                 self.status = global_constants::STATUS_SETTINGS_SAVED.to_string();
@@ -134,6 +239,10 @@ impl AppOrchestrator {
                     let _ = self.rebuild_reverse_image_search_provider();
                 }
 
+                if translation_api_key_changed {
+                    self.rebuild_translation_service();
+                }
+
                 if hotkey_changed {
                     self.log_info_event(
                         "settings_hotkey_changed_restart_required",
@@ -141,6 +250,25 @@ impl AppOrchestrator {
                     );
                     return Task::done(OrchestratorMessage::RestartApp);
                 }
+
+                if ocr_engine_changed {
+                    self.log_info_event(
+                        "settings_ocr_engine_changed",
+                        serde_json::json!({
+                            "engine": self.settings.ocr_engine.to_string(),
+                            "language": self.settings.language,
+                        }),
+                    );
+                    self.status = global_constants::STATUS_LOADING_OCR_ENGINE.to_string();
+                    let rebuild_task = self.build_ocr_service_task(
+                        self.settings.ocr_engine.clone(),
+                        self.settings.language.clone(),
+                    );
+                    return match self.settings_window_id {
+                        Some(id) => Task::batch(vec![rebuild_task, window::close(id)]),
+                        None => rebuild_task,
+                    };
+                }
             }
         }
 
@@ -151,15 +279,132 @@ impl AppOrchestrator {
         Task::none()
     }
 
+    pub fn build_image_hosting_service(settings: &UserSettings) -> Arc<dyn ImageHostingService> {
+        match settings.image_host {
+            ImageHost::Imgbb => Arc::new(ImgbbImageHostingService::from_user_settings(settings)),
+            ImageHost::ZeroXZero => Arc::new(MultipartImageHostingService::new(
+                global_constants::ZERO_X_ZERO_UPLOAD_URL.to_string(),
+                global_constants::DEFAULT_CUSTOM_IMAGE_HOST_FIELD_NAME.to_string(),
+            )),
+            ImageHost::CustomMultipart => Arc::new(MultipartImageHostingService::new(
+                settings.custom_image_host_url.clone(),
+                settings.custom_image_host_field_name.clone(),
+            )),
+        }
+    }
+
+    pub fn build_reverse_image_search_provider(
+        settings: &UserSettings,
+    ) -> Arc<dyn ReverseImageSearchProvider> {
+        let image_hosting_service = Self::build_image_hosting_service(settings);
+        match settings.search_engine {
+            SearchEngine::GoogleLens => Arc::new(GoogleLensSearchProvider::from_user_settings(
+                image_hosting_service,
+                settings,
+            )),
+            SearchEngine::BingVisualSearch => Arc::new(
+                BingVisualSearchProvider::from_user_settings(image_hosting_service, settings),
+            ),
+            SearchEngine::Yandex => Arc::new(YandexImageSearchProvider::from_user_settings(
+                image_hosting_service,
+                settings,
+            )),
+        }
+    }
+
     fn rebuild_reverse_image_search_provider(&mut self) -> bool {
-        let image_hosting_service =
-            Arc::new(ImgbbImageHostingService::from_user_settings(&self.settings));
-        self.reverse_image_search_provider = Arc::new(GoogleLensSearchProvider::new(
-            image_hosting_service,
-            self.settings.image_search_url_template.clone(),
-        ));
-        self.log_info_event("search_provider_rebuilt", serde_json::json!({}));
+        self.reverse_image_search_provider =
+            Self::build_reverse_image_search_provider(&self.settings);
+        self.log_info_event(
+            "search_provider_rebuilt",
+            serde_json::json!({
+                "search_engine": self.settings.search_engine.to_string(),
+                "image_host": self.settings.image_host.to_string(),
+            }),
+        );
 
         true
     }
+
+    pub fn build_translation_service(settings: &UserSettings) -> Arc<dyn TranslationService> {
+        Arc::new(GoogleTranslationService::from_user_settings(settings))
+    }
+
+    fn rebuild_translation_service(&mut self) {
+        self.translation_service = Self::build_translation_service(&self.settings);
+        self.log_info_event(
+            "translation_service_rebuilt",
+            serde_json::json!({"has_api_key": !self.settings.translation_api_key.is_empty()}),
+        );
+    }
+
+    pub(super) fn handle_clear_cache(&mut self) -> Task<OrchestratorMessage> {
+        if !self.ocr_task_handles.is_empty() {
+            self.log_info_event(
+                "clear_cache_blocked",
+                serde_json::json!({"running_ocr_tasks": self.ocr_task_handles.len()}),
+            );
+            self.status = global_constants::STATUS_CLEAR_CACHE_BLOCKED_BY_OCR.to_string();
+            return self.show_main_window_toast(self.status.clone(), ToastKind::Error);
+        }
+
+        let running_ocr_builds = self.ocr_builds_in_flight.load(Ordering::SeqCst);
+        if running_ocr_builds > 0 {
+            self.log_info_event(
+                "clear_cache_blocked",
+                serde_json::json!({"running_ocr_builds": running_ocr_builds}),
+            );
+            self.status = global_constants::STATUS_CLEAR_CACHE_BLOCKED_BY_OCR_BUILD.to_string();
+            return self.show_main_window_toast(self.status.clone(), ToastKind::Error);
+        }
+
+        self.log_info_event("clear_cache_started", serde_json::json!({}));
+        self.status = global_constants::STATUS_CLEARING_CACHE.to_string();
+
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let freed_bytes = tokio::task::spawn_blocking(|| {
+                let cache_paths = crate::infrastructure::utils::get_default_cache_paths();
+                crate::infrastructure::utils::clear_cached_data(&cache_paths)
+            })
+            .await
+            .unwrap_or_else(|join_error| {
+                AppOrchestrator::log_error_event_for_correlation(
+                    correlation_id.clone(),
+                    "clear_cache_failed",
+                    serde_json::json!({"error": join_error.to_string()}),
+                );
+                0
+            });
+
+            AppOrchestrator::log_info_event_for_correlation(
+                correlation_id,
+                "clear_cache_completed",
+                serde_json::json!({"freed_bytes": freed_bytes}),
+            );
+            OrchestratorMessage::CacheCleared(freed_bytes)
+        })
+    }
+
+    pub(super) fn handle_cache_cleared(&mut self, freed_bytes: u64) -> Task<OrchestratorMessage> {
+        self.status = format!(
+            "{}{}",
+            global_constants::STATUS_CACHE_CLEARED_PREFIX,
+            crate::infrastructure::utils::format_byte_size(freed_bytes)
+        );
+        self.log_info_event(
+            "ocr_services_invalidated_after_cache_clear",
+            serde_json::json!({"language_services": self.ocr_language_services.len()}),
+        );
+        self.ocr_language_services.clear();
+        let rebuild_task = self.build_ocr_service_task(
+            self.settings.ocr_engine.clone(),
+            self.settings.language.clone(),
+        );
+        Task::batch(vec![
+            self.show_main_window_toast(self.status.clone(), ToastKind::Info),
+            rebuild_task,
+        ])
+    }
 }
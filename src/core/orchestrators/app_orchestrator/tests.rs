@@ -1,5 +1,6 @@
 use super::*;
-use crate::core::models::OcrResult;
+use crate::core::models::{ImageSearchOutcome, MonitorGeometry, OcrResult};
+use crate::presentation::{CaptureTestStatus, ConnectivityStatus};
 
 struct MockScreenCapturer;
 impl ScreenCapturer for MockScreenCapturer {
@@ -16,6 +17,84 @@ impl ScreenCapturer for MockScreenCapturer {
         let raw_data = vec![255u8; 100 * 100 * 4];
         Ok(CaptureBuffer::build_from_raw_data(1.0, 100, 100, raw_data))
     }
+
+    fn capture_active_window(&self) -> anyhow::Result<Option<CaptureBuffer>> {
+        let raw_data = vec![255u8; 80 * 60 * 4];
+        Ok(Some(CaptureBuffer::build_from_raw_data(
+            1.0, 80, 60, raw_data,
+        )))
+    }
+
+    fn find_focused_window_id(&self) -> anyhow::Result<Option<u32>> {
+        Ok(Some(42))
+    }
+
+    fn is_foreground_window_fullscreen(&self) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
+    fn get_primary_monitor_origin(&self) -> anyhow::Result<ScreenRegion> {
+        Ok(ScreenRegion::at_coordinates(0, 0))
+    }
+
+    fn list_monitors(&self) -> anyhow::Result<Vec<MonitorGeometry>> {
+        Ok(vec![MonitorGeometry {
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            scale_factor: 1.0,
+        }])
+    }
+
+    fn capture_all_monitors(&self) -> anyhow::Result<Vec<CaptureBuffer>> {
+        Ok([(100, 100), (120, 80), (90, 60)]
+            .into_iter()
+            .map(|(width, height)| {
+                let raw_data = vec![255u8; (width * height * 4) as usize];
+                CaptureBuffer::build_from_raw_data(1.0, width, height, raw_data)
+            })
+            .collect())
+    }
+}
+
+struct FailingScreenCapturer;
+impl ScreenCapturer for FailingScreenCapturer {
+    fn capture_screen_at_region(&self, _region: &ScreenRegion) -> anyhow::Result<CaptureBuffer> {
+        anyhow::bail!("screen capture permission denied")
+    }
+
+    fn list_capturable_windows(&self) -> anyhow::Result<Vec<WindowInfo>> {
+        Ok(vec![])
+    }
+
+    fn capture_window_by_id(&self, _window_id: u32) -> anyhow::Result<CaptureBuffer> {
+        anyhow::bail!("screen capture permission denied")
+    }
+
+    fn capture_active_window(&self) -> anyhow::Result<Option<CaptureBuffer>> {
+        anyhow::bail!("screen capture permission denied")
+    }
+
+    fn find_focused_window_id(&self) -> anyhow::Result<Option<u32>> {
+        Ok(None)
+    }
+
+    fn is_foreground_window_fullscreen(&self) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
+    fn get_primary_monitor_origin(&self) -> anyhow::Result<ScreenRegion> {
+        anyhow::bail!("no monitors")
+    }
+
+    fn list_monitors(&self) -> anyhow::Result<Vec<MonitorGeometry>> {
+        Ok(vec![])
+    }
+
+    fn capture_all_monitors(&self) -> anyhow::Result<Vec<CaptureBuffer>> {
+        anyhow::bail!("screen capture permission denied")
+    }
 }
 
 struct MockMouseProvider;
@@ -39,6 +118,13 @@ impl OcrService for MockOcrService {
     }
 }
 
+struct MockBarcodeService;
+impl BarcodeService for MockBarcodeService {
+    fn decode(&self, _image: &image::DynamicImage) -> anyhow::Result<Vec<DecodedCode>> {
+        Ok(vec![])
+    }
+}
+
 struct MockSearchProvider;
 #[async_trait::async_trait]
 impl ReverseImageSearchProvider for MockSearchProvider {
@@ -46,8 +132,19 @@ impl ReverseImageSearchProvider for MockSearchProvider {
         &self,
         _buffer: &CaptureBuffer,
         _query: Option<&str>,
-    ) -> anyhow::Result<String> {
-        Ok("https://test.com/search".to_string())
+    ) -> anyhow::Result<ImageSearchOutcome> {
+        Ok(ImageSearchOutcome {
+            search_url: "https://test.com/search".to_string(),
+            hosted_image_url: "https://hosted.test/image.png".to_string(),
+        })
+    }
+}
+
+struct MockTranslationService;
+#[async_trait::async_trait]
+impl TranslationService for MockTranslationService {
+    async fn translate(&self, text: &str, target_lang: &str) -> anyhow::Result<String> {
+        Ok(format!("[{}] {}", target_lang, text))
     }
 }
 
@@ -56,7 +153,9 @@ fn create_test_orchestrator() -> AppOrchestrator {
         Arc::new(MockScreenCapturer),
         Arc::new(MockMouseProvider),
         Arc::new(MockOcrService),
+        Arc::new(MockBarcodeService),
         Arc::new(MockSearchProvider),
+        Arc::new(MockTranslationService),
         UserSettings::default(),
     )
 }
@@ -96,6 +195,43 @@ fn test_handle_ocr_service_ready_updates_service() {
     assert!(orchestrator.status.contains("Ready"));
 }
 
+#[test]
+fn test_handle_ocr_service_fallback_ready_reports_fallback_engine() {
+    let mut orchestrator = create_test_orchestrator();
+    let fallback_service = Arc::new(MockOcrService) as Arc<dyn OcrService>;
+
+    let _ = orchestrator.update(OrchestratorMessage::OcrServiceFallbackReady(
+        fallback_service,
+        OcrEngine::Neural,
+    ));
+
+    assert!(orchestrator
+        .status
+        .starts_with(global_constants::STATUS_OCR_ENGINE_FALLBACK_PREFIX));
+    assert!(orchestrator.status.contains("Neural"));
+}
+
+#[test]
+fn test_training_data_unavailable_is_reported_instead_of_silent_english() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::OcrTrainingDataUnavailable(
+        OcrLanguage::French,
+    ));
+
+    assert!(matches!(
+        &orchestrator.main_window_toast,
+        Some((message, ToastKind::Error)) if *message == orchestrator.status
+    ));
+    assert_eq!(
+        orchestrator.status,
+        format!(
+            "{}French",
+            global_constants::STATUS_OCR_TRAINING_DATA_UNAVAILABLE_PREFIX
+        )
+    );
+}
+
 #[test]
 fn test_handle_ocr_service_failed_updates_status() {
     let mut orchestrator = create_test_orchestrator();
@@ -185,6 +321,19 @@ fn test_update_image_hosting_public_key_value_modifies_temp_settings() {
     ));
 }
 
+#[test]
+fn test_update_translation_api_key_trims_and_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateTranslationApiKey(
+        "  cloud-key  ".to_string(),
+    ));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.translation_api_key == "cloud-key"
+    ));
+}
+
 #[test]
 fn test_update_image_hosting_expiration_seconds_modifies_temp_settings() {
     let mut orchestrator = create_test_orchestrator();
@@ -199,6 +348,79 @@ fn test_update_image_hosting_expiration_seconds_modifies_temp_settings() {
     ));
 }
 
+#[test]
+fn test_validate_image_search_url_template_accepts_valid_templates() {
+    for template in [
+        global_constants::DEFAULT_IMAGE_SEARCH_URL,
+        "https://www.bing.com/images/search?view=detailv2&iss=sbi&q=imgurl:{}",
+        "http://localhost:8080/search?img={}&lang=en",
+    ] {
+        assert_eq!(
+            AppOrchestrator::validate_image_search_url_template(template),
+            Ok(()),
+            "{}",
+            template
+        );
+    }
+}
+
+#[test]
+fn test_validate_image_search_url_template_rejects_invalid_templates() {
+    assert_eq!(
+        AppOrchestrator::validate_image_search_url_template("https://lens.google.com/upload"),
+        Err(global_constants::IMAGE_SEARCH_URL_VALIDATION_MISSING_PLACEHOLDER.to_string())
+    );
+    assert_eq!(
+        AppOrchestrator::validate_image_search_url_template("https://example.com?a={}&b={}"),
+        Err(format!(
+            "{}2",
+            global_constants::IMAGE_SEARCH_URL_VALIDATION_MULTIPLE_PLACEHOLDERS_PREFIX
+        ))
+    );
+    for template in ["lens.google.com?url={}", "ftp://example.com?url={}", "{}"] {
+        assert_eq!(
+            AppOrchestrator::validate_image_search_url_template(template),
+            Err(global_constants::IMAGE_SEARCH_URL_VALIDATION_INVALID.to_string()),
+            "{}",
+            template
+        );
+    }
+}
+
+#[test]
+fn test_save_settings_rejects_search_url_without_placeholder() {
+    let mut orchestrator = create_test_orchestrator();
+    let mut draft = UserSettings::default();
+    draft.image_search_url_template = "https://lens.google.com/upload".to_string();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(draft);
+
+    let _ = orchestrator.update(OrchestratorMessage::SaveSettings);
+
+    assert!(matches!(
+        orchestrator.settings_edit_state,
+        SettingsEditState::Editing(_)
+    ));
+    assert!(orchestrator
+        .status
+        .ends_with(global_constants::IMAGE_SEARCH_URL_VALIDATION_MISSING_PLACEHOLDER));
+}
+
+#[test]
+fn test_reset_search_url_restores_default_template() {
+    let mut orchestrator = create_test_orchestrator();
+    let mut draft = UserSettings::default();
+    draft.image_search_url_template = "https://broken.example".to_string();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(draft);
+
+    let _ = orchestrator.update(OrchestratorMessage::ResetSearchUrl);
+
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.image_search_url_template == global_constants::DEFAULT_IMAGE_SEARCH_URL
+    ));
+}
+
 #[test]
 fn test_validate_image_hosting_settings_rejects_invalid_provider_url() {
     let mut settings = UserSettings::default();
@@ -236,6 +458,26 @@ fn test_build_clipboard_image_data_returns_original_buffer_without_drawings() {
     assert_eq!(rgba_data, capture_buffer.raw_data);
 }
 
+#[test]
+fn test_build_region_buffer_crops_to_rounded_out_region() {
+    let capture_buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![1u8; 400]);
+
+    let region_buffer = AppOrchestrator::build_region_buffer(
+        &capture_buffer,
+        &[],
+        Rectangle {
+            x: 2.5,
+            y: 3.2,
+            width: 4.0,
+            height: 2.0,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(region_buffer.width, 5);
+    assert_eq!(region_buffer.height, 3);
+}
+
 #[test]
 fn test_update_theme_modifies_temp_settings() {
     let mut orchestrator = create_test_orchestrator();
@@ -247,6 +489,26 @@ fn test_update_theme_modifies_temp_settings() {
     ));
 }
 
+#[test]
+fn test_system_theme_changed_resolves_system_mode_palette() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.theme_mode = ThemeMode::System;
+
+    let _ = orchestrator.update(OrchestratorMessage::SystemThemeChanged(Some(
+        ThemeMode::Light,
+    )));
+    assert_eq!(
+        orchestrator.get_app_theme().palette(),
+        app_theme::get_theme(&ThemeMode::Light, None).palette()
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::SystemThemeChanged(None));
+    assert_eq!(
+        orchestrator.get_app_theme().palette(),
+        app_theme::get_theme(&ThemeMode::Dark, None).palette()
+    );
+}
+
 #[test]
 fn test_get_window_title_returns_correct_title() {
     let orchestrator = create_test_orchestrator();
@@ -255,6 +517,23 @@ fn test_get_window_title_returns_correct_title() {
     assert_eq!(title, "Circle to Search");
 }
 
+#[test]
+fn test_get_window_title_includes_window_kind() {
+    let mut orchestrator = create_test_orchestrator();
+    let settings_id = Id::unique();
+    let main_id = Id::unique();
+    orchestrator
+        .windows
+        .insert(settings_id, AppWindow::Settings);
+    orchestrator.windows.insert(main_id, AppWindow::Main);
+
+    assert_eq!(
+        orchestrator.get_window_title(settings_id),
+        "Circle to Search — Settings"
+    );
+    assert_eq!(orchestrator.get_window_title(main_id), "Circle to Search");
+}
+
 #[test]
 fn test_is_using_default_public_key_returns_true_for_default_settings() {
     let settings = UserSettings::default();
@@ -346,3 +625,2198 @@ fn test_user_settings_default_image_field_name_is_image() {
     let settings = UserSettings::default();
     assert_eq!(settings.image_hosting_image_field_name, "image");
 }
+
+#[test]
+fn test_update_confirm_quit_with_unsaved_annotations_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ =
+        orchestrator.update(OrchestratorMessage::UpdateConfirmQuitWithUnsavedAnnotations(false));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if !settings.confirm_quit_with_unsaved_annotations
+    ));
+}
+
+#[test]
+fn test_collect_unsaved_annotation_counts_reports_only_windows_with_drawings() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let mut annotated_view =
+        crate::presentation::InteractiveOcrView::build(buffer.clone(), ThemeMode::Dark);
+    annotated_view.set_draw_strokes(vec![crate::presentation::DrawStroke {
+        points: vec![Point::new(1.0, 1.0), Point::new(5.0, 5.0)],
+        color: iced::Color::BLACK,
+        width: 2.0,
+        tool: crate::presentation::DrawTool::Pen,
+    }]);
+    let clean_view = crate::presentation::InteractiveOcrView::build(buffer, ThemeMode::Dark);
+    orchestrator
+        .windows
+        .insert(Id::unique(), AppWindow::InteractiveOcr(annotated_view));
+    orchestrator
+        .windows
+        .insert(Id::unique(), AppWindow::InteractiveOcr(clean_view));
+
+    let counts = orchestrator.collect_unsaved_annotation_counts();
+
+    assert_eq!(counts, vec![1]);
+}
+
+#[test]
+fn test_update_capture_delay_clamps_to_maximum() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateCaptureDelayMs(5_000));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.capture_delay_ms == global_constants::MAX_CAPTURE_DELAY_MS
+    ));
+}
+
+#[test]
+fn test_update_enable_type_out_text_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateEnableTypeOutText(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.enable_type_out_text
+    ));
+}
+
+#[test]
+fn test_type_out_text_failure_updates_status() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::TypeOutTextFinished(Err(
+        "no display".to_string()
+    )));
+    assert_eq!(
+        orchestrator.status,
+        format!(
+            "{}no display",
+            global_constants::STATUS_TYPING_TEXT_FAILED_PREFIX
+        )
+    );
+}
+
+#[test]
+fn test_update_auto_run_ocr_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateAutoRunOcr(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.auto_run_ocr
+    ));
+}
+
+#[test]
+fn test_update_quit_on_main_window_close_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateQuitOnMainWindowClose(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.quit_on_main_window_close
+    ));
+}
+
+#[test]
+fn test_main_window_close_quits_when_configured() {
+    assert_eq!(
+        super::window_lifecycle::resolve_main_window_close_action(true, false),
+        super::window_lifecycle::MainWindowCloseAction::Quit
+    );
+}
+
+#[test]
+fn test_main_window_close_shows_tray_notice_only_once() {
+    assert_eq!(
+        super::window_lifecycle::resolve_main_window_close_action(false, false),
+        super::window_lifecycle::MainWindowCloseAction::ShowTrayNotice
+    );
+    assert_eq!(
+        super::window_lifecycle::resolve_main_window_close_action(false, true),
+        super::window_lifecycle::MainWindowCloseAction::KeepRunning
+    );
+}
+
+#[test]
+fn test_dismiss_tray_notice_without_window_is_noop() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::DismissTrayNotice);
+    assert!(orchestrator.tray_notice_window_id.is_none());
+}
+
+#[test]
+fn test_update_scroll_capture_delay_clamps_to_maximum() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateScrollCaptureDelayMs(u32::MAX));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.scroll_capture_delay_ms == global_constants::MAX_SCROLL_CAPTURE_DELAY_MS
+    ));
+}
+
+#[test]
+fn test_scroll_capture_failure_updates_status() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::ScrollCaptureFinished(Err(
+        "no input".to_string()
+    )));
+    assert_eq!(
+        orchestrator.status,
+        format!(
+            "{}no input",
+            global_constants::STATUS_SCROLL_CAPTURE_FAILED_PREFIX
+        )
+    );
+}
+
+#[test]
+fn test_update_ocr_engine_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateOcrEngine(OcrEngine::Neural));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.ocr_engine == OcrEngine::Neural
+    ));
+}
+
+#[test]
+fn test_switching_to_english_only_engine_resets_unsupported_language() {
+    let mut orchestrator = create_test_orchestrator();
+    let mut draft = UserSettings::default();
+    draft.language = OcrLanguage::French.code().to_string();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(draft);
+
+    let _ = orchestrator.update(OrchestratorMessage::UpdateOcrEngine(OcrEngine::Neural));
+
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.language == OcrLanguage::English.code()
+    ));
+}
+
+#[test]
+fn test_switching_to_tesseract_keeps_selected_language() {
+    let mut orchestrator = create_test_orchestrator();
+    let mut draft = UserSettings::default();
+    draft.language = OcrLanguage::French.code().to_string();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(draft);
+
+    let _ = orchestrator.update(OrchestratorMessage::UpdateOcrEngine(OcrEngine::Tesseract));
+
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.language == OcrLanguage::French.code()
+    ));
+}
+
+#[test]
+fn test_update_suspend_hotkey_in_fullscreen_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateSuspendHotkeyInFullscreen(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.suspend_hotkey_in_fullscreen
+    ));
+}
+
+#[test]
+fn test_fullscreen_check_suppresses_capture_with_visible_status() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let task = orchestrator.update(OrchestratorMessage::FullscreenCheckCompleted(
+        CaptureTrigger::Keyboard,
+        Ok(true),
+    ));
+
+    assert!(task.units() > 0);
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_CAPTURE_SUSPENDED_FOR_FULLSCREEN
+    );
+    assert!(matches!(
+        &orchestrator.main_window_toast,
+        Some((message, ToastKind::Info))
+            if message == global_constants::STATUS_CAPTURE_SUSPENDED_FOR_FULLSCREEN
+    ));
+}
+
+#[test]
+fn test_fullscreen_check_proceeds_when_not_fullscreen_or_detection_fails() {
+    for result in [Ok(false), Err("no focused window".to_string())] {
+        let mut orchestrator = create_test_orchestrator();
+
+        let _ = orchestrator.update(OrchestratorMessage::FullscreenCheckCompleted(
+            CaptureTrigger::Keyboard,
+            result,
+        ));
+
+        assert_eq!(
+            orchestrator.status,
+            global_constants::STATUS_PREPARING_CAPTURE
+        );
+    }
+}
+
+#[test]
+fn test_capture_trigger_defers_fullscreen_check_to_background_task() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.suspend_hotkey_in_fullscreen = true;
+
+    let task = orchestrator.handle_global_capture_trigger(CaptureTrigger::Keyboard);
+
+    assert!(task.units() > 0);
+    assert_ne!(
+        orchestrator.status,
+        global_constants::STATUS_PREPARING_CAPTURE
+    );
+}
+
+#[test]
+fn test_update_capture_trigger_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateCaptureTrigger(
+        CaptureTrigger::TrayOnly,
+    ));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.capture_trigger == CaptureTrigger::TrayOnly
+    ));
+}
+
+#[test]
+fn test_capture_hotkey_ignored_in_tray_only_mode() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.capture_trigger = CaptureTrigger::TrayOnly;
+    let status_before = orchestrator.status.clone();
+
+    let _ = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::CaptureHotkeyPressed,
+    ));
+
+    assert_eq!(orchestrator.status, status_before);
+}
+
+#[test]
+fn test_capture_hotkey_starts_capture_when_keyboard_trigger_selected() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::CaptureHotkeyPressed,
+    ));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_PREPARING_CAPTURE
+    );
+}
+
+#[test]
+fn test_update_ocr_timeout_clamps_to_supported_range() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateOcrTimeoutSecs(0));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.ocr_timeout_secs == global_constants::MIN_OCR_TIMEOUT_SECS
+    ));
+}
+
+#[test]
+fn test_update_jpeg_quality_clamps_to_supported_range() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateJpegQuality(0));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.jpeg_quality == global_constants::MIN_JPEG_QUALITY
+    ));
+}
+
+#[test]
+fn test_recent_region_outside_screen_updates_status() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 100, 100, vec![255u8; 100 * 100 * 4]);
+    let region = crate::core::models::RecentRegion {
+        monitor_x: 0,
+        monitor_y: 0,
+        x: 500,
+        y: 500,
+        width: 50,
+        height: 50,
+    };
+
+    let _ = orchestrator.update(OrchestratorMessage::RecentRegionCaptured(Ok((
+        buffer, region,
+    ))));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_RECENT_REGION_OUTSIDE_SCREEN
+    );
+}
+
+#[test]
+fn test_capture_recent_region_ignores_unknown_index() {
+    let mut orchestrator = create_test_orchestrator();
+    let status_before = orchestrator.status.clone();
+
+    let _ = orchestrator.update(OrchestratorMessage::CaptureRecentRegion(3));
+
+    assert_eq!(orchestrator.status, status_before);
+}
+
+#[test]
+fn test_update_auto_close_after_action_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateAutoCloseAfterAction(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.auto_close_after_action
+    ));
+}
+
+#[test]
+fn test_tray_event_before_initialization_is_queued() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::OpenSettings));
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::OpenSettings));
+
+    assert!(orchestrator.settings_window_id.is_none());
+    assert_eq!(
+        orchestrator.pending_tray_events,
+        vec![TrayEvent::OpenSettings]
+    );
+}
+
+#[test]
+fn test_initialization_complete_replays_queued_tray_events() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::OpenSettings));
+
+    let _ = orchestrator.update(OrchestratorMessage::InitializationComplete);
+
+    assert!(orchestrator.is_initialized);
+    assert!(orchestrator.pending_tray_events.is_empty());
+    assert!(orchestrator.settings_window_id.is_some());
+}
+
+#[test]
+fn test_tray_event_after_initialization_is_routed_immediately() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::InitializationComplete);
+
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::OpenSettings));
+
+    assert!(orchestrator.settings_window_id.is_some());
+    assert!(orchestrator.pending_tray_events.is_empty());
+}
+
+#[test]
+fn test_capture_now_tray_event_starts_capture_flow() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::InitializationComplete);
+
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::CaptureNow));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_PREPARING_CAPTURE
+    );
+    assert!(orchestrator.pending_tray_events.is_empty());
+}
+
+#[test]
+fn test_capture_now_tray_event_before_initialization_is_queued() {
+    let mut orchestrator = create_test_orchestrator();
+    let status_before = orchestrator.status.clone();
+
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::CaptureNow));
+
+    assert_eq!(orchestrator.status, status_before);
+    assert_eq!(
+        orchestrator.pending_tray_events,
+        vec![TrayEvent::CaptureNow]
+    );
+}
+
+#[test]
+fn test_recording_hotkey_waits_for_non_modifier_key() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::RecordingHotkey(true));
+
+    let _ = orchestrator.update(OrchestratorMessage::HotkeyKeyPressed(
+        iced::keyboard::Key::Named(iced::keyboard::key::Named::Control),
+        iced::keyboard::Modifiers::CTRL,
+    ));
+    assert!(orchestrator.is_recording_hotkey());
+
+    let _ = orchestrator.update(OrchestratorMessage::HotkeyKeyPressed(
+        iced::keyboard::Key::Character("k".into()),
+        iced::keyboard::Modifiers::CTRL | iced::keyboard::Modifiers::SHIFT,
+    ));
+
+    assert!(!orchestrator.is_recording_hotkey());
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.capture_hotkey == "Ctrl+Shift+K"
+    ));
+}
+
+#[test]
+fn test_recording_hotkey_suppresses_global_capture_trigger() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::RecordingHotkey(true));
+    let status_before = orchestrator.status.clone();
+
+    let _ = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::CaptureHotkeyPressed,
+    ));
+
+    assert_eq!(orchestrator.status, status_before);
+}
+
+#[test]
+fn test_update_draw_mode_shortcut_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+
+    let _ = orchestrator.update(OrchestratorMessage::UpdateDrawModeShortcut(
+        "Alt+P".to_string(),
+    ));
+
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.draw_mode_shortcut == "Alt+P"
+    ));
+}
+
+#[test]
+fn test_update_open_search_in_private_window_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateOpenSearchInPrivateWindow(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.open_search_in_private_window
+    ));
+}
+
+#[test]
+fn test_should_rebuild_search_provider_when_private_window_preference_changes() {
+    let previous_settings = UserSettings::default();
+    let mut next_settings = previous_settings.clone();
+    next_settings.open_search_in_private_window = true;
+
+    let should_rebuild =
+        AppOrchestrator::should_rebuild_search_provider(&previous_settings, &next_settings);
+
+    assert!(should_rebuild);
+}
+
+fn count_interactive_ocr_windows(orchestrator: &AppOrchestrator) -> usize {
+    orchestrator
+        .windows
+        .values()
+        .filter(|window| matches!(window, AppWindow::InteractiveOcr(_)))
+        .count()
+}
+
+#[test]
+fn test_duplicate_capture_does_not_open_second_result_window() {
+    let mut orchestrator = create_test_orchestrator();
+    let capture_buffer = CaptureBuffer::build_from_raw_data(1.0, 100, 100, vec![255u8; 40000]);
+    let selection = Rectangle::new(Point::new(10.0, 10.0), Size::new(20.0, 20.0));
+
+    let _ = orchestrator.handle_show_cropped_image(capture_buffer.clone(), selection);
+    let _ = orchestrator.handle_show_cropped_image(capture_buffer, selection);
+
+    assert_eq!(count_interactive_ocr_windows(&orchestrator), 1);
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_DUPLICATE_CAPTURE_SKIPPED
+    );
+}
+
+#[test]
+fn test_same_capture_opens_again_after_previous_window_closed() {
+    let mut orchestrator = create_test_orchestrator();
+    let capture_buffer = CaptureBuffer::build_from_raw_data(1.0, 100, 100, vec![255u8; 40000]);
+    let selection = Rectangle::new(Point::new(10.0, 10.0), Size::new(20.0, 20.0));
+
+    let _ = orchestrator.handle_show_cropped_image(capture_buffer.clone(), selection);
+    orchestrator.windows.clear();
+    let _ = orchestrator.handle_show_cropped_image(capture_buffer, selection);
+
+    assert_eq!(count_interactive_ocr_windows(&orchestrator), 1);
+}
+
+#[test]
+fn test_update_auto_save_captures_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateAutoSaveCaptures(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.auto_save_captures
+    ));
+}
+
+fn insert_onboarding_window(orchestrator: &mut AppOrchestrator) -> Id {
+    let window_id = Id::unique();
+    orchestrator.windows.insert(
+        window_id,
+        AppWindow::Onboarding(OnboardingView::new(true, true, false)),
+    );
+    window_id
+}
+
+fn get_onboarding_connectivity(
+    orchestrator: &AppOrchestrator,
+    window_id: Id,
+) -> Option<ConnectivityStatus> {
+    match orchestrator.windows.get(&window_id) {
+        Some(AppWindow::Onboarding(view)) => Some(view.connectivity_status()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_onboarding_connectivity_result_marks_view_offline() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = insert_onboarding_window(&mut orchestrator);
+
+    let _ = orchestrator.update(OrchestratorMessage::OnboardingMsg(
+        window_id,
+        OnboardingMessage::ConnectivityChecked(false),
+    ));
+
+    assert_eq!(
+        get_onboarding_connectivity(&orchestrator, window_id),
+        Some(ConnectivityStatus::Offline)
+    );
+}
+
+#[test]
+fn test_onboarding_connectivity_retry_resets_view_to_checking() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = insert_onboarding_window(&mut orchestrator);
+    let _ = orchestrator.update(OrchestratorMessage::OnboardingMsg(
+        window_id,
+        OnboardingMessage::ConnectivityChecked(false),
+    ));
+
+    let _ = orchestrator.update(OrchestratorMessage::OnboardingMsg(
+        window_id,
+        OnboardingMessage::RetryConnectivityCheck,
+    ));
+
+    assert_eq!(
+        get_onboarding_connectivity(&orchestrator, window_id),
+        Some(ConnectivityStatus::Checking)
+    );
+}
+
+fn get_onboarding_capture_test_status(
+    orchestrator: &AppOrchestrator,
+    window_id: Id,
+) -> Option<CaptureTestStatus> {
+    match orchestrator.windows.get(&window_id) {
+        Some(AppWindow::Onboarding(view)) => Some(view.capture_test_status().clone()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_onboarding_advances_from_auto_start_into_capture_test() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = insert_onboarding_window(&mut orchestrator);
+
+    for _ in 0..4 {
+        let _ = orchestrator.update(OrchestratorMessage::OnboardingMsg(
+            window_id,
+            OnboardingMessage::NextStep,
+        ));
+    }
+
+    let current_step_number = match orchestrator.windows.get(&window_id) {
+        Some(AppWindow::Onboarding(view)) => Some(view.current_step().step_number()),
+        _ => None,
+    };
+    assert_eq!(current_step_number, Some(5));
+    assert_eq!(
+        get_onboarding_capture_test_status(&orchestrator, window_id),
+        Some(CaptureTestStatus::Running)
+    );
+}
+
+#[test]
+fn test_onboarding_capture_test_reports_capturer_failure() {
+    let result = onboarding::run_capture_test(&FailingScreenCapturer);
+
+    assert_eq!(result, Err("screen capture permission denied".to_string()));
+    assert_eq!(onboarding::run_capture_test(&MockScreenCapturer), Ok(()));
+}
+
+#[test]
+fn test_onboarding_capture_test_failure_marks_view_failed() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = insert_onboarding_window(&mut orchestrator);
+    let capture_error = onboarding::run_capture_test(&FailingScreenCapturer).unwrap_err();
+
+    let _ = orchestrator.update(OrchestratorMessage::OnboardingMsg(
+        window_id,
+        OnboardingMessage::CaptureTestFinished(Err(capture_error.clone())),
+    ));
+
+    assert_eq!(
+        get_onboarding_capture_test_status(&orchestrator, window_id),
+        Some(CaptureTestStatus::Failed(capture_error))
+    );
+}
+
+#[test]
+fn test_update_include_annotations_in_search_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateIncludeAnnotationsInSearch(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.include_annotations_in_search
+    ));
+}
+
+#[test]
+fn test_update_show_help_hint_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateShowHelpHint(false));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if !settings.show_help_hint
+    ));
+}
+
+#[test]
+fn test_update_play_capture_sound_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdatePlayCaptureSound(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.play_capture_sound
+    ));
+}
+
+#[test]
+fn test_update_show_capture_flash_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateShowCaptureFlash(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.show_capture_flash
+    ));
+}
+
+#[test]
+fn test_update_play_success_sound_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdatePlaySuccessSound(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.play_success_sound
+    ));
+}
+
+#[test]
+fn test_update_play_failure_sound_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdatePlayFailureSound(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.play_failure_sound
+    ));
+}
+
+#[test]
+fn test_update_ocr_language_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateOcrLanguage(OcrLanguage::German));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.language == "deu"
+    ));
+}
+
+#[test]
+fn test_ocr_service_ready_warns_about_unsupported_language() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.language = "xx".to_string();
+
+    let _ = orchestrator.handle_ocr_service_ready(Arc::new(MockOcrService));
+
+    assert!(orchestrator
+        .status
+        .starts_with(global_constants::STATUS_OCR_LANGUAGE_UNSUPPORTED_PREFIX));
+}
+
+#[test]
+fn test_should_rebuild_search_provider_when_search_engine_changes() {
+    let previous_settings = UserSettings::default();
+    let next_settings = UserSettings {
+        search_engine: crate::core::models::SearchEngine::BingVisualSearch,
+        ..UserSettings::default()
+    };
+
+    assert!(AppOrchestrator::should_rebuild_search_provider(
+        &previous_settings,
+        &next_settings
+    ));
+}
+
+#[test]
+fn test_update_search_engine_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateSearchEngine(
+        crate::core::models::SearchEngine::Yandex,
+    ));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.search_engine == crate::core::models::SearchEngine::Yandex
+    ));
+}
+
+#[test]
+fn test_update_max_history_entries_clamps_to_limit() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateMaxHistoryEntries(u32::MAX));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings)
+            if settings.max_history_entries == global_constants::MAX_HISTORY_ENTRIES_LIMIT
+    ));
+}
+
+#[test]
+fn test_capture_history_entry_load_failure_updates_status() {
+    let mut orchestrator = create_test_orchestrator();
+    let _ = orchestrator.update(OrchestratorMessage::CaptureHistoryEntryLoaded(Err(
+        "missing".to_string(),
+    )));
+    assert!(orchestrator
+        .status
+        .starts_with(global_constants::STATUS_CAPTURE_HISTORY_LOAD_FAILED_PREFIX));
+}
+
+#[test]
+fn test_save_settings_rejects_hotkey_without_key() {
+    let mut orchestrator = create_test_orchestrator();
+    let mut settings = UserSettings::default();
+    settings.capture_hotkey = "Ctrl+Shift".to_string();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(settings);
+
+    let _ = orchestrator.update(OrchestratorMessage::SaveSettings);
+
+    assert!(orchestrator
+        .status
+        .ends_with(global_constants::HOTKEY_VALIDATION_MISSING_KEY));
+    assert!(matches!(
+        orchestrator.settings_edit_state,
+        SettingsEditState::Editing(_)
+    ));
+}
+
+#[test]
+fn test_update_min_ocr_confidence_clamps_to_unit_range() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateMinOcrConfidence(1.7));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.min_ocr_confidence == 1.0
+    ));
+}
+
+#[test]
+fn test_recrop_reopens_overlay_seeded_with_current_image() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![7u8; 400]);
+    let ocr_window_id = Id::unique();
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer.clone(),
+            ThemeMode::Dark,
+        )),
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::Recrop,
+    ));
+
+    let (overlay_id, source_window_id) = orchestrator.recrop_overlay.unwrap();
+    assert_eq!(source_window_id, ocr_window_id);
+    assert!(matches!(
+        orchestrator.windows.get(&overlay_id),
+        Some(AppWindow::CaptureOverlay(view)) if view.get_capture_buffer().raw_data == buffer.raw_data
+    ));
+    assert!(matches!(
+        orchestrator.windows.get(&ocr_window_id),
+        Some(AppWindow::InteractiveOcr(_))
+    ));
+}
+
+#[test]
+fn test_recrop_with_empty_selection_keeps_original_image() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![7u8; 400]);
+    let overlay_id = Id::unique();
+    orchestrator.windows.insert(
+        overlay_id,
+        AppWindow::CaptureOverlay(CaptureView::build_with_capture_buffer(buffer.clone())),
+    );
+    let source_window_id = Id::unique();
+    orchestrator.windows.insert(
+        source_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    orchestrator.recrop_overlay = Some((overlay_id, source_window_id));
+
+    let task = orchestrator.update(OrchestratorMessage::ConfirmSelection(overlay_id));
+
+    assert!(task.units() > 0);
+    assert!(orchestrator.recrop_overlay.is_none());
+    assert!(orchestrator.settings.recent_regions.is_empty());
+    assert!(orchestrator.pending_draw_strokes.is_none());
+    assert_eq!(
+        orchestrator
+            .windows
+            .values()
+            .filter(|window| matches!(window, AppWindow::InteractiveOcr(_)))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_dismissing_recrop_overlay_restores_source_window_without_new_capture() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![7u8; 400]);
+    let source_window_id = Id::unique();
+    orchestrator.windows.insert(
+        source_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer.clone(),
+            ThemeMode::Dark,
+        )),
+    );
+    let overlay_id = Id::unique();
+    orchestrator.windows.insert(
+        overlay_id,
+        AppWindow::CaptureOverlay(CaptureView::build_with_capture_buffer(buffer)),
+    );
+    orchestrator.recrop_overlay = Some((overlay_id, source_window_id));
+
+    let task = orchestrator.update(OrchestratorMessage::WindowClosed(overlay_id));
+
+    assert!(task.units() > 0);
+    assert!(orchestrator.recrop_overlay.is_none());
+    assert!(!orchestrator.windows.contains_key(&overlay_id));
+    assert!(matches!(
+        orchestrator.windows.get(&source_window_id),
+        Some(AppWindow::InteractiveOcr(_))
+    ));
+    assert!(orchestrator.pending_draw_strokes.is_none());
+}
+
+#[test]
+fn test_update_screenshot_save_location_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateScreenshotSaveLocation(
+        "/tmp/captures".to_string(),
+    ));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.screenshot_save_location == "/tmp/captures"
+    ));
+}
+
+#[test]
+fn test_barcodes_decoded_keeps_ocr_result_alongside_codes() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let ocr_window_id = Id::unique();
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    let ocr_result = OcrResult {
+        text_blocks: vec![crate::core::models::DetectedText::new(
+            "menu".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.9,
+            vec![],
+        )],
+        full_text: "menu".to_string(),
+    };
+
+    let _ = orchestrator.update(OrchestratorMessage::OcrComplete(
+        ocr_window_id,
+        Ok(ocr_result),
+    ));
+    let _ = orchestrator.update(OrchestratorMessage::BarcodesDecoded(
+        ocr_window_id,
+        vec![DecodedCode::new("https://example.com".to_string())],
+    ));
+
+    let Some(AppWindow::InteractiveOcr(view)) = orchestrator.windows.get(&ocr_window_id) else {
+        panic!("interactive OCR window should still be open");
+    };
+    assert_eq!(view.get_all_text(), "menu");
+    assert_eq!(
+        view.get_decoded_code(0),
+        Some(&DecodedCode::new("https://example.com".to_string()))
+    );
+}
+
+#[test]
+fn test_translate_selected_flows_through_view_states() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let ocr_window_id = Id::unique();
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    let ocr_result = OcrResult {
+        text_blocks: vec![crate::core::models::DetectedText::new(
+            "hola".to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.9,
+            vec![],
+        )],
+        full_text: "hola".to_string(),
+    };
+    let _ = orchestrator.update(OrchestratorMessage::OcrComplete(
+        ocr_window_id,
+        Ok(ocr_result),
+    ));
+    let translation_state =
+        |orchestrator: &AppOrchestrator| match orchestrator.windows.get(&ocr_window_id) {
+            Some(AppWindow::InteractiveOcr(view)) => view.get_translation_state().clone(),
+            _ => panic!("interactive OCR window should still be open"),
+        };
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::TranslateSelected,
+    ));
+    assert_eq!(
+        translation_state(&orchestrator),
+        crate::presentation::TranslationState::Idle
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::SelectAll,
+    ));
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::TranslateSelected,
+    ));
+    assert_eq!(
+        translation_state(&orchestrator),
+        crate::presentation::TranslationState::Translating
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::TranslationCompleted("[en] hola".to_string()),
+    ));
+    assert_eq!(
+        translation_state(&orchestrator),
+        crate::presentation::TranslationState::Completed("[en] hola".to_string())
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::DismissTranslation,
+    ));
+    assert_eq!(
+        translation_state(&orchestrator),
+        crate::presentation::TranslationState::Idle
+    );
+}
+
+#[test]
+fn test_ocr_complete_with_error_surfaces_message_in_status() {
+    let mut orchestrator = create_test_orchestrator();
+    let ocr_window_id = Id::unique();
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]),
+            ThemeMode::Dark,
+        )),
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::OcrComplete(
+        ocr_window_id,
+        Err(global_constants::OCR_RAW_IMAGE_CREATION_FAILED.to_string()),
+    ));
+
+    assert!(orchestrator
+        .status
+        .starts_with(global_constants::STATUS_OCR_FAILED_PREFIX));
+    assert!(orchestrator
+        .status
+        .contains(global_constants::OCR_RAW_IMAGE_CREATION_FAILED));
+}
+
+#[test]
+fn test_toggle_always_on_top_flips_view_and_requests_level_change() {
+    let mut orchestrator = create_test_orchestrator();
+    let ocr_window_id = Id::unique();
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]),
+            ThemeMode::Dark,
+        )),
+    );
+
+    let task = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        ocr_window_id,
+        crate::presentation::InteractiveOcrMessage::ToggleAlwaysOnTop,
+    ));
+
+    assert!(task.units() > 0);
+    assert!(matches!(
+        orchestrator.windows.get(&ocr_window_id),
+        Some(AppWindow::InteractiveOcr(view)) if view.is_always_on_top()
+    ));
+    assert_eq!(
+        super::capture::result_window_level(true),
+        window::Level::AlwaysOnTop
+    );
+    assert_eq!(
+        super::capture::result_window_level(false),
+        window::Level::Normal
+    );
+}
+
+#[test]
+fn test_should_rebuild_search_provider_when_image_host_changes() {
+    let previous_settings = UserSettings::default();
+    let mut next_settings = previous_settings.clone();
+    next_settings.image_host = ImageHost::ZeroXZero;
+
+    let should_rebuild =
+        AppOrchestrator::should_rebuild_search_provider(&previous_settings, &next_settings);
+
+    assert!(should_rebuild);
+}
+
+#[tokio::test]
+async fn test_build_image_hosting_service_uses_configured_custom_host() {
+    let settings = UserSettings {
+        image_host: ImageHost::CustomMultipart,
+        custom_image_host_url: "not a url".to_string(),
+        ..UserSettings::default()
+    };
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+
+    let upload_error = AppOrchestrator::build_image_hosting_service(&settings)
+        .upload_image(&buffer)
+        .await
+        .unwrap_err();
+
+    assert!(upload_error.to_string().contains("not a url"));
+}
+
+#[test]
+fn test_image_search_completed_stores_hosted_url_for_copying() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let ocr_window_id = Id::unique();
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::ImageSearchCompleted(
+        ocr_window_id,
+        "https://hosted.test/image.png".to_string(),
+    ));
+
+    match orchestrator.windows.get(&ocr_window_id) {
+        Some(AppWindow::InteractiveOcr(view)) => assert_eq!(
+            view.get_hosted_image_url(),
+            Some("https://hosted.test/image.png")
+        ),
+        _ => panic!("interactive OCR window should still be open"),
+    }
+}
+
+#[test]
+fn test_active_window_fallback_opens_ocr_view_with_status_note() {
+    let mut orchestrator = create_test_orchestrator();
+    let raw_data = vec![255u8; 100 * 100 * 4];
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 100, 100, raw_data);
+
+    let _ = orchestrator.update(OrchestratorMessage::ActiveWindowCaptureFallback(buffer));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_ACTIVE_WINDOW_FALLBACK
+    );
+    assert!(orchestrator
+        .windows
+        .values()
+        .any(|window| matches!(window, AppWindow::InteractiveOcr(_))));
+}
+
+#[test]
+fn test_tray_pointer_enter_records_window_consumed_by_active_window_capture() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let record_task =
+        orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::PointerEntered));
+    let _ = orchestrator.update(OrchestratorMessage::TrayForegroundWindowRecorded(Some(42)));
+
+    assert!(record_task.units() > 0);
+    assert!(orchestrator.pending_tray_events.is_empty());
+    assert_eq!(orchestrator.tray_foreground_window_id, Some(42));
+
+    orchestrator.is_initialized = true;
+    let capture_task = orchestrator.update(OrchestratorMessage::TrayEvent(
+        TrayEvent::CaptureActiveWindow,
+    ));
+
+    assert!(capture_task.units() > 0);
+    assert_eq!(orchestrator.tray_foreground_window_id, None);
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_CAPTURING_SCREEN
+    );
+}
+
+#[test]
+fn test_active_window_hotkey_ignored_while_recording_or_paused() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.is_recording_hotkey = true;
+
+    let recording_task = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::ActiveWindowHotkeyPressed,
+    ));
+
+    assert_eq!(recording_task.units(), 0);
+
+    orchestrator.is_recording_hotkey = false;
+    orchestrator.is_hotkey_enabled = false;
+    let paused_task = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::ActiveWindowHotkeyPressed,
+    ));
+
+    assert_eq!(paused_task.units(), 0);
+
+    orchestrator.is_hotkey_enabled = true;
+    let capture_task = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::ActiveWindowHotkeyPressed,
+    ));
+
+    assert!(capture_task.units() > 0);
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_CAPTURING_SCREEN
+    );
+}
+
+#[test]
+fn test_cancel_search_aborts_task_and_resets_view_once() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let mut view = crate::presentation::InteractiveOcrView::build(buffer, ThemeMode::Dark);
+    view.update(crate::presentation::InteractiveOcrMessage::SearchUploading);
+    orchestrator
+        .windows
+        .insert(window_id, AppWindow::InteractiveOcr(view));
+    let (_search_task, abort_handle) = Task::<OrchestratorMessage>::none().abortable();
+    orchestrator
+        .search_task_handles
+        .insert(window_id, abort_handle);
+
+    let _ = orchestrator.update(OrchestratorMessage::CancelSearch(window_id));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_SEARCH_CANCELLED
+    );
+    assert!(orchestrator.search_task_handles.is_empty());
+    match orchestrator.windows.get(&window_id) {
+        Some(AppWindow::InteractiveOcr(view)) => assert!(!view.is_searching()),
+        _ => panic!("interactive OCR window should still be open"),
+    }
+
+    orchestrator.status = global_constants::STATUS_READY_SIMPLE.to_string();
+    let _ = orchestrator.update(OrchestratorMessage::CancelSearch(window_id));
+
+    assert_eq!(orchestrator.status, global_constants::STATUS_READY_SIMPLE);
+}
+
+#[test]
+fn test_saved_window_geometry_on_disconnected_monitor_falls_back_to_default() {
+    let orchestrator = create_test_orchestrator();
+    let visible_geometry = crate::core::models::WindowGeometry {
+        x: 100.0,
+        y: 100.0,
+        width: 700.0,
+        height: 800.0,
+    };
+    let stale_geometry = crate::core::models::WindowGeometry {
+        x: 2500.0,
+        y: 100.0,
+        width: 700.0,
+        height: 800.0,
+    };
+
+    assert_eq!(
+        orchestrator.restorable_window_geometry(Some(visible_geometry)),
+        Some(visible_geometry)
+    );
+    assert_eq!(
+        orchestrator.restorable_window_geometry(Some(stale_geometry)),
+        None
+    );
+    assert_eq!(orchestrator.restorable_window_geometry(None), None);
+}
+
+#[test]
+fn test_window_geometry_changes_are_only_tracked_for_main_and_ocr_windows() {
+    let mut orchestrator = create_test_orchestrator();
+    let main_window_id = Id::unique();
+    let other_window_id = Id::unique();
+    orchestrator.main_window_id = Some(main_window_id);
+
+    let _ = orchestrator.update(OrchestratorMessage::WindowGeometryChanged(
+        main_window_id,
+        Some(Point::new(40.0, 60.0)),
+        Some(Size::new(700.0, 800.0)),
+    ));
+    let _ = orchestrator.update(OrchestratorMessage::WindowGeometryChanged(
+        other_window_id,
+        Some(Point::new(10.0, 10.0)),
+        None,
+    ));
+
+    assert!(orchestrator.window_geometries.contains_key(&main_window_id));
+    assert!(!orchestrator
+        .window_geometries
+        .contains_key(&other_window_id));
+}
+
+#[test]
+fn test_main_window_is_only_in_capture_path_on_the_target_monitor() {
+    let mut orchestrator = create_test_orchestrator();
+    let main_window_id = Id::unique();
+    orchestrator.main_window_id = Some(main_window_id);
+
+    assert_eq!(
+        orchestrator.main_window_in_capture_path(Some((10, 10))),
+        Some(main_window_id)
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::WindowGeometryChanged(
+        main_window_id,
+        Some(Point::new(40.0, 60.0)),
+        Some(Size::new(700.0, 800.0)),
+    ));
+    assert_eq!(
+        orchestrator.main_window_in_capture_path(Some((10, 10))),
+        Some(main_window_id)
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::WindowGeometryChanged(
+        main_window_id,
+        Some(Point::new(2600.0, 60.0)),
+        None,
+    ));
+    assert_eq!(
+        orchestrator.main_window_in_capture_path(Some((10, 10))),
+        None
+    );
+    assert_eq!(
+        orchestrator.main_window_in_capture_path(None),
+        Some(main_window_id)
+    );
+}
+
+#[test]
+fn test_quitting_saves_geometry_of_windows_that_are_still_open() {
+    let mut orchestrator = create_test_orchestrator();
+    let ocr_window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    orchestrator.windows.insert(
+        ocr_window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    let _ = orchestrator.update(OrchestratorMessage::WindowGeometryChanged(
+        ocr_window_id,
+        Some(Point::new(120.0, 80.0)),
+        Some(Size::new(640.0, 480.0)),
+    ));
+
+    let _ = orchestrator.update(OrchestratorMessage::ConfirmQuit);
+
+    assert_eq!(
+        orchestrator.settings.ocr_window_geometry,
+        Some(crate::core::models::WindowGeometry {
+            x: 120.0,
+            y: 80.0,
+            width: 640.0,
+            height: 480.0,
+        })
+    );
+    assert!(orchestrator.window_geometries.is_empty());
+}
+
+#[test]
+fn test_result_windows_cascade_from_saved_position() {
+    let saved_geometry = crate::core::models::WindowGeometry {
+        x: 100.0,
+        y: 50.0,
+        width: 640.0,
+        height: 480.0,
+    };
+    let offset = global_constants::RESULT_WINDOW_CASCADE_OFFSET_PX;
+
+    assert_eq!(
+        super::window_geometry::cascaded_window_position(Some(saved_geometry), 0),
+        window::Position::Specific(Point::new(100.0, 50.0))
+    );
+    assert_eq!(
+        super::window_geometry::cascaded_window_position(Some(saved_geometry), 2),
+        window::Position::Specific(Point::new(100.0 + 2.0 * offset, 50.0 + 2.0 * offset))
+    );
+    assert_eq!(
+        super::window_geometry::cascaded_window_position(None, 3),
+        window::Position::Centered
+    );
+}
+
+#[test]
+fn test_mock_capturer_with_three_monitors_yields_three_buffers() {
+    let capture_buffers = MockScreenCapturer.capture_all_monitors().unwrap();
+
+    assert_eq!(capture_buffers.len(), 3);
+}
+
+#[test]
+fn test_all_monitors_captured_opens_one_ocr_window_per_monitor() {
+    let mut orchestrator = create_test_orchestrator();
+    let capture_buffers = MockScreenCapturer.capture_all_monitors().unwrap();
+
+    let _ = orchestrator.update(OrchestratorMessage::AllMonitorsCaptured(Ok(
+        capture_buffers,
+    )));
+
+    let ocr_window_count = orchestrator
+        .windows
+        .values()
+        .filter(|window| matches!(window, AppWindow::InteractiveOcr(_)))
+        .count();
+    assert_eq!(ocr_window_count, 3);
+    assert_eq!(
+        orchestrator.status,
+        format!(
+            "{}{}",
+            global_constants::STATUS_ALL_MONITORS_CAPTURED_PREFIX,
+            3
+        )
+    );
+}
+
+#[test]
+fn test_all_monitors_captured_opens_a_window_for_identical_monitor_captures() {
+    let mut orchestrator = create_test_orchestrator();
+    let capture_buffers = (0..3)
+        .map(|_| CaptureBuffer::build_from_raw_data(1.0, 40, 30, vec![255u8; 40 * 30 * 4]))
+        .collect();
+
+    let _ = orchestrator.update(OrchestratorMessage::AllMonitorsCaptured(Ok(
+        capture_buffers,
+    )));
+
+    let ocr_window_count = orchestrator
+        .windows
+        .values()
+        .filter(|window| matches!(window, AppWindow::InteractiveOcr(_)))
+        .count();
+    assert_eq!(ocr_window_count, 3);
+}
+
+#[test]
+fn test_open_capture_result_with_ocr_returns_opened_window_id() {
+    let mut orchestrator = create_test_orchestrator();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 40, 30, vec![255u8; 40 * 30 * 4]);
+
+    let (opened_window_id, _task) = orchestrator.open_window_capture(buffer.clone());
+    let (duplicate_window_id, _task) = orchestrator.open_window_capture(buffer);
+
+    let opened_window_id = opened_window_id.unwrap();
+    assert!(matches!(
+        orchestrator.windows.get(&opened_window_id),
+        Some(AppWindow::InteractiveOcr(_))
+    ));
+    assert_eq!(duplicate_window_id, None);
+}
+
+#[test]
+fn test_all_monitors_captured_without_buffers_reports_capture_error() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::AllMonitorsCaptured(Ok(Vec::new())));
+
+    let ocr_window_count = orchestrator
+        .windows
+        .values()
+        .filter(|window| matches!(window, AppWindow::InteractiveOcr(_)))
+        .count();
+    assert_eq!(ocr_window_count, 0);
+    assert!(orchestrator
+        .status
+        .contains(global_constants::CAPTURE_ERROR_NO_MONITORS_CAPTURED));
+}
+
+#[test]
+fn test_update_minimize_on_capture_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateMinimizeOnCapture(false));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if !settings.minimize_on_capture
+    ));
+}
+
+#[test]
+fn test_closing_result_window_clears_minimized_for_capture_flag() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.main_window_id = Some(Id::unique());
+    let ocr_window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let view = crate::presentation::InteractiveOcrView::build(buffer, ThemeMode::Dark);
+    orchestrator
+        .windows
+        .insert(ocr_window_id, AppWindow::InteractiveOcr(view));
+
+    let _ = orchestrator.update(OrchestratorMessage::MainWindowMinimizedForCapture);
+    assert!(orchestrator.is_main_window_minimized_for_capture);
+
+    let _ = orchestrator.update(OrchestratorMessage::WindowClosed(ocr_window_id));
+    assert!(!orchestrator.is_main_window_minimized_for_capture);
+}
+
+#[test]
+fn test_clipboard_without_image_shows_friendly_status() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::ClipboardImageRead(Err(
+        global_constants::CLIPBOARD_ERROR_NO_IMAGE.to_string(),
+    )));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::CLIPBOARD_ERROR_NO_IMAGE
+    );
+    assert!(orchestrator.windows.is_empty());
+}
+
+#[test]
+fn test_clipboard_image_opens_result_window() {
+    let mut orchestrator = create_test_orchestrator();
+    let capture_buffer = CaptureBuffer::build_from_raw_data(1.0, 4, 3, vec![200u8; 4 * 3 * 4]);
+
+    let _ = orchestrator.update(OrchestratorMessage::ClipboardImageRead(Ok(capture_buffer)));
+
+    assert!(orchestrator
+        .windows
+        .values()
+        .any(|window| matches!(window, AppWindow::InteractiveOcr(_))));
+}
+
+#[test]
+fn test_file_dropped_on_non_main_window_is_ignored() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.main_window_id = Some(Id::unique());
+    orchestrator.status = "Ready".to_string();
+
+    let _ = orchestrator.update(OrchestratorMessage::FileDropped(
+        Id::unique(),
+        std::path::PathBuf::from("dropped.png"),
+    ));
+
+    assert_eq!(orchestrator.status, "Ready");
+}
+
+#[test]
+fn test_dropped_non_image_file_shows_error_status() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::DroppedFileLoaded(Err(
+        global_constants::DROPPED_FILE_ERROR_UNSUPPORTED.to_string(),
+    )));
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::DROPPED_FILE_ERROR_UNSUPPORTED
+    );
+    assert!(orchestrator.windows.is_empty());
+}
+
+#[test]
+fn test_dropped_image_opens_result_window() {
+    let mut orchestrator = create_test_orchestrator();
+    let capture_buffer = CaptureBuffer::build_from_raw_data(1.0, 4, 3, vec![200u8; 4 * 3 * 4]);
+
+    let _ = orchestrator.update(OrchestratorMessage::DroppedFileLoaded(Ok(capture_buffer)));
+
+    assert!(orchestrator
+        .windows
+        .values()
+        .any(|window| matches!(window, AppWindow::InteractiveOcr(_))));
+}
+
+#[test]
+fn test_paused_hotkey_ignores_capture_trigger_until_resumed() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.is_initialized = true;
+
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::ToggleHotkey));
+    assert!(!orchestrator.is_hotkey_enabled);
+    assert_eq!(orchestrator.status, global_constants::STATUS_HOTKEY_PAUSED);
+
+    let _ = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::CaptureHotkeyPressed,
+    ));
+    assert_eq!(orchestrator.status, global_constants::STATUS_HOTKEY_PAUSED);
+
+    let _ = orchestrator.update(OrchestratorMessage::TrayEvent(TrayEvent::ToggleHotkey));
+    assert!(orchestrator.is_hotkey_enabled);
+
+    let _ = orchestrator.update(OrchestratorMessage::Keyboard(
+        GlobalKeyboardEvent::CaptureHotkeyPressed,
+    ));
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_PREPARING_CAPTURE
+    );
+}
+
+#[test]
+fn test_reprocess_with_language_restarts_ocr_with_cached_language_service() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+    orchestrator.windows.insert(
+        window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    orchestrator
+        .ocr_language_services
+        .insert("deu".to_string(), orchestrator.ocr_service.clone());
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        window_id,
+        crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage("deu".to_string()),
+    ));
+
+    assert_eq!(orchestrator.status, global_constants::STATUS_PROCESSING_OCR);
+    assert!(matches!(
+        orchestrator.windows.get(&window_id),
+        Some(AppWindow::InteractiveOcr(view)) if view.get_ocr_language() == "deu"
+    ));
+}
+
+#[test]
+fn test_reprocess_with_uncached_language_loads_service_before_ocr() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+    orchestrator.windows.insert(
+        window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        window_id,
+        crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage("jpn".to_string()),
+    ));
+    assert_eq!(
+        orchestrator.status,
+        format!(
+            "{}jpn",
+            global_constants::STATUS_LOADING_OCR_LANGUAGE_PREFIX
+        )
+    );
+
+    let service = orchestrator.ocr_service.clone();
+    let _ = orchestrator.update(OrchestratorMessage::OcrLanguageServiceReady(
+        window_id,
+        "jpn".to_string(),
+        Ok(service),
+    ));
+
+    assert!(orchestrator.ocr_language_services.contains_key("jpn"));
+    assert_eq!(orchestrator.status, global_constants::STATUS_PROCESSING_OCR);
+}
+
+#[test]
+fn test_language_service_ready_runs_ocr_with_the_built_service() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+    orchestrator.windows.insert(
+        window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        window_id,
+        crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage("jpn".to_string()),
+    ));
+
+    let japanese_service: Arc<dyn OcrService> = Arc::new(MockOcrService);
+    let task = orchestrator.update(OrchestratorMessage::OcrLanguageServiceReady(
+        window_id,
+        "jpn".to_string(),
+        Ok(japanese_service.clone()),
+    ));
+
+    assert!(task.units() > 0);
+    assert_eq!(orchestrator.status, global_constants::STATUS_PROCESSING_OCR);
+    assert!(Arc::ptr_eq(
+        &orchestrator.ocr_service_for_window(window_id),
+        &japanese_service
+    ));
+}
+
+#[test]
+fn test_stale_language_service_build_does_not_run_ocr() {
+    let mut orchestrator = create_test_orchestrator();
+    let window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+    orchestrator.windows.insert(
+        window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        window_id,
+        crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage("jpn".to_string()),
+    ));
+    let german_service: Arc<dyn OcrService> = Arc::new(MockOcrService);
+    orchestrator
+        .ocr_language_services
+        .insert("deu".to_string(), german_service.clone());
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        window_id,
+        crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage("deu".to_string()),
+    ));
+    orchestrator.status = String::new();
+
+    let task = orchestrator.update(OrchestratorMessage::OcrLanguageServiceReady(
+        window_id,
+        "jpn".to_string(),
+        Ok(Arc::new(MockOcrService)),
+    ));
+
+    assert_eq!(task.units(), 0);
+    assert!(orchestrator.status.is_empty());
+    assert!(orchestrator.ocr_language_services.contains_key("jpn"));
+    assert!(Arc::ptr_eq(
+        &orchestrator.ocr_service_for_window(window_id),
+        &german_service
+    ));
+}
+
+#[test]
+fn test_reprocess_with_language_skips_rebuild_for_fixed_language_engines() {
+    for engine in [OcrEngine::Neural, OcrEngine::Onnx] {
+        let mut orchestrator = create_test_orchestrator();
+        orchestrator.settings.ocr_engine = engine;
+        let window_id = Id::unique();
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+        orchestrator.windows.insert(
+            window_id,
+            AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+                buffer,
+                ThemeMode::Dark,
+            )),
+        );
+
+        let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+            window_id,
+            crate::presentation::InteractiveOcrMessage::ReprocessWithLanguage("jpn".to_string()),
+        ));
+
+        assert_eq!(orchestrator.status, global_constants::STATUS_PROCESSING_OCR);
+        assert!(Arc::ptr_eq(
+            &orchestrator.ocr_service_for_window(window_id),
+            &orchestrator.ocr_service
+        ));
+    }
+}
+
+#[test]
+fn test_clear_cache_is_blocked_while_ocr_is_running() {
+    let mut orchestrator = create_test_orchestrator();
+    let (_ocr_task, abort_handle) = iced::Task::<OrchestratorMessage>::none().abortable();
+    orchestrator
+        .ocr_task_handles
+        .insert(Id::unique(), abort_handle);
+
+    let _ = orchestrator.update(OrchestratorMessage::ClearCache);
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_CLEAR_CACHE_BLOCKED_BY_OCR
+    );
+}
+
+#[test]
+fn test_clear_cache_is_blocked_while_ocr_service_build_is_running() {
+    let mut orchestrator = create_test_orchestrator();
+    let build_task = orchestrator.build_ocr_service_task(OcrEngine::Tesseract, "eng".to_string());
+
+    let _ = orchestrator.update(OrchestratorMessage::ClearCache);
+
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_CLEAR_CACHE_BLOCKED_BY_OCR_BUILD
+    );
+
+    drop(build_task);
+    let _ = orchestrator.update(OrchestratorMessage::ClearCache);
+
+    assert_eq!(orchestrator.status, global_constants::STATUS_CLEARING_CACHE);
+}
+
+#[test]
+fn test_cache_cleared_drops_language_services_and_rebuilds_ocr_service() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator
+        .ocr_language_services
+        .insert("deu".to_string(), Arc::new(MockOcrService));
+
+    let task = orchestrator.update(OrchestratorMessage::CacheCleared(1024));
+
+    assert!(task.units() > 0);
+    assert!(orchestrator.ocr_language_services.is_empty());
+    assert_eq!(orchestrator.ocr_builds_in_flight.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_clear_cache_reports_freed_size() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::ClearCache);
+    assert_eq!(orchestrator.status, global_constants::STATUS_CLEARING_CACHE);
+
+    let _ = orchestrator.update(OrchestratorMessage::CacheCleared(3 * 1024 * 1024));
+
+    assert_eq!(
+        orchestrator.status,
+        format!("{}3.0 MB", global_constants::STATUS_CACHE_CLEARED_PREFIX)
+    );
+}
+
+#[test]
+fn test_capture_error_shows_toast_and_schedules_hide() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let task = orchestrator.update(OrchestratorMessage::CaptureError("Test error".to_string()));
+
+    assert!(matches!(
+        &orchestrator.main_window_toast,
+        Some((message, ToastKind::Error)) if message.contains("Test error")
+    ));
+    assert!(task.units() > 0);
+}
+
+#[test]
+fn test_successive_errors_replace_toast_and_ignore_stale_hide() {
+    let mut orchestrator = create_test_orchestrator();
+
+    let _ = orchestrator.update(OrchestratorMessage::CaptureError("first".to_string()));
+    let first_generation = orchestrator.main_window_toast_generation;
+    let _ = orchestrator.update(OrchestratorMessage::OcrServiceFailed("second".to_string()));
+
+    assert!(matches!(
+        &orchestrator.main_window_toast,
+        Some((message, ToastKind::Error)) if message.contains("second")
+    ));
+
+    let _ = orchestrator.update(OrchestratorMessage::HideMainWindowToast(first_generation));
+    assert!(orchestrator.main_window_toast.is_some());
+
+    let latest_generation = orchestrator.main_window_toast_generation;
+    let _ = orchestrator.update(OrchestratorMessage::HideMainWindowToast(latest_generation));
+    assert!(orchestrator.main_window_toast.is_none());
+}
+
+#[test]
+fn test_update_auto_copy_on_ocr_modifies_temp_settings() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings_edit_state = SettingsEditState::Editing(UserSettings::default());
+    let _ = orchestrator.update(OrchestratorMessage::UpdateAutoCopyOnOcr(true));
+    assert!(matches!(
+        &orchestrator.settings_edit_state,
+        SettingsEditState::Editing(settings) if settings.auto_copy_on_ocr
+    ));
+}
+
+fn build_single_block_ocr_result(content: &str) -> OcrResult {
+    OcrResult {
+        text_blocks: vec![crate::core::models::DetectedText::new(
+            content.to_string(),
+            0.0,
+            0.0,
+            40.0,
+            10.0,
+            0.9,
+            vec![],
+        )],
+        full_text: content.to_string(),
+    }
+}
+
+fn insert_test_ocr_window(orchestrator: &mut AppOrchestrator) -> Id {
+    let window_id = Id::unique();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![255u8; 16]);
+    orchestrator.windows.insert(
+        window_id,
+        AppWindow::InteractiveOcr(crate::presentation::InteractiveOcrView::build(
+            buffer,
+            ThemeMode::Dark,
+        )),
+    );
+    window_id
+}
+
+thread_local! {
+    static RECORDED_CLIPBOARD_TEXTS: std::cell::RefCell<Vec<String>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+fn record_clipboard_text(text: &str) -> Result<(), String> {
+    RECORDED_CLIPBOARD_TEXTS.with(|texts| texts.borrow_mut().push(text.to_string()));
+    Ok(())
+}
+
+fn take_recorded_clipboard_texts() -> Vec<String> {
+    RECORDED_CLIPBOARD_TEXTS.with(|texts| std::mem::take(&mut *texts.borrow_mut()))
+}
+
+fn insert_test_ocr_window_with_recording_clipboard(orchestrator: &mut AppOrchestrator) -> Id {
+    let window_id = insert_test_ocr_window(orchestrator);
+    if let Some(AppWindow::InteractiveOcr(view)) = orchestrator.windows.get_mut(&window_id) {
+        view.set_text_clipboard_writer(record_clipboard_text);
+    }
+    take_recorded_clipboard_texts();
+    window_id
+}
+
+#[test]
+fn test_ocr_complete_with_auto_copy_copies_recognized_text() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.auto_copy_on_ocr = true;
+    let window_id = insert_test_ocr_window_with_recording_clipboard(&mut orchestrator);
+
+    let task = orchestrator.update(OrchestratorMessage::OcrComplete(
+        window_id,
+        Ok(build_single_block_ocr_result("menu")),
+    ));
+
+    assert!(task.units() > 0);
+    assert_eq!(take_recorded_clipboard_texts(), vec!["menu".to_string()]);
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_OCR_TEXT_AUTO_COPIED
+    );
+}
+
+#[test]
+fn test_auto_copy_only_runs_for_first_ocr_of_a_capture() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.auto_copy_on_ocr = true;
+    let window_id = insert_test_ocr_window_with_recording_clipboard(&mut orchestrator);
+
+    let _ = orchestrator.update(OrchestratorMessage::OcrComplete(
+        window_id,
+        Ok(build_single_block_ocr_result("menu")),
+    ));
+    let _ = orchestrator.update(OrchestratorMessage::OcrComplete(
+        window_id,
+        Ok(build_single_block_ocr_result("reran")),
+    ));
+
+    assert_eq!(take_recorded_clipboard_texts(), vec!["menu".to_string()]);
+    assert_eq!(orchestrator.status, global_constants::STATUS_OCR_COMPLETE);
+}
+
+#[test]
+fn test_ocr_complete_skips_auto_copy_for_short_text() {
+    let mut orchestrator = create_test_orchestrator();
+    orchestrator.settings.auto_copy_on_ocr = true;
+    let window_id = insert_test_ocr_window_with_recording_clipboard(&mut orchestrator);
+
+    let _ = orchestrator.update(OrchestratorMessage::OcrComplete(
+        window_id,
+        Ok(build_single_block_ocr_result("a")),
+    ));
+
+    assert!(take_recorded_clipboard_texts().is_empty());
+    assert_eq!(orchestrator.status, global_constants::STATUS_OCR_COMPLETE);
+}
+
+fn insert_test_overlay_with_selection(orchestrator: &mut AppOrchestrator) -> Id {
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+    let mut capture_view = CaptureView::build_with_capture_buffer(buffer);
+    capture_view.update(CaptureViewMessage::MousePressed(Point::new(1.0, 1.0)));
+    capture_view.update(CaptureViewMessage::MouseMoved(Point::new(6.0, 6.0)));
+    capture_view.update(CaptureViewMessage::MouseReleased);
+    let overlay_id = Id::unique();
+    orchestrator
+        .windows
+        .insert(overlay_id, AppWindow::CaptureOverlay(capture_view));
+    overlay_id
+}
+
+#[test]
+fn test_confirmed_selection_on_high_dpi_capture_crops_physical_pixels() {
+    let mut orchestrator = create_test_orchestrator();
+    let raw_data = (0..20 * 20)
+        .flat_map(|index| [(index % 251) as u8, (index / 20) as u8, 7, 255])
+        .collect();
+    let buffer = CaptureBuffer::build_from_raw_data(2.0, 20, 20, raw_data);
+    let mut capture_view = CaptureView::build_with_capture_buffer(buffer.clone());
+    capture_view.update(CaptureViewMessage::MousePressed(Point::new(1.0, 1.0)));
+    capture_view.update(CaptureViewMessage::MouseMoved(Point::new(6.0, 6.0)));
+    capture_view.update(CaptureViewMessage::MouseReleased);
+    let selection_rect = capture_view.get_selected_region().unwrap();
+
+    let _task = orchestrator.update(OrchestratorMessage::ShowCroppedImage(
+        buffer.clone(),
+        selection_rect,
+    ));
+
+    let ocr_buffer = orchestrator
+        .windows
+        .values()
+        .find_map(|window| match window {
+            AppWindow::InteractiveOcr(view) => Some(view.get_capture_buffer().clone()),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!((ocr_buffer.width, ocr_buffer.height), (10, 10));
+    assert_eq!(
+        ocr_buffer.raw_data,
+        buffer.crop_region(2, 2, 10, 10).unwrap().raw_data
+    );
+}
+
+fn find_opened_ocr_buffer(orchestrator: &AppOrchestrator) -> Option<CaptureBuffer> {
+    orchestrator
+        .windows
+        .values()
+        .find_map(|window| match window {
+            AppWindow::InteractiveOcr(view) => Some(view.get_capture_buffer().clone()),
+            _ => None,
+        })
+}
+
+fn build_transparent_padded_buffer() -> CaptureBuffer {
+    let raw_data = (0..10 * 10)
+        .flat_map(|index| {
+            let (x, y) = (index % 10, index / 10);
+            if (3..7).contains(&x) && (2..5).contains(&y) {
+                [10, 20, 30, 255]
+            } else {
+                [0, 0, 0, 0]
+            }
+        })
+        .collect();
+    CaptureBuffer::build_from_raw_data(1.0, 10, 10, raw_data)
+}
+
+#[test]
+fn test_rectangle_crop_keeps_transparent_padding() {
+    let mut orchestrator = create_test_orchestrator();
+    let selection = Rectangle::new(Point::new(0.0, 0.0), Size::new(10.0, 10.0));
+
+    let _ = orchestrator.handle_show_cropped_image(build_transparent_padded_buffer(), selection);
+
+    let ocr_buffer = find_opened_ocr_buffer(&orchestrator).unwrap();
+    assert_eq!((ocr_buffer.width, ocr_buffer.height), (10, 10));
+}
+
+#[test]
+fn test_masked_crop_trims_transparent_padding_once() {
+    let mut orchestrator = create_test_orchestrator();
+    let selection = Rectangle::new(Point::new(0.0, 0.0), Size::new(10.0, 10.0));
+    orchestrator.is_pending_crop_masked = true;
+
+    let _ = orchestrator.handle_show_cropped_image(build_transparent_padded_buffer(), selection);
+
+    let ocr_buffer = find_opened_ocr_buffer(&orchestrator).unwrap();
+    assert_eq!((ocr_buffer.width, ocr_buffer.height), (4, 3));
+    assert!(!orchestrator.is_pending_crop_masked);
+}
+
+fn find_search_progress_window(orchestrator: &AppOrchestrator) -> Option<Id> {
+    orchestrator
+        .windows
+        .iter()
+        .find(|(_, window)| matches!(window, AppWindow::SearchProgress(..)))
+        .map(|(id, _)| *id)
+}
+
+#[test]
+fn test_shift_confirm_search_opens_progress_window_instead_of_ocr_window() {
+    let mut orchestrator = create_test_orchestrator();
+    let overlay_id = insert_test_overlay_with_selection(&mut orchestrator);
+
+    let task = orchestrator.update(OrchestratorMessage::CaptureOverlayMessage(
+        overlay_id,
+        CaptureViewMessage::SearchSelection,
+    ));
+
+    assert!(task.units() > 0);
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_SEARCHING_SELECTION
+    );
+    let progress_id = find_search_progress_window(&orchestrator).unwrap();
+    assert_ne!(progress_id, overlay_id);
+    assert!(!orchestrator
+        .windows
+        .values()
+        .any(|window| matches!(window, AppWindow::InteractiveOcr(_))));
+}
+
+#[test]
+fn test_closing_overlay_keeps_direct_search_running() {
+    let mut orchestrator = create_test_orchestrator();
+    let overlay_id = insert_test_overlay_with_selection(&mut orchestrator);
+    let _ = orchestrator.update(OrchestratorMessage::CaptureOverlayMessage(
+        overlay_id,
+        CaptureViewMessage::SearchSelection,
+    ));
+    let progress_id = find_search_progress_window(&orchestrator).unwrap();
+    let buffer = CaptureBuffer::build_from_raw_data(1.0, 5, 5, vec![0u8; 100]);
+    let _ = orchestrator.update(OrchestratorMessage::PerformImageSearch(
+        progress_id,
+        buffer,
+        None,
+    ));
+
+    let _ = orchestrator.update(OrchestratorMessage::WindowClosed(overlay_id));
+
+    assert!(orchestrator.search_task_handles.contains_key(&progress_id));
+    assert_eq!(
+        orchestrator.status,
+        global_constants::STATUS_SEARCHING_SELECTION
+    );
+}
+
+#[test]
+fn test_direct_search_result_is_shown_in_progress_window() {
+    let mut orchestrator = create_test_orchestrator();
+    let overlay_id = insert_test_overlay_with_selection(&mut orchestrator);
+    let _ = orchestrator.update(OrchestratorMessage::CaptureOverlayMessage(
+        overlay_id,
+        CaptureViewMessage::SearchSelection,
+    ));
+    let progress_id = find_search_progress_window(&orchestrator).unwrap();
+
+    let close_task = orchestrator.update(OrchestratorMessage::ImageSearchCompleted(
+        progress_id,
+        "https://hosted.test/image.png".to_string(),
+    ));
+    assert!(close_task.units() > 0);
+    assert!(matches!(
+        orchestrator.windows.get(&progress_id),
+        Some(AppWindow::SearchProgress(message, ToastKind::Info))
+            if message == global_constants::STATUS_SELECTION_SEARCH_OPENED
+    ));
+
+    let _ = orchestrator.update(OrchestratorMessage::InteractiveOcrMessage(
+        progress_id,
+        crate::presentation::InteractiveOcrMessage::SearchFailed("upload failed".to_string()),
+    ));
+    assert!(matches!(
+        orchestrator.windows.get(&progress_id),
+        Some(AppWindow::SearchProgress(message, ToastKind::Error)) if message == "upload failed"
+    ));
+    assert_eq!(orchestrator.status, "upload failed");
+}
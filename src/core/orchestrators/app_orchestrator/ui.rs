@@ -1,4 +1,7 @@
 use super::*;
 
 mod main_window;
+mod quit_confirmation;
+mod search_progress;
 mod settings_window;
+mod tray_notice;
@@ -1,10 +1,10 @@
 use super::*;
-use iced::widget::{button, column, container, row, text, Space};
+use iced::widget::{button, column, container, row, stack, text, Space};
 use iced::{Alignment, Background, Color, Element, Length};
 
 impl AppOrchestrator {
     pub fn render_main_window(&self) -> Element<'_, OrchestratorMessage> {
-        let theme = app_theme::get_theme(&self.settings.theme_mode);
+        let theme = self.get_app_theme();
 
         let logo_icon = text(global_constants::MAIN_WINDOW_ICON_SEARCH).size(64);
         let title = text(global_constants::APPLICATION_TITLE).size(36);
@@ -30,6 +30,18 @@ impl AppOrchestrator {
         .style(|theme, status| app_theme::primary_button_style(theme, status))
         .on_press(OrchestratorMessage::CaptureScreen);
 
+        let clipboard_btn = button(
+            row![
+                text(global_constants::MAIN_WINDOW_ICON_CLIPBOARD).size(16),
+                text(global_constants::MAIN_WINDOW_OCR_CLIPBOARD_BUTTON_LABEL).size(14)
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        )
+        .padding([10, 24])
+        .style(|theme, status| app_theme::secondary_button_style(theme, status))
+        .on_press(OrchestratorMessage::OcrClipboardImage);
+
         let hotkey_text = container(
             text(global_constants::MAIN_WINDOW_HOTKEY_HINT_TEMPLATE.replace(
                 global_constants::MAIN_WINDOW_HOTKEY_TEMPLATE_TOKEN,
@@ -55,11 +67,15 @@ impl AppOrchestrator {
         .width(Length::Fill)
         .center_x(Length::Fill);
 
-        let action_content = column![hotkey_text, or_text, capture_btn]
+        let mut action_content = column![hotkey_text, or_text, capture_btn, clipboard_btn]
             .spacing(12)
             .align_x(Alignment::Center)
             .width(Length::Fill);
 
+        if !self.settings.recent_regions.is_empty() {
+            action_content = action_content.push(self.render_recent_regions_menu());
+        }
+
         let action_panel = container(action_content)
             .padding([28, 32])
             .width(Length::Fill)
@@ -103,7 +119,35 @@ impl AppOrchestrator {
         .style(|theme, status| app_theme::secondary_button_style(theme, status))
         .on_press(OrchestratorMessage::OpenSettings);
 
-        let footer_content = column![system_tray_row, settings_btn]
+        let open_project_btn = button(
+            row![
+                text(global_constants::MAIN_WINDOW_ICON_OPEN_PROJECT).size(16),
+                text(global_constants::MAIN_WINDOW_OPEN_PROJECT_BUTTON_LABEL).size(14)
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        )
+        .padding([12, 24])
+        .style(|theme, status| app_theme::secondary_button_style(theme, status))
+        .on_press(OrchestratorMessage::OpenAnnotationProject);
+
+        let history_btn = button(
+            row![
+                text(global_constants::MAIN_WINDOW_ICON_HISTORY).size(16),
+                text(global_constants::MAIN_WINDOW_HISTORY_BUTTON_LABEL).size(14)
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        )
+        .padding([12, 24])
+        .style(|theme, status| app_theme::secondary_button_style(theme, status))
+        .on_press(OrchestratorMessage::OpenCaptureHistory);
+
+        let footer_buttons = row![open_project_btn, history_btn, settings_btn]
+            .spacing(12)
+            .align_y(Alignment::Center);
+
+        let footer_content = column![system_tray_row, footer_buttons]
             .spacing(16)
             .align_x(Alignment::Center);
 
@@ -141,7 +185,7 @@ impl AppOrchestrator {
         .align_x(Alignment::Center)
         .max_width(500);
 
-        container(content)
+        let main_content = container(content)
             .width(Length::Fill)
             .height(Length::Fill)
             .center_x(Length::Fill)
@@ -153,7 +197,74 @@ impl AppOrchestrator {
                     text_color: Some(palette.text),
                     ..Default::default()
                 }
-            })
+            });
+
+        match self.render_main_window_toast() {
+            Some(toast) => stack![main_content, toast].into(),
+            None => main_content.into(),
+        }
+    }
+
+    fn render_main_window_toast(&self) -> Option<Element<'_, OrchestratorMessage>> {
+        let (message, kind) = self.main_window_toast.as_ref()?;
+        let toast_color = match kind {
+            ToastKind::Error => Color::from_rgb(0.9, 0.3, 0.3),
+            ToastKind::Info => Color::from_rgb(0.2, 0.8, 0.4),
+        };
+
+        let toast = container(text(message.as_str()).size(14).style(
+            move |_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(toast_color),
+            },
+        ))
+        .padding([8, 16])
+        .max_width(460)
+        .style(app_theme::toast_container_style);
+
+        Some(
+            container(toast)
+                .width(Length::Fill)
+                .padding(iced::Padding {
+                    top: global_constants::MAIN_WINDOW_TOAST_TOP_OFFSET,
+                    right: 0.0,
+                    bottom: 0.0,
+                    left: 0.0,
+                })
+                .align_x(Alignment::Center)
+                .into(),
+        )
+    }
+
+    fn render_recent_regions_menu(&self) -> Element<'_, OrchestratorMessage> {
+        let title = text(global_constants::MAIN_WINDOW_RECENT_REGIONS_TITLE)
+            .size(13)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(0.5, 0.5, 0.5, 1.0)),
+            });
+
+        let region_buttons = self.settings.recent_regions.iter().enumerate().fold(
+            column![title].spacing(6).align_x(Alignment::Center),
+            |menu, (region_index, region)| {
+                menu.push(
+                    button(
+                        row![
+                            text(global_constants::MAIN_WINDOW_ICON_RECENT_REGION).size(14),
+                            text(region.to_string()).size(13)
+                        ]
+                        .spacing(8)
+                        .align_y(Alignment::Center),
+                    )
+                    .padding([6, 16])
+                    .style(|theme, status| app_theme::secondary_button_style(theme, status))
+                    .on_press(OrchestratorMessage::CaptureRecentRegion(region_index)),
+                )
+            },
+        );
+
+        container(region_buttons)
+            .padding([8, 0])
+            .width(Length::Fill)
+            .center_x(Length::Fill)
             .into()
     }
 
@@ -0,0 +1,77 @@
+use super::*;
+use iced::widget::{button, column, container, row, text, Space};
+use iced::{Alignment, Background, Color, Element, Length};
+
+impl AppOrchestrator {
+    pub fn render_quit_confirmation_window(&self) -> Element<'_, OrchestratorMessage> {
+        let theme = self.get_app_theme();
+
+        let header = column![
+            text(global_constants::QUIT_CONFIRMATION_ICON).size(36),
+            text(global_constants::QUIT_CONFIRMATION_TITLE).size(20),
+        ]
+        .spacing(6)
+        .align_x(Alignment::Center);
+
+        let mut unsaved_items = column![text(global_constants::QUIT_CONFIRMATION_MESSAGE).size(13)]
+            .spacing(6)
+            .width(Length::Fill);
+        for (index, count) in self
+            .collect_unsaved_annotation_counts()
+            .into_iter()
+            .enumerate()
+        {
+            let item_text = global_constants::QUIT_CONFIRMATION_ITEM_TEMPLATE
+                .replace(
+                    global_constants::QUIT_CONFIRMATION_INDEX_TOKEN,
+                    &(index + 1).to_string(),
+                )
+                .replace(
+                    global_constants::QUIT_CONFIRMATION_COUNT_TOKEN,
+                    &count.to_string(),
+                );
+            unsaved_items =
+                unsaved_items.push(text(item_text).size(12).style(|_theme: &iced::Theme| {
+                    iced::widget::text::Style {
+                        color: Some(Color::from_rgba(0.7, 0.7, 0.7, 1.0)),
+                    }
+                }));
+        }
+
+        let cancel_btn = button(text(global_constants::QUIT_CONFIRMATION_CANCEL_LABEL).size(14))
+            .padding([10, 24])
+            .style(|theme, status| app_theme::secondary_button_style(theme, status))
+            .on_press(OrchestratorMessage::CancelQuit);
+        let quit_btn = button(text(global_constants::QUIT_CONFIRMATION_QUIT_LABEL).size(14))
+            .padding([10, 24])
+            .style(|theme, status| app_theme::primary_button_style(theme, status))
+            .on_press(OrchestratorMessage::ConfirmQuit);
+        let actions = row![cancel_btn, quit_btn]
+            .spacing(12)
+            .align_y(Alignment::Center);
+
+        let content = column![
+            header,
+            Space::new().height(Length::Fixed(16.0)),
+            unsaved_items,
+            Space::new().height(Length::Fixed(20.0)),
+            actions,
+        ]
+        .padding(24)
+        .align_x(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .style(move |_theme| {
+                let palette = theme.palette();
+                iced::widget::container::Style {
+                    background: Some(Background::Color(palette.background)),
+                    text_color: Some(palette.text),
+                    ..Default::default()
+                }
+            })
+            .into()
+    }
+}
@@ -0,0 +1,49 @@
+use super::*;
+use iced::widget::{button, column, container, text, Space};
+use iced::{Alignment, Background, Element, Length};
+
+impl AppOrchestrator {
+    pub fn render_search_progress_window(
+        &self,
+        window_id: Id,
+        message: &str,
+        kind: ToastKind,
+    ) -> Element<'_, OrchestratorMessage> {
+        let theme = self.get_app_theme();
+        let icon = match kind {
+            ToastKind::Info => global_constants::SEARCH_PROGRESS_ICON,
+            ToastKind::Error => global_constants::SEARCH_PROGRESS_ERROR_ICON,
+        };
+
+        let close_btn = button(text(global_constants::SEARCH_PROGRESS_CLOSE_LABEL).size(13))
+            .padding([6, 18])
+            .style(|theme, status| app_theme::primary_button_style(theme, status))
+            .on_press(OrchestratorMessage::CloseWindow(window_id));
+
+        let content = column![
+            text(icon).size(22),
+            text(message.to_string())
+                .size(13)
+                .align_x(iced::widget::text::Alignment::Center),
+            Space::new().height(Length::Fixed(6.0)),
+            close_btn,
+        ]
+        .spacing(6)
+        .padding(14)
+        .align_x(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .style(move |_theme| {
+                let palette = theme.palette();
+                iced::widget::container::Style {
+                    background: Some(Background::Color(palette.background)),
+                    text_color: Some(palette.text),
+                    ..Default::default()
+                }
+            })
+            .into()
+    }
+}
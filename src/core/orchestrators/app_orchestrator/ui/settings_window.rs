@@ -4,7 +4,7 @@ use iced::{Alignment, Background, Color, Element, Length};
 
 impl AppOrchestrator {
     pub fn render_settings_window(&self) -> Element<'_, OrchestratorMessage> {
-        let theme = app_theme::get_theme(&self.settings.theme_mode);
+        let theme = self.get_app_theme();
         let temp = self.get_settings_for_rendering();
         let content = self.render_settings_content(temp);
 
@@ -31,6 +31,7 @@ impl AppOrchestrator {
         let image_hosting_section = self.render_image_hosting_settings_section(temp);
         let keyboard_section = self.render_keyboard_settings_section(temp);
         let appearance_section = self.render_appearance_settings_section(temp);
+        let behavior_section = self.render_behavior_settings_section(temp);
         let save_button = self.render_settings_save_button();
 
         column![
@@ -43,6 +44,8 @@ impl AppOrchestrator {
             keyboard_section,
             Space::new().height(Length::Fixed(16.0)),
             appearance_section,
+            Space::new().height(Length::Fixed(16.0)),
+            behavior_section,
             Space::new().height(Length::Fixed(28.0)),
             save_button,
         ]
@@ -64,22 +67,58 @@ impl AppOrchestrator {
         &self,
         temp: &UserSettings,
     ) -> Element<'_, OrchestratorMessage> {
-        use iced::widget::text_input;
+        use iced::widget::{pick_list, text_input};
 
         self.render_settings_section(
             global_constants::SETTINGS_SECTION_SEARCH_TITLE,
             global_constants::SETTINGS_SECTION_SEARCH_ICON,
-            column![self.render_setting_row(
-                global_constants::SETTINGS_LABEL_IMAGE_SEARCH_URL,
-                global_constants::SETTINGS_DESCRIPTION_IMAGE_SEARCH_URL,
-                text_input(
-                    global_constants::DEFAULT_IMAGE_SEARCH_URL,
-                    &temp.image_search_url_template,
-                )
-                .on_input(OrchestratorMessage::UpdateSearchUrl)
-                .padding(12)
-                .into(),
-            ),]
+            column![
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SEARCH_ENGINE,
+                    global_constants::SETTINGS_DESCRIPTION_SEARCH_ENGINE,
+                    pick_list(
+                        vec![
+                            crate::core::models::SearchEngine::GoogleLens,
+                            crate::core::models::SearchEngine::BingVisualSearch,
+                            crate::core::models::SearchEngine::Yandex,
+                        ],
+                        Some(temp.search_engine.clone()),
+                        OrchestratorMessage::UpdateSearchEngine,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_IMAGE_SEARCH_URL,
+                    global_constants::SETTINGS_DESCRIPTION_IMAGE_SEARCH_URL,
+                    row![
+                        text_input(
+                            global_constants::DEFAULT_IMAGE_SEARCH_URL,
+                            &temp.image_search_url_template,
+                        )
+                        .on_input(OrchestratorMessage::UpdateSearchUrl)
+                        .padding(12),
+                        button(text(global_constants::SETTINGS_RESET_TO_DEFAULT_LABEL).size(14))
+                            .padding([10, 16])
+                            .style(|theme, status| app_theme::secondary_button_style(theme, status))
+                            .on_press(OrchestratorMessage::ResetSearchUrl),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_TEXT_SEARCH_URL,
+                    global_constants::SETTINGS_DESCRIPTION_TEXT_SEARCH_URL,
+                    text_input(
+                        global_constants::DEFAULT_TEXT_SEARCH_URL,
+                        &temp.text_search_url_template,
+                    )
+                    .on_input(OrchestratorMessage::UpdateTextSearchUrl)
+                    .padding(12)
+                    .into(),
+                ),
+            ]
             .spacing(12),
         )
     }
@@ -100,6 +139,43 @@ impl AppOrchestrator {
             global_constants::SETTINGS_SECTION_IMAGE_HOSTING_TITLE,
             global_constants::SETTINGS_SECTION_IMAGE_HOSTING_ICON,
             column![
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_IMAGE_HOST,
+                    global_constants::SETTINGS_DESCRIPTION_IMAGE_HOST,
+                    pick_list(
+                        vec![
+                            ImageHost::Imgbb,
+                            ImageHost::ZeroXZero,
+                            ImageHost::CustomMultipart,
+                        ],
+                        Some(temp.image_host.clone()),
+                        OrchestratorMessage::UpdateImageHost,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CUSTOM_IMAGE_HOST_URL,
+                    global_constants::SETTINGS_DESCRIPTION_CUSTOM_IMAGE_HOST_URL,
+                    text_input(
+                        global_constants::SETTINGS_CUSTOM_IMAGE_HOST_URL_PLACEHOLDER,
+                        &temp.custom_image_host_url,
+                    )
+                    .on_input(OrchestratorMessage::UpdateCustomImageHostUrl)
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CUSTOM_IMAGE_HOST_FIELD_NAME,
+                    global_constants::SETTINGS_DESCRIPTION_CUSTOM_IMAGE_HOST_FIELD_NAME,
+                    text_input(
+                        global_constants::DEFAULT_CUSTOM_IMAGE_HOST_FIELD_NAME,
+                        &temp.custom_image_host_field_name,
+                    )
+                    .on_input(OrchestratorMessage::UpdateCustomImageHostFieldName)
+                    .padding(12)
+                    .into(),
+                ),
                 self.render_setting_row(
                     global_constants::SETTINGS_LABEL_PROVIDER_URL,
                     global_constants::SETTINGS_DESCRIPTION_PROVIDER_URL,
@@ -195,7 +271,19 @@ impl AppOrchestrator {
         &self,
         temp: &UserSettings,
     ) -> Element<'_, OrchestratorMessage> {
-        use iced::widget::text_input;
+        use iced::widget::{checkbox, pick_list, text_input};
+
+        let record_hotkey_button = if self.is_recording_hotkey {
+            button(text(global_constants::SETTINGS_RECORDING_HOTKEY_LABEL).size(14))
+                .padding([10, 16])
+                .style(|theme, status| app_theme::primary_button_style(theme, status))
+                .on_press(OrchestratorMessage::RecordingHotkey(false))
+        } else {
+            button(text(global_constants::SETTINGS_RECORD_HOTKEY_LABEL).size(14))
+                .padding([10, 16])
+                .style(|theme, status| app_theme::secondary_button_style(theme, status))
+                .on_press(OrchestratorMessage::RecordingHotkey(true))
+        };
 
         let hotkey_warning = text(global_constants::SETTINGS_RESTART_REQUIRED_WARNING)
             .size(11)
@@ -206,21 +294,83 @@ impl AppOrchestrator {
         self.render_settings_section(
             global_constants::SETTINGS_SECTION_KEYBOARD_TITLE,
             global_constants::SETTINGS_SECTION_KEYBOARD_ICON,
-            column![self.render_setting_row(
-                global_constants::SETTINGS_LABEL_CAPTURE_HOTKEY,
-                global_constants::SETTINGS_DESCRIPTION_CAPTURE_HOTKEY,
-                column![
+            column![
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CAPTURE_TRIGGER,
+                    global_constants::SETTINGS_DESCRIPTION_CAPTURE_TRIGGER,
+                    pick_list(
+                        vec![
+                            CaptureTrigger::Keyboard,
+                            CaptureTrigger::MiddleClickHold,
+                            CaptureTrigger::TrayOnly,
+                        ],
+                        Some(temp.capture_trigger.clone()),
+                        OrchestratorMessage::UpdateCaptureTrigger,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CAPTURE_HOTKEY,
+                    global_constants::SETTINGS_DESCRIPTION_CAPTURE_HOTKEY,
+                    column![
+                        row![
+                            text_input(
+                                global_constants::DEFAULT_CAPTURE_HOTKEY,
+                                &temp.capture_hotkey
+                            )
+                            .on_input(OrchestratorMessage::UpdateHotkey)
+                            .padding(12),
+                            record_hotkey_button,
+                        ]
+                        .spacing(8)
+                        .align_y(Alignment::Center),
+                        hotkey_warning,
+                    ]
+                    .spacing(4)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_DRAW_MODE_SHORTCUT,
+                    global_constants::SETTINGS_DESCRIPTION_DRAW_MODE_SHORTCUT,
+                    text_input(
+                        global_constants::DEFAULT_DRAW_MODE_SHORTCUT,
+                        &temp.draw_mode_shortcut
+                    )
+                    .on_input(OrchestratorMessage::UpdateDrawModeShortcut)
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SAVE_IMAGE_SHORTCUT,
+                    global_constants::SETTINGS_DESCRIPTION_SAVE_IMAGE_SHORTCUT,
                     text_input(
-                        global_constants::DEFAULT_CAPTURE_HOTKEY,
-                        &temp.capture_hotkey
+                        global_constants::DEFAULT_SAVE_IMAGE_SHORTCUT,
+                        &temp.save_image_shortcut
                     )
-                    .on_input(OrchestratorMessage::UpdateHotkey)
-                    .padding(12),
-                    hotkey_warning,
-                ]
-                .spacing(4)
-                .into(),
-            ),]
+                    .on_input(OrchestratorMessage::UpdateSaveImageShortcut)
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_COPY_IMAGE_SHORTCUT,
+                    global_constants::SETTINGS_DESCRIPTION_COPY_IMAGE_SHORTCUT,
+                    text_input(
+                        global_constants::DEFAULT_COPY_IMAGE_SHORTCUT,
+                        &temp.copy_image_shortcut
+                    )
+                    .on_input(OrchestratorMessage::UpdateCopyImageShortcut)
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SUSPEND_HOTKEY_IN_FULLSCREEN,
+                    global_constants::SETTINGS_DESCRIPTION_SUSPEND_HOTKEY_IN_FULLSCREEN,
+                    checkbox(temp.suspend_hotkey_in_fullscreen)
+                        .on_toggle(OrchestratorMessage::UpdateSuspendHotkeyInFullscreen)
+                        .into(),
+                ),
+            ]
             .spacing(12),
         )
     }
@@ -234,17 +384,406 @@ impl AppOrchestrator {
         self.render_settings_section(
             global_constants::SETTINGS_SECTION_APPEARANCE_TITLE,
             global_constants::SETTINGS_SECTION_APPEARANCE_ICON,
-            column![self.render_setting_row(
-                global_constants::SETTINGS_LABEL_THEME,
-                global_constants::SETTINGS_DESCRIPTION_THEME,
-                pick_list(
-                    vec![ThemeMode::Dark, ThemeMode::Light],
-                    Some(temp.theme_mode.clone()),
-                    OrchestratorMessage::UpdateTheme,
-                )
-                .padding(12)
-                .into(),
-            ),]
+            column![
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_THEME,
+                    global_constants::SETTINGS_DESCRIPTION_THEME,
+                    pick_list(
+                        vec![ThemeMode::System, ThemeMode::Dark, ThemeMode::Light],
+                        Some(temp.theme_mode.clone()),
+                        OrchestratorMessage::UpdateTheme,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_OCR_ENGINE,
+                    global_constants::SETTINGS_DESCRIPTION_OCR_ENGINE,
+                    pick_list(
+                        vec![
+                            OcrEngine::Auto,
+                            OcrEngine::Tesseract,
+                            OcrEngine::Neural,
+                            OcrEngine::Onnx,
+                            OcrEngine::Ensemble,
+                        ],
+                        Some(temp.ocr_engine.clone()),
+                        OrchestratorMessage::UpdateOcrEngine,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_OCR_LANGUAGE,
+                    global_constants::SETTINGS_DESCRIPTION_OCR_LANGUAGE,
+                    pick_list(
+                        temp.ocr_engine.supported_languages(),
+                        OcrLanguage::from_code(&temp.language),
+                        OrchestratorMessage::UpdateOcrLanguage,
+                    )
+                    .placeholder(temp.language.clone())
+                    .padding(12)
+                    .into(),
+                ),
+            ]
+            .spacing(12),
+        )
+    }
+
+    fn render_behavior_settings_section(
+        &self,
+        temp: &UserSettings,
+    ) -> Element<'_, OrchestratorMessage> {
+        use iced::widget::{checkbox, pick_list, slider, text_input};
+
+        self.render_settings_section(
+            global_constants::SETTINGS_SECTION_BEHAVIOR_TITLE,
+            global_constants::SETTINGS_SECTION_BEHAVIOR_ICON,
+            column![
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CONFIRM_QUIT,
+                    global_constants::SETTINGS_DESCRIPTION_CONFIRM_QUIT,
+                    checkbox(temp.confirm_quit_with_unsaved_annotations)
+                        .on_toggle(OrchestratorMessage::UpdateConfirmQuitWithUnsavedAnnotations)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_TYPE_OUT_TEXT,
+                    global_constants::SETTINGS_DESCRIPTION_TYPE_OUT_TEXT,
+                    checkbox(temp.enable_type_out_text)
+                        .on_toggle(OrchestratorMessage::UpdateEnableTypeOutText)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_AUTO_RUN_OCR,
+                    global_constants::SETTINGS_DESCRIPTION_AUTO_RUN_OCR,
+                    checkbox(temp.auto_run_ocr)
+                        .on_toggle(OrchestratorMessage::UpdateAutoRunOcr)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_AUTO_COPY_ON_OCR,
+                    global_constants::SETTINGS_DESCRIPTION_AUTO_COPY_ON_OCR,
+                    checkbox(temp.auto_copy_on_ocr)
+                        .on_toggle(OrchestratorMessage::UpdateAutoCopyOnOcr)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_QUIT_ON_CLOSE,
+                    global_constants::SETTINGS_DESCRIPTION_QUIT_ON_CLOSE,
+                    checkbox(temp.quit_on_main_window_close)
+                        .on_toggle(OrchestratorMessage::UpdateQuitOnMainWindowClose)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_AUTO_CLOSE_AFTER_ACTION,
+                    global_constants::SETTINGS_DESCRIPTION_AUTO_CLOSE_AFTER_ACTION,
+                    checkbox(temp.auto_close_after_action)
+                        .on_toggle(OrchestratorMessage::UpdateAutoCloseAfterAction)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_MINIMIZE_ON_CAPTURE,
+                    global_constants::SETTINGS_DESCRIPTION_MINIMIZE_ON_CAPTURE,
+                    checkbox(temp.minimize_on_capture)
+                        .on_toggle(OrchestratorMessage::UpdateMinimizeOnCapture)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_PIN_RESULT_WINDOW_ON_TOP,
+                    global_constants::SETTINGS_DESCRIPTION_PIN_RESULT_WINDOW_ON_TOP,
+                    checkbox(temp.pin_result_window_on_top)
+                        .on_toggle(OrchestratorMessage::UpdatePinResultWindowOnTop)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SHOW_MAGNIFIER,
+                    global_constants::SETTINGS_DESCRIPTION_SHOW_MAGNIFIER,
+                    checkbox(temp.show_magnifier)
+                        .on_toggle(OrchestratorMessage::UpdateShowMagnifier)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_OPEN_SEARCH_IN_PRIVATE_WINDOW,
+                    global_constants::SETTINGS_DESCRIPTION_OPEN_SEARCH_IN_PRIVATE_WINDOW,
+                    checkbox(temp.open_search_in_private_window)
+                        .on_toggle(OrchestratorMessage::UpdateOpenSearchInPrivateWindow)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SCREENSHOT_SAVE_LOCATION,
+                    global_constants::SETTINGS_DESCRIPTION_SCREENSHOT_SAVE_LOCATION,
+                    text_input("", &temp.screenshot_save_location)
+                        .on_input(OrchestratorMessage::UpdateScreenshotSaveLocation)
+                        .padding(12)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_AUTO_SAVE_CAPTURES,
+                    global_constants::SETTINGS_DESCRIPTION_AUTO_SAVE_CAPTURES,
+                    checkbox(temp.auto_save_captures)
+                        .on_toggle(OrchestratorMessage::UpdateAutoSaveCaptures)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SAVE_FORMAT,
+                    global_constants::SETTINGS_DESCRIPTION_SAVE_FORMAT,
+                    pick_list(
+                        crate::core::models::ImageSaveFormat::ALL.to_vec(),
+                        Some(temp.save_format),
+                        OrchestratorMessage::UpdateSaveFormat,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_JPEG_QUALITY,
+                    global_constants::SETTINGS_DESCRIPTION_JPEG_QUALITY,
+                    row![
+                        slider(
+                            global_constants::MIN_JPEG_QUALITY..=global_constants::MAX_JPEG_QUALITY,
+                            temp.jpeg_quality,
+                            OrchestratorMessage::UpdateJpegQuality,
+                        )
+                        .step(global_constants::JPEG_QUALITY_STEP),
+                        text(format!("{}%", temp.jpeg_quality)).size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_AUTO_SAVE_LOCATION,
+                    global_constants::SETTINGS_DESCRIPTION_AUTO_SAVE_LOCATION,
+                    text_input("", &temp.auto_save_location)
+                        .on_input(OrchestratorMessage::UpdateAutoSaveLocation)
+                        .padding(12)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_TRANSLATION_TARGET_LANGUAGE,
+                    global_constants::SETTINGS_DESCRIPTION_TRANSLATION_TARGET_LANGUAGE,
+                    text_input("", &temp.translation_target_language)
+                        .on_input(OrchestratorMessage::UpdateTranslationTargetLanguage)
+                        .padding(12)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_TRANSLATION_API_KEY,
+                    global_constants::SETTINGS_DESCRIPTION_TRANSLATION_API_KEY,
+                    text_input("", &temp.translation_api_key)
+                        .on_input(OrchestratorMessage::UpdateTranslationApiKey)
+                        .secure(true)
+                        .padding(12)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CAPTURE_MONITOR,
+                    global_constants::SETTINGS_DESCRIPTION_CAPTURE_MONITOR,
+                    pick_list(
+                        vec![CaptureMonitor::UnderCursor, CaptureMonitor::Primary],
+                        Some(temp.capture_monitor.clone()),
+                        OrchestratorMessage::UpdateCaptureMonitor,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_INCLUDE_ANNOTATIONS_IN_SEARCH,
+                    global_constants::SETTINGS_DESCRIPTION_INCLUDE_ANNOTATIONS_IN_SEARCH,
+                    checkbox(temp.include_annotations_in_search)
+                        .on_toggle(OrchestratorMessage::UpdateIncludeAnnotationsInSearch)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_LOG_LEVEL,
+                    global_constants::SETTINGS_DESCRIPTION_LOG_LEVEL,
+                    pick_list(
+                        crate::core::models::LogLevel::ALL.to_vec(),
+                        Some(temp.log_level),
+                        OrchestratorMessage::UpdateLogLevel,
+                    )
+                    .padding(12)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CLEAR_CACHE,
+                    global_constants::SETTINGS_DESCRIPTION_CLEAR_CACHE,
+                    button(text(global_constants::SETTINGS_CLEAR_CACHE_BUTTON_LABEL).size(14))
+                        .padding([10, 16])
+                        .style(|theme, status| app_theme::secondary_button_style(theme, status))
+                        .on_press(OrchestratorMessage::ClearCache)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SHOW_HELP_HINT,
+                    global_constants::SETTINGS_DESCRIPTION_SHOW_HELP_HINT,
+                    checkbox(temp.show_help_hint)
+                        .on_toggle(OrchestratorMessage::UpdateShowHelpHint)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_PLAY_CAPTURE_SOUND,
+                    global_constants::SETTINGS_DESCRIPTION_PLAY_CAPTURE_SOUND,
+                    checkbox(temp.play_capture_sound)
+                        .on_toggle(OrchestratorMessage::UpdatePlayCaptureSound)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_PLAY_SUCCESS_SOUND,
+                    global_constants::SETTINGS_DESCRIPTION_PLAY_SUCCESS_SOUND,
+                    checkbox(temp.play_success_sound)
+                        .on_toggle(OrchestratorMessage::UpdatePlaySuccessSound)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_PLAY_FAILURE_SOUND,
+                    global_constants::SETTINGS_DESCRIPTION_PLAY_FAILURE_SOUND,
+                    checkbox(temp.play_failure_sound)
+                        .on_toggle(OrchestratorMessage::UpdatePlayFailureSound)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SHOW_CAPTURE_FLASH,
+                    global_constants::SETTINGS_DESCRIPTION_SHOW_CAPTURE_FLASH,
+                    checkbox(temp.show_capture_flash)
+                        .on_toggle(OrchestratorMessage::UpdateShowCaptureFlash)
+                        .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CAPTURE_DELAY,
+                    global_constants::SETTINGS_DESCRIPTION_CAPTURE_DELAY,
+                    row![
+                        slider(
+                            0..=global_constants::MAX_CAPTURE_DELAY_MS,
+                            temp.capture_delay_ms,
+                            OrchestratorMessage::UpdateCaptureDelayMs,
+                        )
+                        .step(global_constants::CAPTURE_DELAY_STEP_MS),
+                        text(format!(
+                            "{} {}",
+                            temp.capture_delay_ms,
+                            global_constants::SETTINGS_CAPTURE_DELAY_UNIT
+                        ))
+                        .size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CAPTURE_REGION_WIDTH,
+                    global_constants::SETTINGS_DESCRIPTION_CAPTURE_REGION_WIDTH,
+                    row![
+                        slider(
+                            0..=global_constants::MAX_CAPTURE_REGION_SIZE,
+                            temp.capture_region_width,
+                            OrchestratorMessage::UpdateCaptureRegionWidth,
+                        )
+                        .step(global_constants::CAPTURE_REGION_SIZE_STEP),
+                        text(format_capture_region_size(temp.capture_region_width)).size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_CAPTURE_REGION_HEIGHT,
+                    global_constants::SETTINGS_DESCRIPTION_CAPTURE_REGION_HEIGHT,
+                    row![
+                        slider(
+                            0..=global_constants::MAX_CAPTURE_REGION_SIZE,
+                            temp.capture_region_height,
+                            OrchestratorMessage::UpdateCaptureRegionHeight,
+                        )
+                        .step(global_constants::CAPTURE_REGION_SIZE_STEP),
+                        text(format_capture_region_size(temp.capture_region_height)).size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_SCROLL_CAPTURE_DELAY,
+                    global_constants::SETTINGS_DESCRIPTION_SCROLL_CAPTURE_DELAY,
+                    row![
+                        slider(
+                            0..=global_constants::MAX_SCROLL_CAPTURE_DELAY_MS,
+                            temp.scroll_capture_delay_ms,
+                            OrchestratorMessage::UpdateScrollCaptureDelayMs,
+                        )
+                        .step(global_constants::SCROLL_CAPTURE_DELAY_STEP_MS),
+                        text(format!(
+                            "{} {}",
+                            temp.scroll_capture_delay_ms,
+                            global_constants::SETTINGS_CAPTURE_DELAY_UNIT
+                        ))
+                        .size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_MAX_HISTORY_ENTRIES,
+                    global_constants::SETTINGS_DESCRIPTION_MAX_HISTORY_ENTRIES,
+                    row![
+                        slider(
+                            0..=global_constants::MAX_HISTORY_ENTRIES_LIMIT,
+                            temp.max_history_entries,
+                            OrchestratorMessage::UpdateMaxHistoryEntries,
+                        )
+                        .step(global_constants::HISTORY_ENTRIES_STEP),
+                        text(format!(
+                            "{} {}",
+                            temp.max_history_entries,
+                            global_constants::SETTINGS_HISTORY_ENTRIES_UNIT
+                        ))
+                        .size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_OCR_TIMEOUT,
+                    global_constants::SETTINGS_DESCRIPTION_OCR_TIMEOUT,
+                    row![
+                        slider(
+                            global_constants::MIN_OCR_TIMEOUT_SECS
+                                ..=global_constants::MAX_OCR_TIMEOUT_SECS,
+                            temp.ocr_timeout_secs,
+                            OrchestratorMessage::UpdateOcrTimeoutSecs,
+                        )
+                        .step(global_constants::OCR_TIMEOUT_STEP_SECS),
+                        text(format!(
+                            "{} {}",
+                            temp.ocr_timeout_secs,
+                            global_constants::SETTINGS_OCR_TIMEOUT_UNIT
+                        ))
+                        .size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+                self.render_setting_row(
+                    global_constants::SETTINGS_LABEL_MIN_OCR_CONFIDENCE,
+                    global_constants::SETTINGS_DESCRIPTION_MIN_OCR_CONFIDENCE,
+                    row![
+                        slider(
+                            0.0..=1.0,
+                            temp.min_ocr_confidence,
+                            OrchestratorMessage::UpdateMinOcrConfidence,
+                        )
+                        .step(global_constants::MIN_OCR_CONFIDENCE_STEP),
+                        text(format!("{:.0}%", temp.min_ocr_confidence * 100.0)).size(14),
+                    ]
+                    .spacing(12)
+                    .align_y(Alignment::Center)
+                    .into(),
+                ),
+            ]
             .spacing(12),
         )
     }
@@ -317,3 +856,15 @@ impl AppOrchestrator {
             .into()
     }
 }
+
+fn format_capture_region_size(size: u32) -> String {
+    if size == 0 {
+        return global_constants::SETTINGS_CAPTURE_REGION_SIZE_OFF.to_string();
+    }
+
+    format!(
+        "{} {}",
+        size,
+        global_constants::SETTINGS_CAPTURE_REGION_SIZE_UNIT
+    )
+}
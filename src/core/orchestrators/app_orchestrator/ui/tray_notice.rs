@@ -0,0 +1,47 @@
+use super::*;
+use iced::widget::{button, column, container, text, Space};
+use iced::{Alignment, Background, Element, Length};
+
+impl AppOrchestrator {
+    pub fn render_tray_notice_window(&self) -> Element<'_, OrchestratorMessage> {
+        let theme = self.get_app_theme();
+
+        let header = column![
+            text(global_constants::TRAY_NOTICE_ICON).size(28),
+            text(global_constants::TRAY_NOTICE_TITLE).size(18),
+        ]
+        .spacing(6)
+        .align_x(Alignment::Center);
+
+        let dismiss_btn = button(text(global_constants::TRAY_NOTICE_DISMISS_LABEL).size(14))
+            .padding([8, 24])
+            .style(|theme, status| app_theme::primary_button_style(theme, status))
+            .on_press(OrchestratorMessage::DismissTrayNotice);
+
+        let content = column![
+            header,
+            Space::new().height(Length::Fixed(10.0)),
+            text(global_constants::TRAY_NOTICE_MESSAGE)
+                .size(13)
+                .align_x(iced::widget::text::Alignment::Center),
+            Space::new().height(Length::Fixed(14.0)),
+            dismiss_btn,
+        ]
+        .padding(20)
+        .align_x(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .style(move |_theme| {
+                let palette = theme.palette();
+                iced::widget::container::Style {
+                    background: Some(Background::Color(palette.background)),
+                    text_color: Some(palette.text),
+                    ..Default::default()
+                }
+            })
+            .into()
+    }
+}
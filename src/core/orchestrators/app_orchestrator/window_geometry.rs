@@ -0,0 +1,188 @@
+use super::*;
+use crate::core::models::WindowGeometry;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct TrackedWindowGeometry {
+    position: Option<Point>,
+    size: Option<Size>,
+}
+
+impl TrackedWindowGeometry {
+    fn to_window_geometry(self) -> Option<WindowGeometry> {
+        let position = self.position?;
+        let size = self.size?;
+
+        Some(WindowGeometry {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        })
+    }
+}
+
+impl AppOrchestrator {
+    pub(super) fn handle_window_geometry_changed(
+        &mut self,
+        id: Id,
+        position: Option<Point>,
+        size: Option<Size>,
+    ) -> Task<OrchestratorMessage> {
+        let is_tracked_window = Some(id) == self.main_window_id
+            || matches!(self.windows.get(&id), Some(AppWindow::InteractiveOcr(_)));
+        if !is_tracked_window {
+            return Task::none();
+        }
+
+        let tracked_geometry = self.window_geometries.entry(id).or_default();
+        if position.is_some() {
+            tracked_geometry.position = position;
+        }
+        if size.is_some() {
+            tracked_geometry.size = size;
+        }
+
+        Task::none()
+    }
+
+    pub(super) fn main_window_in_capture_path(
+        &self,
+        capture_point: Option<(i32, i32)>,
+    ) -> Option<Id> {
+        let main_window_id = self.main_window_id?;
+        let main_window_geometry = self
+            .window_geometries
+            .get(&main_window_id)
+            .and_then(|tracked_geometry| tracked_geometry.to_window_geometry());
+        let (Some(geometry), Some((capture_x, capture_y))) = (main_window_geometry, capture_point)
+        else {
+            return Some(main_window_id);
+        };
+
+        let monitors = self.screen_capturer.list_monitors().unwrap_or_default();
+        let Some(capture_monitor) = select_monitor_for_point(&monitors, capture_x, capture_y)
+        else {
+            return Some(main_window_id);
+        };
+
+        if geometry.is_visible_on_monitor(&capture_monitor, &monitors) {
+            return Some(main_window_id);
+        }
+
+        self.log_info_event(
+            "capture_main_window_not_on_target_monitor",
+            serde_json::json!({
+                "capture_x": capture_x,
+                "capture_y": capture_y,
+                "window_x": geometry.x,
+                "window_y": geometry.y,
+            }),
+        );
+        None
+    }
+
+    pub(super) fn remember_closed_window_geometry(&mut self, id: Id) {
+        let Some(tracked_geometry) = self.window_geometries.remove(&id) else {
+            return;
+        };
+
+        if self.store_window_geometry(id, tracked_geometry) {
+            self.save_window_geometries();
+        }
+    }
+
+    pub(super) fn remember_open_window_geometries(&mut self) {
+        let tracked_geometries = std::mem::take(&mut self.window_geometries);
+        let has_stored_geometry = tracked_geometries.into_iter().fold(
+            false,
+            |has_stored_geometry, (id, tracked_geometry)| {
+                self.store_window_geometry(id, tracked_geometry) || has_stored_geometry
+            },
+        );
+
+        if has_stored_geometry {
+            self.save_window_geometries();
+        }
+    }
+
+    fn store_window_geometry(&mut self, id: Id, tracked_geometry: TrackedWindowGeometry) -> bool {
+        let Some(geometry) = tracked_geometry.to_window_geometry() else {
+            return false;
+        };
+
+        let is_main_window = Some(id) == self.main_window_id;
+        let window_role = if is_main_window { "main" } else { "ocr" };
+        self.log_info_event(
+            "window_geometry_remembered",
+            serde_json::json!({
+                "window_role": window_role,
+                "x": geometry.x,
+                "y": geometry.y,
+                "width": geometry.width,
+                "height": geometry.height,
+            }),
+        );
+
+        if is_main_window {
+            self.settings.main_window_geometry = Some(geometry);
+            let _ = self.update_settings_draft(|settings| {
+                settings.main_window_geometry = Some(geometry);
+            });
+        } else {
+            self.settings.ocr_window_geometry = Some(geometry);
+            let _ = self.update_settings_draft(|settings| {
+                settings.ocr_window_geometry = Some(geometry);
+            });
+        }
+
+        true
+    }
+
+    fn save_window_geometries(&self) {
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "window_geometry_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+    }
+
+    pub(super) fn restorable_window_geometry(
+        &self,
+        saved_geometry: Option<WindowGeometry>,
+    ) -> Option<WindowGeometry> {
+        let geometry = saved_geometry?;
+        let monitors = self.screen_capturer.list_monitors().unwrap_or_default();
+        if geometry.is_visible_on_monitors(&monitors) {
+            return Some(geometry);
+        }
+
+        self.log_info_event(
+            "saved_window_geometry_off_screen",
+            serde_json::json!({
+                "x": geometry.x,
+                "y": geometry.y,
+                "monitor_count": monitors.len(),
+            }),
+        );
+        None
+    }
+}
+
+pub(super) fn window_position_for_geometry(geometry: Option<WindowGeometry>) -> window::Position {
+    cascaded_window_position(geometry, 0)
+}
+
+pub(super) fn cascaded_window_position(
+    geometry: Option<WindowGeometry>,
+    open_window_count: usize,
+) -> window::Position {
+    match geometry {
+        Some(geometry) => {
+            let offset =
+                open_window_count as f32 * global_constants::RESULT_WINDOW_CASCADE_OFFSET_PX;
+            window::Position::Specific(Point::new(geometry.x + offset, geometry.y + offset))
+        }
+        None => window::Position::Centered,
+    }
+}
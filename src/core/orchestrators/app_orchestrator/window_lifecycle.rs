@@ -1,3 +1,4 @@
+use super::window_geometry::window_position_for_geometry;
 use super::*;
 use crate::adapters::macos_app_behavior;
 
@@ -38,9 +39,10 @@ impl AppOrchestrator {
             );
         }
 
+        let restored_geometry = self.restorable_window_geometry(self.settings.main_window_geometry);
         let (id, task) = window::open(window::Settings {
             size: Size::new(700.0, 800.0),
-            position: window::Position::Centered,
+            position: window_position_for_geometry(restored_geometry),
             resizable: false,
             ..Default::default()
         });
@@ -63,6 +65,7 @@ impl AppOrchestrator {
             "window_closed",
             serde_json::json!({"window_id": format!("{:?}", id)}),
         );
+        self.remember_closed_window_geometry(id);
 
         if Some(id) == self.hidden_window_id {
             self.log_error_event(
@@ -78,6 +81,28 @@ impl AppOrchestrator {
             self.log_info_event("main_window_closed", serde_json::json!({}));
             self.windows.remove(&id);
             self.main_window_id = None;
+            self.is_main_window_minimized_for_capture = false;
+
+            return match resolve_main_window_close_action(
+                self.settings.quit_on_main_window_close,
+                self.settings.close_to_tray_notice_shown,
+            ) {
+                MainWindowCloseAction::Quit => self.handle_quit_requested(),
+                MainWindowCloseAction::ShowTrayNotice => self.show_close_to_tray_notice(),
+                MainWindowCloseAction::KeepRunning => Task::none(),
+            };
+        }
+
+        if let Some(source_window_id) = self.take_recrop_source(id) {
+            self.log_info_event("recrop_overlay_dismissed", serde_json::json!({}));
+            self.windows.remove(&id);
+            return self.restore_recrop_source(source_window_id);
+        }
+
+        if Some(id) == self.tray_notice_window_id {
+            self.log_info_event("tray_notice_window_closed", serde_json::json!({}));
+            self.windows.remove(&id);
+            self.tray_notice_window_id = None;
             return Task::none();
         }
 
@@ -88,6 +113,13 @@ impl AppOrchestrator {
             return Task::none();
         }
 
+        if Some(id) == self.quit_confirmation_window_id {
+            self.log_info_event("quit_confirmation_window_closed", serde_json::json!({}));
+            self.windows.remove(&id);
+            self.quit_confirmation_window_id = None;
+            return Task::none();
+        }
+
         if Some(id) == self.window_picker_window_id {
             self.log_info_event("window_picker_closed", serde_json::json!({}));
             self.windows.remove(&id);
@@ -95,8 +127,16 @@ impl AppOrchestrator {
             return Task::none();
         }
 
+        if Some(id) == self.capture_history_window_id {
+            self.log_info_event("capture_history_closed", serde_json::json!({}));
+            self.windows.remove(&id);
+            self.capture_history_window_id = None;
+            return Task::none();
+        }
+
         let was_ocr_window = matches!(self.windows.get(&id), Some(AppWindow::InteractiveOcr(_)));
         self.windows.remove(&id);
+        self.abort_window_tasks(id);
         if Some(id) == self.settings_window_id {
             self.settings_window_id = None;
             self.discard_settings_edit();
@@ -105,11 +145,14 @@ impl AppOrchestrator {
             "window_removed_from_tracking",
             serde_json::json!({"remaining_windows": self.windows.len()}),
         );
-        self.status = global_constants::STATUS_READY.to_string();
+        if self.search_task_handles.is_empty() {
+            self.status = global_constants::STATUS_READY.to_string();
+        }
 
-        if !was_ocr_window {
+        if !was_ocr_window || !self.is_main_window_minimized_for_capture {
             return Task::none();
         }
+        self.is_main_window_minimized_for_capture = false;
 
         let Some(main_id) = self.main_window_id else {
             return Task::none();
@@ -181,23 +224,171 @@ impl AppOrchestrator {
         Task::batch(focus_tasks)
     }
 
+    pub(super) fn handle_initialization_complete(&mut self) -> Task<OrchestratorMessage> {
+        if self.is_initialized {
+            return Task::none();
+        }
+
+        self.is_initialized = true;
+        let pending_tray_events = std::mem::take(&mut self.pending_tray_events);
+        self.log_info_event(
+            "initialization_complete",
+            serde_json::json!({"queued_tray_events": pending_tray_events.len()}),
+        );
+
+        let mut tasks = vec![self.refresh_recent_captures()];
+        tasks.extend(
+            pending_tray_events
+                .into_iter()
+                .map(|event| self.handle_tray_event(event)),
+        );
+        Task::batch(tasks)
+    }
+
     pub(super) fn handle_tray_event(&mut self, event: TrayEvent) -> Task<OrchestratorMessage> {
         self.log_info_event(
             "tray_event_received",
             serde_json::json!({"event": format!("{:?}", event)}),
         );
 
+        if event == TrayEvent::PointerEntered {
+            return self.handle_tray_pointer_entered();
+        }
+
+        if !self.is_initialized {
+            self.log_info_event(
+                "tray_event_queued_until_initialized",
+                serde_json::json!({"event": format!("{:?}", event)}),
+            );
+            if !self.pending_tray_events.contains(&event) {
+                self.pending_tray_events.push(event);
+            }
+            return Task::none();
+        }
+
         match event {
             TrayEvent::ShowWindow => self.handle_open_main_window(),
+            TrayEvent::CaptureNow => self.handle_capture_screen(),
+            TrayEvent::OpenRecent(entry_id) => self.reopen_capture_history_entry(entry_id),
             TrayEvent::SelectWindow => self.handle_open_window_picker(),
-            TrayEvent::OpenSettings => self.handle_open_settings(),
-            TrayEvent::Quit => {
-                self.log_info_event("quit_requested_from_tray", serde_json::json!({}));
-                iced::exit()
+            TrayEvent::PointerEntered => self.handle_tray_pointer_entered(),
+            TrayEvent::CaptureActiveWindow => {
+                let recorded_window_id = self.tray_foreground_window_id.take();
+                self.handle_capture_active_window(recorded_window_id)
             }
+            TrayEvent::CaptureAllMonitors => self.handle_capture_all_monitors(),
+            TrayEvent::OcrClipboardImage => self.handle_ocr_clipboard_image(),
+            TrayEvent::ToggleHotkey => self.handle_toggle_hotkey(),
+            TrayEvent::OpenHistory => self.handle_open_capture_history(),
+            TrayEvent::OpenSettings => self.handle_open_settings(),
+            TrayEvent::Quit => self.handle_quit_requested(),
         }
     }
 
+    pub(super) fn handle_quit_requested(&mut self) -> Task<OrchestratorMessage> {
+        let unsaved_annotation_counts = self.collect_unsaved_annotation_counts();
+        self.log_info_event(
+            "quit_requested_from_tray",
+            serde_json::json!({"windows_with_unsaved_annotations": unsaved_annotation_counts.len()}),
+        );
+
+        if !self.settings.confirm_quit_with_unsaved_annotations
+            || unsaved_annotation_counts.is_empty()
+        {
+            self.remember_open_window_geometries();
+            return iced::exit();
+        }
+
+        if let Some(id) = self.quit_confirmation_window_id {
+            return window::gain_focus(id);
+        }
+
+        let (id, task) = window::open(window::Settings {
+            size: Size::new(420.0, 320.0),
+            position: window::Position::Centered,
+            resizable: false,
+            level: window::Level::AlwaysOnTop,
+            ..Default::default()
+        });
+
+        self.quit_confirmation_window_id = Some(id);
+        self.windows.insert(id, AppWindow::QuitConfirmation);
+        macos_app_behavior::macos::activate_app_ignoring_other_apps();
+        self.log_info_event(
+            "quit_confirmation_window_created",
+            serde_json::json!({"window_id": format!("{:?}", id)}),
+        );
+
+        task.discard().chain(window::gain_focus(id))
+    }
+
+    fn show_close_to_tray_notice(&mut self) -> Task<OrchestratorMessage> {
+        self.settings.close_to_tray_notice_shown = true;
+        if let Err(save_error) = self.settings.save() {
+            self.log_error_event(
+                "close_to_tray_notice_setting_save_failed",
+                serde_json::json!({"error": save_error.to_string()}),
+            );
+        }
+
+        if let Some(id) = self.tray_notice_window_id {
+            return window::gain_focus(id);
+        }
+
+        let (id, task) = window::open(window::Settings {
+            size: Size::new(380.0, 200.0),
+            position: window::Position::Centered,
+            resizable: false,
+            level: window::Level::AlwaysOnTop,
+            ..Default::default()
+        });
+
+        self.tray_notice_window_id = Some(id);
+        self.windows.insert(id, AppWindow::TrayNotice);
+        self.log_info_event(
+            "tray_notice_window_created",
+            serde_json::json!({"window_id": format!("{:?}", id)}),
+        );
+
+        Task::batch(vec![
+            task.discard(),
+            Task::future(async {
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    global_constants::TRAY_NOTICE_AUTO_DISMISS_MS,
+                ))
+                .await;
+                OrchestratorMessage::DismissTrayNotice
+            }),
+        ])
+    }
+
+    pub(super) fn handle_dismiss_tray_notice(&mut self) -> Task<OrchestratorMessage> {
+        match self.tray_notice_window_id {
+            Some(id) => window::close(id),
+            None => Task::none(),
+        }
+    }
+
+    pub(super) fn handle_cancel_quit(&mut self) -> Task<OrchestratorMessage> {
+        self.log_info_event("quit_cancelled", serde_json::json!({}));
+
+        match self.quit_confirmation_window_id {
+            Some(id) => window::close(id),
+            None => Task::none(),
+        }
+    }
+
+    pub(super) fn collect_unsaved_annotation_counts(&self) -> Vec<usize> {
+        self.windows
+            .values()
+            .filter_map(|window| match window {
+                AppWindow::InteractiveOcr(view) => Some(view.get_unsaved_annotation_count()),
+                _ => None,
+            })
+            .filter(|count| *count > 0)
+            .collect()
+    }
+
     pub(super) fn handle_hide_main_window(&mut self) -> Task<OrchestratorMessage> {
         self.log_info_event("main_window_hiding", serde_json::json!({}));
 
@@ -208,3 +399,23 @@ impl AppOrchestrator {
         Task::none()
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum MainWindowCloseAction {
+    Quit,
+    ShowTrayNotice,
+    KeepRunning,
+}
+
+pub(super) fn resolve_main_window_close_action(
+    quit_on_close: bool,
+    tray_notice_shown: bool,
+) -> MainWindowCloseAction {
+    if quit_on_close {
+        MainWindowCloseAction::Quit
+    } else if !tray_notice_shown {
+        MainWindowCloseAction::ShowTrayNotice
+    } else {
+        MainWindowCloseAction::KeepRunning
+    }
+}
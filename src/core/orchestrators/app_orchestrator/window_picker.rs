@@ -255,10 +255,147 @@ impl AppOrchestrator {
         })
     }
 
+    pub(super) fn handle_active_window_hotkey(&mut self) -> Task<OrchestratorMessage> {
+        if self.is_recording_hotkey || !self.is_hotkey_enabled {
+            self.log_info_event(
+                "active_window_hotkey_ignored",
+                serde_json::json!({
+                    "is_recording_hotkey": self.is_recording_hotkey,
+                    "is_hotkey_enabled": self.is_hotkey_enabled,
+                }),
+            );
+            return Task::none();
+        }
+
+        self.handle_capture_active_window(None)
+    }
+
+    pub(super) fn handle_tray_pointer_entered(&mut self) -> Task<OrchestratorMessage> {
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+
+        Task::future(async move {
+            let focused_window_id =
+                tokio::task::spawn_blocking(move || screen_capturer.find_focused_window_id())
+                    .await
+                    .map_err(|join_error| anyhow::anyhow!(join_error.to_string()))
+                    .and_then(|result| result)
+                    .unwrap_or_else(|error| {
+                        log::warn!(
+                            "[ORCHESTRATOR] Could not read the focused window: {:#}",
+                            error
+                        );
+                        None
+                    });
+            OrchestratorMessage::TrayForegroundWindowRecorded(focused_window_id)
+        })
+    }
+
+    pub(super) fn handle_tray_foreground_window_recorded(&mut self, window_id: Option<u32>) {
+        self.log_info_event(
+            "tray_foreground_window_recorded",
+            serde_json::json!({"window_id": window_id}),
+        );
+        self.tray_foreground_window_id = window_id;
+    }
+
+    pub(super) fn handle_capture_active_window(
+        &mut self,
+        recorded_window_id: Option<u32>,
+    ) -> Task<OrchestratorMessage> {
+        self.log_info_event(
+            "active_window_capture_started",
+            serde_json::json!({"recorded_window_id": recorded_window_id}),
+        );
+        self.status = global_constants::STATUS_CAPTURING_SCREEN.to_string();
+
+        let screen_capturer = Arc::clone(&self.screen_capturer);
+        let cursor_region = self.mouse_provider.get_current_mouse_position();
+        let correlation_id = self.current_correlation_id();
+
+        Task::future(async move {
+            let window_capturer = Arc::clone(&screen_capturer);
+            let window_capture = tokio::task::spawn_blocking(move || match recorded_window_id {
+                Some(window_id) => window_capturer.capture_window_by_id(window_id).map(Some),
+                None => window_capturer.capture_active_window(),
+            })
+            .await
+            .map_err(|join_error| anyhow::anyhow!(join_error.to_string()))
+            .and_then(|result| result);
+
+            match window_capture {
+                Ok(Some(capture_buffer)) => {
+                    AppOrchestrator::log_info_event_for_correlation(
+                        correlation_id,
+                        "active_window_capture_completed",
+                        serde_json::json!({
+                            "width": capture_buffer.width,
+                            "height": capture_buffer.height,
+                        }),
+                    );
+                    return OrchestratorMessage::WindowCaptureComplete(capture_buffer);
+                }
+                Ok(None) => AppOrchestrator::log_info_event_for_correlation(
+                    correlation_id.clone(),
+                    "active_window_not_found",
+                    serde_json::json!({}),
+                ),
+                Err(error) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id,
+                        "active_window_capture_failed",
+                        serde_json::json!({"error": error.to_string()}),
+                    );
+                    return OrchestratorMessage::WindowCaptureError(error.to_string());
+                }
+            }
+
+            let fallback = tokio::task::spawn_blocking(move || {
+                let region = match cursor_region {
+                    Ok(region) => region,
+                    Err(_) => screen_capturer.get_primary_monitor_origin()?,
+                };
+                screen_capturer.capture_screen_at_region(&region)
+            })
+            .await
+            .map_err(|join_error| anyhow::anyhow!(join_error.to_string()))
+            .and_then(|result| result);
+            match fallback {
+                Ok(capture_buffer) => {
+                    OrchestratorMessage::ActiveWindowCaptureFallback(capture_buffer)
+                }
+                Err(error) => {
+                    AppOrchestrator::log_error_event_for_correlation(
+                        correlation_id,
+                        "active_window_fallback_capture_failed",
+                        serde_json::json!({"error": error.to_string()}),
+                    );
+                    OrchestratorMessage::CaptureError(error.to_string())
+                }
+            }
+        })
+    }
+
+    pub(super) fn handle_active_window_capture_fallback(
+        &mut self,
+        capture_buffer: CaptureBuffer,
+    ) -> Task<OrchestratorMessage> {
+        let task = self.handle_window_capture_complete(capture_buffer);
+        self.status = global_constants::STATUS_ACTIVE_WINDOW_FALLBACK.to_string();
+        task
+    }
+
     pub(super) fn handle_window_capture_complete(
         &mut self,
         capture_buffer: CaptureBuffer,
     ) -> Task<OrchestratorMessage> {
+        let (_, task) = self.open_window_capture(capture_buffer);
+        task
+    }
+
+    pub(super) fn open_window_capture(
+        &mut self,
+        capture_buffer: CaptureBuffer,
+    ) -> (Option<Id>, Task<OrchestratorMessage>) {
         log::info!(
             "[ORCHESTRATOR] Processing window capture: {}x{}",
             capture_buffer.width,
@@ -272,9 +409,6 @@ impl AppOrchestrator {
             height: capture_buffer.height as f32,
         };
 
-        self.update(OrchestratorMessage::ShowCroppedImage(
-            capture_buffer,
-            selection_rect,
-        ))
+        self.open_cropped_image_window(capture_buffer, selection_rect)
     }
 }
@@ -2,6 +2,16 @@
 
 pub const APPLICATION_NAME: &str = "Circle to Search - Desktop";
 pub const APPLICATION_TITLE: &str = "Circle to Search";
+pub const WINDOW_TITLE_SEPARATOR: &str = " — ";
+pub const WINDOW_TITLE_CAPTURE: &str = "Capture";
+pub const WINDOW_TITLE_RESULT: &str = "Result";
+pub const WINDOW_TITLE_SETTINGS: &str = "Settings";
+pub const WINDOW_TITLE_ONBOARDING: &str = "Onboarding";
+pub const WINDOW_TITLE_WINDOW_PICKER: &str = "Select Window";
+pub const WINDOW_TITLE_CAPTURE_HISTORY: &str = "Capture History";
+pub const WINDOW_TITLE_QUIT_CONFIRMATION: &str = "Quit";
+pub const WINDOW_TITLE_TRAY_NOTICE: &str = "Running in Tray";
+pub const WINDOW_TITLE_SEARCH_PROGRESS: &str = "Searching";
 
 pub const HOTKEY_CAPTURE: &str = "Alt+Shift+S";
 pub const HOTKEY_CANCEL: &str = "Escape";
@@ -30,8 +40,17 @@ pub const MESSAGE_KEYBOARD_ALT_PRESSED: &str = "Alt pressed";
 pub const MESSAGE_KEYBOARD_ALT_RELEASED: &str = "Alt released";
 pub const MESSAGE_KEYBOARD_SHIFT_PRESSED: &str = "Shift pressed";
 pub const MESSAGE_KEYBOARD_SHIFT_RELEASED: &str = "Shift released";
-pub const MESSAGE_KEYBOARD_HOTKEY_DETECTED: &str = "Alt+Shift+S detected - opening capture";
+pub const MESSAGE_KEYBOARD_CTRL_PRESSED: &str = "Ctrl pressed";
+pub const MESSAGE_KEYBOARD_CTRL_RELEASED: &str = "Ctrl released";
+pub const MESSAGE_KEYBOARD_META_PRESSED: &str = "Super pressed";
+pub const MESSAGE_KEYBOARD_META_RELEASED: &str = "Super released";
+pub const MESSAGE_KEYBOARD_HOTKEY_DETECTED: &str = "Capture hotkey detected - opening capture";
+pub const MESSAGE_KEYBOARD_ACTIVE_WINDOW_HOTKEY_DETECTED: &str =
+    "Active window hotkey detected - capturing the focused window";
+pub const MESSAGE_KEYBOARD_MIDDLE_CLICK_HELD: &str = "Middle-click hold detected - opening capture";
+pub const MIDDLE_CLICK_HOLD_TRIGGER_MS: u64 = 600;
 pub const MESSAGE_KEYBOARD_ESCAPE_PRESSED: &str = "Escape pressed - canceling";
+pub const MESSAGE_KEYBOARD_CAPTURE_PAUSED: &str = "Capture trigger ignored - hotkey is paused";
 
 pub const USER_MESSAGE_INFO_OPENING: &str = "[INFO] Opening capture window...";
 pub const USER_MESSAGE_SUCCESS_OPENED: &str =
@@ -53,6 +72,10 @@ pub const IMGBB_PUBLIC_KEY: &str = "851a50a8320bc2c59b0f564f0a1343de";
 pub const IMGBB_EXPIRATION_SECONDS: &str = "900";
 pub const IMGBB_PUBLIC_KEY_QUERY_NAME: &str = "key";
 pub const IMGBB_PUBLIC_KEY_ENV_VAR_NAME: &str = "IMGBB_API_KEY";
+pub const ZERO_X_ZERO_UPLOAD_URL: &str = "https://0x0.st";
+pub const DEFAULT_CUSTOM_IMAGE_HOST_FIELD_NAME: &str = "file";
+pub const IMAGE_HOST_UPLOAD_TIMEOUT_SECONDS: u64 = 20;
+pub const APP_USER_AGENT: &str = concat!("circle-to-search-pc/", env!("CARGO_PKG_VERSION"));
 pub const IMGBB_HTTP_METHOD: &str = "POST";
 pub const IMGBB_IMAGE_FIELD_NAME: &str = "image";
 pub const IMAGE_HOSTING_VALIDATION_URL_EMPTY: &str = "Image hosting URL cannot be empty";
@@ -63,27 +86,152 @@ pub const IMAGE_HOSTING_VALIDATION_KEY_EMPTY: &str = "Public key cannot be empty
 pub const IMAGE_HOSTING_VALIDATION_EXPIRATION_EMPTY: &str = "Expiration seconds cannot be empty";
 pub const IMAGE_HOSTING_VALIDATION_EXPIRATION_INVALID: &str =
     "Expiration seconds must be a positive integer";
+pub const IMAGE_SEARCH_URL_PLACEHOLDER: &str = "{}";
+pub const IMAGE_SEARCH_URL_VALIDATION_MISSING_PLACEHOLDER: &str =
+    "Image search URL must contain a {} placeholder for the image link";
+pub const IMAGE_SEARCH_URL_VALIDATION_MULTIPLE_PLACEHOLDERS_PREFIX: &str =
+    "Image search URL must contain exactly one {} placeholder, found ";
+pub const IMAGE_SEARCH_URL_VALIDATION_INVALID: &str =
+    "Image search URL must be a valid http:// or https:// URL";
 pub const IMAGE_HOSTING_SETTINGS_TIP: &str =
     "Tip: If uploads fail, replace Provider URL, Auth Mode, and public key with your own account values.";
 
 pub const DEFAULT_IMAGE_SEARCH_URL: &str = "https://lens.google.com/uploadbyurl?url={}";
+pub const DEFAULT_TEXT_SEARCH_URL: &str = "https://www.google.com/search?q={}";
+pub const BING_VISUAL_SEARCH_URL_TEMPLATE: &str =
+    "https://www.bing.com/images/search?view=detailv2&iss=sbi&form=SBIVSP&q=imgurl:{}";
+pub const YANDEX_IMAGE_SEARCH_URL_TEMPLATE: &str =
+    "https://yandex.com/images/search?rpt=imageview&url={}";
+pub const GOOGLE_TRANSLATE_KEYLESS_API_URL: &str =
+    "https://translate.googleapis.com/translate_a/single";
+pub const GOOGLE_CLOUD_TRANSLATE_API_URL: &str =
+    "https://translation.googleapis.com/language/translate/v2";
+pub const DEFAULT_TRANSLATION_TARGET_LANGUAGE: &str = "en";
 
 pub const DEFAULT_CAPTURE_HOTKEY: &str = "Alt+Shift+S";
+pub const DEFAULT_ACTIVE_WINDOW_HOTKEY: &str = "Alt+Shift+W";
+pub const HOTKEY_VALIDATION_EMPTY: &str = "Capture hotkey cannot be empty";
+pub const HOTKEY_VALIDATION_MISSING_KEY: &str =
+    "Capture hotkey needs a key besides Ctrl, Alt, Shift or Super";
+pub const HOTKEY_VALIDATION_INVALID_PREFIX: &str = "Capture hotkey is malformed: ";
+pub const HOTKEY_VALIDATION_MULTIPLE_KEYS_PREFIX: &str =
+    "Capture hotkey can only contain one non-modifier key: ";
+pub const HOTKEY_VALIDATION_UNKNOWN_KEY_PREFIX: &str = "Capture hotkey uses an unknown key: ";
+pub const DEFAULT_DRAW_MODE_SHORTCUT: &str = "D";
+pub const DEFAULT_SAVE_IMAGE_SHORTCUT: &str = "Ctrl+S";
+pub const DEFAULT_COPY_IMAGE_SHORTCUT: &str = "Ctrl+D";
 
 pub const SETTINGS_FILE_NAME: &str = "settings.json";
+pub const LOG_DIRECTORY_NAME: &str = "logs";
+pub const LOG_FILE_PREFIX: &str = "circle-to-search-";
+pub const LOG_FILE_SUFFIX: &str = ".log";
+pub const LOG_MAX_RETAINED_BYTES: u64 = 20 * 1024 * 1024;
 
 pub const STATUS_INITIALIZING: &str = "Initializing OCR service...";
 pub const STATUS_PREPARING_CAPTURE: &str = "Preparing to capture...";
+pub const DEFAULT_CAPTURE_DELAY_MS: u32 = 200;
+pub const SYSTEM_THEME_POLL_INTERVAL_SECS: u64 = 2;
+pub const BASELINE_SCREEN_DPI: f64 = 96.0;
+pub const MAX_CAPTURE_DELAY_MS: u32 = 1000;
+pub const CAPTURE_DELAY_STEP_MS: u32 = 10;
+pub const DEFAULT_SCROLL_CAPTURE_DELAY_MS: u32 = 400;
+pub const MAX_SCROLL_CAPTURE_DELAY_MS: u32 = 2000;
+pub const SCROLL_CAPTURE_DELAY_STEP_MS: u32 = 50;
+pub const MAX_CAPTURE_REGION_SIZE: u32 = 4000;
+pub const CAPTURE_REGION_SIZE_STEP: u32 = 50;
+pub const DEFAULT_OCR_TIMEOUT_SECS: u32 = 15;
+pub const MIN_OCR_TIMEOUT_SECS: u32 = 5;
+pub const MAX_OCR_TIMEOUT_SECS: u32 = 120;
+pub const OCR_TIMEOUT_STEP_SECS: u32 = 5;
+pub const DEFAULT_MIN_OCR_CONFIDENCE: f32 = 0.0;
+pub const MIN_OCR_CONFIDENCE_STEP: f32 = 0.05;
+pub const DEFAULT_DRAW_WIDTH: f32 = 3.0;
+pub const DRAW_WIDTH_THIN: f32 = 1.5;
+pub const DRAW_WIDTH_THICK: f32 = 6.0;
+pub const HIGHLIGHTER_ALPHA: f32 = 0.35;
+pub const HIGHLIGHTER_WIDTH_MULTIPLIER: f32 = 4.0;
+pub const SCROLL_CAPTURE_MAX_FRAMES: usize = 12;
+pub const SCROLL_CAPTURE_LINES_PER_STEP: i32 = 5;
 pub const STATUS_CAPTURING_SCREEN: &str = "Capturing screen...";
+pub const STATUS_CAPTURING_ALL_MONITORS: &str = "Capturing all monitors...";
+pub const STATUS_READING_CLIPBOARD_IMAGE: &str = "Reading image from clipboard...";
+pub const STATUS_HOTKEY_PAUSED: &str = "Capture hotkey paused - resume it from the tray menu";
+pub const STATUS_HOTKEY_RESUMED: &str = "Capture hotkey resumed";
+pub const STATUS_CAPTURE_SUSPENDED_FOR_FULLSCREEN: &str =
+    "Capture skipped - a full-screen app is focused (change this in Settings)";
+pub const OCR_MODEL_CACHE_DIR_NAME: &str = "circle-to-search-models";
+pub const TESSDATA_TEMP_DIR_NAME: &str = "circle-to-search-tessdata";
+pub const UPLOAD_TEMP_IMAGE_FILENAME: &str = "circle_to_search_image.png";
+pub const STATUS_CLEARING_CACHE: &str = "Clearing cached data...";
+pub const STATUS_CACHE_CLEARED_PREFIX: &str = "Cleared cached data, freed ";
+pub const STATUS_CLEAR_CACHE_BLOCKED_BY_OCR: &str =
+    "Can't clear cached data while text recognition is running - try again when it finishes";
+pub const STATUS_CLEAR_CACHE_BLOCKED_BY_OCR_BUILD: &str =
+    "Can't clear cached data while OCR models are downloading or loading - try again when they finish";
+pub const MAIN_WINDOW_TOAST_DURATION_MS: u64 = 4000;
+pub const RESULT_WINDOW_CASCADE_OFFSET_PX: f32 = 32.0;
+pub const MAIN_WINDOW_TOAST_TOP_OFFSET: f32 = 16.0;
+pub const STATUS_LOADING_DROPPED_IMAGE: &str = "Loading dropped image...";
+pub const DROPPED_FILE_SUPPORTED_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "webp"];
+pub const DROPPED_FILE_ERROR_UNSUPPORTED: &str =
+    "That file isn't a supported image - drop a PNG, JPEG, BMP or WebP file";
+pub const CLIPBOARD_ERROR_NO_IMAGE: &str =
+    "The clipboard doesn't contain an image - copy an image first and try again";
+pub const STATUS_ALL_MONITORS_CAPTURED_PREFIX: &str = "Monitors captured: ";
 pub const STATUS_OVERLAY_READY: &str = "Overlay ready!";
+pub const STATUS_ACTIVE_WINDOW_FALLBACK: &str =
+    "No active window found - captured the monitor under the cursor instead";
 pub const STATUS_PROCESSING_SELECTION: &str = "Processing selection...";
+pub const STATUS_SEARCHING_SELECTION: &str = "Searching selection...";
+pub const STATUS_SELECTION_SEARCH_OPENED: &str = "Search results opened in your browser";
+pub const STATUS_SCROLL_CAPTURING: &str = "Scrolling and capturing...";
+pub const STATUS_SCROLL_CAPTURE_FAILED_PREFIX: &str = "Scroll capture failed: ";
+pub const STATUS_RECENT_REGION_OUTSIDE_SCREEN: &str =
+    "That saved region is no longer on screen. Capture a new area instead.";
+pub const CONNECTIVITY_CHECK_TIMEOUT_SECS: u64 = 5;
+pub const AUTO_SAVE_FOLDER_NAME: &str = "Circle to Search Captures";
+pub const CAPTURE_HISTORY_FOLDER_NAME: &str = "capture_history";
+pub const CAPTURE_HISTORY_THUMBNAIL_MAX_SIZE: u32 = 160;
+pub const DEFAULT_MAX_HISTORY_ENTRIES: u32 = 20;
+pub const MAX_HISTORY_ENTRIES_LIMIT: u32 = 100;
+pub const HISTORY_ENTRIES_STEP: u32 = 5;
+pub const DEFAULT_JPEG_QUALITY: u8 = 90;
+pub const MIN_JPEG_QUALITY: u8 = 10;
+pub const MAX_JPEG_QUALITY: u8 = 100;
+pub const JPEG_QUALITY_STEP: u8 = 5;
+pub const TRAY_RECENT_CAPTURES_LIMIT: usize = 5;
+pub const DUPLICATE_CAPTURE_WINDOW_MS: u64 = 1500;
+pub const STATUS_DUPLICATE_CAPTURE_SKIPPED: &str =
+    "Same region was just captured - showing the existing result";
+pub const RECENT_REGIONS_LIMIT: usize = 5;
+pub const RECENT_DRAW_COLORS_LIMIT: usize = 6;
+pub const WINDOW_GEOMETRY_MIN_VISIBLE_PX: f32 = 48.0;
+pub const AUTO_CLOSE_AFTER_ACTION_DELAY_MS: u64 = 800;
+pub const STATUS_LOADING_ANNOTATION_PROJECT: &str = "Opening annotation project...";
+pub const STATUS_ANNOTATION_PROJECT_LOAD_FAILED_PREFIX: &str = "Could not open project: ";
+pub const ANNOTATION_PROJECT_FORMAT_VERSION: u32 = 1;
+pub const ANNOTATION_PROJECT_FILE_EXTENSION: &str = "json";
+pub const ANNOTATION_PROJECT_FILTER_NAME: &str = "Annotation project";
+pub const ANNOTATION_PROJECT_DIALOG_TITLE: &str = "Open Annotation Project";
+pub const STATUS_TYPING_TEXT: &str = "Typing text into the focused app...";
+pub const STATUS_TYPING_TEXT_DONE: &str = "Finished typing text";
+pub const STATUS_TYPING_TEXT_FAILED_PREFIX: &str = "Failed to type text: ";
+pub const STATUS_TYPING_TEXT_PERMISSION_REQUIRED: &str =
+    "Input Monitoring permission is required to type text";
+pub const TYPE_OUT_TEXT_FOCUS_DELAY_MS: u64 = 500;
 pub const STATUS_READY_SIMPLE: &str = "Ready";
 pub const STATUS_OCR_COMPLETE: &str = "OCR complete";
+pub const STATUS_OCR_TEXT_AUTO_COPIED: &str = "OCR complete - text copied to clipboard";
+pub const STATUS_OCR_AUTO_COPY_FAILED: &str = "OCR complete - couldn't copy text to the clipboard";
+pub const AUTO_COPY_OCR_MIN_TEXT_LENGTH: usize = 2;
 pub const STATUS_READY: &str = "Ready - Press Alt+Shift+S to capture";
 pub const STATUS_PROCESSING_OCR: &str = "Processing OCR...";
+pub const STATUS_OCR_CANCELLED: &str = "OCR cancelled";
+pub const STATUS_SEARCH_CANCELLED: &str = "Image search cancelled";
 pub const STATUS_SETTINGS_SAVED: &str = "Settings saved";
 pub const STATUS_SETTINGS_EDITOR_NOT_ACTIVE: &str = "Settings editor is not active";
 pub const STATUS_SETTINGS_SAVE_FAILED_PREFIX: &str = "Failed to save settings: ";
+pub const ONBOARDING_CAPTURE_TEST_EMPTY_ERROR: &str = "Test capture returned an empty image";
 pub const STATUS_ONBOARDING_SCREEN_RECORDING_FAILED: &str =
     "Failed to open Screen Recording settings";
 pub const STATUS_ONBOARDING_INPUT_MONITORING_FAILED: &str =
@@ -96,6 +244,8 @@ pub const STATUS_KEYWORD_FAILED: &str = "Failed";
 
 pub const CAPTURE_ERROR_MONITOR_PREFIX: &str = "Monitor error: ";
 pub const CAPTURE_ERROR_CROP_PREFIX: &str = "Error cropping image: ";
+pub const CAPTURE_ERROR_NO_MONITORS_CAPTURED: &str =
+    "None of the connected monitors could be captured";
 pub const CAPTURE_ERROR_GENERIC_PREFIX: &str = "Capture failed: ";
 pub const CAPTURE_ERROR_GENERIC_SUFFIX: &str = ". Try closing other instances.";
 pub const CAPTURE_ERROR_LINUX_PERMISSION_PREFIX: &str = "Screen capture failed: ";
@@ -111,26 +261,74 @@ pub const CAPTURE_ERROR_KEYWORD_ACCESS: &str = "access";
 pub const CAPTURE_ERROR_KEYWORD_DENIED: &str = "denied";
 pub const CAPTURE_ERROR_KEYWORD_PIPEWIRE: &str = "pipewire";
 pub const CAPTURE_ERROR_KEYWORD_PORTAL: &str = "portal";
+pub const CAPTURE_ERROR_KEYWORD_PROTECTED: &str = "protected from capture";
+pub const CAPTURE_ERROR_DISPLAY_PROTECTED: &str =
+    "This display is protected from capture. Move the cursor to another display and try again.";
 
 pub const IMAGE_SEARCH_FAILURE_SUFFIX: &str =
     " - Update Settings > Image Hosting or use Copy Image to clipboard";
 pub const IMAGE_SEARCH_TIMEOUT_SECONDS: u64 = 30;
 pub const IMAGE_SEARCH_TIMEOUT_MESSAGE: &str =
     "Search timed out after 30 seconds - Update Settings > Image Hosting or use Copy Image to clipboard";
+pub const TRANSLATION_TIMEOUT_SECONDS: u64 = 30;
+pub const TRANSLATION_TIMEOUT_MESSAGE: &str = "Translation timed out after 30 seconds";
 pub const OCR_RAW_IMAGE_CREATION_FAILED: &str = "Failed to create image from raw data";
+pub const OCR_TIMED_OUT: &str = "OCR timed out";
+pub const STATUS_OCR_FAILED_PREFIX: &str = "OCR failed: ";
+pub const STATUS_CAPTURE_HISTORY_LOAD_FAILED_PREFIX: &str = "Could not open past capture: ";
+pub const STATUS_LOADING_OCR_ENGINE: &str = "Loading OCR engine...";
+pub const STATUS_LOADING_OCR_LANGUAGE_PREFIX: &str = "Loading OCR language: ";
+pub const STATUS_OCR_LANGUAGE_UNSUPPORTED_PREFIX: &str =
+    "Unsupported OCR language, using English instead: ";
 pub const OCR_INITIALIZATION_FAILED_PREFIX: &str = "OCR initialization failed: ";
+pub const STATUS_OCR_TRAINING_DATA_UNAVAILABLE_PREFIX: &str =
+    "Couldn't download OCR training data, reading text as English instead of ";
+pub const STATUS_OCR_ENGINE_FALLBACK_PREFIX: &str = "Preferred OCR engine unavailable, using ";
+pub const STATUS_DOWNLOADING_OCR_MODELS_PREFIX: &str = "Downloading OCR models… ";
+pub const STATUS_DOWNLOAD_SPINNER_FRAMES: [&str; 4] = ["◐", "◓", "◑", "◒"];
+pub const STATUS_DOWNLOAD_SPINNER_BYTES_PER_FRAME: u64 = 256 * 1024;
 
 pub const UI_GENERIC_LOADING: &str = "Loading...";
 
+pub const QUIT_CONFIRMATION_ICON: &str = "⚠";
+pub const QUIT_CONFIRMATION_TITLE: &str = "Quit Circle to Search?";
+pub const QUIT_CONFIRMATION_MESSAGE: &str = "The following unsaved work will be lost:";
+pub const QUIT_CONFIRMATION_ITEM_TEMPLATE: &str =
+    "• Capture window {index}: {count} unsaved drawing(s)";
+pub const QUIT_CONFIRMATION_INDEX_TOKEN: &str = "{index}";
+pub const QUIT_CONFIRMATION_COUNT_TOKEN: &str = "{count}";
+pub const QUIT_CONFIRMATION_QUIT_LABEL: &str = "Quit Anyway";
+pub const QUIT_CONFIRMATION_CANCEL_LABEL: &str = "Cancel";
+pub const TRAY_NOTICE_ICON: &str = "ℹ";
+pub const TRAY_NOTICE_TITLE: &str = "Still running in the tray";
+pub const TRAY_NOTICE_MESSAGE: &str =
+    "Circle to Search keeps running after the window closes. Use the tray icon to reopen it or quit, or turn on Quit On Close in Settings.";
+pub const TRAY_NOTICE_DISMISS_LABEL: &str = "Got it";
+pub const TRAY_NOTICE_AUTO_DISMISS_MS: u64 = 8000;
+pub const SEARCH_PROGRESS_ICON: &str = "🔍";
+pub const SEARCH_PROGRESS_ERROR_ICON: &str = "⚠";
+pub const SEARCH_PROGRESS_CLOSE_LABEL: &str = "Close";
+pub const SEARCH_PROGRESS_WINDOW_WIDTH: f32 = 340.0;
+pub const SEARCH_PROGRESS_WINDOW_HEIGHT: f32 = 150.0;
+pub const SEARCH_PROGRESS_AUTO_CLOSE_MS: u64 = 2000;
+
 pub const MAIN_WINDOW_ICON_SEARCH: &str = "🔍";
 pub const MAIN_WINDOW_SUBTITLE: &str = "Search anything on your screen instantly";
 pub const MAIN_WINDOW_ICON_CAPTURE: &str = "📸";
 pub const MAIN_WINDOW_CAPTURE_BUTTON_LABEL: &str = "Capture Screen";
+pub const MAIN_WINDOW_ICON_CLIPBOARD: &str = "📋";
+pub const MAIN_WINDOW_OCR_CLIPBOARD_BUTTON_LABEL: &str = "OCR Clipboard Image";
 pub const MAIN_WINDOW_HOTKEY_HINT_TEMPLATE: &str = "Press {hotkey} anywhere";
 pub const MAIN_WINDOW_OR_TEXT: &str = "OR";
 pub const MAIN_WINDOW_KEEP_RUNNING_LABEL: &str = "Keep running in background";
 pub const MAIN_WINDOW_ICON_SETTINGS: &str = "⚙";
 pub const MAIN_WINDOW_SETTINGS_BUTTON_LABEL: &str = "Settings";
+pub const MAIN_WINDOW_RECENT_REGIONS_TITLE: &str = "Recent regions";
+pub const MAIN_WINDOW_ICON_RECENT_REGION: &str = "📐";
+pub const MAIN_WINDOW_ICON_OPEN_PROJECT: &str = "📂";
+pub const MAIN_WINDOW_OPEN_PROJECT_BUTTON_LABEL: &str = "Open Project";
+pub const MAIN_WINDOW_ICON_HISTORY: &str = "🕘";
+pub const MAIN_WINDOW_HISTORY_BUTTON_LABEL: &str = "History";
 pub const MAIN_WINDOW_HOTKEY_TEMPLATE_TOKEN: &str = "{hotkey}";
 pub const MAIN_WINDOW_STATUS_ICON_FILLED: &str = "●";
 pub const MAIN_WINDOW_STATUS_ICON_EMPTY: &str = "○";
@@ -139,10 +337,28 @@ pub const SETTINGS_WINDOW_ICON: &str = "⚙";
 pub const SETTINGS_WINDOW_TITLE: &str = "Settings";
 pub const SETTINGS_SECTION_SEARCH_TITLE: &str = "Search";
 pub const SETTINGS_SECTION_SEARCH_ICON: &str = "🔍";
+pub const SETTINGS_LABEL_SEARCH_ENGINE: &str = "Search Engine";
+pub const SETTINGS_DESCRIPTION_SEARCH_ENGINE: &str =
+    "Reverse image search service to open results in";
 pub const SETTINGS_LABEL_IMAGE_SEARCH_URL: &str = "Image Search URL";
-pub const SETTINGS_DESCRIPTION_IMAGE_SEARCH_URL: &str = "Template URL for reverse image search";
+pub const SETTINGS_DESCRIPTION_IMAGE_SEARCH_URL: &str =
+    "Template URL for Google Lens reverse image search";
+pub const SETTINGS_RESET_TO_DEFAULT_LABEL: &str = "Reset to default";
+pub const SETTINGS_LABEL_TEXT_SEARCH_URL: &str = "Text Search URL";
+pub const SETTINGS_DESCRIPTION_TEXT_SEARCH_URL: &str =
+    "Template URL for text-only searches; {} is replaced with the query";
 pub const SETTINGS_SECTION_IMAGE_HOSTING_TITLE: &str = "Image Hosting";
 pub const SETTINGS_SECTION_IMAGE_HOSTING_ICON: &str = "🖼";
+pub const SETTINGS_LABEL_IMAGE_HOST: &str = "Image Host";
+pub const SETTINGS_DESCRIPTION_IMAGE_HOST: &str =
+    "Where captures are uploaded before a reverse image search";
+pub const SETTINGS_LABEL_CUSTOM_IMAGE_HOST_URL: &str = "Custom Host URL";
+pub const SETTINGS_DESCRIPTION_CUSTOM_IMAGE_HOST_URL: &str =
+    "Multipart upload endpoint that replies with the image URL as plain text";
+pub const SETTINGS_CUSTOM_IMAGE_HOST_URL_PLACEHOLDER: &str = "https://example.com/upload";
+pub const SETTINGS_LABEL_CUSTOM_IMAGE_HOST_FIELD_NAME: &str = "Custom Host Field Name";
+pub const SETTINGS_DESCRIPTION_CUSTOM_IMAGE_HOST_FIELD_NAME: &str =
+    "Multipart form field name for the PNG file";
 pub const SETTINGS_LABEL_PROVIDER_URL: &str = "Provider URL";
 pub const SETTINGS_DESCRIPTION_PROVIDER_URL: &str = "Upload endpoint URL";
 pub const SETTINGS_LABEL_AUTH_MODE: &str = "Auth Mode";
@@ -164,12 +380,142 @@ pub const SETTINGS_IMAGE_FIELD_NAME_PLACEHOLDER: &str = "image";
 pub const SETTINGS_RESTART_REQUIRED_WARNING: &str = "Requires app restart to take effect";
 pub const SETTINGS_SECTION_KEYBOARD_TITLE: &str = "Keyboard";
 pub const SETTINGS_SECTION_KEYBOARD_ICON: &str = "⌨";
+pub const SETTINGS_LABEL_CAPTURE_TRIGGER: &str = "Capture Trigger";
+pub const SETTINGS_DESCRIPTION_CAPTURE_TRIGGER: &str =
+    "Choose what opens a capture; use the tray menu if shortcuts clash with games";
 pub const SETTINGS_LABEL_CAPTURE_HOTKEY: &str = "Capture Hotkey";
 pub const SETTINGS_DESCRIPTION_CAPTURE_HOTKEY: &str = "Global shortcut to start capture";
+pub const SETTINGS_RECORD_HOTKEY_LABEL: &str = "Record";
+pub const SETTINGS_RECORDING_HOTKEY_LABEL: &str = "Press keys...";
+pub const SETTINGS_LABEL_DRAW_MODE_SHORTCUT: &str = "Draw Mode Shortcut";
+pub const SETTINGS_DESCRIPTION_DRAW_MODE_SHORTCUT: &str =
+    "Toggle drawing in the result window (leave empty to disable)";
+pub const SETTINGS_LABEL_SAVE_IMAGE_SHORTCUT: &str = "Save Image Shortcut";
+pub const SETTINGS_DESCRIPTION_SAVE_IMAGE_SHORTCUT: &str =
+    "Save the annotated image from the result window";
+pub const SETTINGS_LABEL_COPY_IMAGE_SHORTCUT: &str = "Copy Image Shortcut";
+pub const SETTINGS_DESCRIPTION_COPY_IMAGE_SHORTCUT: &str =
+    "Copy the annotated image from the result window to the clipboard";
 pub const SETTINGS_SECTION_APPEARANCE_TITLE: &str = "Appearance";
 pub const SETTINGS_SECTION_APPEARANCE_ICON: &str = "🎨";
 pub const SETTINGS_LABEL_THEME: &str = "Theme";
+pub const SETTINGS_LABEL_OCR_ENGINE: &str = "OCR Engine";
+pub const SETTINGS_DESCRIPTION_OCR_ENGINE: &str =
+    "Tesseract works offline; Neural and ONNX download their models on first use; Auto picks whichever works";
+pub const SETTINGS_LABEL_OCR_LANGUAGE: &str = "OCR Language";
+pub const SETTINGS_DESCRIPTION_OCR_LANGUAGE: &str =
+    "Language to recognize; extra Tesseract languages download on first use";
 pub const SETTINGS_DESCRIPTION_THEME: &str = "Choose light or dark mode";
+pub const SETTINGS_SECTION_BEHAVIOR_TITLE: &str = "Behavior";
+pub const SETTINGS_SECTION_BEHAVIOR_ICON: &str = "🛠";
+pub const SETTINGS_LABEL_CAPTURE_MONITOR: &str = "Capture Monitor";
+pub const SETTINGS_DESCRIPTION_CAPTURE_MONITOR: &str =
+    "Which screen to grab when a capture starts from the hotkey or the tray";
+pub const SETTINGS_LABEL_CAPTURE_DELAY: &str = "Capture Delay";
+pub const SETTINGS_DESCRIPTION_CAPTURE_DELAY: &str =
+    "Wait after minimizing the main window before capturing (0 for fastest)";
+pub const SETTINGS_CAPTURE_DELAY_UNIT: &str = "ms";
+pub const SETTINGS_LABEL_MAX_HISTORY_ENTRIES: &str = "Capture History Size";
+pub const SETTINGS_DESCRIPTION_MAX_HISTORY_ENTRIES: &str =
+    "How many past captures to keep for reopening; 0 turns history off";
+pub const SETTINGS_HISTORY_ENTRIES_UNIT: &str = "captures";
+pub const SETTINGS_LABEL_CAPTURE_REGION_WIDTH: &str = "Capture Region Width";
+pub const SETTINGS_DESCRIPTION_CAPTURE_REGION_WIDTH: &str =
+    "Grab a fixed-size area around the cursor instead of drawing a selection (0 to select manually)";
+pub const SETTINGS_LABEL_CAPTURE_REGION_HEIGHT: &str = "Capture Region Height";
+pub const SETTINGS_DESCRIPTION_CAPTURE_REGION_HEIGHT: &str =
+    "Height of the area around the cursor; both sizes must be set to skip the selection overlay";
+pub const SETTINGS_CAPTURE_REGION_SIZE_UNIT: &str = "px";
+pub const SETTINGS_CAPTURE_REGION_SIZE_OFF: &str = "Off";
+pub const SETTINGS_LABEL_SCROLL_CAPTURE_DELAY: &str = "Scroll Capture Delay";
+pub const SETTINGS_DESCRIPTION_SCROLL_CAPTURE_DELAY: &str =
+    "Wait after each scroll before capturing the next part of a long page";
+pub const SETTINGS_LABEL_MIN_OCR_CONFIDENCE: &str = "Minimum OCR Confidence";
+pub const SETTINGS_DESCRIPTION_MIN_OCR_CONFIDENCE: &str =
+    "Hide detected words the OCR engine is less sure about than this (the neural engine reports no score, so its words are always kept)";
+pub const SETTINGS_LABEL_OCR_TIMEOUT: &str = "OCR Timeout";
+pub const SETTINGS_DESCRIPTION_OCR_TIMEOUT: &str =
+    "Give up on text recognition after this long so the image stays usable";
+pub const SETTINGS_OCR_TIMEOUT_UNIT: &str = "s";
+pub const SETTINGS_LABEL_TYPE_OUT_TEXT: &str = "Type Out Text";
+pub const SETTINGS_DESCRIPTION_TYPE_OUT_TEXT: &str =
+    "Show an action that types selected text as keystrokes into the focused app";
+pub const SETTINGS_LABEL_AUTO_RUN_OCR: &str = "Auto Run OCR";
+pub const SETTINGS_DESCRIPTION_AUTO_RUN_OCR: &str =
+    "Extract text as soon as a capture opens instead of waiting for the OCR button";
+pub const SETTINGS_LABEL_QUIT_ON_CLOSE: &str = "Quit On Close";
+pub const SETTINGS_DESCRIPTION_QUIT_ON_CLOSE: &str =
+    "Quit the app when the main window is closed instead of keeping it running in the tray";
+pub const SETTINGS_LABEL_SUSPEND_HOTKEY_IN_FULLSCREEN: &str = "Pause in full-screen apps";
+pub const SETTINGS_DESCRIPTION_SUSPEND_HOTKEY_IN_FULLSCREEN: &str =
+    "Ignore the capture trigger while a full-screen game or app is in the foreground";
+pub const SETTINGS_LABEL_AUTO_CLOSE_AFTER_ACTION: &str = "Auto-close After Action";
+pub const SETTINGS_DESCRIPTION_AUTO_CLOSE_AFTER_ACTION: &str =
+    "Close the result window after copying text, finishing a search or saving";
+pub const SETTINGS_LABEL_MINIMIZE_ON_CAPTURE: &str = "Minimize on Capture";
+pub const SETTINGS_DESCRIPTION_MINIMIZE_ON_CAPTURE: &str =
+    "Minimize the main window while capturing and restore it when the result window closes";
+pub const SETTINGS_LABEL_PIN_RESULT_WINDOW_ON_TOP: &str = "Pin Result Window";
+pub const SETTINGS_DESCRIPTION_PIN_RESULT_WINDOW_ON_TOP: &str =
+    "Keep new result windows above other apps; use the pin button to change a single window";
+pub const SETTINGS_LABEL_SHOW_MAGNIFIER: &str = "Show Magnifier";
+pub const SETTINGS_DESCRIPTION_SHOW_MAGNIFIER: &str =
+    "Show a zoomed loupe under the cursor while selecting a region (toggle with M)";
+pub const SETTINGS_LABEL_OPEN_SEARCH_IN_PRIVATE_WINDOW: &str = "Private Search Window";
+pub const SETTINGS_DESCRIPTION_OPEN_SEARCH_IN_PRIVATE_WINDOW: &str =
+    "Open reverse image searches in an incognito/private browser window when supported";
+pub const SETTINGS_LABEL_AUTO_SAVE_CAPTURES: &str = "Auto-save Captures";
+pub const SETTINGS_DESCRIPTION_AUTO_SAVE_CAPTURES: &str =
+    "Write every capture to the auto-save folder as soon as it opens";
+pub const SETTINGS_LABEL_SCREENSHOT_SAVE_LOCATION: &str = "Save Folder";
+pub const SETTINGS_DESCRIPTION_SCREENSHOT_SAVE_LOCATION: &str =
+    "Folder used by Save Image and Save Project; created if it does not exist";
+pub const SETTINGS_LABEL_SAVE_FORMAT: &str = "Image Format";
+pub const SETTINGS_DESCRIPTION_SAVE_FORMAT: &str =
+    "File type used by Save Image and auto-save; JPEG drops transparency onto white";
+pub const SETTINGS_LABEL_JPEG_QUALITY: &str = "JPEG Quality";
+pub const SETTINGS_DESCRIPTION_JPEG_QUALITY: &str =
+    "Higher keeps more detail but makes larger JPEG files";
+pub const SETTINGS_LABEL_AUTO_SAVE_LOCATION: &str = "Auto-save Folder";
+pub const SETTINGS_DESCRIPTION_AUTO_SAVE_LOCATION: &str =
+    "Folder that receives a copy of every capture when auto-save is on";
+pub const SETTINGS_LABEL_TRANSLATION_TARGET_LANGUAGE: &str = "Translate To";
+pub const SETTINGS_DESCRIPTION_TRANSLATION_TARGET_LANGUAGE: &str =
+    "Language code used when translating selected text (e.g. en, es, fr, ja)";
+pub const SETTINGS_LABEL_TRANSLATION_API_KEY: &str = "Translation API Key";
+pub const SETTINGS_DESCRIPTION_TRANSLATION_API_KEY: &str =
+    "Google Cloud Translation API key. Leave empty to use the unofficial keyless endpoint, which is best-effort and may be rate limited or stop working";
+pub const SETTINGS_LABEL_INCLUDE_ANNOTATIONS_IN_SEARCH: &str = "Include Drawings in Search";
+pub const SETTINGS_DESCRIPTION_INCLUDE_ANNOTATIONS_IN_SEARCH: &str =
+    "Send your circles and drawings along with the image when running a reverse image search";
+pub const SETTINGS_LABEL_SHOW_HELP_HINT: &str = "Show Selection Tips";
+pub const SETTINGS_DESCRIPTION_SHOW_HELP_HINT: &str =
+    "Show the text selection tips after OCR finds text";
+pub const SETTINGS_LABEL_PLAY_CAPTURE_SOUND: &str = "Capture Sound";
+pub const SETTINGS_DESCRIPTION_PLAY_CAPTURE_SOUND: &str =
+    "Play a short click when a capture is taken";
+pub const SETTINGS_LABEL_PLAY_SUCCESS_SOUND: &str = "Success Sound";
+pub const SETTINGS_DESCRIPTION_PLAY_SUCCESS_SOUND: &str =
+    "Play a chime when a copy, save or search succeeds";
+pub const SETTINGS_LABEL_PLAY_FAILURE_SOUND: &str = "Failure Sound";
+pub const SETTINGS_DESCRIPTION_PLAY_FAILURE_SOUND: &str = "Play a low tone when an action fails";
+pub const SETTINGS_LABEL_SHOW_CAPTURE_FLASH: &str = "Capture Flash";
+pub const SETTINGS_DESCRIPTION_SHOW_CAPTURE_FLASH: &str =
+    "Briefly flash the result window when a capture is taken";
+pub const CAPTURE_FLASH_DURATION_MS: u64 = 150;
+pub const SETTINGS_LABEL_LOG_LEVEL: &str = "Log Level";
+pub const SETTINGS_DESCRIPTION_LOG_LEVEL: &str =
+    "How much detail to write to the log files; takes effect after restarting";
+pub const SETTINGS_LABEL_CLEAR_CACHE: &str = "Cached Data";
+pub const SETTINGS_DESCRIPTION_CLEAR_CACHE: &str =
+    "Delete downloaded OCR models and temporary images; models download again when next needed";
+pub const SETTINGS_CLEAR_CACHE_BUTTON_LABEL: &str = "Clear Cache";
+pub const SETTINGS_LABEL_AUTO_COPY_ON_OCR: &str = "Auto-Copy OCR Text";
+pub const SETTINGS_DESCRIPTION_AUTO_COPY_ON_OCR: &str =
+    "Copy recognized text to the clipboard as soon as OCR finishes";
+pub const SETTINGS_LABEL_CONFIRM_QUIT: &str = "Confirm Quit";
+pub const SETTINGS_DESCRIPTION_CONFIRM_QUIT: &str =
+    "Ask before quitting when capture windows have unsaved drawings";
 pub const SETTINGS_ICON_SAVE: &str = "💾";
 pub const SETTINGS_SAVE_CHANGES_LABEL: &str = "Save Changes";
 
@@ -0,0 +1,314 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::global_constants::{
+    LOG_DIRECTORY_NAME, LOG_FILE_PREFIX, LOG_FILE_SUFFIX, LOG_MAX_RETAINED_BYTES,
+};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+struct LogFileState {
+    day: u64,
+    file: File,
+}
+
+pub struct FileLogger {
+    level: LevelFilter,
+    log_directory: PathBuf,
+    file_state: Mutex<LogFileState>,
+    stderr_logger: Option<env_logger::Logger>,
+}
+
+pub fn initialize(level: LevelFilter) {
+    let stderr_logger = cfg!(debug_assertions).then(|| build_stderr_logger(level));
+
+    let log_directory = match crate::core::models::UserSettings::get_config_directory() {
+        Ok(config_directory) => config_directory.join(LOG_DIRECTORY_NAME),
+        Err(error) => {
+            initialize_stderr_fallback(level, &error.to_string());
+            return;
+        }
+    };
+
+    match FileLogger::build(&log_directory, level, stderr_logger) {
+        Ok(file_logger) => {
+            let max_level = file_logger.max_level();
+            if log::set_boxed_logger(Box::new(file_logger)).is_ok() {
+                log::set_max_level(max_level);
+            }
+            log::info!("[LOGGER] Writing logs to {}", log_directory.display());
+        }
+        Err(error) => initialize_stderr_fallback(level, &error.to_string()),
+    }
+}
+
+fn initialize_stderr_fallback(level: LevelFilter, reason: &str) {
+    let stderr_logger = build_stderr_logger(level);
+    let max_level = stderr_logger.filter();
+    if log::set_boxed_logger(Box::new(stderr_logger)).is_ok() {
+        log::set_max_level(max_level);
+    }
+    log::warn!(
+        "[LOGGER] Log directory unavailable, logging to stderr only: {}",
+        reason
+    );
+}
+
+fn build_stderr_logger(level: LevelFilter) -> env_logger::Logger {
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .build()
+}
+
+impl FileLogger {
+    pub fn build(
+        log_directory: &Path,
+        level: LevelFilter,
+        stderr_logger: Option<env_logger::Logger>,
+    ) -> std::io::Result<Self> {
+        fs::create_dir_all(log_directory)?;
+        let day = current_unix_seconds() / SECONDS_PER_DAY;
+        let file = open_log_file(log_directory, day)?;
+        prune_log_files(log_directory, LOG_MAX_RETAINED_BYTES);
+
+        Ok(Self {
+            level,
+            log_directory: log_directory.to_path_buf(),
+            file_state: Mutex::new(LogFileState { day, file }),
+            stderr_logger,
+        })
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.stderr_logger
+            .as_ref()
+            .map(|stderr_logger| stderr_logger.filter().max(self.level))
+            .unwrap_or(self.level)
+    }
+
+    fn write_record(&self, record: &Record) {
+        let unix_seconds = current_unix_seconds();
+        let Ok(mut file_state) = self.file_state.lock() else {
+            return;
+        };
+
+        let day = unix_seconds / SECONDS_PER_DAY;
+        if day != file_state.day {
+            match open_log_file(&self.log_directory, day) {
+                Ok(file) => {
+                    *file_state = LogFileState { day, file };
+                    prune_log_files(&self.log_directory, LOG_MAX_RETAINED_BYTES);
+                }
+                Err(error) => eprintln!("[LOGGER] Failed to rotate log file: {}", error),
+            }
+        }
+
+        let _ = writeln!(
+            file_state.file,
+            "{} {:<5} {} {}",
+            format_utc_timestamp(unix_seconds),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+            || self
+                .stderr_logger
+                .as_ref()
+                .is_some_and(|stderr_logger| stderr_logger.enabled(metadata))
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() <= self.level {
+            self.write_record(record);
+        }
+        if let Some(stderr_logger) = &self.stderr_logger {
+            stderr_logger.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file_state) = self.file_state.lock() {
+            let _ = file_state.file.flush();
+        }
+    }
+}
+
+pub fn resolve_log_file_path(log_directory: &Path, unix_seconds: u64) -> PathBuf {
+    let (year, month, day) = civil_date_from_days(unix_seconds / SECONDS_PER_DAY);
+    log_directory.join(format!(
+        "{}{:04}-{:02}-{:02}{}",
+        LOG_FILE_PREFIX, year, month, day, LOG_FILE_SUFFIX
+    ))
+}
+
+fn open_log_file(log_directory: &Path, day: u64) -> std::io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(resolve_log_file_path(log_directory, day * SECONDS_PER_DAY))
+}
+
+fn prune_log_files(log_directory: &Path, max_retained_bytes: u64) {
+    let Ok(entries) = fs::read_dir(log_directory) else {
+        return;
+    };
+
+    let mut log_files: Vec<(PathBuf, u64)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            file_name.starts_with(LOG_FILE_PREFIX) && file_name.ends_with(LOG_FILE_SUFFIX)
+        })
+        .filter_map(|entry| Some((entry.path(), entry.metadata().ok()?.len())))
+        .collect();
+    log_files.sort_by(|(left, _), (right, _)| right.cmp(left));
+
+    let mut retained_bytes = 0u64;
+    for (index, (path, size)) in log_files.into_iter().enumerate() {
+        retained_bytes += size;
+        if index > 0 && retained_bytes > max_retained_bytes {
+            if let Err(error) = fs::remove_file(&path) {
+                eprintln!(
+                    "[LOGGER] Failed to remove old log file {}: {}",
+                    path.display(),
+                    error
+                );
+            }
+        }
+    }
+}
+
+fn current_unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+fn format_utc_timestamp(unix_seconds: u64) -> String {
+    let (year, month, day) = civil_date_from_days(unix_seconds / SECONDS_PER_DAY);
+    let seconds_of_day = unix_seconds % SECONDS_PER_DAY;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+fn civil_date_from_days(days_since_epoch: u64) -> (i64, u32, u32) {
+    let shifted_days = days_since_epoch as i64 + 719_468;
+    let era = shifted_days.div_euclid(146_097);
+    let day_of_era = shifted_days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Level;
+
+    fn build_test_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("test-logger-{}-{}", name, std::process::id()));
+        fs::remove_dir_all(&directory).ok();
+        directory
+    }
+
+    #[test]
+    fn test_resolve_log_file_path_uses_utc_date() {
+        let directory = Path::new("logs");
+
+        assert_eq!(
+            resolve_log_file_path(directory, 1_792_155_909),
+            directory.join("circle-to-search-2026-10-16.log")
+        );
+        assert_eq!(
+            resolve_log_file_path(directory, 0),
+            directory.join("circle-to-search-1970-01-01.log")
+        );
+        assert_eq!(format_utc_timestamp(1_792_155_909), "2026-10-16T13:05:09Z");
+    }
+
+    #[test]
+    fn test_file_logger_writes_only_records_within_configured_level() {
+        let directory = build_test_directory("level");
+        let logger = FileLogger::build(&directory, LevelFilter::Info, None).unwrap();
+
+        logger.log(
+            &Record::builder()
+                .args(format_args!("kept info line"))
+                .level(Level::Info)
+                .target("test")
+                .build(),
+        );
+        logger.log(
+            &Record::builder()
+                .args(format_args!("dropped debug line"))
+                .level(Level::Debug)
+                .target("test")
+                .build(),
+        );
+        logger.flush();
+
+        let contents =
+            fs::read_to_string(resolve_log_file_path(&directory, current_unix_seconds())).unwrap();
+        fs::remove_dir_all(&directory).ok();
+
+        assert!(contents.contains("INFO  test kept info line"));
+        assert!(!contents.contains("dropped debug line"));
+        assert!(!logger.enabled(&Metadata::builder().level(Level::Debug).build()));
+    }
+
+    #[test]
+    fn test_prune_log_files_removes_oldest_beyond_retained_size() {
+        let directory = build_test_directory("prune");
+        fs::create_dir_all(&directory).unwrap();
+        for day in 1..=3 {
+            fs::write(
+                directory.join(format!("circle-to-search-2026-01-0{}.log", day)),
+                vec![b'x'; 10],
+            )
+            .unwrap();
+        }
+
+        prune_log_files(&directory, 20);
+
+        let exists = |day: u32| {
+            directory
+                .join(format!("circle-to-search-2026-01-0{}.log", day))
+                .exists()
+        };
+        let remaining = (exists(1), exists(2), exists(3));
+        fs::remove_dir_all(&directory).ok();
+
+        assert_eq!(remaining, (false, true, true));
+    }
+}
@@ -3,11 +3,53 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
 
+use crate::core::models::{CaptureBuffer, ImageSaveFormat};
+
 const APP_LOCK_FILENAME: &str = "circle-to-search-pc.lock";
 const CLIPBOARD_TEMP_IMAGE_FILENAME: &str = "circle_to_search_clipboard.png";
 const CLIPBOARD_TEMP_PATH_INVALID: &str = "Invalid temp path";
 const SCREENSHOT_FILENAME_PREFIX: &str = "screenshot_";
-const SCREENSHOT_FILENAME_SUFFIX: &str = ".png";
+const PROJECT_FILENAME_PREFIX: &str = "annotation_project_";
+const PROJECT_FILENAME_SUFFIX: &str = ".json";
+const TEXT_EXPORT_FILENAME_PREFIX: &str = "recognized_text_";
+const TEXT_EXPORT_FILENAME_SUFFIX: &str = ".txt";
+const PNG_SIGNATURE_LENGTH: usize = 8;
+const PNG_IHDR_CHUNK_LENGTH: usize = 25;
+const PNG_CHUNK_TYPE_IHDR: &[u8] = b"IHDR";
+const PNG_CHUNK_TYPE_PHYS: &[u8] = b"pHYs";
+const PNG_PHYS_UNIT_METER: u8 = 1;
+const INCHES_PER_METER: f64 = 39.3701;
+const CLIPBOARD_USES_PREMULTIPLIED_ALPHA: bool = cfg!(target_os = "windows");
+const PRIVATE_BROWSER_NOT_FOUND: &str = "No browser with private window support found";
+#[cfg(target_os = "linux")]
+const PRIVATE_BROWSER_LAUNCHERS: &[(&str, &[&str])] = &[
+    ("google-chrome", &["--incognito"]),
+    ("chromium", &["--incognito"]),
+    ("brave-browser", &["--incognito"]),
+    ("microsoft-edge", &["--inprivate"]),
+    ("firefox", &["--private-window"]),
+];
+#[cfg(target_os = "macos")]
+const PRIVATE_BROWSER_LAUNCHERS: &[(&str, &[&str])] = &[
+    ("open", &["-na", "Google Chrome", "--args", "--incognito"]),
+    ("open", &["-na", "Brave Browser", "--args", "--incognito"]),
+    ("open", &["-na", "Microsoft Edge", "--args", "--inprivate"]),
+    ("open", &["-na", "Firefox", "--args", "--private-window"]),
+];
+#[cfg(target_os = "windows")]
+const PRIVATE_BROWSER_LAUNCHERS: &[(&str, &[&str])] = &[
+    (r"Microsoft\Edge\Application\msedge.exe", &["--inprivate"]),
+    (r"Google\Chrome\Application\chrome.exe", &["--incognito"]),
+    (
+        r"BraveSoftware\Brave-Browser\Application\brave.exe",
+        &["--incognito"],
+    ),
+];
+#[cfg(target_os = "windows")]
+const WINDOWS_PROGRAM_ROOT_ENV_VARS: &[&str] =
+    &["ProgramFiles(x86)", "ProgramFiles", "LOCALAPPDATA"];
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+const PRIVATE_BROWSER_LAUNCHERS: &[(&str, &[&str])] = &[];
 #[cfg(not(target_os = "macos"))]
 const WINDOW_FOCUS_NOT_SUPPORTED: &str = "Window focus not supported on this platform";
 
@@ -28,14 +70,8 @@ pub fn ensure_single_instance_using_lock_file(lock_file_path: &Path) -> bool {
         if let Ok(pid) = pid_string.trim().parse::<u32>() {
             log::info!("[INSTANCE] Found existing instance with PID: {}", pid);
 
-            let mut system = System::new();
-            system.refresh_processes_specifics(
-                ProcessesToUpdate::All,
-                true,
-                ProcessRefreshKind::nothing(),
-            );
-
-            if let Some(process) = system.process(Pid::from_u32(pid)) {
+            let system = load_process_snapshot();
+            if let Some(process) = find_running_process(&system, pid) {
                 log::warn!("[INSTANCE] Killing existing instance (PID: {})", pid);
                 process.kill();
                 std::thread::sleep(std::time::Duration::from_millis(500));
@@ -64,6 +100,116 @@ pub fn ensure_single_instance_using_lock_file(lock_file_path: &Path) -> bool {
     true
 }
 
+fn load_process_snapshot() -> System {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing());
+    system
+}
+
+fn find_running_process(system: &System, pid: u32) -> Option<&sysinfo::Process> {
+    system.process(Pid::from_u32(pid))
+}
+
+pub fn get_default_cache_paths() -> Vec<PathBuf> {
+    let temp_root = std::env::temp_dir();
+    let cache_root = dirs::cache_dir().unwrap_or_else(|| temp_root.clone());
+    enumerate_cache_paths(&cache_root, &temp_root)
+}
+
+pub fn enumerate_cache_paths(cache_root: &Path, temp_root: &Path) -> Vec<PathBuf> {
+    vec![
+        cache_root.join(crate::global_constants::OCR_MODEL_CACHE_DIR_NAME),
+        temp_root.join(crate::global_constants::TESSDATA_TEMP_DIR_NAME),
+        temp_root.join(crate::global_constants::UPLOAD_TEMP_IMAGE_FILENAME),
+        temp_root.join(CLIPBOARD_TEMP_IMAGE_FILENAME),
+        temp_root.join(APP_LOCK_FILENAME),
+    ]
+}
+
+pub fn clear_cached_data(paths: &[PathBuf]) -> u64 {
+    paths.iter().map(|path| remove_cached_path(path)).sum()
+}
+
+fn remove_cached_path(path: &Path) -> u64 {
+    if !path.exists() {
+        return 0;
+    }
+
+    if path.file_name().and_then(|name| name.to_str()) == Some(APP_LOCK_FILENAME)
+        && is_lock_file_held(path)
+    {
+        log::info!(
+            "[CACHE] Keeping lock file held by a running instance: {:?}",
+            path
+        );
+        return 0;
+    }
+
+    let freed_bytes = measure_path_size(path);
+    let remove_result = if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+
+    match remove_result {
+        Ok(()) => {
+            log::info!("[CACHE] Removed {:?} ({} bytes)", path, freed_bytes);
+            freed_bytes
+        }
+        Err(error) => {
+            log::warn!("[CACHE] Failed to remove {:?}: {}", path, error);
+            0
+        }
+    }
+}
+
+fn is_lock_file_held(lock_file_path: &Path) -> bool {
+    let Some(pid) = fs::read_to_string(lock_file_path)
+        .ok()
+        .and_then(|pid_string| pid_string.trim().parse::<u32>().ok())
+    else {
+        return false;
+    };
+
+    pid == std::process::id() || find_running_process(&load_process_snapshot(), pid).is_some()
+}
+
+fn measure_path_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| measure_path_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+pub fn format_byte_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    if unit_index == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit_index])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,6 +283,379 @@ mod tests {
 
         fs::remove_file(&test_lock_path).ok();
     }
+
+    #[test]
+    fn test_format_dimensions_with_points_divides_by_scale_factor() {
+        let readout = format_dimensions_with_points(640, 480, 2.0);
+
+        assert_eq!(readout, "640 × 480 px · 320 × 240 pt @ 192 DPI");
+    }
+
+    #[test]
+    fn test_calculate_dpi_for_invalid_scale_factor_uses_baseline() {
+        assert_eq!(
+            calculate_dpi_for_scale_factor(0.0),
+            crate::global_constants::BASELINE_SCREEN_DPI
+        );
+        assert_eq!(
+            calculate_dpi_for_scale_factor(f64::NAN),
+            crate::global_constants::BASELINE_SCREEN_DPI
+        );
+    }
+
+    #[test]
+    fn test_insert_png_dpi_metadata_writes_readable_phys_chunk() {
+        let png_data = encode_rgba_image(&[255u8; 16], 2, 2, image::ImageFormat::Png).unwrap();
+
+        let with_dpi = insert_png_dpi_metadata(png_data, 144.0);
+
+        let decoder = image::codecs::png::PngDecoder::new(std::io::Cursor::new(&with_dpi));
+        assert!(decoder.is_ok());
+        let phys_offset = PNG_SIGNATURE_LENGTH + PNG_IHDR_CHUNK_LENGTH;
+        assert_eq!(
+            &with_dpi[phys_offset + 4..phys_offset + 8],
+            PNG_CHUNK_TYPE_PHYS
+        );
+        let pixels_per_meter = u32::from_be_bytes(
+            with_dpi[phys_offset + 8..phys_offset + 12]
+                .try_into()
+                .unwrap(),
+        );
+        assert_eq!(pixels_per_meter, (144.0 * INCHES_PER_METER).round() as u32);
+    }
+
+    #[test]
+    fn test_premultiply_alpha_leaves_opaque_pixels_unchanged() {
+        let mut rgba_data = vec![200, 100, 50, 255];
+
+        premultiply_alpha(&mut rgba_data);
+
+        assert_eq!(rgba_data, vec![200, 100, 50, 255]);
+    }
+
+    #[test]
+    fn test_premultiply_alpha_scales_color_channels() {
+        let mut rgba_data = vec![200, 100, 50, 128, 255, 255, 255, 0];
+
+        premultiply_alpha(&mut rgba_data);
+
+        assert_eq!(rgba_data, vec![100, 50, 25, 128, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_unpremultiply_alpha_restores_premultiplied_colors() {
+        let original = vec![200u8, 100, 50, 128, 10, 240, 90, 200];
+        let mut rgba_data = original.clone();
+
+        premultiply_alpha(&mut rgba_data);
+        unpremultiply_alpha(&mut rgba_data);
+
+        for (restored, expected) in rgba_data.iter().zip(original.iter()) {
+            assert!((*restored as i32 - *expected as i32).abs() <= 2);
+        }
+    }
+
+    #[test]
+    fn test_unpremultiply_alpha_clamps_invalid_channels() {
+        let mut rgba_data = vec![200, 0, 0, 100, 50, 50, 50, 0];
+
+        unpremultiply_alpha(&mut rgba_data);
+
+        assert_eq!(rgba_data, vec![255, 0, 0, 100, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_calculate_png_crc_matches_known_value() {
+        assert_eq!(calculate_png_crc(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn test_png_base64_round_trip_preserves_pixels() {
+        let rgba_data = vec![
+            255, 0, 0, 255, 0, 255, 0, 128, 0, 0, 255, 255, 10, 20, 30, 0,
+        ];
+
+        let encoded = encode_rgba_as_png_base64(&rgba_data, 2, 2).unwrap();
+        let (decoded, width, height) = decode_png_base64_to_rgba(&encoded).unwrap();
+
+        assert_eq!((width, height), (2, 2));
+        assert_eq!(decoded, rgba_data);
+    }
+
+    #[test]
+    fn test_save_image_to_file_writes_timestamped_png_into_new_directory() {
+        let save_location =
+            std::env::temp_dir().join(format!("test-save-image-{}", std::process::id()));
+        std::fs::remove_dir_all(&save_location).ok();
+
+        let saved_path = save_image_to_file(
+            &[255u8; 2 * 3 * 4],
+            2,
+            3,
+            1.0,
+            &save_location.to_string_lossy(),
+            ImageSaveFormat::Png,
+            crate::global_constants::DEFAULT_JPEG_QUALITY,
+        )
+        .unwrap();
+
+        let file_name = std::path::Path::new(&saved_path)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        assert!(file_name.starts_with(SCREENSHOT_FILENAME_PREFIX));
+        assert!(file_name.ends_with(".png"));
+        let saved_image = image::open(&saved_path).unwrap();
+        assert_eq!((saved_image.width(), saved_image.height()), (2, 3));
+        std::fs::remove_dir_all(&save_location).ok();
+    }
+
+    #[test]
+    fn test_save_image_to_file_writes_decodable_file_for_each_format() {
+        let save_location =
+            std::env::temp_dir().join(format!("test-save-formats-{}", std::process::id()));
+        std::fs::remove_dir_all(&save_location).ok();
+
+        for save_format in ImageSaveFormat::ALL {
+            let saved_path = save_image_to_file(
+                &[128u8; 4 * 3 * 4],
+                4,
+                3,
+                1.0,
+                &save_location.to_string_lossy(),
+                save_format,
+                crate::global_constants::DEFAULT_JPEG_QUALITY,
+            )
+            .unwrap();
+
+            assert_eq!(
+                std::path::Path::new(&saved_path)
+                    .extension()
+                    .and_then(|extension| extension.to_str()),
+                Some(save_format.file_extension())
+            );
+            let saved_image = image::open(&saved_path).unwrap();
+            assert_eq!((saved_image.width(), saved_image.height()), (4, 3));
+            std::fs::remove_file(&saved_path).ok();
+        }
+        std::fs::remove_dir_all(&save_location).ok();
+    }
+
+    #[test]
+    fn test_save_image_to_file_as_jpeg_uses_jpg_extension_and_flattens_alpha() {
+        let save_location =
+            std::env::temp_dir().join(format!("test-save-jpeg-{}", std::process::id()));
+        std::fs::remove_dir_all(&save_location).ok();
+
+        let saved_path = save_image_to_file(
+            &[0u8; 8 * 8 * 4],
+            8,
+            8,
+            1.0,
+            &save_location.to_string_lossy(),
+            ImageSaveFormat::Jpeg,
+            crate::global_constants::MAX_JPEG_QUALITY,
+        )
+        .unwrap();
+
+        assert!(saved_path.ends_with(".jpg"));
+        let saved_image = image::open(&saved_path).unwrap().to_rgb8();
+        assert!(saved_image
+            .pixels()
+            .all(|pixel| pixel.0.iter().all(|channel| *channel > 245)));
+        std::fs::remove_dir_all(&save_location).ok();
+    }
+
+    #[test]
+    fn test_save_text_to_file_writes_timestamped_txt() {
+        let save_location =
+            std::env::temp_dir().join(format!("test-save-text-{}", std::process::id()));
+        std::fs::remove_dir_all(&save_location).ok();
+
+        let saved_path =
+            save_text_to_file("line one\nline two", &save_location.to_string_lossy()).unwrap();
+
+        assert!(saved_path.ends_with(TEXT_EXPORT_FILENAME_SUFFIX));
+        assert_eq!(
+            std::fs::read_to_string(&saved_path).unwrap(),
+            "line one\nline two"
+        );
+        std::fs::remove_dir_all(&save_location).ok();
+    }
+
+    #[test]
+    fn test_composite_drawings_blends_translucent_strokes_once() {
+        let white_pixels = vec![255u8; 10 * 10 * 4];
+        let strokes = vec![(
+            vec![(2.0, 5.0), (5.0, 5.0), (8.0, 5.0)],
+            (0.0, 0.0, 0.0, 0.5),
+            4.0,
+        )];
+
+        let composited = composite_drawings_on_image(&white_pixels, 10, 10, &strokes).unwrap();
+
+        let center_index = (5 * 10 + 5) * 4;
+        let shade = composited[center_index];
+        assert!((120..=135).contains(&shade), "unexpected shade {}", shade);
+        assert_eq!(composited[center_index + 3], 255);
+        assert_eq!(&composited[0..4], &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_composite_drawings_flattens_red_horizontal_stroke_clipped_to_bounds() {
+        let white_pixels = vec![255u8; 10 * 10 * 4];
+        let strokes = vec![(vec![(-5.0, 5.0), (15.0, 5.0)], (1.0, 0.0, 0.0, 1.0), 2.0)];
+
+        let composited = composite_drawings_on_image(&white_pixels, 10, 10, &strokes).unwrap();
+
+        assert_eq!(composited.len(), white_pixels.len());
+        for x in 0..10 {
+            let index = (5 * 10 + x) * 4;
+            assert_eq!(&composited[index..index + 4], &[255, 0, 0, 255]);
+        }
+        for y in [0, 1, 2, 8, 9] {
+            let index = (y * 10 + 5) * 4;
+            assert_eq!(&composited[index..index + 4], &[255, 255, 255, 255]);
+        }
+    }
+
+    #[test]
+    fn test_convert_clipboard_image_to_capture_buffer_preserves_dimensions() {
+        let image_data = arboard::ImageData {
+            width: 3,
+            height: 2,
+            bytes: std::borrow::Cow::Owned(vec![255u8; 3 * 2 * 4]),
+        };
+
+        let capture_buffer = convert_clipboard_image_to_capture_buffer(image_data).unwrap();
+
+        assert_eq!((capture_buffer.width, capture_buffer.height), (3, 2));
+        assert_eq!(capture_buffer.raw_data.len(), 3 * 2 * 4);
+    }
+
+    #[test]
+    fn test_convert_clipboard_image_to_capture_buffer_rejects_empty_image() {
+        let image_data = arboard::ImageData {
+            width: 0,
+            height: 0,
+            bytes: std::borrow::Cow::Owned(Vec::new()),
+        };
+
+        assert_eq!(
+            convert_clipboard_image_to_capture_buffer(image_data).unwrap_err(),
+            crate::global_constants::CLIPBOARD_ERROR_NO_IMAGE
+        );
+    }
+
+    #[test]
+    fn test_load_image_file_as_capture_buffer_reads_png_dimensions() {
+        let path = std::env::temp_dir().join(format!(
+            "circle_to_search_dropped_{}.png",
+            std::process::id()
+        ));
+        image::RgbaImage::from_pixel(7, 5, image::Rgba([10, 20, 30, 255]))
+            .save(&path)
+            .unwrap();
+
+        let capture_buffer = load_image_file_as_capture_buffer(&path);
+        let _ = fs::remove_file(&path);
+
+        let capture_buffer = capture_buffer.unwrap();
+        assert_eq!((capture_buffer.width, capture_buffer.height), (7, 5));
+        assert_eq!(&capture_buffer.raw_data[..4], &[10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn test_load_image_file_as_capture_buffer_rejects_non_image_files() {
+        let path = std::env::temp_dir().join(format!(
+            "circle_to_search_dropped_{}.txt",
+            std::process::id()
+        ));
+        fs::write(&path, "not an image").unwrap();
+        let fake_png_path = path.with_extension("png");
+        fs::write(&fake_png_path, "not an image").unwrap();
+
+        let text_result = load_image_file_as_capture_buffer(&path);
+        let fake_png_result = load_image_file_as_capture_buffer(&fake_png_path);
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&fake_png_path);
+
+        assert_eq!(
+            text_result.unwrap_err(),
+            crate::global_constants::DROPPED_FILE_ERROR_UNSUPPORTED
+        );
+        assert_eq!(
+            fake_png_result.unwrap_err(),
+            crate::global_constants::DROPPED_FILE_ERROR_UNSUPPORTED
+        );
+    }
+
+    #[test]
+    fn test_enumerate_cache_paths_returns_expected_locations() {
+        let cache_root = Path::new("/cache-root");
+        let temp_root = Path::new("/temp-root");
+
+        let paths = enumerate_cache_paths(cache_root, temp_root);
+
+        assert_eq!(
+            paths,
+            vec![
+                cache_root.join("circle-to-search-models"),
+                temp_root.join("circle-to-search-tessdata"),
+                temp_root.join("circle_to_search_image.png"),
+                temp_root.join("circle_to_search_clipboard.png"),
+                temp_root.join("circle-to-search-pc.lock"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clear_cached_data_removes_files_and_keeps_held_lock() {
+        let root =
+            std::env::temp_dir().join(format!("circle_to_search_cache_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let paths = enumerate_cache_paths(&root, &root);
+        fs::create_dir_all(paths[0].join("nested")).unwrap();
+        fs::write(paths[0].join("nested").join("model.rten"), [0u8; 100]).unwrap();
+        fs::write(&paths[2], [0u8; 20]).unwrap();
+        fs::write(&paths[4], std::process::id().to_string()).unwrap();
+
+        let freed_bytes = clear_cached_data(&paths);
+        let lock_still_exists = paths[4].exists();
+        let models_removed = !paths[0].exists() && !paths[2].exists();
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(freed_bytes, 120);
+        assert!(models_removed);
+        assert!(lock_still_exists);
+    }
+
+    #[test]
+    fn test_private_browser_command_passes_url_as_single_argument() {
+        let url = "https://example.com/search?q=a&b=\"c\"|d^e";
+
+        let command = build_private_browser_command("browser", &["--private"], url);
+        let args: Vec<&std::ffi::OsStr> = command.get_args().collect();
+
+        assert_eq!(
+            args,
+            vec![std::ffi::OsStr::new("--private"), std::ffi::OsStr::new(url)]
+        );
+        assert!(!command.get_program().to_string_lossy().contains("cmd"));
+    }
+
+    #[test]
+    fn test_format_byte_size_uses_readable_units() {
+        assert_eq!(format_byte_size(512), "512 B");
+        assert_eq!(format_byte_size(1536), "1.5 KB");
+        assert_eq!(format_byte_size(5 * 1024 * 1024), "5.0 MB");
+    }
+
+    #[test]
+    fn test_decode_png_base64_to_rgba_rejects_invalid_data() {
+        assert!(decode_png_base64_to_rgba("not a png").is_err());
+    }
 }
 
 pub fn copy_text_to_clipboard(text: &str) -> Result<(), String> {
@@ -190,6 +709,59 @@ pub fn copy_text_to_clipboard(text: &str) -> Result<(), String> {
     }
 }
 
+pub fn type_text_as_keyboard_input(text: &str) -> Result<(), String> {
+    use enigo::{Enigo, Keyboard, Settings};
+
+    log::info!(
+        "[KEYBOARD_INPUT] Typing {} characters",
+        text.chars().count()
+    );
+
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|error| {
+        let error_message = format!("Failed to initialize keyboard input: {}", error);
+        log::error!("[KEYBOARD_INPUT] {}", error_message);
+        error_message
+    })?;
+
+    enigo.text(text).map_err(|error| {
+        let error_message = format!("Failed to type text: {}", error);
+        log::error!("[KEYBOARD_INPUT] {}", error_message);
+        error_message
+    })
+}
+
+pub fn scroll_mouse_wheel(pointer_position: Option<(i32, i32)>, lines: i32) -> Result<(), String> {
+    use enigo::{Axis, Coordinate, Enigo, Mouse, Settings};
+
+    log::info!(
+        "[MOUSE_INPUT] Scrolling {} lines at {:?}",
+        lines,
+        pointer_position
+    );
+
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|error| {
+        let error_message = format!("Failed to initialize mouse input: {}", error);
+        log::error!("[MOUSE_INPUT] {}", error_message);
+        error_message
+    })?;
+
+    if let Some((pointer_x, pointer_y)) = pointer_position {
+        enigo
+            .move_mouse(pointer_x, pointer_y, Coordinate::Abs)
+            .map_err(|error| {
+                let error_message = format!("Failed to move the pointer: {}", error);
+                log::error!("[MOUSE_INPUT] {}", error_message);
+                error_message
+            })?;
+    }
+
+    enigo.scroll(lines, Axis::Vertical).map_err(|error| {
+        let error_message = format!("Failed to scroll: {}", error);
+        log::error!("[MOUSE_INPUT] {}", error_message);
+        error_message
+    })
+}
+
 pub fn copy_image_to_clipboard(rgba_data: &[u8], width: u32, height: u32) -> Result<(), String> {
     log::info!(
         "[CLIPBOARD] Copying image to clipboard: {}x{}",
@@ -201,7 +773,7 @@ pub fn copy_image_to_clipboard(rgba_data: &[u8], width: u32, height: u32) -> Res
     {
         use std::process::Command;
 
-        let png_data = convert_rgba_to_png(rgba_data, width, height)?;
+        let png_data = encode_rgba_image(rgba_data, width, height, image::ImageFormat::Png)?;
 
         let temp_path = std::env::temp_dir().join(CLIPBOARD_TEMP_IMAGE_FILENAME);
         std::fs::write(&temp_path, &png_data)
@@ -243,12 +815,17 @@ pub fn copy_image_to_clipboard(rgba_data: &[u8], width: u32, height: u32) -> Res
 
     #[cfg(not(target_os = "macos"))]
     {
+        let mut clipboard_rgba = rgba_data.to_vec();
+        if CLIPBOARD_USES_PREMULTIPLIED_ALPHA {
+            premultiply_alpha(&mut clipboard_rgba);
+        }
+
         match arboard::Clipboard::new() {
             Ok(mut clipboard) => {
                 let img_data = arboard::ImageData {
                     width: width as usize,
                     height: height as usize,
-                    bytes: rgba_data.into(),
+                    bytes: clipboard_rgba.into(),
                 };
                 match clipboard.set_image(img_data) {
                     Ok(()) => {
@@ -271,19 +848,228 @@ pub fn copy_image_to_clipboard(rgba_data: &[u8], width: u32, height: u32) -> Res
     }
 }
 
+pub fn read_image_from_clipboard() -> Result<CaptureBuffer, String> {
+    let mut clipboard = arboard::Clipboard::new().map_err(|error| {
+        let error_message = format!("Failed to access clipboard: {}", error);
+        log::error!("[CLIPBOARD] {}", error_message);
+        error_message
+    })?;
+    let image_data = clipboard.get_image().map_err(|error| match error {
+        arboard::Error::ContentNotAvailable => {
+            log::info!("[CLIPBOARD] Clipboard does not contain an image");
+            crate::global_constants::CLIPBOARD_ERROR_NO_IMAGE.to_string()
+        }
+        other_error => {
+            let error_message = format!("Failed to read image from clipboard: {}", other_error);
+            log::error!("[CLIPBOARD] {}", error_message);
+            error_message
+        }
+    })?;
+
+    log::info!(
+        "[CLIPBOARD] Read image from clipboard: {}x{}",
+        image_data.width,
+        image_data.height
+    );
+    convert_clipboard_image_to_capture_buffer(image_data)
+}
+
+fn convert_clipboard_image_to_capture_buffer(
+    image_data: arboard::ImageData,
+) -> Result<CaptureBuffer, String> {
+    let width = u32::try_from(image_data.width).unwrap_or(0);
+    let height = u32::try_from(image_data.height).unwrap_or(0);
+    let mut rgba_data = image_data.bytes.into_owned();
+
+    if width == 0 || height == 0 || rgba_data.len() != width as usize * height as usize * 4 {
+        return Err(crate::global_constants::CLIPBOARD_ERROR_NO_IMAGE.to_string());
+    }
+
+    if CLIPBOARD_USES_PREMULTIPLIED_ALPHA {
+        unpremultiply_alpha(&mut rgba_data);
+    }
+
+    Ok(CaptureBuffer::build_from_raw_data(
+        1.0, width, height, rgba_data,
+    ))
+}
+
+pub fn load_image_file_as_capture_buffer(path: &Path) -> Result<CaptureBuffer, String> {
+    let has_supported_extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| {
+            crate::global_constants::DROPPED_FILE_SUPPORTED_EXTENSIONS
+                .iter()
+                .any(|supported| extension.eq_ignore_ascii_case(supported))
+        })
+        .unwrap_or(false);
+
+    if !has_supported_extension {
+        log::info!("[IMAGE_FILE] Rejected unsupported file: {}", path.display());
+        return Err(crate::global_constants::DROPPED_FILE_ERROR_UNSUPPORTED.to_string());
+    }
+
+    let rgba_image = image::open(path)
+        .map_err(|error| {
+            log::error!(
+                "[IMAGE_FILE] Failed to decode {}: {}",
+                path.display(),
+                error
+            );
+            crate::global_constants::DROPPED_FILE_ERROR_UNSUPPORTED.to_string()
+        })?
+        .to_rgba8();
+    let (width, height) = rgba_image.dimensions();
+
+    if width == 0 || height == 0 {
+        return Err(crate::global_constants::DROPPED_FILE_ERROR_UNSUPPORTED.to_string());
+    }
+
+    log::info!(
+        "[IMAGE_FILE] Loaded {} ({}x{})",
+        path.display(),
+        width,
+        height
+    );
+    Ok(CaptureBuffer::build_from_raw_data(
+        1.0,
+        width,
+        height,
+        rgba_image.into_raw(),
+    ))
+}
+
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+pub fn premultiply_alpha(rgba_data: &mut [u8]) {
+    for pixel in rgba_data.chunks_exact_mut(4) {
+        let alpha = pixel[3] as u32;
+        if alpha == u8::MAX as u32 {
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            *channel = ((*channel as u32 * alpha + 127) / 255) as u8;
+        }
+    }
+}
+
+pub fn unpremultiply_alpha(rgba_data: &mut [u8]) {
+    for pixel in rgba_data.chunks_exact_mut(4) {
+        let alpha = pixel[3] as u32;
+        if alpha == u8::MAX as u32 {
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            *channel = if alpha == 0 {
+                0
+            } else {
+                ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8
+            };
+        }
+    }
+}
+
 pub fn save_image_to_file(
     rgba_data: &[u8],
     width: u32,
     height: u32,
+    scale_factor: f64,
     save_location: &str,
+    save_format: ImageSaveFormat,
+    jpeg_quality: u8,
 ) -> Result<String, String> {
     log::info!(
-        "[FILE_SAVE] Saving image {}x{} to {}",
+        "[FILE_SAVE] Saving image {}x{} ({}) as {} to {}",
         width,
         height,
+        format_dimensions_with_points(width, height, scale_factor),
+        save_format,
+        save_location
+    );
+
+    let save_path = prepare_timestamped_save_path(
+        save_location,
+        SCREENSHOT_FILENAME_PREFIX,
+        &format!(".{}", save_format.file_extension()),
+    )?;
+
+    let image_data = match save_format {
+        ImageSaveFormat::Png => insert_png_dpi_metadata(
+            encode_rgba_image(rgba_data, width, height, image::ImageFormat::Png)?,
+            calculate_dpi_for_scale_factor(scale_factor),
+        ),
+        ImageSaveFormat::Jpeg => convert_rgba_to_jpeg(rgba_data, width, height, jpeg_quality)?,
+        ImageSaveFormat::WebP => {
+            encode_rgba_image(rgba_data, width, height, image::ImageFormat::WebP)?
+        }
+    };
+
+    std::fs::write(&save_path, image_data).map_err(|e| {
+        let error_message = format!("Failed to write file: {}", e);
+        log::error!("[FILE_SAVE] {}", error_message);
+        error_message
+    })?;
+
+    let path_str = save_path.to_string_lossy().to_string();
+    log::info!("[FILE_SAVE] Successfully saved image to {}", path_str);
+    Ok(path_str)
+}
+
+pub fn save_annotation_project_to_file(
+    project_json: &str,
+    save_location: &str,
+) -> Result<String, String> {
+    log::info!("[FILE_SAVE] Saving annotation project to {}", save_location);
+
+    let save_path = prepare_timestamped_save_path(
+        save_location,
+        PROJECT_FILENAME_PREFIX,
+        PROJECT_FILENAME_SUFFIX,
+    )?;
+
+    std::fs::write(&save_path, project_json).map_err(|e| {
+        let error_message = format!("Failed to write file: {}", e);
+        log::error!("[FILE_SAVE] {}", error_message);
+        error_message
+    })?;
+
+    let path_str = save_path.to_string_lossy().to_string();
+    log::info!(
+        "[FILE_SAVE] Successfully saved annotation project to {}",
+        path_str
+    );
+    Ok(path_str)
+}
+
+pub fn save_text_to_file(text: &str, save_location: &str) -> Result<String, String> {
+    log::info!(
+        "[FILE_SAVE] Exporting {} characters of text to {}",
+        text.chars().count(),
         save_location
     );
 
+    let save_path = prepare_timestamped_save_path(
+        save_location,
+        TEXT_EXPORT_FILENAME_PREFIX,
+        TEXT_EXPORT_FILENAME_SUFFIX,
+    )?;
+
+    std::fs::write(&save_path, text).map_err(|e| {
+        let error_message = format!("Failed to write file: {}", e);
+        log::error!("[FILE_SAVE] {}", error_message);
+        error_message
+    })?;
+
+    let path_str = save_path.to_string_lossy().to_string();
+    log::info!("[FILE_SAVE] Successfully exported text to {}", path_str);
+    Ok(path_str)
+}
+
+fn prepare_timestamped_save_path(
+    save_location: &str,
+    filename_prefix: &str,
+    filename_suffix: &str,
+) -> Result<PathBuf, String> {
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|error| {
@@ -292,10 +1078,7 @@ pub fn save_image_to_file(
             error_message
         })?
         .as_secs();
-    let filename = format!(
-        "{}{}{}",
-        SCREENSHOT_FILENAME_PREFIX, timestamp, SCREENSHOT_FILENAME_SUFFIX
-    );
+    let filename = format!("{}{}{}", filename_prefix, timestamp, filename_suffix);
     let save_path = PathBuf::from(save_location).join(&filename);
 
     if let Some(parent) = save_path.parent() {
@@ -306,39 +1089,170 @@ pub fn save_image_to_file(
         })?;
     }
 
-    let png_data = convert_rgba_to_png(rgba_data, width, height)?;
+    Ok(save_path)
+}
 
-    std::fs::write(&save_path, png_data).map_err(|e| {
-        let error_message = format!("Failed to write file: {}", e);
-        log::error!("[FILE_SAVE] {}", error_message);
-        error_message
-    })?;
+pub fn encode_rgba_as_png_base64(
+    rgba_data: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<String, String> {
+    use base64::Engine;
 
-    let path_str = save_path.to_string_lossy().to_string();
-    log::info!("[FILE_SAVE] Successfully saved image to {}", path_str);
-    Ok(path_str)
+    let png_data = encode_rgba_image(rgba_data, width, height, image::ImageFormat::Png)?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(png_data))
 }
 
-fn convert_rgba_to_png(rgba_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
-    let img = image::RgbaImage::from_raw(width, height, rgba_data.to_vec()).ok_or_else(|| {
-        let error_message = crate::global_constants::OCR_RAW_IMAGE_CREATION_FAILED.to_string();
-        log::error!("[IMAGE_CONVERT] {}", error_message);
-        error_message
-    })?;
+pub fn decode_png_base64_to_rgba(encoded_png: &str) -> Result<(Vec<u8>, u32, u32), String> {
+    use base64::Engine;
 
-    let mut png_data = Vec::new();
+    let png_data = base64::engine::general_purpose::STANDARD
+        .decode(encoded_png)
+        .map_err(|e| {
+            let error_message = format!("Failed to decode image data: {}", e);
+            log::error!("[IMAGE_CONVERT] {}", error_message);
+            error_message
+        })?;
+    let rgba_image = image::load_from_memory(&png_data)
+        .map_err(|e| {
+            let error_message = format!("Failed to decode PNG: {}", e);
+            log::error!("[IMAGE_CONVERT] {}", error_message);
+            error_message
+        })?
+        .to_rgba8();
+
+    let (width, height) = rgba_image.dimensions();
+    Ok((rgba_image.into_raw(), width, height))
+}
+
+fn encode_rgba_image(
+    rgba_data: &[u8],
+    width: u32,
+    height: u32,
+    image_format: image::ImageFormat,
+) -> Result<Vec<u8>, String> {
+    let img = build_rgba_image(rgba_data, width, height)?;
+
+    let mut encoded_data = Vec::new();
     image::DynamicImage::ImageRgba8(img)
-        .write_to(
-            &mut std::io::Cursor::new(&mut png_data),
-            image::ImageFormat::Png,
-        )
+        .write_to(&mut std::io::Cursor::new(&mut encoded_data), image_format)
         .map_err(|e| {
-            let error_message = format!("Failed to encode PNG: {}", e);
+            let error_message = format!("Failed to encode {:?}: {}", image_format, e);
             log::error!("[IMAGE_CONVERT] {}", error_message);
             error_message
         })?;
 
-    Ok(png_data)
+    Ok(encoded_data)
+}
+
+fn convert_rgba_to_jpeg(
+    rgba_data: &[u8],
+    width: u32,
+    height: u32,
+    jpeg_quality: u8,
+) -> Result<Vec<u8>, String> {
+    let img = build_rgba_image(rgba_data, width, height)?;
+    let flattened_img = flatten_rgba_onto_white(&img);
+
+    let mut jpeg_data = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(
+        &mut std::io::Cursor::new(&mut jpeg_data),
+        jpeg_quality.clamp(
+            crate::global_constants::MIN_JPEG_QUALITY,
+            crate::global_constants::MAX_JPEG_QUALITY,
+        ),
+    )
+    .encode_image(&flattened_img)
+    .map_err(|e| {
+        let error_message = format!("Failed to encode JPEG: {}", e);
+        log::error!("[IMAGE_CONVERT] {}", error_message);
+        error_message
+    })?;
+
+    Ok(jpeg_data)
+}
+
+fn build_rgba_image(rgba_data: &[u8], width: u32, height: u32) -> Result<image::RgbaImage, String> {
+    image::RgbaImage::from_raw(width, height, rgba_data.to_vec()).ok_or_else(|| {
+        let error_message = crate::global_constants::OCR_RAW_IMAGE_CREATION_FAILED.to_string();
+        log::error!("[IMAGE_CONVERT] {}", error_message);
+        error_message
+    })
+}
+
+fn flatten_rgba_onto_white(img: &image::RgbaImage) -> image::RgbImage {
+    image::RgbImage::from_fn(img.width(), img.height(), |x, y| {
+        let [red, green, blue, alpha] = img.get_pixel(x, y).0;
+        let alpha = alpha as u32;
+        let blend = |channel: u8| {
+            ((channel as u32 * alpha + u8::MAX as u32 * (u8::MAX as u32 - alpha) + 127) / 255) as u8
+        };
+        image::Rgb([blend(red), blend(green), blend(blue)])
+    })
+}
+
+pub fn calculate_dpi_for_scale_factor(scale_factor: f64) -> f64 {
+    if scale_factor.is_finite() && scale_factor > 0.0 {
+        crate::global_constants::BASELINE_SCREEN_DPI * scale_factor
+    } else {
+        crate::global_constants::BASELINE_SCREEN_DPI
+    }
+}
+
+pub fn format_dimensions_with_points(width: u32, height: u32, scale_factor: f64) -> String {
+    let dpi = calculate_dpi_for_scale_factor(scale_factor);
+    let points_scale = crate::global_constants::BASELINE_SCREEN_DPI / dpi;
+
+    format!(
+        "{} × {} px · {} × {} pt @ {} DPI",
+        width,
+        height,
+        (width as f64 * points_scale).round() as u32,
+        (height as f64 * points_scale).round() as u32,
+        dpi.round() as u32
+    )
+}
+
+fn insert_png_dpi_metadata(png_data: Vec<u8>, dpi: f64) -> Vec<u8> {
+    let ihdr_end = PNG_SIGNATURE_LENGTH + PNG_IHDR_CHUNK_LENGTH;
+    let has_ihdr = png_data.len() >= ihdr_end
+        && &png_data[PNG_SIGNATURE_LENGTH + 4..PNG_SIGNATURE_LENGTH + 8] == PNG_CHUNK_TYPE_IHDR;
+
+    if !has_ihdr {
+        log::warn!("[IMAGE_CONVERT] PNG has no IHDR chunk, skipping DPI metadata");
+        return png_data;
+    }
+
+    let pixels_per_meter = (dpi * INCHES_PER_METER).round() as u32;
+    let mut chunk_body = Vec::with_capacity(13);
+    chunk_body.extend_from_slice(PNG_CHUNK_TYPE_PHYS);
+    chunk_body.extend_from_slice(&pixels_per_meter.to_be_bytes());
+    chunk_body.extend_from_slice(&pixels_per_meter.to_be_bytes());
+    chunk_body.push(PNG_PHYS_UNIT_METER);
+
+    let data_length = (chunk_body.len() - PNG_CHUNK_TYPE_PHYS.len()) as u32;
+    let mut output = Vec::with_capacity(png_data.len() + chunk_body.len() + 8);
+    output.extend_from_slice(&png_data[..ihdr_end]);
+    output.extend_from_slice(&data_length.to_be_bytes());
+    output.extend_from_slice(&chunk_body);
+    output.extend_from_slice(&calculate_png_crc(&chunk_body).to_be_bytes());
+    output.extend_from_slice(&png_data[ihdr_end..]);
+    output
+}
+
+fn calculate_png_crc(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
 }
 
 pub fn composite_drawings_on_image(
@@ -347,7 +1261,8 @@ pub fn composite_drawings_on_image(
     height: u32,
     draw_strokes: &[(Vec<(f32, f32)>, (f32, f32, f32, f32), f32)],
 ) -> Result<Vec<u8>, String> {
-    use image::{Rgba, RgbaImage};
+    use image::{Pixel, Rgba, RgbaImage};
+    use std::collections::HashSet;
 
     let mut img = RgbaImage::from_raw(width, height, rgba_data.to_vec())
         .ok_or_else(|| crate::global_constants::OCR_RAW_IMAGE_CREATION_FAILED.to_string())?;
@@ -364,31 +1279,41 @@ pub fn composite_drawings_on_image(
             (a * 255.0) as u8,
         ]);
 
+        let mut covered_pixels = HashSet::new();
         for window in points.windows(2) {
             let (x1, y1) = window[0];
             let (x2, y2) = window[1];
-            draw_thick_line(&mut img, x1, y1, x2, y2, *stroke_width, color);
+            collect_thick_line_pixels(
+                &mut covered_pixels,
+                (width, height),
+                (x1, y1),
+                (x2, y2),
+                *stroke_width,
+            );
+        }
+
+        for (px, py) in covered_pixels {
+            img.get_pixel_mut(px, py).blend(&color);
         }
     }
 
     Ok(img.into_raw())
 }
 
-fn draw_thick_line(
-    img: &mut image::RgbaImage,
-    x0: f32,
-    y0: f32,
-    x1: f32,
-    y1: f32,
+fn collect_thick_line_pixels(
+    covered_pixels: &mut std::collections::HashSet<(u32, u32)>,
+    (width, height): (u32, u32),
+    (x0, y0): (f32, f32),
+    (x1, y1): (f32, f32),
     thickness: f32,
-    color: image::Rgba<u8>,
 ) {
     let dx = x1 - x0;
     let dy = y1 - y0;
     let length = (dx * dx + dy * dy).sqrt();
+    let radius = (thickness / 2.0) as i32;
 
     if length < 0.001 {
-        draw_filled_circle(img, x0 as i32, y0 as i32, (thickness / 2.0) as i32, color);
+        collect_filled_circle_pixels(covered_pixels, width, height, x0 as i32, y0 as i32, radius);
         return;
     }
 
@@ -397,17 +1322,17 @@ fn draw_thick_line(
         let t = i as f32 / steps as f32;
         let cx = x0 + t * dx;
         let cy = y0 + t * dy;
-        draw_filled_circle(img, cx as i32, cy as i32, (thickness / 2.0) as i32, color);
+        collect_filled_circle_pixels(covered_pixels, width, height, cx as i32, cy as i32, radius);
     }
 
-    fn draw_filled_circle(
-        img: &mut image::RgbaImage,
+    fn collect_filled_circle_pixels(
+        covered_pixels: &mut std::collections::HashSet<(u32, u32)>,
+        width: u32,
+        height: u32,
         cx: i32,
         cy: i32,
         radius: i32,
-        color: image::Rgba<u8>,
     ) {
-        let (width, height) = img.dimensions();
         let radius = radius.max(1);
 
         for dy in -radius..=radius {
@@ -416,7 +1341,7 @@ fn draw_thick_line(
                     let px = cx + dx;
                     let py = cy + dy;
                     if px >= 0 && py >= 0 && (px as u32) < width && (py as u32) < height {
-                        img.put_pixel(px as u32, py as u32, color);
+                        covered_pixels.insert((px as u32, py as u32));
                     }
                 }
             }
@@ -469,3 +1394,69 @@ pub fn focus_external_window_by_app_name(app_name: &str) -> Result<(), String> {
         Err(WINDOW_FOCUS_NOT_SUPPORTED.to_string())
     }
 }
+
+pub fn open_search_url(search_url: &str, open_in_private_window: bool) -> Result<(), String> {
+    if open_in_private_window {
+        match open_url_in_private_window(search_url) {
+            Ok(()) => return Ok(()),
+            Err(private_error) => log::warn!(
+                "[PRIVATE_BROWSER] Private window unavailable, opening normally: {}",
+                private_error
+            ),
+        }
+    }
+
+    open::that(search_url).map_err(|error| error.to_string())
+}
+
+pub fn open_url_in_private_window(url: &str) -> Result<(), String> {
+    for (program, args) in PRIVATE_BROWSER_LAUNCHERS {
+        let mut command = build_private_browser_command(program, args, url);
+
+        let launched = if cfg!(not(target_os = "macos")) {
+            command.spawn().map(|_| true)
+        } else {
+            command.status().map(|status| status.success())
+        };
+
+        match launched {
+            Ok(true) => {
+                log::info!(
+                    "[PRIVATE_BROWSER] Opened URL in private window via {} {}",
+                    program,
+                    args.join(" ")
+                );
+                return Ok(());
+            }
+            Ok(false) => {
+                log::debug!("[PRIVATE_BROWSER] {} {} failed", program, args.join(" "));
+            }
+            Err(error) => {
+                log::debug!("[PRIVATE_BROWSER] {} unavailable: {}", program, error);
+            }
+        }
+    }
+
+    Err(PRIVATE_BROWSER_NOT_FOUND.to_string())
+}
+
+fn build_private_browser_command(program: &str, args: &[&str], url: &str) -> std::process::Command {
+    let mut command = std::process::Command::new(resolve_private_browser_program(program));
+    command.args(args).arg(url);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn resolve_private_browser_program(program: &str) -> PathBuf {
+    WINDOWS_PROGRAM_ROOT_ENV_VARS
+        .iter()
+        .filter_map(|env_var| std::env::var_os(env_var))
+        .map(|root| PathBuf::from(root).join(program))
+        .find(|candidate| candidate.exists())
+        .unwrap_or_else(|| PathBuf::from(program))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn resolve_private_browser_program(program: &str) -> PathBuf {
+    PathBuf::from(program)
+}
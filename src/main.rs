@@ -10,7 +10,10 @@ mod presentation;
 use iced::daemon;
 
 fn main() -> iced::Result {
-    env_logger::init();
+    let log_level = core::models::UserSettings::load()
+        .map(|settings| settings.log_level)
+        .unwrap_or_default();
+    infrastructure::file_logger::initialize(log_level.to_level_filter());
 
     log::info!("[MAIN] Starting Circle to Search application");
 
@@ -30,6 +33,7 @@ fn main() -> iced::Result {
         core::orchestrators::app::CircleApp::handle_update,
         core::orchestrators::app::CircleApp::render_view,
     )
+    .title(core::orchestrators::app::CircleApp::window_title)
     .subscription(core::orchestrators::app::CircleApp::handle_subscription)
     .run()
 }
@@ -0,0 +1,376 @@
+use rdev::Key;
+use std::hash::{Hash, Hasher};
+
+use crate::global_constants;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HotkeyCombo {
+    pub requires_ctrl: bool,
+    pub requires_alt: bool,
+    pub requires_shift: bool,
+    pub requires_meta: bool,
+    pub key: Key,
+}
+
+impl HotkeyCombo {
+    pub const DEFAULT_CAPTURE: Self = Self {
+        requires_ctrl: false,
+        requires_alt: true,
+        requires_shift: true,
+        requires_meta: false,
+        key: Key::KeyS,
+    };
+
+    pub fn parse(binding: &str) -> Result<Self, String> {
+        if binding.trim().is_empty() {
+            return Err(global_constants::HOTKEY_VALIDATION_EMPTY.to_string());
+        }
+
+        let mut combo_key = None;
+        let mut requires_ctrl = false;
+        let mut requires_alt = false;
+        let mut requires_shift = false;
+        let mut requires_meta = false;
+
+        for part in binding.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => requires_ctrl = true,
+                "alt" | "option" => requires_alt = true,
+                "shift" => requires_shift = true,
+                "super" | "meta" | "cmd" | "command" | "win" => requires_meta = true,
+                "" => {
+                    return Err(format!(
+                        "{}{}",
+                        global_constants::HOTKEY_VALIDATION_INVALID_PREFIX,
+                        binding
+                    ))
+                }
+                other => {
+                    if combo_key.is_some() {
+                        return Err(format!(
+                            "{}{}",
+                            global_constants::HOTKEY_VALIDATION_MULTIPLE_KEYS_PREFIX,
+                            binding
+                        ));
+                    }
+                    combo_key = Some(parse_key_name(other).ok_or_else(|| {
+                        format!(
+                            "{}{}",
+                            global_constants::HOTKEY_VALIDATION_UNKNOWN_KEY_PREFIX,
+                            part
+                        )
+                    })?);
+                }
+            }
+        }
+
+        let key =
+            combo_key.ok_or_else(|| global_constants::HOTKEY_VALIDATION_MISSING_KEY.to_string())?;
+
+        Ok(Self {
+            requires_ctrl,
+            requires_alt,
+            requires_shift,
+            requires_meta,
+            key,
+        })
+    }
+
+    pub fn matches(&self, key: Key, modifiers: HotkeyModifiers) -> bool {
+        key == self.key
+            && modifiers.is_ctrl_pressed == self.requires_ctrl
+            && modifiers.is_alt_pressed == self.requires_alt
+            && modifiers.is_shift_pressed == self.requires_shift
+            && modifiers.is_meta_pressed == self.requires_meta
+    }
+}
+
+impl Default for HotkeyCombo {
+    fn default() -> Self {
+        Self::DEFAULT_CAPTURE
+    }
+}
+
+impl Hash for HotkeyCombo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.requires_ctrl.hash(state);
+        self.requires_alt.hash(state);
+        self.requires_shift.hash(state);
+        self.requires_meta.hash(state);
+        std::mem::discriminant(&self.key).hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HotkeyModifiers {
+    pub is_ctrl_pressed: bool,
+    pub is_alt_pressed: bool,
+    pub is_shift_pressed: bool,
+    pub is_meta_pressed: bool,
+}
+
+pub fn format_key_press_binding(
+    key: &iced::keyboard::Key,
+    modifiers: iced::keyboard::Modifiers,
+) -> Option<String> {
+    let key_name = format_key_name(key)?;
+    let modifier_names = [
+        (modifiers.control(), "Ctrl"),
+        (modifiers.alt(), "Alt"),
+        (modifiers.shift(), "Shift"),
+        (modifiers.logo(), "Super"),
+    ];
+
+    let mut parts = modifier_names
+        .iter()
+        .filter(|(is_pressed, _)| *is_pressed)
+        .map(|(_, name)| name.to_string())
+        .collect::<Vec<_>>();
+    parts.push(key_name);
+
+    Some(parts.join("+"))
+}
+
+fn format_key_name(key: &iced::keyboard::Key) -> Option<String> {
+    use iced::keyboard::key::Named;
+
+    match key {
+        iced::keyboard::Key::Character(character) => {
+            let mut chars = character.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => {
+                    Some(c.to_ascii_uppercase().to_string())
+                }
+                _ => None,
+            }
+        }
+        iced::keyboard::Key::Named(named) => {
+            let name = match named {
+                Named::F1 => "F1",
+                Named::F2 => "F2",
+                Named::F3 => "F3",
+                Named::F4 => "F4",
+                Named::F5 => "F5",
+                Named::F6 => "F6",
+                Named::F7 => "F7",
+                Named::F8 => "F8",
+                Named::F9 => "F9",
+                Named::F10 => "F10",
+                Named::F11 => "F11",
+                Named::F12 => "F12",
+                Named::Space => "Space",
+                Named::Enter => "Enter",
+                Named::Tab => "Tab",
+                Named::Insert => "Insert",
+                Named::Delete => "Delete",
+                Named::Home => "Home",
+                Named::End => "End",
+                Named::PageUp => "PageUp",
+                Named::PageDown => "PageDown",
+                Named::PrintScreen => "PrintScreen",
+                _ => return None,
+            };
+            Some(name.to_string())
+        }
+        _ => None,
+    }
+}
+
+fn parse_key_name(name: &str) -> Option<Key> {
+    let key = match name {
+        "a" => Key::KeyA,
+        "b" => Key::KeyB,
+        "c" => Key::KeyC,
+        "d" => Key::KeyD,
+        "e" => Key::KeyE,
+        "f" => Key::KeyF,
+        "g" => Key::KeyG,
+        "h" => Key::KeyH,
+        "i" => Key::KeyI,
+        "j" => Key::KeyJ,
+        "k" => Key::KeyK,
+        "l" => Key::KeyL,
+        "m" => Key::KeyM,
+        "n" => Key::KeyN,
+        "o" => Key::KeyO,
+        "p" => Key::KeyP,
+        "q" => Key::KeyQ,
+        "r" => Key::KeyR,
+        "s" => Key::KeyS,
+        "t" => Key::KeyT,
+        "u" => Key::KeyU,
+        "v" => Key::KeyV,
+        "w" => Key::KeyW,
+        "x" => Key::KeyX,
+        "y" => Key::KeyY,
+        "z" => Key::KeyZ,
+        "0" => Key::Num0,
+        "1" => Key::Num1,
+        "2" => Key::Num2,
+        "3" => Key::Num3,
+        "4" => Key::Num4,
+        "5" => Key::Num5,
+        "6" => Key::Num6,
+        "7" => Key::Num7,
+        "8" => Key::Num8,
+        "9" => Key::Num9,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        "space" => Key::Space,
+        "enter" | "return" => Key::Return,
+        "tab" => Key::Tab,
+        "insert" => Key::Insert,
+        "delete" => Key::Delete,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "printscreen" => Key::PrintScreen,
+        _ => return None,
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accepts_valid_combos() {
+        let combo = HotkeyCombo::parse("Ctrl+Shift+C").unwrap();
+        assert!(combo.requires_ctrl && combo.requires_shift);
+        assert!(!combo.requires_alt && !combo.requires_meta);
+        assert_eq!(combo.key, Key::KeyC);
+
+        let combo = HotkeyCombo::parse(" alt + F5 ").unwrap();
+        assert!(combo.requires_alt);
+        assert_eq!(combo.key, Key::F5);
+
+        assert_eq!(HotkeyCombo::parse("Cmd+1").unwrap().key, Key::Num1);
+    }
+
+    #[test]
+    fn test_default_combo_is_alt_shift_s() {
+        let combo = HotkeyCombo::default();
+
+        assert!(combo.requires_alt && combo.requires_shift);
+        assert!(!combo.requires_ctrl);
+        assert_eq!(combo.key, Key::KeyS);
+        assert_eq!(
+            HotkeyCombo::parse(global_constants::DEFAULT_CAPTURE_HOTKEY).unwrap(),
+            combo
+        );
+    }
+
+    #[test]
+    fn test_hash_distinguishes_keys_and_matches_equal_combos() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash_of = |combo: HotkeyCombo| {
+            let mut hasher = DefaultHasher::new();
+            combo.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(
+            hash_of(HotkeyCombo::parse("Alt+Shift+S").unwrap()),
+            hash_of(HotkeyCombo::default())
+        );
+        assert_ne!(
+            hash_of(HotkeyCombo::parse("Alt+Shift+W").unwrap()),
+            hash_of(HotkeyCombo::default())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_combos() {
+        assert_eq!(
+            HotkeyCombo::parse("   ").unwrap_err(),
+            global_constants::HOTKEY_VALIDATION_EMPTY
+        );
+        assert_eq!(
+            HotkeyCombo::parse("Ctrl+Shift").unwrap_err(),
+            global_constants::HOTKEY_VALIDATION_MISSING_KEY
+        );
+        assert!(HotkeyCombo::parse("Ctrl++S").is_err());
+        assert!(HotkeyCombo::parse("Ctrl+S+D").is_err());
+        assert!(HotkeyCombo::parse("Ctrl+Banana").is_err());
+    }
+
+    #[test]
+    fn test_format_key_press_binding_produces_canonical_parseable_string() {
+        let key = iced::keyboard::Key::Character("s".into());
+        let modifiers = iced::keyboard::Modifiers::SHIFT | iced::keyboard::Modifiers::ALT;
+
+        let binding = format_key_press_binding(&key, modifiers).unwrap();
+
+        assert_eq!(binding, "Alt+Shift+S");
+        assert_eq!(
+            HotkeyCombo::parse(&binding).unwrap(),
+            HotkeyCombo::default()
+        );
+    }
+
+    #[test]
+    fn test_format_key_press_binding_orders_all_modifiers() {
+        let key = iced::keyboard::Key::Named(iced::keyboard::key::Named::F5);
+        let modifiers = iced::keyboard::Modifiers::all();
+
+        assert_eq!(
+            format_key_press_binding(&key, modifiers).as_deref(),
+            Some("Ctrl+Alt+Shift+Super+F5")
+        );
+    }
+
+    #[test]
+    fn test_format_key_press_binding_waits_for_non_modifier_key() {
+        let shift = iced::keyboard::Key::Named(iced::keyboard::key::Named::Shift);
+        let control = iced::keyboard::Key::Named(iced::keyboard::key::Named::Control);
+
+        assert_eq!(
+            format_key_press_binding(&shift, iced::keyboard::Modifiers::SHIFT),
+            None
+        );
+        assert_eq!(
+            format_key_press_binding(&control, iced::keyboard::Modifiers::CTRL),
+            None
+        );
+        assert_eq!(
+            format_key_press_binding(
+                &iced::keyboard::Key::Character("é".into()),
+                iced::keyboard::Modifiers::empty()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_matches_requires_exact_modifiers() {
+        let combo = HotkeyCombo::parse("Alt+Shift+S").unwrap();
+        let alt_shift = HotkeyModifiers {
+            is_alt_pressed: true,
+            is_shift_pressed: true,
+            ..HotkeyModifiers::default()
+        };
+
+        assert!(combo.matches(Key::KeyS, alt_shift));
+        assert!(!combo.matches(Key::KeyD, alt_shift));
+        assert!(!combo.matches(
+            Key::KeyS,
+            HotkeyModifiers {
+                is_ctrl_pressed: true,
+                ..alt_shift
+            }
+        ));
+    }
+}
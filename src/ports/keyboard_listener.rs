@@ -1,19 +1,29 @@
 use iced::futures::{channel::mpsc, SinkExt, Stream, StreamExt};
 use iced::stream;
-use rdev::{listen, EventType, Key};
+use rdev::{listen, Button, EventType, Key};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
 
+use super::hotkey_combo::{HotkeyCombo, HotkeyModifiers};
 use crate::global_constants::{
-    LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_ALT_PRESSED, MESSAGE_KEYBOARD_ALT_RELEASED,
-    MESSAGE_KEYBOARD_ESCAPE_PRESSED, MESSAGE_KEYBOARD_HOTKEY_DETECTED,
-    MESSAGE_KEYBOARD_SHIFT_PRESSED, MESSAGE_KEYBOARD_SHIFT_RELEASED,
+    LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_ACTIVE_WINDOW_HOTKEY_DETECTED, MESSAGE_KEYBOARD_ALT_PRESSED,
+    MESSAGE_KEYBOARD_ALT_RELEASED, MESSAGE_KEYBOARD_CAPTURE_PAUSED, MESSAGE_KEYBOARD_CTRL_PRESSED,
+    MESSAGE_KEYBOARD_CTRL_RELEASED, MESSAGE_KEYBOARD_ESCAPE_PRESSED,
+    MESSAGE_KEYBOARD_HOTKEY_DETECTED, MESSAGE_KEYBOARD_META_PRESSED,
+    MESSAGE_KEYBOARD_META_RELEASED, MESSAGE_KEYBOARD_MIDDLE_CLICK_HELD,
+    MESSAGE_KEYBOARD_SHIFT_PRESSED, MESSAGE_KEYBOARD_SHIFT_RELEASED, MIDDLE_CLICK_HOLD_TRIGGER_MS,
 };
 
 #[derive(Debug, Clone)]
 pub enum GlobalKeyboardEvent {
     CaptureHotkeyPressed,
+    ActiveWindowHotkeyPressed,
+    MiddleClickHeld,
     EscapePressed,
 }
 
+static CAPTURE_HOTKEY_ENABLED: AtomicBool = AtomicBool::new(true);
+
 pub struct GlobalKeyboardListener;
 
 impl GlobalKeyboardListener {
@@ -23,7 +33,25 @@ impl GlobalKeyboardListener {
         Self
     }
 
-    pub fn create_event_stream() -> impl Stream<Item = GlobalKeyboardEvent> {
+    pub fn set_capture_hotkey_enabled(enabled: bool) {
+        log::info!(
+            "{} Capture hotkey {}",
+            LOG_TAG_KEYBOARD,
+            if enabled { "resumed" } else { "paused" }
+        );
+        CAPTURE_HOTKEY_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn create_event_stream(
+        hotkey: HotkeyCombo,
+        active_window_hotkey: HotkeyCombo,
+    ) -> impl Stream<Item = GlobalKeyboardEvent> {
+        log::info!(
+            "{} Listening for capture hotkey {:?} and active window hotkey {:?}",
+            LOG_TAG_KEYBOARD,
+            hotkey,
+            active_window_hotkey
+        );
         stream::channel(
             1,
             |mut output_channel: mpsc::Sender<GlobalKeyboardEvent>| async move {
@@ -31,10 +59,15 @@ impl GlobalKeyboardListener {
 
                 Self::spawn_keyboard_listener_thread(keyboard_sender);
 
-                let mut state = KeyboardState::default();
+                let mut state = KeyboardState {
+                    hotkey,
+                    active_window_hotkey: Some(active_window_hotkey),
+                    ..KeyboardState::default()
+                };
 
                 loop {
                     let keyboard_event = keyboard_receiver.select_next_some().await;
+                    state.is_capture_paused = !CAPTURE_HOTKEY_ENABLED.load(Ordering::Relaxed);
                     if let Some(global_event) = state.process_event(keyboard_event) {
                         let _ = output_channel.send(global_event).await;
                     }
@@ -60,8 +93,11 @@ impl GlobalKeyboardListener {
 
 #[derive(Default)]
 struct KeyboardState {
-    is_alt_pressed: bool,
-    is_shift_pressed: bool,
+    hotkey: HotkeyCombo,
+    active_window_hotkey: Option<HotkeyCombo>,
+    modifiers: HotkeyModifiers,
+    middle_button_pressed_at: Option<SystemTime>,
+    is_capture_paused: bool,
 }
 
 impl KeyboardState {
@@ -69,6 +105,13 @@ impl KeyboardState {
         match event.event_type {
             EventType::KeyPress(key) => self.handle_key_press(key),
             EventType::KeyRelease(key) => self.handle_key_release(key),
+            EventType::ButtonPress(Button::Middle) => {
+                self.middle_button_pressed_at = Some(event.time);
+                None
+            }
+            EventType::ButtonRelease(Button::Middle) => {
+                self.handle_middle_button_release(event.time)
+            }
             _ => None,
         }
     }
@@ -77,18 +120,48 @@ impl KeyboardState {
         match key {
             Key::Alt => {
                 log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_ALT_PRESSED);
-                self.is_alt_pressed = true;
+                self.modifiers.is_alt_pressed = true;
                 None
             }
             Key::ShiftLeft | Key::ShiftRight => {
                 log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_SHIFT_PRESSED);
-                self.is_shift_pressed = true;
+                self.modifiers.is_shift_pressed = true;
                 None
             }
-            Key::KeyS if self.is_alt_pressed && self.is_shift_pressed => {
+            Key::ControlLeft | Key::ControlRight => {
+                log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_CTRL_PRESSED);
+                self.modifiers.is_ctrl_pressed = true;
+                None
+            }
+            Key::MetaLeft | Key::MetaRight => {
+                log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_META_PRESSED);
+                self.modifiers.is_meta_pressed = true;
+                None
+            }
+            pressed_key if self.hotkey.matches(pressed_key, self.modifiers) => {
+                if self.is_capture_paused {
+                    log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_CAPTURE_PAUSED);
+                    return None;
+                }
                 log::info!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_HOTKEY_DETECTED);
                 Some(GlobalKeyboardEvent::CaptureHotkeyPressed)
             }
+            pressed_key
+                if self
+                    .active_window_hotkey
+                    .is_some_and(|hotkey| hotkey.matches(pressed_key, self.modifiers)) =>
+            {
+                if self.is_capture_paused {
+                    log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_CAPTURE_PAUSED);
+                    return None;
+                }
+                log::info!(
+                    "{} {}",
+                    LOG_TAG_KEYBOARD,
+                    MESSAGE_KEYBOARD_ACTIVE_WINDOW_HOTKEY_DETECTED
+                );
+                Some(GlobalKeyboardEvent::ActiveWindowHotkeyPressed)
+            }
             Key::Escape => {
                 log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_ESCAPE_PRESSED);
                 Some(GlobalKeyboardEvent::EscapePressed)
@@ -101,14 +174,166 @@ impl KeyboardState {
         match key {
             Key::Alt => {
                 log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_ALT_RELEASED);
-                self.is_alt_pressed = false;
+                self.modifiers.is_alt_pressed = false;
             }
             Key::ShiftLeft | Key::ShiftRight => {
                 log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_SHIFT_RELEASED);
-                self.is_shift_pressed = false;
+                self.modifiers.is_shift_pressed = false;
+            }
+            Key::ControlLeft | Key::ControlRight => {
+                log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_CTRL_RELEASED);
+                self.modifiers.is_ctrl_pressed = false;
+            }
+            Key::MetaLeft | Key::MetaRight => {
+                log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_META_RELEASED);
+                self.modifiers.is_meta_pressed = false;
             }
             _ => {}
         }
         None
     }
+    fn handle_middle_button_release(
+        &mut self,
+        released_at: SystemTime,
+    ) -> Option<GlobalKeyboardEvent> {
+        let pressed_at = self.middle_button_pressed_at.take()?;
+        let held_duration = released_at
+            .duration_since(pressed_at)
+            .unwrap_or(Duration::ZERO);
+
+        if held_duration < Duration::from_millis(MIDDLE_CLICK_HOLD_TRIGGER_MS) {
+            return None;
+        }
+
+        if self.is_capture_paused {
+            log::debug!("{} {}", LOG_TAG_KEYBOARD, MESSAGE_KEYBOARD_CAPTURE_PAUSED);
+            return None;
+        }
+
+        log::info!(
+            "{} {}",
+            LOG_TAG_KEYBOARD,
+            MESSAGE_KEYBOARD_MIDDLE_CLICK_HELD
+        );
+        Some(GlobalKeyboardEvent::MiddleClickHeld)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_middle_button_release_after_hold_triggers_capture() {
+        let pressed_at = SystemTime::UNIX_EPOCH;
+        let mut state = KeyboardState {
+            middle_button_pressed_at: Some(pressed_at),
+            ..KeyboardState::default()
+        };
+
+        let event = state.handle_middle_button_release(
+            pressed_at + Duration::from_millis(MIDDLE_CLICK_HOLD_TRIGGER_MS),
+        );
+
+        assert!(matches!(event, Some(GlobalKeyboardEvent::MiddleClickHeld)));
+        assert!(state.middle_button_pressed_at.is_none());
+    }
+
+    #[test]
+    fn test_quick_middle_click_does_not_trigger_capture() {
+        let pressed_at = SystemTime::UNIX_EPOCH;
+        let mut state = KeyboardState {
+            middle_button_pressed_at: Some(pressed_at),
+            ..KeyboardState::default()
+        };
+
+        let event = state.handle_middle_button_release(pressed_at + Duration::from_millis(50));
+
+        assert!(event.is_none());
+    }
+
+    #[test]
+    fn test_configured_hotkey_triggers_capture() {
+        let mut state = KeyboardState {
+            hotkey: HotkeyCombo::parse("Ctrl+Shift+C").unwrap(),
+            ..KeyboardState::default()
+        };
+
+        assert!(state.handle_key_press(Key::KeyC).is_none());
+        state.handle_key_press(Key::ControlLeft);
+        state.handle_key_press(Key::ShiftRight);
+
+        assert!(matches!(
+            state.handle_key_press(Key::KeyC),
+            Some(GlobalKeyboardEvent::CaptureHotkeyPressed)
+        ));
+
+        state.handle_key_release(Key::ControlLeft);
+        assert!(state.handle_key_press(Key::KeyC).is_none());
+    }
+
+    #[test]
+    fn test_active_window_hotkey_triggers_active_window_capture() {
+        let mut state = KeyboardState {
+            hotkey: HotkeyCombo::parse("Alt+Shift+S").unwrap(),
+            active_window_hotkey: Some(HotkeyCombo::parse("Alt+Shift+W").unwrap()),
+            ..KeyboardState::default()
+        };
+
+        state.handle_key_press(Key::Alt);
+        state.handle_key_press(Key::ShiftLeft);
+
+        assert!(matches!(
+            state.handle_key_press(Key::KeyW),
+            Some(GlobalKeyboardEvent::ActiveWindowHotkeyPressed)
+        ));
+        assert!(matches!(
+            state.handle_key_press(Key::KeyS),
+            Some(GlobalKeyboardEvent::CaptureHotkeyPressed)
+        ));
+
+        state.is_capture_paused = true;
+        assert!(state.handle_key_press(Key::KeyW).is_none());
+    }
+
+    #[test]
+    fn test_paused_listener_suppresses_capture_but_forwards_escape() {
+        let pressed_at = SystemTime::UNIX_EPOCH;
+        let mut state = KeyboardState {
+            hotkey: HotkeyCombo::parse("Ctrl+Shift+C").unwrap(),
+            is_capture_paused: true,
+            ..KeyboardState::default()
+        };
+
+        state.handle_key_press(Key::ControlLeft);
+        state.handle_key_press(Key::ShiftRight);
+        assert!(state.handle_key_press(Key::KeyC).is_none());
+
+        state.middle_button_pressed_at = Some(pressed_at);
+        assert!(state
+            .handle_middle_button_release(
+                pressed_at + Duration::from_millis(MIDDLE_CLICK_HOLD_TRIGGER_MS)
+            )
+            .is_none());
+
+        assert!(matches!(
+            state.handle_key_press(Key::Escape),
+            Some(GlobalKeyboardEvent::EscapePressed)
+        ));
+
+        state.is_capture_paused = false;
+        assert!(matches!(
+            state.handle_key_press(Key::KeyC),
+            Some(GlobalKeyboardEvent::CaptureHotkeyPressed)
+        ));
+    }
+
+    #[test]
+    fn test_middle_button_release_without_press_is_ignored() {
+        let mut state = KeyboardState::default();
+
+        let event = state.handle_middle_button_release(SystemTime::UNIX_EPOCH);
+
+        assert!(event.is_none());
+    }
 }
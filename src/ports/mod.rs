@@ -1,9 +1,11 @@
+mod hotkey_combo;
 mod keyboard_listener;
 mod mouse_position_provider;
 pub mod system_tray;
 mod xcap_screen_capturer;
 
+pub use hotkey_combo::{format_key_press_binding, HotkeyCombo};
 pub use keyboard_listener::{GlobalKeyboardEvent, GlobalKeyboardListener};
 pub use mouse_position_provider::SystemMousePositionProvider;
-pub use system_tray::{SystemTray, TrayEvent};
+pub use system_tray::{RecentCaptureMenuItem, SystemTray, TrayEvent};
 pub use xcap_screen_capturer::XcapScreenCapturer;
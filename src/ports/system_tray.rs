@@ -1,15 +1,32 @@
-use std::sync::OnceLock;
-use tray_icon::menu::{Menu, MenuEvent, MenuId, MenuItem};
-use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+use std::sync::{Mutex, OnceLock};
+use tray_icon::menu::{Menu, MenuEvent, MenuId, MenuItem, Submenu};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder, TrayIconEvent};
 
 use crate::global_constants;
 
 const TRAY_MENU_SHOW_WINDOW: &str = "Show Window";
+const TRAY_MENU_CAPTURE_NOW: &str = "Capture Now";
+const TRAY_MENU_RECENT_CAPTURES: &str = "Recent Captures";
+const TRAY_MENU_NO_RECENT_CAPTURES: &str = "No recent captures";
 const TRAY_MENU_SELECT_WINDOW: &str = "Select Window to Capture...";
+const TRAY_MENU_CAPTURE_ACTIVE_WINDOW: &str = "Capture Active Window";
+const TRAY_MENU_CAPTURE_ALL_MONITORS: &str = "Capture All Monitors";
+const TRAY_MENU_OCR_CLIPBOARD_IMAGE: &str = "OCR Clipboard Image";
+const TRAY_MENU_PAUSE_HOTKEY: &str = "Pause Capture Hotkey";
+const TRAY_MENU_RESUME_HOTKEY: &str = "Resume Capture Hotkey";
+const TRAY_TOOLTIP_HOTKEY_PAUSED_SUFFIX: &str = " (hotkey paused)";
+const TRAY_MENU_HISTORY: &str = "Capture History...";
 const TRAY_MENU_QUIT: &str = "Quit";
 
 static SHOW_WINDOW_ID: OnceLock<MenuId> = OnceLock::new();
+static CAPTURE_NOW_ID: OnceLock<MenuId> = OnceLock::new();
+static RECENT_CAPTURE_IDS: Mutex<Vec<(MenuId, String)>> = Mutex::new(Vec::new());
 static SELECT_WINDOW_ID: OnceLock<MenuId> = OnceLock::new();
+static CAPTURE_ACTIVE_WINDOW_ID: OnceLock<MenuId> = OnceLock::new();
+static CAPTURE_ALL_MONITORS_ID: OnceLock<MenuId> = OnceLock::new();
+static OCR_CLIPBOARD_IMAGE_ID: OnceLock<MenuId> = OnceLock::new();
+static TOGGLE_HOTKEY_ID: OnceLock<MenuId> = OnceLock::new();
+static HISTORY_ID: OnceLock<MenuId> = OnceLock::new();
 static SETTINGS_ID: OnceLock<MenuId> = OnceLock::new();
 static QUIT_ID: OnceLock<MenuId> = OnceLock::new();
 
@@ -17,15 +34,37 @@ pub struct SystemTray {
     _tray_icon: TrayIcon,
     _menu: Menu,
     _show_window_item: MenuItem,
+    _capture_now_item: MenuItem,
+    _recent_captures_menu: Submenu,
+    recent_capture_items: Vec<MenuItem>,
     _select_window_item: MenuItem,
+    _capture_active_window_item: MenuItem,
+    _capture_all_monitors_item: MenuItem,
+    _ocr_clipboard_image_item: MenuItem,
+    toggle_hotkey_item: MenuItem,
+    _history_item: MenuItem,
     _settings_item: MenuItem,
     _quit_item: MenuItem,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentCaptureMenuItem {
+    pub entry_id: String,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum TrayEvent {
     ShowWindow,
+    CaptureNow,
+    OpenRecent(String),
     SelectWindow,
+    PointerEntered,
+    CaptureActiveWindow,
+    CaptureAllMonitors,
+    OcrClipboardImage,
+    ToggleHotkey,
+    OpenHistory,
     OpenSettings,
     Quit,
 }
@@ -42,26 +81,53 @@ impl SystemTray {
         let icon = Icon::from_rgba(icon_rgba.into_raw(), width, height)?;
 
         let menu = Menu::new();
-        let show_window_item = MenuItem::new(TRAY_MENU_SHOW_WINDOW, true, None);
-        let select_window_item = MenuItem::new(TRAY_MENU_SELECT_WINDOW, true, None);
-        let settings_item = MenuItem::new(global_constants::SETTINGS_WINDOW_TITLE, true, None);
+        let show_window_item = MenuItem::new(TRAY_MENU_SHOW_WINDOW, false, None);
+        let capture_now_item = MenuItem::new(TRAY_MENU_CAPTURE_NOW, false, None);
+        let recent_captures_menu = Submenu::new(TRAY_MENU_RECENT_CAPTURES, false);
+        let select_window_item = MenuItem::new(TRAY_MENU_SELECT_WINDOW, false, None);
+        let capture_active_window_item =
+            MenuItem::new(TRAY_MENU_CAPTURE_ACTIVE_WINDOW, false, None);
+        let capture_all_monitors_item = MenuItem::new(TRAY_MENU_CAPTURE_ALL_MONITORS, false, None);
+        let ocr_clipboard_image_item = MenuItem::new(TRAY_MENU_OCR_CLIPBOARD_IMAGE, false, None);
+        let toggle_hotkey_item = MenuItem::new(hotkey_toggle_label(true), false, None);
+        let history_item = MenuItem::new(TRAY_MENU_HISTORY, false, None);
+        let settings_item = MenuItem::new(global_constants::SETTINGS_WINDOW_TITLE, false, None);
         let quit_item = MenuItem::new(TRAY_MENU_QUIT, true, None);
 
         let _ = SHOW_WINDOW_ID.set(show_window_item.id().clone());
+        let _ = CAPTURE_NOW_ID.set(capture_now_item.id().clone());
         let _ = SELECT_WINDOW_ID.set(select_window_item.id().clone());
+        let _ = CAPTURE_ACTIVE_WINDOW_ID.set(capture_active_window_item.id().clone());
+        let _ = CAPTURE_ALL_MONITORS_ID.set(capture_all_monitors_item.id().clone());
+        let _ = OCR_CLIPBOARD_IMAGE_ID.set(ocr_clipboard_image_item.id().clone());
+        let _ = TOGGLE_HOTKEY_ID.set(toggle_hotkey_item.id().clone());
+        let _ = HISTORY_ID.set(history_item.id().clone());
         let _ = SETTINGS_ID.set(settings_item.id().clone());
         let _ = QUIT_ID.set(quit_item.id().clone());
 
         log::info!(
-            "[SYSTEM_TRAY] Menu item IDs - Show: {:?}, SelectWindow: {:?}, Settings: {:?}, Quit: {:?}",
+            "[SYSTEM_TRAY] Menu item IDs - Show: {:?}, CaptureNow: {:?}, SelectWindow: {:?}, CaptureActiveWindow: {:?}, CaptureAllMonitors: {:?}, OcrClipboardImage: {:?}, ToggleHotkey: {:?}, History: {:?}, Settings: {:?}, Quit: {:?}",
             show_window_item.id(),
+            capture_now_item.id(),
             select_window_item.id(),
+            capture_active_window_item.id(),
+            capture_all_monitors_item.id(),
+            ocr_clipboard_image_item.id(),
+            toggle_hotkey_item.id(),
+            history_item.id(),
             settings_item.id(),
             quit_item.id()
         );
 
         menu.append(&show_window_item)?;
+        menu.append(&capture_now_item)?;
+        menu.append(&recent_captures_menu)?;
         menu.append(&select_window_item)?;
+        menu.append(&capture_active_window_item)?;
+        menu.append(&capture_all_monitors_item)?;
+        menu.append(&ocr_clipboard_image_item)?;
+        menu.append(&toggle_hotkey_item)?;
+        menu.append(&history_item)?;
         menu.append(&settings_item)?;
         menu.append(&quit_item)?;
 
@@ -73,14 +139,91 @@ impl SystemTray {
 
         log::info!("[SYSTEM_TRAY] System tray initialized successfully");
 
-        Ok(Self {
+        let mut tray = Self {
             _tray_icon: tray_icon,
             _menu: menu,
             _show_window_item: show_window_item,
+            _capture_now_item: capture_now_item,
+            _recent_captures_menu: recent_captures_menu,
+            recent_capture_items: Vec::new(),
             _select_window_item: select_window_item,
+            _capture_active_window_item: capture_active_window_item,
+            _capture_all_monitors_item: capture_all_monitors_item,
+            _ocr_clipboard_image_item: ocr_clipboard_image_item,
+            toggle_hotkey_item,
+            _history_item: history_item,
             _settings_item: settings_item,
             _quit_item: quit_item,
-        })
+        };
+        tray.set_recent_captures(&[])?;
+
+        Ok(tray)
+    }
+
+    pub fn set_recent_captures(
+        &mut self,
+        captures: &[RecentCaptureMenuItem],
+    ) -> anyhow::Result<()> {
+        log::info!(
+            "[SYSTEM_TRAY] Rebuilding recent captures menu with {} entries",
+            captures.len()
+        );
+
+        for item in self.recent_capture_items.drain(..) {
+            self._recent_captures_menu.remove(&item)?;
+        }
+
+        let mut recent_capture_ids = Vec::new();
+        for row in build_recent_capture_rows(captures) {
+            let item = MenuItem::new(&row.label, row.entry_id.is_some(), None);
+            self._recent_captures_menu.append(&item)?;
+            if let Some(entry_id) = row.entry_id {
+                recent_capture_ids.push((item.id().clone(), entry_id));
+            }
+            self.recent_capture_items.push(item);
+        }
+
+        if let Ok(mut ids) = RECENT_CAPTURE_IDS.lock() {
+            *ids = recent_capture_ids;
+        }
+
+        Ok(())
+    }
+
+    pub fn set_ready(&self) {
+        log::info!("[SYSTEM_TRAY] Enabling menu items after initialization");
+        self._show_window_item.set_enabled(true);
+        self._capture_now_item.set_enabled(true);
+        self._recent_captures_menu.set_enabled(true);
+        self._select_window_item.set_enabled(true);
+        self._capture_active_window_item.set_enabled(true);
+        self._capture_all_monitors_item.set_enabled(true);
+        self._ocr_clipboard_image_item.set_enabled(true);
+        self.toggle_hotkey_item.set_enabled(true);
+        self._history_item.set_enabled(true);
+        self._settings_item.set_enabled(true);
+    }
+
+    pub fn set_hotkey_enabled(&self, enabled: bool) {
+        log::info!(
+            "[SYSTEM_TRAY] Updating hotkey toggle for enabled={}",
+            enabled
+        );
+        self.toggle_hotkey_item
+            .set_text(hotkey_toggle_label(enabled));
+
+        let tooltip = if enabled {
+            global_constants::APPLICATION_TITLE.to_string()
+        } else {
+            format!(
+                "{}{}",
+                global_constants::APPLICATION_TITLE,
+                TRAY_TOOLTIP_HOTKEY_PAUSED_SUFFIX
+            )
+        };
+        if let Err(e) = self._tray_icon.set_tooltip(Some(tooltip)) {
+            log::warn!("[SYSTEM_TRAY] Failed to update tooltip: {}", e);
+        }
     }
 
     pub fn poll_events() -> Option<TrayEvent> {
@@ -88,7 +231,13 @@ impl SystemTray {
             log::info!("[SYSTEM_TRAY] Received menu event: {:?}", event.id);
             return TrayEvent::from_menu_event(&event);
         }
-        None
+
+        let has_pointer_entered = TrayIconEvent::receiver()
+            .try_iter()
+            .fold(false, |has_entered, event| {
+                has_entered || matches!(event, TrayIconEvent::Enter { .. })
+            });
+        has_pointer_entered.then_some(TrayEvent::PointerEntered)
     }
 }
 
@@ -105,6 +254,25 @@ impl TrayEvent {
             return Some(TrayEvent::ShowWindow);
         }
 
+        if CAPTURE_NOW_ID
+            .get()
+            .map(|id| id == event_id)
+            .unwrap_or(false)
+        {
+            log::info!("[SYSTEM_TRAY] Capture Now clicked");
+            return Some(TrayEvent::CaptureNow);
+        }
+
+        let recent_entry_id = RECENT_CAPTURE_IDS.lock().ok().and_then(|ids| {
+            ids.iter()
+                .find(|(id, _)| id == event_id)
+                .map(|(_, entry_id)| entry_id.clone())
+        });
+        if let Some(entry_id) = recent_entry_id {
+            log::info!("[SYSTEM_TRAY] Recent capture {} clicked", entry_id);
+            return Some(TrayEvent::OpenRecent(entry_id));
+        }
+
         if SELECT_WINDOW_ID
             .get()
             .map(|id| id == event_id)
@@ -114,6 +282,47 @@ impl TrayEvent {
             return Some(TrayEvent::SelectWindow);
         }
 
+        if CAPTURE_ACTIVE_WINDOW_ID
+            .get()
+            .map(|id| id == event_id)
+            .unwrap_or(false)
+        {
+            log::info!("[SYSTEM_TRAY] Capture Active Window clicked");
+            return Some(TrayEvent::CaptureActiveWindow);
+        }
+
+        if CAPTURE_ALL_MONITORS_ID
+            .get()
+            .map(|id| id == event_id)
+            .unwrap_or(false)
+        {
+            log::info!("[SYSTEM_TRAY] Capture All Monitors clicked");
+            return Some(TrayEvent::CaptureAllMonitors);
+        }
+
+        if OCR_CLIPBOARD_IMAGE_ID
+            .get()
+            .map(|id| id == event_id)
+            .unwrap_or(false)
+        {
+            log::info!("[SYSTEM_TRAY] OCR Clipboard Image clicked");
+            return Some(TrayEvent::OcrClipboardImage);
+        }
+
+        if TOGGLE_HOTKEY_ID
+            .get()
+            .map(|id| id == event_id)
+            .unwrap_or(false)
+        {
+            log::info!("[SYSTEM_TRAY] Toggle Capture Hotkey clicked");
+            return Some(TrayEvent::ToggleHotkey);
+        }
+
+        if HISTORY_ID.get().map(|id| id == event_id).unwrap_or(false) {
+            log::info!("[SYSTEM_TRAY] Capture History clicked");
+            return Some(TrayEvent::OpenHistory);
+        }
+
         if SETTINGS_ID.get().map(|id| id == event_id).unwrap_or(false) {
             log::info!("[SYSTEM_TRAY] Settings clicked");
             return Some(TrayEvent::OpenSettings);
@@ -129,9 +338,81 @@ impl TrayEvent {
     }
 }
 
+fn hotkey_toggle_label(hotkey_enabled: bool) -> &'static str {
+    if hotkey_enabled {
+        TRAY_MENU_PAUSE_HOTKEY
+    } else {
+        TRAY_MENU_RESUME_HOTKEY
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RecentCaptureRow {
+    label: String,
+    entry_id: Option<String>,
+}
+
+fn build_recent_capture_rows(captures: &[RecentCaptureMenuItem]) -> Vec<RecentCaptureRow> {
+    if captures.is_empty() {
+        return vec![RecentCaptureRow {
+            label: TRAY_MENU_NO_RECENT_CAPTURES.to_string(),
+            entry_id: None,
+        }];
+    }
+
+    captures
+        .iter()
+        .map(|capture| RecentCaptureRow {
+            label: capture.label.clone(),
+            entry_id: Some(capture.entry_id.clone()),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TrayEvent;
+    use super::*;
+
+    #[test]
+    fn test_build_recent_capture_rows_shows_disabled_placeholder_when_empty() {
+        assert_eq!(
+            build_recent_capture_rows(&[]),
+            vec![RecentCaptureRow {
+                label: TRAY_MENU_NO_RECENT_CAPTURES.to_string(),
+                entry_id: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_build_recent_capture_rows_keeps_history_order() {
+        let captures = [
+            RecentCaptureMenuItem {
+                entry_id: "2".to_string(),
+                label: "800×600 · Just now".to_string(),
+            },
+            RecentCaptureMenuItem {
+                entry_id: "1".to_string(),
+                label: "1920×1080 · 5 min ago".to_string(),
+            },
+        ];
+
+        let rows = build_recent_capture_rows(&captures);
+
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.entry_id.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("2"), Some("1")]
+        );
+        assert_eq!(rows[0].label, "800×600 · Just now");
+    }
+
+    #[test]
+    fn test_hotkey_toggle_label_offers_the_opposite_action() {
+        assert_eq!(hotkey_toggle_label(true), TRAY_MENU_PAUSE_HOTKEY);
+        assert_eq!(hotkey_toggle_label(false), TRAY_MENU_RESUME_HOTKEY);
+    }
 
     #[test]
     fn test_tray_event_debug_implements() {
@@ -2,9 +2,12 @@ use anyhow::{Context, Result};
 use iced::widget::image::Handle;
 
 use crate::core::interfaces::ports::ScreenCapturer;
-use crate::core::models::{CaptureBuffer, ScreenRegion, WindowInfo};
+use crate::core::models::{
+    select_monitor_for_point, CaptureBuffer, MonitorGeometry, ScreenRegion, WindowInfo,
+};
 use crate::global_constants::{
-    ERROR_CONTEXT_CAPTURE_MONITOR, ERROR_CONTEXT_SCALE_FACTOR, LOG_TAG_CAPTURE,
+    CAPTURE_ERROR_DISPLAY_PROTECTED, ERROR_CONTEXT_CAPTURE_MONITOR, ERROR_CONTEXT_SCALE_FACTOR,
+    LOG_TAG_CAPTURE,
 };
 
 const THUMBNAIL_MAX_SIZE: u32 = 120;
@@ -12,6 +15,10 @@ const XCAP_ERROR_FAILED_TO_LIST_WINDOWS: &str = "Failed to list windows";
 const XCAP_ERROR_WINDOW_NOT_FOUND_PREFIX: &str = "Window with id ";
 const XCAP_ERROR_WINDOW_NOT_FOUND_SUFFIX: &str = " not found";
 const XCAP_ERROR_FAILED_TO_CAPTURE_WINDOW_PREFIX: &str = "Failed to capture window ";
+const XCAP_ERROR_FAILED_TO_LIST_MONITORS: &str = "Failed to list monitors";
+const XCAP_ERROR_ACTIVE_WINDOW_EMPTY: &str = "Active window capture was empty";
+const PROTECTED_CONTENT_ERROR_KEYWORDS: [&str; 4] =
+    ["protected", "drm", "hdcp", "content protection"];
 
 pub struct XcapScreenCapturer;
 
@@ -22,11 +29,39 @@ impl XcapScreenCapturer {
     }
 
     fn get_monitor_at_position(&self, region: &ScreenRegion) -> Result<xcap::Monitor> {
-        xcap::Monitor::from_point(region.x_position, region.y_position).with_context(|| {
-            format!(
-                "failed to find monitor at ({}, {})",
-                region.x_position, region.y_position
-            )
+        let monitors = xcap::Monitor::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_MONITORS)?;
+        let candidates = monitors
+            .into_iter()
+            .filter_map(|monitor| {
+                let geometry = self.read_monitor_geometry(&monitor)?;
+                Some((monitor, geometry))
+            })
+            .collect::<Vec<_>>();
+        let geometries = candidates
+            .iter()
+            .map(|(_, geometry)| *geometry)
+            .collect::<Vec<_>>();
+        let selected = select_monitor_for_point(&geometries, region.x_position, region.y_position);
+
+        candidates
+            .into_iter()
+            .find(|(_, geometry)| Some(*geometry) == selected)
+            .map(|(monitor, _)| monitor)
+            .with_context(|| {
+                format!(
+                    "failed to find monitor at ({}, {})",
+                    region.x_position, region.y_position
+                )
+            })
+    }
+
+    fn read_monitor_geometry(&self, monitor: &xcap::Monitor) -> Option<MonitorGeometry> {
+        Some(MonitorGeometry {
+            x: monitor.x().ok()?,
+            y: monitor.y().ok()?,
+            width: monitor.width().ok()?,
+            height: monitor.height().ok()?,
+            scale_factor: monitor.scale_factor().unwrap_or(1.0),
         })
     }
 
@@ -45,6 +80,13 @@ impl XcapScreenCapturer {
             .with_context(|| ERROR_CONTEXT_CAPTURE_MONITOR)
     }
 
+    fn capture_monitor(&self, monitor: &xcap::Monitor) -> Result<CaptureBuffer> {
+        let scale_factor = self.extract_scale_factor_from_monitor(monitor)?;
+        let captured_image = self.capture_monitor_image(monitor)?;
+
+        Ok(self.convert_image_to_capture_buffer(captured_image, scale_factor))
+    }
+
     fn convert_image_to_capture_buffer(
         &self,
         image: xcap::image::RgbaImage,
@@ -91,6 +133,23 @@ impl XcapScreenCapturer {
         Some(Handle::from_rgba(new_width, new_height, resized.into_raw()))
     }
 
+    fn capture_xcap_window(&self, window: &xcap::Window, window_id: u32) -> Result<CaptureBuffer> {
+        let captured_image = window.capture_image().with_context(|| {
+            format!(
+                "{}{}",
+                XCAP_ERROR_FAILED_TO_CAPTURE_WINDOW_PREFIX, window_id
+            )
+        })?;
+
+        let monitor = window.current_monitor().ok();
+        let scale_factor = monitor
+            .as_ref()
+            .and_then(|m| m.scale_factor().ok())
+            .unwrap_or(1.0) as f64;
+
+        Ok(self.convert_image_to_capture_buffer(captured_image, scale_factor))
+    }
+
     fn find_window_by_id(&self, window_id: u32) -> Result<xcap::Window> {
         let windows = xcap::Window::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_WINDOWS)?;
 
@@ -120,11 +179,16 @@ impl ScreenCapturer for XcapScreenCapturer {
         );
 
         let monitor_at_position = self.get_monitor_at_position(region)?;
-        let scale_factor = self.extract_scale_factor_from_monitor(&monitor_at_position)?;
-        let captured_image = self.capture_monitor_image(&monitor_at_position)?;
-        let capture_buffer = self.convert_image_to_capture_buffer(captured_image, scale_factor);
-
-        Ok(capture_buffer)
+        self.capture_monitor(&monitor_at_position).map_err(|error| {
+            log::warn!(
+                "{} monitor at ({}, {}) could not be captured: {:#}",
+                LOG_TAG_CAPTURE,
+                region.x_position,
+                region.y_position,
+                error
+            );
+            annotate_protected_content_error(error)
+        })
     }
 
     fn list_capturable_windows(&self) -> Result<Vec<WindowInfo>> {
@@ -196,25 +260,201 @@ impl ScreenCapturer for XcapScreenCapturer {
         log::debug!("{} capturing window with id {}", LOG_TAG_CAPTURE, window_id);
 
         let window = self.find_window_by_id(window_id)?;
-        let captured_image = window.capture_image().with_context(|| {
-            format!(
-                "{}{}",
-                XCAP_ERROR_FAILED_TO_CAPTURE_WINDOW_PREFIX, window_id
-            )
-        })?;
+        self.capture_xcap_window(&window, window_id)
+    }
 
-        let monitor = window.current_monitor().ok();
-        let scale_factor = monitor
-            .as_ref()
-            .and_then(|m| m.scale_factor().ok())
-            .unwrap_or(1.0) as f64;
+    fn capture_active_window(&self) -> Result<Option<CaptureBuffer>> {
+        log::debug!("{} capturing active window", LOG_TAG_CAPTURE);
+
+        let windows = xcap::Window::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_WINDOWS)?;
+        capture_focused_window(
+            &windows,
+            |window| {
+                window.is_focused().unwrap_or(false) && !window.is_minimized().unwrap_or(false)
+            },
+            |window| self.capture_xcap_window(window, window.id().unwrap_or_default()),
+        )
+    }
+
+    fn find_focused_window_id(&self) -> Result<Option<u32>> {
+        let windows = xcap::Window::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_WINDOWS)?;
+        let focused_window_id = windows
+            .iter()
+            .find(|window| {
+                window.is_focused().unwrap_or(false) && !window.is_minimized().unwrap_or(false)
+            })
+            .and_then(|window| window.id().ok());
+
+        log::debug!(
+            "{} focused window id: {:?}",
+            LOG_TAG_CAPTURE,
+            focused_window_id
+        );
+        Ok(focused_window_id)
+    }
+
+    fn is_foreground_window_fullscreen(&self) -> Result<bool> {
+        let windows = xcap::Window::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_WINDOWS)?;
+
+        let Some(focused_window) = windows.into_iter().find(|window| {
+            window.is_focused().unwrap_or(false) && !window.is_minimized().unwrap_or(false)
+        }) else {
+            return Ok(false);
+        };
+
+        let monitor = focused_window
+            .current_monitor()
+            .with_context(|| XCAP_ERROR_FAILED_TO_LIST_MONITORS)?;
+        let window_bounds = (
+            focused_window.x()?,
+            focused_window.y()?,
+            focused_window.width()?,
+            focused_window.height()?,
+        );
+        let monitor_bounds = (
+            monitor.x()?,
+            monitor.y()?,
+            monitor.width()?,
+            monitor.height()?,
+        );
+
+        let is_fullscreen = window_covers_monitor(window_bounds, monitor_bounds);
+        log::debug!(
+            "{} foreground window {} fullscreen",
+            LOG_TAG_CAPTURE,
+            if is_fullscreen { "is" } else { "is not" }
+        );
+        Ok(is_fullscreen)
+    }
 
-        let capture_buffer = self.convert_image_to_capture_buffer(captured_image, scale_factor);
+    fn get_primary_monitor_origin(&self) -> Result<ScreenRegion> {
+        let monitors = xcap::Monitor::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_MONITORS)?;
+        let monitor_origins = monitors
+            .iter()
+            .filter_map(|monitor| {
+                Some((
+                    monitor.x().ok()?,
+                    monitor.y().ok()?,
+                    monitor.is_primary().unwrap_or(false),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let (x, y) = select_primary_monitor_origin(&monitor_origins)
+            .with_context(|| XCAP_ERROR_FAILED_TO_LIST_MONITORS)?;
+        log::debug!("{} primary monitor at ({}, {})", LOG_TAG_CAPTURE, x, y);
+        Ok(ScreenRegion::at_coordinates(x, y))
+    }
+
+    fn list_monitors(&self) -> Result<Vec<MonitorGeometry>> {
+        let monitors = xcap::Monitor::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_MONITORS)?;
+        let geometries = monitors
+            .iter()
+            .filter_map(|monitor| self.read_monitor_geometry(monitor))
+            .collect::<Vec<_>>();
 
-        Ok(capture_buffer)
+        log::debug!("{} found {} monitors", LOG_TAG_CAPTURE, geometries.len());
+        Ok(geometries)
+    }
+
+    fn capture_all_monitors(&self) -> Result<Vec<CaptureBuffer>> {
+        let monitors = xcap::Monitor::all().with_context(|| XCAP_ERROR_FAILED_TO_LIST_MONITORS)?;
+        let capture_buffers =
+            capture_each_available(&monitors, |monitor| self.capture_monitor(monitor));
+
+        log::info!(
+            "{} captured {} of {} monitors",
+            LOG_TAG_CAPTURE,
+            capture_buffers.len(),
+            monitors.len()
+        );
+        Ok(capture_buffers)
+    }
+}
+
+fn select_primary_monitor_origin(monitor_origins: &[(i32, i32, bool)]) -> Option<(i32, i32)> {
+    monitor_origins
+        .iter()
+        .find(|(_, _, is_primary)| *is_primary)
+        .or_else(|| monitor_origins.first())
+        .map(|(x, y, _)| (*x, *y))
+}
+
+fn window_covers_monitor(
+    (window_x, window_y, window_width, window_height): (i32, i32, u32, u32),
+    (monitor_x, monitor_y, monitor_width, monitor_height): (i32, i32, u32, u32),
+) -> bool {
+    let window_right = window_x as i64 + window_width as i64;
+    let window_bottom = window_y as i64 + window_height as i64;
+    let monitor_right = monitor_x as i64 + monitor_width as i64;
+    let monitor_bottom = monitor_y as i64 + monitor_height as i64;
+
+    window_x <= monitor_x
+        && window_y <= monitor_y
+        && window_right >= monitor_right
+        && window_bottom >= monitor_bottom
+}
+
+fn capture_focused_window<T>(
+    windows: &[T],
+    is_focused: impl Fn(&T) -> bool,
+    capture: impl FnOnce(&T) -> Result<CaptureBuffer>,
+) -> Result<Option<CaptureBuffer>> {
+    let Some(focused_window) = windows.iter().find(|window| is_focused(window)) else {
+        return Ok(None);
+    };
+    let capture_buffer = capture(focused_window)?;
+
+    if capture_buffer.width == 0 || capture_buffer.height == 0 {
+        anyhow::bail!(XCAP_ERROR_ACTIVE_WINDOW_EMPTY);
+    }
+
+    Ok(Some(capture_buffer))
+}
+
+fn annotate_protected_content_error(error: anyhow::Error) -> anyhow::Error {
+    let error_text = format!("{:#}", error).to_lowercase();
+    let is_protected_content = PROTECTED_CONTENT_ERROR_KEYWORDS
+        .iter()
+        .any(|keyword| error_text.contains(keyword));
+
+    if is_protected_content {
+        error.context(CAPTURE_ERROR_DISPLAY_PROTECTED)
+    } else {
+        error
     }
 }
 
+fn capture_each_available<T>(
+    candidates: &[T],
+    mut capture: impl FnMut(&T) -> Result<CaptureBuffer>,
+) -> Vec<CaptureBuffer> {
+    candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| match capture(candidate) {
+            Ok(capture_buffer) if capture_buffer.width == 0 || capture_buffer.height == 0 => {
+                log::warn!(
+                    "{} skipping monitor {} with zero area",
+                    LOG_TAG_CAPTURE,
+                    index
+                );
+                None
+            }
+            Ok(capture_buffer) => Some(capture_buffer),
+            Err(error) => {
+                log::warn!(
+                    "{} skipping monitor {}: {:#}",
+                    LOG_TAG_CAPTURE,
+                    index,
+                    error
+                );
+                None
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,4 +493,141 @@ mod tests {
 
         assert_eq!(buffer._scale_factor, 1.5);
     }
+
+    #[test]
+    fn test_annotate_protected_content_error_flags_drm_protected_displays() {
+        let error = anyhow::anyhow!("HDCP protected output").context(ERROR_CONTEXT_CAPTURE_MONITOR);
+
+        let annotated = annotate_protected_content_error(error);
+
+        assert_eq!(annotated.to_string(), CAPTURE_ERROR_DISPLAY_PROTECTED);
+        assert!(format!("{:#}", annotated).contains("HDCP protected output"));
+    }
+
+    #[test]
+    fn test_annotate_protected_content_error_keeps_other_failures_unchanged() {
+        let error = anyhow::anyhow!("PipeWire portal request was denied")
+            .context(ERROR_CONTEXT_CAPTURE_MONITOR);
+
+        let annotated = annotate_protected_content_error(error);
+
+        assert_eq!(annotated.to_string(), ERROR_CONTEXT_CAPTURE_MONITOR);
+        assert!(!format!("{:#}", annotated).contains(CAPTURE_ERROR_DISPLAY_PROTECTED));
+    }
+
+    #[test]
+    fn test_capture_each_available_skips_failed_and_empty_monitors() {
+        let candidates = [(10, 10), (0, 0), (20, 5), (30, 30)];
+
+        let capture_buffers = capture_each_available(&candidates, |(width, height)| {
+            if *width == 20 {
+                anyhow::bail!("monitor is protected")
+            }
+            let raw_data = vec![0u8; (width * height * 4) as usize];
+            Ok(CaptureBuffer::build_from_raw_data(
+                1.0, *width, *height, raw_data,
+            ))
+        });
+
+        let dimensions = capture_buffers
+            .iter()
+            .map(|buffer| (buffer.width, buffer.height))
+            .collect::<Vec<_>>();
+        assert_eq!(dimensions, vec![(10, 10), (30, 30)]);
+    }
+
+    struct MockWindow {
+        is_focused: bool,
+        width: u32,
+        height: u32,
+    }
+
+    fn capture_mock_window(window: &MockWindow) -> Result<CaptureBuffer> {
+        let raw_data = vec![255u8; (window.width * window.height * 4) as usize];
+        Ok(CaptureBuffer::build_from_raw_data(
+            1.0,
+            window.width,
+            window.height,
+            raw_data,
+        ))
+    }
+
+    #[test]
+    fn test_capture_focused_window_returns_non_empty_buffer() {
+        let windows = [
+            MockWindow {
+                is_focused: false,
+                width: 10,
+                height: 10,
+            },
+            MockWindow {
+                is_focused: true,
+                width: 40,
+                height: 20,
+            },
+        ];
+
+        let buffer =
+            capture_focused_window(&windows, |window| window.is_focused, capture_mock_window)
+                .unwrap()
+                .unwrap();
+
+        assert_eq!((buffer.width, buffer.height), (40, 20));
+        assert!(!buffer.raw_data.is_empty());
+    }
+
+    #[test]
+    fn test_capture_focused_window_reports_missing_window_separately_from_empty_capture() {
+        let unfocused = [MockWindow {
+            is_focused: false,
+            width: 10,
+            height: 10,
+        }];
+        let empty = [MockWindow {
+            is_focused: true,
+            width: 0,
+            height: 0,
+        }];
+
+        let missing =
+            capture_focused_window(&unfocused, |window| window.is_focused, capture_mock_window);
+        let blank = capture_focused_window(&empty, |window| window.is_focused, capture_mock_window);
+
+        assert!(missing.unwrap().is_none());
+        assert_eq!(
+            blank.unwrap_err().to_string(),
+            XCAP_ERROR_ACTIVE_WINDOW_EMPTY
+        );
+    }
+
+    #[test]
+    fn test_select_primary_monitor_origin_prefers_primary_flag() {
+        let monitors = [(-1920, 0, false), (0, 0, true), (1920, 0, false)];
+
+        assert_eq!(select_primary_monitor_origin(&monitors), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_select_primary_monitor_origin_falls_back_to_first_monitor() {
+        let monitors = [(1920, 0, false), (0, 0, false)];
+
+        assert_eq!(select_primary_monitor_origin(&monitors), Some((1920, 0)));
+        assert_eq!(select_primary_monitor_origin(&[]), None);
+    }
+
+    #[test]
+    fn test_window_covers_monitor_detects_fullscreen_window() {
+        assert!(window_covers_monitor(
+            (1920, 0, 2560, 1440),
+            (1920, 0, 2560, 1440)
+        ));
+    }
+
+    #[test]
+    fn test_window_covers_monitor_rejects_maximized_window_above_taskbar() {
+        assert!(!window_covers_monitor(
+            (-8, -8, 1936, 1056),
+            (0, 0, 1920, 1080)
+        ));
+    }
 }
@@ -1,21 +1,10 @@
-use iced::widget::button;
-use iced::{Background, Border, Color, Shadow, Theme};
+use iced::widget::{button, container};
+use iced::{Background, Border, Color, Shadow, Theme, Vector};
 
 use crate::core::models::ThemeMode;
 
-pub fn get_theme(mode: &ThemeMode) -> Theme {
-    match mode {
-        ThemeMode::Dark => Theme::custom(
-            "Dark".to_string(),
-            iced::theme::Palette {
-                background: Color::from_rgb(0.0, 0.0, 0.0),
-                text: Color::from_rgb(1.0, 1.0, 1.0),
-                primary: Color::from_rgb(0.4, 0.6, 1.0),
-                success: Color::from_rgb(0.2, 0.9, 0.4),
-                danger: Color::from_rgb(1.0, 0.3, 0.3),
-                warning: Color::from_rgb(1.0, 0.7, 0.0),
-            },
-        ),
+pub fn get_theme(mode: &ThemeMode, system_theme: Option<&ThemeMode>) -> Theme {
+    match resolve_theme_mode(mode, system_theme == Some(&ThemeMode::Light)) {
         ThemeMode::Light => Theme::custom(
             "Light".to_string(),
             iced::theme::Palette {
@@ -27,6 +16,25 @@ pub fn get_theme(mode: &ThemeMode) -> Theme {
                 warning: Color::from_rgb(0.9, 0.6, 0.0),
             },
         ),
+        ThemeMode::Dark | ThemeMode::System => Theme::custom(
+            "Dark".to_string(),
+            iced::theme::Palette {
+                background: Color::from_rgb(0.0, 0.0, 0.0),
+                text: Color::from_rgb(1.0, 1.0, 1.0),
+                primary: Color::from_rgb(0.4, 0.6, 1.0),
+                success: Color::from_rgb(0.2, 0.9, 0.4),
+                danger: Color::from_rgb(1.0, 0.3, 0.3),
+                warning: Color::from_rgb(1.0, 0.7, 0.0),
+            },
+        ),
+    }
+}
+
+fn resolve_theme_mode(mode: &ThemeMode, system_prefers_light: bool) -> ThemeMode {
+    match mode {
+        ThemeMode::System if system_prefers_light => ThemeMode::Light,
+        ThemeMode::System => ThemeMode::Dark,
+        concrete_mode => concrete_mode.clone(),
     }
 }
 
@@ -130,6 +138,24 @@ pub fn purple_button_style(_theme: &Theme, status: button::Status) -> button::St
     }
 }
 
+pub fn toast_container_style(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgba(0.1, 0.1, 0.1, 0.9))),
+        border: Border {
+            color: Color::from_rgba(0.4, 0.4, 0.4, 0.5),
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 6.0,
+        },
+        text_color: None,
+        snap: false,
+    }
+}
+
 pub fn secondary_button_style(_theme: &Theme, status: button::Status) -> button::Style {
     match status {
         button::Status::Active => button::Style {
@@ -238,7 +264,7 @@ mod tests {
 
     #[test]
     fn test_get_theme_dark_mode() {
-        let theme = get_theme(&ThemeMode::Dark);
+        let theme = get_theme(&ThemeMode::Dark, Some(&ThemeMode::Light));
         let palette = theme.palette();
 
         assert_eq!(palette.background, Color::from_rgb(0.0, 0.0, 0.0));
@@ -247,13 +273,46 @@ mod tests {
 
     #[test]
     fn test_get_theme_light_mode() {
-        let theme = get_theme(&ThemeMode::Light);
+        let theme = get_theme(&ThemeMode::Light, Some(&ThemeMode::Dark));
         let palette = theme.palette();
 
         assert_eq!(palette.background, Color::from_rgb(0.95, 0.95, 0.97));
         assert_eq!(palette.text, Color::from_rgb(0.1, 0.1, 0.1));
     }
 
+    #[test]
+    fn test_system_mode_resolves_to_detected_palette() {
+        let dark_palette = get_theme(&ThemeMode::Dark, None).palette();
+        let light_palette = get_theme(&ThemeMode::Light, None).palette();
+
+        assert_eq!(
+            get_theme(&ThemeMode::System, Some(&ThemeMode::Light)).palette(),
+            light_palette
+        );
+        assert_eq!(
+            get_theme(&ThemeMode::System, Some(&ThemeMode::Dark)).palette(),
+            dark_palette
+        );
+        assert_eq!(get_theme(&ThemeMode::System, None).palette(), dark_palette);
+    }
+
+    #[test]
+    fn test_resolve_theme_mode_follows_system_preference() {
+        assert_eq!(
+            resolve_theme_mode(&ThemeMode::System, true),
+            ThemeMode::Light
+        );
+        assert_eq!(
+            resolve_theme_mode(&ThemeMode::System, false),
+            ThemeMode::Dark
+        );
+        assert_eq!(resolve_theme_mode(&ThemeMode::Dark, true), ThemeMode::Dark);
+        assert_eq!(
+            resolve_theme_mode(&ThemeMode::Light, false),
+            ThemeMode::Light
+        );
+    }
+
     #[test]
     fn test_primary_button_style_active_has_green_background() {
         let theme = Theme::Dark;
@@ -0,0 +1,226 @@
+use iced::widget::image::Handle;
+use iced::widget::{button, column, container, image, row, scrollable, text, Space};
+use iced::{Alignment, Border, Color, Element, Length, Shadow};
+
+use crate::core::models::CaptureHistoryEntry;
+
+pub type CaptureHistoryItem = (CaptureHistoryEntry, Option<Handle>);
+
+pub struct CaptureHistoryView {
+    entries: Vec<CaptureHistoryItem>,
+    is_loading: bool,
+    now_unix_ms: u128,
+}
+
+#[derive(Debug, Clone)]
+pub enum CaptureHistoryMessage {
+    Reopen(String),
+    Close,
+}
+
+impl CaptureHistoryView {
+    pub fn build() -> Self {
+        log::info!("[CAPTURE_HISTORY] Creating view");
+        Self {
+            entries: Vec::new(),
+            is_loading: true,
+            now_unix_ms: 0,
+        }
+    }
+
+    pub fn set_entries(&mut self, entries: Vec<CaptureHistoryItem>, now_unix_ms: u128) {
+        self.entries = entries;
+        self.now_unix_ms = now_unix_ms;
+        self.is_loading = false;
+    }
+
+    pub fn render_ui(&self) -> Element<'_, CaptureHistoryMessage> {
+        let title = text("Capture History")
+            .size(24)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(Color::WHITE),
+            });
+
+        let subtitle = text("Reopen a recent capture in the results window")
+            .size(14)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(0.7, 0.7, 0.7, 1.0)),
+            });
+
+        let header = column![title, subtitle]
+            .spacing(8)
+            .align_x(Alignment::Center);
+
+        let entry_list: Element<'_, CaptureHistoryMessage> = if self.is_loading {
+            render_placeholder("Loading history...")
+        } else if self.entries.is_empty() {
+            render_placeholder("No captures yet")
+        } else {
+            let entry_items: Vec<Element<'_, CaptureHistoryMessage>> = self
+                .entries
+                .iter()
+                .map(|(entry, thumbnail)| self.render_entry_item(entry, thumbnail.as_ref()))
+                .collect();
+
+            scrollable(column(entry_items).spacing(8).padding(4))
+                .height(Length::FillPortion(1))
+                .into()
+        };
+
+        let close_btn = button(text("Close").size(14))
+            .padding([10, 20])
+            .style(|_theme: &iced::Theme, status| {
+                let bg = match status {
+                    button::Status::Hovered => Color::from_rgba(0.3, 0.3, 0.3, 0.9),
+                    button::Status::Pressed => Color::from_rgba(0.2, 0.2, 0.2, 0.9),
+                    _ => Color::from_rgba(0.2, 0.2, 0.2, 0.8),
+                };
+                button::Style {
+                    background: Some(iced::Background::Color(bg)),
+                    text_color: Color::WHITE,
+                    border: Border {
+                        color: Color::from_rgba(0.4, 0.4, 0.4, 0.5),
+                        width: 1.0,
+                        radius: 6.0.into(),
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                }
+            })
+            .on_press(CaptureHistoryMessage::Close);
+
+        let bottom_row = row![Space::new().width(Length::Fill), close_btn].spacing(12);
+
+        let content = column![header, entry_list, bottom_row]
+            .spacing(16)
+            .padding(24)
+            .width(Length::Fill)
+            .height(Length::Fill);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_theme| iced::widget::container::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.1, 0.1, 0.12))),
+                border: Border::default(),
+                shadow: Shadow::default(),
+                text_color: None,
+                snap: false,
+            })
+            .into()
+    }
+
+    fn render_entry_item(
+        &self,
+        entry: &CaptureHistoryEntry,
+        thumbnail: Option<&Handle>,
+    ) -> Element<'_, CaptureHistoryMessage> {
+        let thumbnail_element: Element<'_, CaptureHistoryMessage> = match thumbnail {
+            Some(handle) => image(handle.clone())
+                .width(Length::Fixed(80.0))
+                .height(Length::Fixed(60.0))
+                .into(),
+            None => container(text("📷").size(24))
+                .width(Length::Fixed(80.0))
+                .height(Length::Fixed(60.0))
+                .center_x(Length::Fixed(80.0))
+                .center_y(Length::Fixed(60.0))
+                .into(),
+        };
+
+        let entry_info = column![
+            text(format!("{}x{}", entry.width, entry.height))
+                .size(14)
+                .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(Color::WHITE),
+                }),
+            text(format_capture_age(
+                self.now_unix_ms,
+                entry.captured_at_unix_ms
+            ))
+            .size(11)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(0.5, 0.5, 0.5, 1.0)),
+            }),
+        ]
+        .spacing(4);
+
+        let content = row![thumbnail_element, entry_info]
+            .spacing(12)
+            .align_y(Alignment::Center);
+
+        button(content)
+            .width(Length::Fill)
+            .padding([12, 16])
+            .style(|_theme: &iced::Theme, status| {
+                let bg = match status {
+                    button::Status::Hovered => Color::from_rgba(0.2, 0.2, 0.25, 0.9),
+                    button::Status::Pressed => Color::from_rgba(0.15, 0.15, 0.2, 0.9),
+                    _ => Color::from_rgba(0.15, 0.15, 0.18, 0.8),
+                };
+                button::Style {
+                    background: Some(iced::Background::Color(bg)),
+                    text_color: Color::WHITE,
+                    border: Border {
+                        color: Color::from_rgba(0.3, 0.3, 0.3, 0.4),
+                        width: 1.0,
+                        radius: 8.0.into(),
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                }
+            })
+            .on_press(CaptureHistoryMessage::Reopen(entry.id.clone()))
+            .into()
+    }
+}
+
+fn render_placeholder(label: &str) -> Element<'_, CaptureHistoryMessage> {
+    container(
+        text(label)
+            .size(16)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(0.6, 0.6, 0.6, 1.0)),
+            }),
+    )
+    .padding(40)
+    .center_x(Length::Fill)
+    .into()
+}
+
+pub fn format_capture_age(now_unix_ms: u128, captured_at_unix_ms: u128) -> String {
+    let elapsed_seconds = now_unix_ms.saturating_sub(captured_at_unix_ms) / 1000;
+
+    match elapsed_seconds {
+        0..=59 => "Just now".to_string(),
+        60..=3599 => format!("{} min ago", elapsed_seconds / 60),
+        3600..=86_399 => format!("{} h ago", elapsed_seconds / 3600),
+        _ => format!("{} d ago", elapsed_seconds / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_capture_age_buckets_elapsed_time() {
+        let now = 10 * 86_400 * 1000;
+
+        assert_eq!(format_capture_age(now, now - 5_000), "Just now");
+        assert_eq!(format_capture_age(now, now - 125_000), "2 min ago");
+        assert_eq!(format_capture_age(now, now - 7_200_000), "2 h ago");
+        assert_eq!(format_capture_age(now, now - 3 * 86_400_000), "3 d ago");
+        assert_eq!(format_capture_age(now, now + 1_000), "Just now");
+    }
+
+    #[test]
+    fn test_set_entries_clears_loading_state() {
+        let mut view = CaptureHistoryView::build();
+
+        view.set_entries(Vec::new(), 0);
+
+        assert!(!view.is_loading);
+        assert!(view.entries.is_empty());
+    }
+}
@@ -1,9 +1,25 @@
 use iced::{Point, Rectangle, Size};
 
 mod canvas_program;
+mod edge_snapping;
 mod ui;
 
+use edge_snapping::EdgeMap;
+
 use crate::core::models::CaptureBuffer;
+use crate::infrastructure::utils::format_dimensions_with_points;
+use crate::presentation::image_transform::ImageTransform;
+
+const MAGNIFIER_DEFAULT_ZOOM: f32 = 8.0;
+const MAGNIFIER_MIN_ZOOM: f32 = 2.0;
+const MAGNIFIER_MAX_ZOOM: f32 = 16.0;
+const MAGNIFIER_ZOOM_STEP: f32 = 1.0;
+const MAGNIFIER_DEFAULT_SIZE: f32 = 120.0;
+const MAGNIFIER_MIN_SIZE: f32 = 80.0;
+const MAGNIFIER_MAX_SIZE: f32 = 240.0;
+const MAGNIFIER_SIZE_STEP: f32 = 20.0;
+const MAGNIFIER_CURSOR_OFFSET: f32 = 24.0;
+const SNAP_TO_EDGES_THRESHOLD: f32 = 8.0;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DrawMode {
@@ -11,15 +27,38 @@ pub enum DrawMode {
     Freeform,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DimensionUnits {
+    PixelsAndPoints,
+    Pixels,
+    Points,
+}
+
+impl DimensionUnits {
+    fn next(self) -> Self {
+        match self {
+            DimensionUnits::PixelsAndPoints => DimensionUnits::Pixels,
+            DimensionUnits::Pixels => DimensionUnits::Points,
+            DimensionUnits::Points => DimensionUnits::PixelsAndPoints,
+        }
+    }
+}
+
 pub struct CaptureView {
     capture_buffer: CaptureBuffer,
     selection_start: Option<Point>,
     selection_current: Option<Point>,
     is_selecting: bool,
-    viewer_bounds: std::cell::Cell<Rectangle>,
+    image_transform: std::cell::Cell<Option<ImageTransform>>,
     draw_mode: DrawMode,
     freeform_points: Vec<Point>,
     is_shape_closed: bool,
+    magnifier_enabled: bool,
+    magnifier_zoom: f32,
+    magnifier_size: f32,
+    dimension_units: DimensionUnits,
+    snap_to_edges: bool,
+    edge_map: std::cell::OnceCell<EdgeMap>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,10 +67,16 @@ pub enum CaptureViewMessage {
     MouseMoved(Point),
     MouseReleased,
     ConfirmSelection,
+    SearchSelection,
+    ScrollCaptureSelection,
     #[allow(dead_code)]
     CancelRequested,
     SetDrawMode(DrawMode),
     SelectWindow,
+    ToggleMagnifier,
+    MagnifierScrolled(f32, bool),
+    CycleDimensionUnits,
+    SnapToEdgesChanged(bool),
 }
 
 impl CaptureView {
@@ -42,17 +87,28 @@ impl CaptureView {
             selection_start: None,
             selection_current: None,
             is_selecting: false,
-            viewer_bounds: std::cell::Cell::new(Rectangle::new(Point::ORIGIN, Size::ZERO)),
+            image_transform: std::cell::Cell::new(None),
             draw_mode: DrawMode::Rectangle,
             freeform_points: Vec::new(),
             is_shape_closed: false,
+            magnifier_enabled: false,
+            magnifier_zoom: MAGNIFIER_DEFAULT_ZOOM,
+            magnifier_size: MAGNIFIER_DEFAULT_SIZE,
+            dimension_units: DimensionUnits::PixelsAndPoints,
+            snap_to_edges: false,
+            edge_map: std::cell::OnceCell::new(),
         }
     }
 
+    pub fn set_magnifier_enabled(&mut self, enabled: bool) {
+        self.magnifier_enabled = enabled;
+    }
+
     pub fn update(&mut self, message: CaptureViewMessage) {
         match message {
             CaptureViewMessage::MousePressed(point) => match self.draw_mode {
                 DrawMode::Rectangle => {
+                    let point = self.snap_selection_point(point);
                     self.selection_start = Some(point);
                     self.selection_current = Some(point);
                     self.is_selecting = true;
@@ -70,7 +126,7 @@ impl CaptureView {
                 if self.is_selecting {
                     match self.draw_mode {
                         DrawMode::Rectangle => {
-                            self.selection_current = Some(point);
+                            self.selection_current = Some(self.snap_selection_point(point));
                         }
                         DrawMode::Freeform => {
                             self.freeform_points.push(point);
@@ -122,10 +178,87 @@ impl CaptureView {
                 self.freeform_points.clear();
                 self.is_shape_closed = false;
             }
-            CaptureViewMessage::SelectWindow => {}
+            CaptureViewMessage::SearchSelection
+            | CaptureViewMessage::ScrollCaptureSelection
+            | CaptureViewMessage::SelectWindow => {}
+            CaptureViewMessage::ToggleMagnifier => {
+                self.magnifier_enabled = !self.magnifier_enabled;
+                log::debug!(
+                    "[CAPTURE_VIEW] Magnifier toggled: {}",
+                    self.magnifier_enabled
+                );
+            }
+            CaptureViewMessage::MagnifierScrolled(scroll_steps, adjust_size) => {
+                self.handle_magnifier_scrolled(scroll_steps, adjust_size)
+            }
+            CaptureViewMessage::CycleDimensionUnits => {
+                self.dimension_units = self.dimension_units.next();
+                log::debug!("[CAPTURE_VIEW] Dimension units: {:?}", self.dimension_units);
+            }
+            CaptureViewMessage::SnapToEdgesChanged(enabled) => {
+                self.snap_to_edges = enabled;
+            }
         }
     }
 
+    fn snap_selection_point(&self, point: Point) -> Point {
+        if !self.snap_to_edges {
+            return point;
+        }
+        let Some(transform) = self.image_transform.get() else {
+            return point;
+        };
+
+        let edge_map = self
+            .edge_map
+            .get_or_init(|| EdgeMap::detect(&self.capture_buffer));
+        let snapped = edge_map.snap_point(
+            transform.to_image(point),
+            SNAP_TO_EDGES_THRESHOLD / transform.scale_x(),
+            SNAP_TO_EDGES_THRESHOLD / transform.scale_y(),
+        );
+        transform.to_screen(snapped)
+    }
+
+    fn build_dimension_label(&self, pixel_width: u32, pixel_height: u32) -> String {
+        let scale_factor = self.capture_buffer._scale_factor;
+
+        match self.dimension_units {
+            DimensionUnits::PixelsAndPoints => {
+                format_dimensions_with_points(pixel_width, pixel_height, scale_factor)
+            }
+            DimensionUnits::Pixels => format!("{} × {} px", pixel_width, pixel_height),
+            DimensionUnits::Points => {
+                let scale_factor = self.capture_buffer.effective_scale_factor();
+                format!(
+                    "{} × {} pt",
+                    (pixel_width as f64 / scale_factor).round() as u32,
+                    (pixel_height as f64 / scale_factor).round() as u32
+                )
+            }
+        }
+    }
+
+    fn handle_magnifier_scrolled(&mut self, scroll_steps: f32, adjust_size: bool) {
+        if !self.magnifier_enabled {
+            return;
+        }
+
+        if adjust_size {
+            self.magnifier_size = (self.magnifier_size + scroll_steps * MAGNIFIER_SIZE_STEP)
+                .clamp(MAGNIFIER_MIN_SIZE, MAGNIFIER_MAX_SIZE);
+        } else {
+            self.magnifier_zoom = (self.magnifier_zoom + scroll_steps * MAGNIFIER_ZOOM_STEP)
+                .clamp(MAGNIFIER_MIN_ZOOM, MAGNIFIER_MAX_ZOOM);
+        }
+
+        log::debug!(
+            "[CAPTURE_VIEW] Magnifier zoom={} size={}",
+            self.magnifier_zoom,
+            self.magnifier_size
+        );
+    }
+
     fn calculate_selection_rectangle(&self) -> Option<(Point, Size)> {
         match self.draw_mode {
             DrawMode::Rectangle => match (self.selection_start, self.selection_current) {
@@ -170,45 +303,259 @@ impl CaptureView {
         self.calculate_selection_rectangle().map(|(point, size)| {
             let selection_rect = Rectangle::new(point, size);
 
-            let viewer_bounds = self.viewer_bounds.get();
-            if viewer_bounds.width == 0.0 || viewer_bounds.height == 0.0 {
-                log::warn!("[CAPTURE_VIEW] Viewer bounds not set, using raw selection");
-                return selection_rect;
-            }
-
-            let scale_x = self.capture_buffer.width as f32 / viewer_bounds.width;
-            let scale_y = self.capture_buffer.height as f32 / viewer_bounds.height;
+            let Some(transform) = self.image_transform.get() else {
+                log::warn!(
+                    "[CAPTURE_VIEW] Viewer bounds not set, scaling raw selection by {}",
+                    self.capture_buffer.effective_scale_factor()
+                );
+                return normalize_selection_rect(
+                    self.capture_buffer.logical_to_physical_rect(selection_rect),
+                    self.capture_buffer.width as f32,
+                    self.capture_buffer.height as f32,
+                );
+            };
 
-            let image_x = (selection_rect.x - viewer_bounds.x) * scale_x;
-            let image_y = (selection_rect.y - viewer_bounds.y) * scale_y;
-            let image_width = selection_rect.width * scale_x;
-            let image_height = selection_rect.height * scale_y;
+            let image_rect = normalize_selection_rect(
+                transform.rect_to_image(selection_rect),
+                self.capture_buffer.width as f32,
+                self.capture_buffer.height as f32,
+            );
 
             log::debug!(
-                "[CAPTURE_VIEW] Selection coords: {:?} -> Image coords: ({}, {}) {}x{}",
+                "[CAPTURE_VIEW] Selection coords: {:?} -> Image coords: {:?}",
                 selection_rect,
-                image_x,
-                image_y,
-                image_width,
-                image_height
+                image_rect
             );
             log::debug!(
                 "[CAPTURE_VIEW] Viewer bounds: {:?}, Image size: {}x{}, Scale: ({}, {})",
-                viewer_bounds,
+                transform.display_bounds(),
                 self.capture_buffer.width,
                 self.capture_buffer.height,
-                scale_x,
-                scale_y
+                transform.scale_x(),
+                transform.scale_y()
             );
 
-            Rectangle::new(
-                Point::new(image_x, image_y),
-                Size::new(image_width, image_height),
-            )
+            image_rect
         })
     }
 
+    pub fn is_freeform_selection(&self) -> bool {
+        self.draw_mode == DrawMode::Freeform
+    }
+
     pub fn get_capture_buffer(&self) -> &CaptureBuffer {
         &self.capture_buffer
     }
 }
+
+fn normalize_selection_rect(rect: Rectangle, max_width: f32, max_height: f32) -> Rectangle {
+    let left = rect.x.min(rect.x + rect.width).clamp(0.0, max_width);
+    let right = rect.x.max(rect.x + rect.width).clamp(0.0, max_width);
+    let top = rect.y.min(rect.y + rect.height).clamp(0.0, max_height);
+    let bottom = rect.y.max(rect.y + rect.height).clamp(0.0, max_height);
+
+    Rectangle::new(Point::new(left, top), Size::new(right - left, bottom - top))
+}
+
+fn calculate_magnifier_origin(cursor_position: Point, loupe_size: f32, bounds: Size) -> Point {
+    let place_on_axis = |cursor: f32, limit: f32| {
+        let after_cursor = cursor + MAGNIFIER_CURSOR_OFFSET;
+        if after_cursor + loupe_size <= limit {
+            return after_cursor;
+        }
+        (cursor - MAGNIFIER_CURSOR_OFFSET - loupe_size).max(0.0)
+    };
+
+    Point::new(
+        place_on_axis(cursor_position.x, bounds.width),
+        place_on_axis(cursor_position.y, bounds.height),
+    )
+}
+
+fn calculate_magnifier_source_rect(
+    center_x: i64,
+    center_y: i64,
+    half_span: i64,
+    image_width: u32,
+    image_height: u32,
+) -> Option<(std::ops::Range<i64>, std::ops::Range<i64>)> {
+    let columns = (center_x - half_span).max(0)..(center_x + half_span + 1).min(image_width as i64);
+    let rows = (center_y - half_span).max(0)..(center_y + half_span + 1).min(image_height as i64);
+
+    if columns.is_empty() || rows.is_empty() {
+        return None;
+    }
+    Some((columns, rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_selection_rect_flips_inverted_extents() {
+        let rect = Rectangle::new(Point::new(80.0, 60.0), Size::new(-50.0, -40.0));
+
+        let normalized = normalize_selection_rect(rect, 100.0, 100.0);
+
+        assert_eq!(
+            normalized,
+            Rectangle::new(Point::new(30.0, 20.0), Size::new(50.0, 40.0))
+        );
+    }
+
+    #[test]
+    fn test_normalize_selection_rect_clamps_to_image_bounds() {
+        let rect = Rectangle::new(Point::new(-10.0, 90.0), Size::new(30.0, 30.0));
+
+        let normalized = normalize_selection_rect(rect, 100.0, 100.0);
+
+        assert_eq!(
+            normalized,
+            Rectangle::new(Point::new(0.0, 90.0), Size::new(20.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn test_get_selected_region_handles_bottom_right_to_top_left_drag() {
+        let mut view = build_test_view();
+        view.update(CaptureViewMessage::MousePressed(Point::new(8.0, 9.0)));
+        view.update(CaptureViewMessage::MouseMoved(Point::new(2.0, 3.0)));
+
+        let region = view.get_selected_region().unwrap();
+
+        assert_eq!(
+            region,
+            Rectangle::new(Point::new(2.0, 3.0), Size::new(6.0, 6.0))
+        );
+    }
+
+    #[test]
+    fn test_magnifier_source_rect_is_clipped_near_top_left_edge() {
+        let (columns, rows) = calculate_magnifier_source_rect(1, 0, 3, 10, 10).unwrap();
+
+        assert_eq!(columns, 0..5);
+        assert_eq!(rows, 0..4);
+    }
+
+    #[test]
+    fn test_magnifier_source_rect_is_clipped_near_bottom_right_edge() {
+        let (columns, rows) = calculate_magnifier_source_rect(9, 8, 3, 10, 10).unwrap();
+
+        assert_eq!(columns, 6..10);
+        assert_eq!(rows, 5..10);
+    }
+
+    #[test]
+    fn test_magnifier_source_rect_is_none_outside_image() {
+        assert!(calculate_magnifier_source_rect(20, 5, 3, 10, 10).is_none());
+    }
+
+    #[test]
+    fn test_magnifier_origin_flips_to_stay_on_screen_near_edges() {
+        let bounds = Size::new(400.0, 300.0);
+
+        let away_from_edges = calculate_magnifier_origin(Point::new(100.0, 100.0), 120.0, bounds);
+        let near_bottom_right = calculate_magnifier_origin(Point::new(390.0, 290.0), 120.0, bounds);
+
+        assert_eq!(away_from_edges, Point::new(124.0, 124.0));
+        assert_eq!(near_bottom_right, Point::new(246.0, 146.0));
+    }
+
+    #[test]
+    fn test_set_magnifier_enabled_allows_scroll_adjustments() {
+        let mut view = build_test_view();
+        view.set_magnifier_enabled(true);
+
+        view.update(CaptureViewMessage::MagnifierScrolled(1.0, false));
+
+        assert_eq!(
+            view.magnifier_zoom,
+            MAGNIFIER_DEFAULT_ZOOM + MAGNIFIER_ZOOM_STEP
+        );
+    }
+
+    #[test]
+    fn test_selection_snaps_to_detected_edge_only_while_modifier_held() {
+        let raw_data = (0..10)
+            .flat_map(|_| (0..10).flat_map(|x| if x < 5 { [0u8, 0, 0, 255] } else { [255u8; 4] }))
+            .collect();
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, raw_data);
+        let mut view = CaptureView::build_with_capture_buffer(buffer);
+        view.image_transform.set(Some(ImageTransform::fit(
+            10.0,
+            10.0,
+            Size::new(100.0, 100.0),
+        )));
+
+        view.update(CaptureViewMessage::MousePressed(Point::new(56.0, 30.0)));
+        assert_eq!(view.selection_start, Some(Point::new(56.0, 30.0)));
+
+        view.update(CaptureViewMessage::SnapToEdgesChanged(true));
+        view.update(CaptureViewMessage::MousePressed(Point::new(56.0, 30.0)));
+        assert_eq!(view.selection_start, Some(Point::new(50.0, 30.0)));
+    }
+
+    fn build_test_view() -> CaptureView {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        CaptureView::build_with_capture_buffer(buffer)
+    }
+
+    #[test]
+    fn test_dimension_label_cycles_through_units() {
+        let buffer = CaptureBuffer::build_from_raw_data(2.0, 10, 10, vec![0u8; 400]);
+        let mut view = CaptureView::build_with_capture_buffer(buffer);
+
+        assert_eq!(
+            view.build_dimension_label(200, 100),
+            "200 × 100 px · 100 × 50 pt @ 192 DPI"
+        );
+
+        view.update(CaptureViewMessage::CycleDimensionUnits);
+        assert_eq!(view.build_dimension_label(200, 100), "200 × 100 px");
+
+        view.update(CaptureViewMessage::CycleDimensionUnits);
+        assert_eq!(view.build_dimension_label(200, 100), "100 × 50 pt");
+
+        view.update(CaptureViewMessage::CycleDimensionUnits);
+        assert_eq!(view.dimension_units, DimensionUnits::PixelsAndPoints);
+    }
+
+    #[test]
+    fn test_magnifier_scroll_is_ignored_while_disabled() {
+        let mut view = build_test_view();
+
+        view.update(CaptureViewMessage::MagnifierScrolled(3.0, false));
+
+        assert_eq!(view.magnifier_zoom, MAGNIFIER_DEFAULT_ZOOM);
+    }
+
+    #[test]
+    fn test_magnifier_scroll_adjusts_zoom_within_limits() {
+        let mut view = build_test_view();
+        view.update(CaptureViewMessage::ToggleMagnifier);
+
+        view.update(CaptureViewMessage::MagnifierScrolled(2.0, false));
+        assert_eq!(view.magnifier_zoom, MAGNIFIER_DEFAULT_ZOOM + 2.0);
+
+        view.update(CaptureViewMessage::MagnifierScrolled(100.0, false));
+        assert_eq!(view.magnifier_zoom, MAGNIFIER_MAX_ZOOM);
+
+        view.update(CaptureViewMessage::MagnifierScrolled(-100.0, false));
+        assert_eq!(view.magnifier_zoom, MAGNIFIER_MIN_ZOOM);
+    }
+
+    #[test]
+    fn test_magnifier_scroll_with_modifier_adjusts_size() {
+        let mut view = build_test_view();
+        view.update(CaptureViewMessage::ToggleMagnifier);
+
+        view.update(CaptureViewMessage::MagnifierScrolled(-1.0, true));
+
+        assert_eq!(
+            view.magnifier_size,
+            MAGNIFIER_DEFAULT_SIZE - MAGNIFIER_SIZE_STEP
+        );
+        assert_eq!(view.magnifier_zoom, MAGNIFIER_DEFAULT_ZOOM);
+    }
+}
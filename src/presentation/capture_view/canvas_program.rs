@@ -3,12 +3,22 @@ use iced::mouse;
 use iced::widget::canvas;
 use iced::{Color, Point, Rectangle, Size};
 
+const MAGNIFIER_TOGGLE_KEY: &str = "m";
+const SEARCH_SELECTION_KEY: &str = "s";
+const DIMENSION_UNITS_KEY: &str = "u";
+const SCROLL_CAPTURE_KEY: &str = "l";
+const DIMENSION_LABEL_TEXT_SIZE: f32 = 13.0;
+const DIMENSION_LABEL_HEIGHT: f32 = 22.0;
+const DIMENSION_LABEL_PADDING: f32 = 6.0;
+const DIMENSION_LABEL_CHAR_WIDTH: f32 = 7.5;
+const SCROLL_PIXELS_PER_STEP: f32 = 40.0;
+
 impl canvas::Program<CaptureViewMessage> for CaptureView {
-    type State = ();
+    type State = iced::keyboard::Modifiers;
 
     fn update(
         &self,
-        _state: &mut Self::State,
+        modifiers: &mut Self::State,
         event: &iced::Event,
         _bounds: Rectangle,
         cursor: mouse::Cursor,
@@ -30,15 +40,71 @@ impl canvas::Program<CaptureViewMessage> for CaptureView {
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     Some(canvas::Action::publish(CaptureViewMessage::MouseReleased))
                 }
+                mouse::Event::WheelScrolled { delta } if self.magnifier_enabled => {
+                    let scroll_steps = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => *y,
+                        mouse::ScrollDelta::Pixels { y, .. } => *y / SCROLL_PIXELS_PER_STEP,
+                    };
+                    Some(
+                        canvas::Action::publish(CaptureViewMessage::MagnifierScrolled(
+                            scroll_steps,
+                            modifiers.shift(),
+                        ))
+                        .and_capture(),
+                    )
+                }
                 _ => None,
             },
             iced::Event::Keyboard(keyboard_event) => match keyboard_event {
+                iced::keyboard::Event::KeyPressed {
+                    key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Enter),
+                    modifiers: key_modifiers,
+                    ..
+                } if key_modifiers.shift() => {
+                    Some(canvas::Action::publish(CaptureViewMessage::SearchSelection))
+                }
                 iced::keyboard::Event::KeyPressed {
                     key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Enter),
                     ..
                 } => Some(canvas::Action::publish(
                     CaptureViewMessage::ConfirmSelection,
                 )),
+                iced::keyboard::Event::KeyPressed {
+                    key: iced::keyboard::Key::Character(c),
+                    ..
+                } if c.as_str().eq_ignore_ascii_case(MAGNIFIER_TOGGLE_KEY) => {
+                    Some(canvas::Action::publish(CaptureViewMessage::ToggleMagnifier))
+                }
+                iced::keyboard::Event::KeyPressed {
+                    key: iced::keyboard::Key::Character(c),
+                    modifiers: key_modifiers,
+                    ..
+                } if key_modifiers.shift()
+                    && c.as_str().eq_ignore_ascii_case(SEARCH_SELECTION_KEY) =>
+                {
+                    Some(canvas::Action::publish(CaptureViewMessage::SearchSelection))
+                }
+                iced::keyboard::Event::KeyPressed {
+                    key: iced::keyboard::Key::Character(c),
+                    ..
+                } if c.as_str().eq_ignore_ascii_case(SCROLL_CAPTURE_KEY) => Some(
+                    canvas::Action::publish(CaptureViewMessage::ScrollCaptureSelection),
+                ),
+                iced::keyboard::Event::KeyPressed {
+                    key: iced::keyboard::Key::Character(c),
+                    ..
+                } if c.as_str().eq_ignore_ascii_case(DIMENSION_UNITS_KEY) => Some(
+                    canvas::Action::publish(CaptureViewMessage::CycleDimensionUnits),
+                ),
+                iced::keyboard::Event::ModifiersChanged(new_modifiers) => {
+                    let snap_changed = modifiers.alt() != new_modifiers.alt();
+                    *modifiers = *new_modifiers;
+                    snap_changed.then(|| {
+                        canvas::Action::publish(CaptureViewMessage::SnapToEdgesChanged(
+                            new_modifiers.alt(),
+                        ))
+                    })
+                }
                 _ => None,
             },
             _ => None,
@@ -51,36 +117,13 @@ impl canvas::Program<CaptureViewMessage> for CaptureView {
         renderer: &iced::Renderer,
         _theme: &iced::Theme,
         bounds: Rectangle,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
     ) -> Vec<canvas::Geometry<iced::Renderer>> {
-        let img_width = self.capture_buffer.width as f32;
-        let img_height = self.capture_buffer.height as f32;
-        let img_aspect = img_width / img_height;
-        let bounds_aspect = bounds.width / bounds.height;
-        let (display_width, display_height, offset_x, offset_y) = if img_aspect > bounds_aspect {
-            let display_width = bounds.width;
-            let display_height = bounds.width / img_aspect;
-            (
-                display_width,
-                display_height,
-                0.0,
-                (bounds.height - display_height) / 2.0,
-            )
-        } else {
-            let display_height = bounds.height;
-            let display_width = bounds.height * img_aspect;
-            (
-                display_width,
-                display_height,
-                (bounds.width - display_width) / 2.0,
-                0.0,
-            )
-        };
-
-        self.viewer_bounds.set(Rectangle::new(
-            Point::new(offset_x, offset_y),
-            Size::new(display_width, display_height),
-        ));
+        self.image_transform.set(Some(ImageTransform::fit(
+            self.capture_buffer.width as f32,
+            self.capture_buffer.height as f32,
+            bounds.size(),
+        )));
         let mut frame = canvas::Frame::new(renderer, bounds.size());
 
         match self.draw_mode {
@@ -88,6 +131,14 @@ impl canvas::Program<CaptureViewMessage> for CaptureView {
             DrawMode::Freeform => self.draw_freeform_overlay(&mut frame, bounds),
         }
 
+        self.draw_dimension_label(&mut frame);
+
+        if self.magnifier_enabled {
+            if let Some(cursor_position) = cursor.position_in(bounds) {
+                self.draw_magnifier(&mut frame, cursor_position);
+            }
+        }
+
         vec![frame.into_geometry()]
     }
 }
@@ -173,6 +224,132 @@ impl CaptureView {
         );
     }
 
+    fn draw_dimension_label(&self, frame: &mut canvas::Frame<iced::Renderer>) {
+        let (Some(transform), Some((top_left, size))) = (
+            self.image_transform.get(),
+            self.calculate_selection_rectangle(),
+        ) else {
+            return;
+        };
+
+        if size.width < 1.0 || size.height < 1.0 {
+            return;
+        }
+
+        let image_rect = transform.rect_to_image(Rectangle::new(top_left, size));
+        let label = self.build_dimension_label(
+            image_rect.width.round() as u32,
+            image_rect.height.round() as u32,
+        );
+        let label_width = label.chars().count() as f32 * DIMENSION_LABEL_CHAR_WIDTH
+            + DIMENSION_LABEL_PADDING * 2.0;
+        let label_y = if top_left.y >= DIMENSION_LABEL_HEIGHT + DIMENSION_LABEL_PADDING {
+            top_left.y - DIMENSION_LABEL_HEIGHT - DIMENSION_LABEL_PADDING
+        } else {
+            top_left.y + size.height + DIMENSION_LABEL_PADDING
+        };
+        let label_origin = Point::new(top_left.x, label_y);
+
+        frame.fill_rectangle(
+            label_origin,
+            Size::new(label_width, DIMENSION_LABEL_HEIGHT),
+            Color::from_rgba(0.0, 0.0, 0.0, 0.75),
+        );
+        frame.fill_text(canvas::Text {
+            content: label,
+            position: Point::new(
+                label_origin.x + DIMENSION_LABEL_PADDING,
+                label_origin.y + DIMENSION_LABEL_HEIGHT / 2.0,
+            ),
+            color: Color::WHITE,
+            size: iced::Pixels(DIMENSION_LABEL_TEXT_SIZE),
+            align_y: iced::alignment::Vertical::Center,
+            ..canvas::Text::default()
+        });
+    }
+
+    fn draw_magnifier(&self, frame: &mut canvas::Frame<iced::Renderer>, cursor_position: Point) {
+        let Some(transform) = self.image_transform.get() else {
+            return;
+        };
+
+        let image_point = transform.to_image(cursor_position);
+        let image_x = image_point.x.floor() as i64;
+        let image_y = image_point.y.floor() as i64;
+        let half_span = ((self.magnifier_size / self.magnifier_zoom) / 2.0).floor() as i64;
+        let cell_size = self.magnifier_size / (2 * half_span + 1) as f32;
+        let loupe_origin =
+            calculate_magnifier_origin(cursor_position, self.magnifier_size, frame.size());
+        let loupe_size = Size::new(self.magnifier_size, self.magnifier_size);
+
+        frame.fill_rectangle(loupe_origin, loupe_size, Color::BLACK);
+
+        if let Some((columns, rows)) = calculate_magnifier_source_rect(
+            image_x,
+            image_y,
+            half_span,
+            self.capture_buffer.width,
+            self.capture_buffer.height,
+        ) {
+            for row in rows {
+                for column in columns.clone() {
+                    let Some(pixel_color) = self.sample_pixel_color(column, row) else {
+                        continue;
+                    };
+                    frame.fill_rectangle(
+                        Point::new(
+                            loupe_origin.x + (column - image_x + half_span) as f32 * cell_size,
+                            loupe_origin.y + (row - image_y + half_span) as f32 * cell_size,
+                        ),
+                        Size::new(cell_size, cell_size),
+                        pixel_color,
+                    );
+                }
+            }
+        }
+
+        frame.stroke(
+            &canvas::Path::rectangle(
+                Point::new(
+                    loupe_origin.x + half_span as f32 * cell_size,
+                    loupe_origin.y + half_span as f32 * cell_size,
+                ),
+                Size::new(cell_size, cell_size),
+            ),
+            canvas::Stroke::default()
+                .with_color(Color::from_rgb(1.0, 0.2, 0.2))
+                .with_width(1.0),
+        );
+        frame.stroke(
+            &canvas::Path::rectangle(loupe_origin, loupe_size),
+            canvas::Stroke::default()
+                .with_color(Color::from_rgb(0.3, 0.6, 1.0))
+                .with_width(2.0),
+        );
+    }
+
+    fn sample_pixel_color(&self, image_x: i64, image_y: i64) -> Option<Color> {
+        if image_x < 0
+            || image_y < 0
+            || image_x >= self.capture_buffer.width as i64
+            || image_y >= self.capture_buffer.height as i64
+        {
+            return None;
+        }
+
+        let pixel_start = ((image_y * self.capture_buffer.width as i64 + image_x) * 4) as usize;
+        let pixel = self
+            .capture_buffer
+            .raw_data
+            .get(pixel_start..pixel_start + 4)?;
+        Some(Color::from_rgba8(
+            pixel[0],
+            pixel[1],
+            pixel[2],
+            pixel[3] as f32 / 255.0,
+        ))
+    }
+
     fn fill_mask_around_selection(
         &self,
         frame: &mut canvas::Frame<iced::Renderer>,
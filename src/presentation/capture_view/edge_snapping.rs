@@ -0,0 +1,133 @@
+use crate::core::models::CaptureBuffer;
+use iced::Point;
+
+const EDGE_PIXEL_CONTRAST: u8 = 48;
+const EDGE_MIN_COVERAGE: f32 = 0.2;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct EdgeMap {
+    pub(super) vertical: Vec<f32>,
+    pub(super) horizontal: Vec<f32>,
+}
+
+impl EdgeMap {
+    pub(super) fn detect(capture_buffer: &CaptureBuffer) -> Self {
+        let width = capture_buffer.width as usize;
+        let height = capture_buffer.height as usize;
+        if width == 0 || height == 0 || capture_buffer.raw_data.len() < width * height * 4 {
+            log::warn!("[CAPTURE_VIEW] Skipping edge detection for malformed buffer");
+            return Self::default();
+        }
+
+        let luminance: Vec<u8> = capture_buffer
+            .raw_data
+            .chunks_exact(4)
+            .take(width * height)
+            .map(|pixel| {
+                ((pixel[0] as u32 * 299 + pixel[1] as u32 * 587 + pixel[2] as u32 * 114) / 1000)
+                    as u8
+            })
+            .collect();
+        let is_strong =
+            |a: usize, b: usize| luminance[a].abs_diff(luminance[b]) >= EDGE_PIXEL_CONTRAST;
+
+        let vertical = (1..width)
+            .filter(|&x| {
+                let hits = (0..height)
+                    .filter(|&y| is_strong(y * width + x, y * width + x - 1))
+                    .count();
+                hits as f32 >= height as f32 * EDGE_MIN_COVERAGE
+            })
+            .map(|x| x as f32)
+            .collect();
+        let horizontal = (1..height)
+            .filter(|&y| {
+                let hits = (0..width)
+                    .filter(|&x| is_strong(y * width + x, (y - 1) * width + x))
+                    .count();
+                hits as f32 >= width as f32 * EDGE_MIN_COVERAGE
+            })
+            .map(|y| y as f32)
+            .collect();
+
+        let edge_map = Self {
+            vertical,
+            horizontal,
+        };
+        log::debug!(
+            "[CAPTURE_VIEW] Detected {} vertical and {} horizontal edges",
+            edge_map.vertical.len(),
+            edge_map.horizontal.len()
+        );
+        edge_map
+    }
+
+    pub(super) fn snap_point(
+        &self,
+        image_point: Point,
+        threshold_x: f32,
+        threshold_y: f32,
+    ) -> Point {
+        Point::new(
+            snap_to_nearest_boundary(image_point.x, &self.vertical, threshold_x),
+            snap_to_nearest_boundary(image_point.y, &self.horizontal, threshold_y),
+        )
+    }
+}
+
+fn snap_to_nearest_boundary(value: f32, boundaries: &[f32], threshold: f32) -> f32 {
+    boundaries
+        .iter()
+        .copied()
+        .filter(|boundary| (boundary - value).abs() <= threshold)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_synthetic_edge_map() -> EdgeMap {
+        EdgeMap {
+            vertical: vec![10.0, 50.0],
+            horizontal: vec![20.0, 80.0],
+        }
+    }
+
+    #[test]
+    fn test_snap_point_moves_to_boundaries_just_inside_threshold() {
+        let edge_map = build_synthetic_edge_map();
+
+        let snapped = edge_map.snap_point(Point::new(52.9, 77.1), 3.0, 3.0);
+
+        assert_eq!(snapped, Point::new(50.0, 80.0));
+    }
+
+    #[test]
+    fn test_snap_point_leaves_free_movement_without_nearby_edges() {
+        let edge_map = build_synthetic_edge_map();
+
+        let snapped = edge_map.snap_point(Point::new(30.0, 23.5), 3.0, 3.0);
+
+        assert_eq!(snapped, Point::new(30.0, 23.5));
+    }
+
+    #[test]
+    fn test_snap_to_nearest_boundary_prefers_closest_candidate() {
+        assert_eq!(snap_to_nearest_boundary(12.0, &[10.0, 13.0], 3.0), 13.0);
+    }
+
+    #[test]
+    fn test_detect_finds_boundary_between_dark_and_light_halves() {
+        let raw_data = (0..10)
+            .flat_map(|_| (0..10).flat_map(|x| if x < 5 { [0u8, 0, 0, 255] } else { [255u8; 4] }))
+            .collect();
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, raw_data);
+
+        let edge_map = EdgeMap::detect(&buffer);
+
+        assert_eq!(edge_map.vertical, vec![5.0]);
+        assert!(edge_map.horizontal.is_empty());
+    }
+}
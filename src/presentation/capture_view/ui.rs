@@ -24,10 +24,12 @@ impl CaptureView {
 
     fn build_status_banner(&self) -> Element<'_, CaptureViewMessage> {
         let status_message = if self.calculate_selection_rectangle().is_some() {
-            "Press Enter to confirm selection or draw a new region"
+            "Press Enter to confirm selection, Shift+S or Shift+Enter to search image, L to scroll-capture, U to switch units, or draw a new region"
         } else {
             match self.draw_mode {
-                DrawMode::Rectangle => "Click and drag to select a region",
+                DrawMode::Rectangle => {
+                    "Click and drag to select a region (hold Alt to snap to edges)"
+                }
                 DrawMode::Freeform => "Click and drag to draw a freeform shape",
             }
         };
@@ -84,12 +86,29 @@ impl CaptureView {
             })
             .on_press(CaptureViewMessage::SelectWindow);
 
-        let toolbar = container(
-            row![rect_btn, freeform_btn, window_btn]
-                .spacing(8)
-                .padding(8),
-        )
-        .style(|_theme| container::Style {
+        let mut toolbar_row = row![rect_btn, freeform_btn, window_btn]
+            .spacing(8)
+            .padding(8);
+        if self.calculate_selection_rectangle().is_some() {
+            toolbar_row = toolbar_row.push(
+                button(text("🔍 Search"))
+                    .padding([8, 16])
+                    .style(move |theme: &iced::Theme, status| {
+                        self.toolbar_button_style(theme, status, false)
+                    })
+                    .on_press(CaptureViewMessage::SearchSelection),
+            );
+            toolbar_row = toolbar_row.push(
+                button(text("📜 Scroll"))
+                    .padding([8, 16])
+                    .style(move |theme: &iced::Theme, status| {
+                        self.toolbar_button_style(theme, status, false)
+                    })
+                    .on_press(CaptureViewMessage::ScrollCaptureSelection),
+            );
+        }
+
+        let toolbar = container(toolbar_row).style(|_theme| container::Style {
             background: Some(Background::Color(Color::from_rgba(0.2, 0.2, 0.2, 0.85))),
             border: Border {
                 color: Color::from_rgba(0.4, 0.4, 0.4, 0.9),
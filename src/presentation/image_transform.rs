@@ -0,0 +1,258 @@
+use iced::{Point, Rectangle, Size, Vector};
+
+const MIN_DIMENSION: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageTransform {
+    image_size: Size,
+    display_bounds: Rectangle,
+}
+
+impl ImageTransform {
+    pub fn fit(image_width: f32, image_height: f32, bounds: Size) -> Self {
+        let image_width = sanitize_dimension(image_width);
+        let image_height = sanitize_dimension(image_height);
+        let bounds_width = sanitize_dimension(bounds.width);
+        let bounds_height = sanitize_dimension(bounds.height);
+        let image_aspect = image_width / image_height;
+        let bounds_aspect = bounds_width / bounds_height;
+
+        let display_bounds = if image_aspect > bounds_aspect {
+            let display_height = (bounds_width / image_aspect).max(MIN_DIMENSION);
+            let offset_y = (bounds_height - display_height) / 2.0;
+            Rectangle::new(
+                Point::new(0.0, offset_y),
+                Size::new(bounds_width, display_height),
+            )
+        } else {
+            let display_width = (bounds_height * image_aspect).max(MIN_DIMENSION);
+            let offset_x = (bounds_width - display_width) / 2.0;
+            Rectangle::new(
+                Point::new(offset_x, 0.0),
+                Size::new(display_width, bounds_height),
+            )
+        };
+
+        Self {
+            image_size: Size::new(image_width, image_height),
+            display_bounds,
+        }
+    }
+
+    pub fn zoomed(self, zoom: f32, pan: Vector, bounds: Size) -> Self {
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let fitted = self.display_bounds;
+        let display_bounds = Rectangle::new(
+            Point::new(
+                center.x + (fitted.x - center.x) * zoom + pan.x,
+                center.y + (fitted.y - center.y) * zoom + pan.y,
+            ),
+            Size::new(fitted.width * zoom, fitted.height * zoom),
+        );
+
+        Self {
+            image_size: self.image_size,
+            display_bounds,
+        }
+    }
+
+    pub fn pan_keeping_anchor(
+        self,
+        zoom: f32,
+        pan: Vector,
+        next_zoom: f32,
+        anchor: Point,
+        bounds: Size,
+    ) -> Vector {
+        let image_point = self.zoomed(zoom, pan, bounds).to_image(anchor);
+        let unpanned_anchor = self
+            .zoomed(next_zoom, Vector::ZERO, bounds)
+            .to_screen(image_point);
+        anchor - unpanned_anchor
+    }
+
+    pub fn display_bounds(&self) -> Rectangle {
+        self.display_bounds
+    }
+
+    pub fn scale_x(&self) -> f32 {
+        self.display_bounds.width / self.image_size.width
+    }
+
+    pub fn scale_y(&self) -> f32 {
+        self.display_bounds.height / self.image_size.height
+    }
+
+    pub fn to_screen(&self, image_point: Point) -> Point {
+        Point::new(
+            self.display_bounds.x + image_point.x * self.scale_x(),
+            self.display_bounds.y + image_point.y * self.scale_y(),
+        )
+    }
+
+    pub fn to_image(&self, screen_point: Point) -> Point {
+        Point::new(
+            (screen_point.x - self.display_bounds.x) / self.scale_x(),
+            (screen_point.y - self.display_bounds.y) / self.scale_y(),
+        )
+    }
+
+    pub fn rect_to_screen(&self, image_rect: Rectangle) -> Rectangle {
+        Rectangle::new(
+            self.to_screen(image_rect.position()),
+            Size::new(
+                image_rect.width * self.scale_x(),
+                image_rect.height * self.scale_y(),
+            ),
+        )
+    }
+
+    pub fn rect_to_image(&self, screen_rect: Rectangle) -> Rectangle {
+        Rectangle::new(
+            self.to_image(screen_rect.position()),
+            Size::new(
+                screen_rect.width / self.scale_x(),
+                screen_rect.height / self.scale_y(),
+            ),
+        )
+    }
+}
+
+fn sanitize_dimension(value: f32) -> f32 {
+    if value.is_finite() {
+        value.max(MIN_DIMENSION)
+    } else {
+        MIN_DIMENSION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_letterboxes_wide_image_vertically() {
+        let transform = ImageTransform::fit(200.0, 100.0, Size::new(400.0, 400.0));
+
+        assert_eq!(
+            transform.display_bounds(),
+            Rectangle::new(Point::new(0.0, 100.0), Size::new(400.0, 200.0))
+        );
+        assert_eq!(
+            transform.to_screen(Point::new(0.0, 0.0)),
+            Point::new(0.0, 100.0)
+        );
+        assert_eq!(
+            transform.to_image(Point::new(400.0, 300.0)),
+            Point::new(200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_fit_pillarboxes_tall_image_horizontally() {
+        let transform = ImageTransform::fit(100.0, 200.0, Size::new(400.0, 400.0));
+
+        assert_eq!(
+            transform.display_bounds(),
+            Rectangle::new(Point::new(100.0, 0.0), Size::new(200.0, 400.0))
+        );
+        assert_eq!(
+            transform.to_screen(Point::new(50.0, 100.0)),
+            Point::new(200.0, 200.0)
+        );
+        assert_eq!(
+            transform.to_image(Point::new(100.0, 0.0)),
+            Point::new(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_hidpi_image_maps_logical_points_to_physical_pixels() {
+        let transform = ImageTransform::fit(2880.0, 1800.0, Size::new(1440.0, 900.0));
+
+        assert_eq!(transform.scale_x(), 0.5);
+        assert_eq!(
+            transform.to_image(Point::new(100.0, 50.0)),
+            Point::new(200.0, 100.0)
+        );
+        assert_eq!(
+            transform.rect_to_image(Rectangle::new(
+                Point::new(10.0, 20.0),
+                Size::new(30.0, 40.0)
+            )),
+            Rectangle::new(Point::new(20.0, 40.0), Size::new(60.0, 80.0))
+        );
+    }
+
+    #[test]
+    fn test_to_image_inverts_to_screen() {
+        let transform = ImageTransform::fit(333.0, 127.0, Size::new(801.0, 613.0));
+        let image_point = Point::new(42.5, 99.25);
+
+        let round_trip = transform.to_image(transform.to_screen(image_point));
+
+        assert!((round_trip.x - image_point.x).abs() < 1e-3);
+        assert!((round_trip.y - image_point.y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_zoomed_scales_display_bounds_around_center_and_applies_pan() {
+        let bounds = Size::new(400.0, 400.0);
+        let transform =
+            ImageTransform::fit(200.0, 100.0, bounds).zoomed(2.0, Vector::new(10.0, -20.0), bounds);
+
+        assert_eq!(
+            transform.display_bounds(),
+            Rectangle::new(Point::new(-190.0, -20.0), Size::new(800.0, 400.0))
+        );
+        assert_eq!(
+            transform.to_image(Point::new(410.0, 180.0)),
+            Point::new(150.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn test_pan_keeping_anchor_keeps_image_point_under_cursor() {
+        let bounds = Size::new(400.0, 300.0);
+        let fitted = ImageTransform::fit(800.0, 600.0, bounds);
+        let anchor = Point::new(100.0, 75.0);
+        let image_point_before = fitted.zoomed(1.0, Vector::ZERO, bounds).to_image(anchor);
+
+        let next_pan = fitted.pan_keeping_anchor(1.0, Vector::ZERO, 3.0, anchor, bounds);
+        let image_point_after = fitted.zoomed(3.0, next_pan, bounds).to_image(anchor);
+
+        assert!((image_point_after.x - image_point_before.x).abs() < 1e-3);
+        assert!((image_point_after.y - image_point_before.y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_fit_handles_zero_height_bounds() {
+        let transform = ImageTransform::fit(100.0, 100.0, Size::new(400.0, 0.0));
+        let display_bounds = transform.display_bounds();
+
+        assert!(display_bounds.width.is_finite() && display_bounds.width >= MIN_DIMENSION);
+        assert!(display_bounds.height.is_finite() && display_bounds.height >= MIN_DIMENSION);
+        assert!(display_bounds.x.is_finite());
+        assert!(display_bounds.y.is_finite());
+    }
+
+    #[test]
+    fn test_fit_keeps_one_pixel_tall_strip_visible() {
+        let transform = ImageTransform::fit(100_000.0, 1.0, Size::new(800.0, 600.0));
+        let display_bounds = transform.display_bounds();
+
+        assert_eq!(display_bounds.width, 800.0);
+        assert_eq!(display_bounds.height, MIN_DIMENSION);
+        assert!(display_bounds.y.is_finite());
+    }
+
+    #[test]
+    fn test_fit_replaces_non_finite_image_dimensions() {
+        let transform = ImageTransform::fit(f32::NAN, 0.0, Size::new(300.0, 200.0));
+        let display_bounds = transform.display_bounds();
+
+        assert!(display_bounds.width.is_finite());
+        assert!(display_bounds.height.is_finite());
+        assert!(transform.to_image(Point::new(10.0, 10.0)).x.is_finite());
+    }
+}
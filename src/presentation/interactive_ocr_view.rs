@@ -1,15 +1,25 @@
-use iced::widget::{button, canvas, container, image, row, stack, text, text_input, tooltip};
+use iced::widget::{button, canvas, container, row, stack, text, text_input, tooltip};
 use iced::{Alignment, Border, Color, Element, Length, Point, Rectangle, Shadow, Vector};
 
 mod ocr_overlay;
 mod ocr_processing;
+mod shortcuts;
 mod state;
 mod ui;
 mod update;
 use ocr_overlay::OcrOverlay;
-use state::{build_selected_text_with_layout, build_status_text};
+pub use shortcuts::ResultWindowShortcuts;
+use state::{
+    build_markdown_from_layout_text, build_reading_order_text, build_selected_text_with_layout,
+    build_selection_bounds, build_shape_strokes, build_status_text, build_step_marker_strokes,
+    collect_line_char_indices, collect_spatial_range, collect_word_char_indices, color_to_hsv,
+    hsv_to_color, move_selection_focus, push_recent_draw_color,
+};
 
-use crate::core::models::{CaptureBuffer, OcrResult, ThemeMode};
+use crate::core::models::{
+    AnnotationStepMarker, AnnotationStroke, CaptureBuffer, DecodedCode, OcrLanguage, OcrPreprocess,
+    OcrResult, ThemeMode,
+};
 use crate::infrastructure::utils::copy_text_to_clipboard;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -24,6 +34,7 @@ pub enum SearchState {
 pub enum CopyState {
     Idle,
     Success,
+    Empty,
     Failed,
 }
 
@@ -45,6 +56,14 @@ pub enum SaveState {
     Failed(String),
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum TranslationState {
+    Idle,
+    Translating,
+    Completed(String),
+    Failed(String),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum OcrState {
     Idle,
@@ -53,6 +72,41 @@ pub enum OcrState {
     Completed,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnnotationMode {
+    None,
+    Draw,
+    ReadingOrder,
+    StepMarker,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DrawTool {
+    #[default]
+    Pen,
+    Highlighter,
+    Eraser,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HsvColor {
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    Arrow,
+    Rectangle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SelectionStep {
+    Character,
+    Word,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CharPosition {
     pub word_index: usize,
@@ -66,6 +120,35 @@ pub struct DrawStroke {
     pub points: Vec<Point>,
     pub color: Color,
     pub width: f32,
+    pub tool: DrawTool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShapeAnnotation {
+    pub shape: Shape,
+    pub start: Point,
+    pub end: Point,
+    pub color: Color,
+    pub width: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnnotationLayer {
+    Stroke,
+    Shape,
+}
+
+#[derive(Debug, Clone)]
+pub enum UndoableAnnotation {
+    Stroke(DrawStroke),
+    Shape(ShapeAnnotation),
+}
+
+#[derive(Debug, Clone)]
+pub struct StepMarker {
+    pub position: Point,
+    pub number: u32,
+    pub color: Color,
 }
 
 pub struct InteractiveOcrView {
@@ -74,30 +157,65 @@ pub struct InteractiveOcrView {
     image_height: u32,
     capture_buffer: CaptureBuffer,
     ocr_result: Option<OcrResult>,
+    decoded_codes: Vec<DecodedCode>,
+    hosted_image_url: Option<String>,
+    ocr_preprocess: OcrPreprocess,
+    ocr_preprocess_panel_open: bool,
+    color_picker_open: bool,
+    color_picker_hsv: HsvColor,
+    recent_draw_colors: Vec<Color>,
+    ocr_language: String,
+    available_ocr_languages: Vec<OcrLanguage>,
     char_positions: Vec<CharPosition>,
     selected_chars: Vec<usize>,
     drag_start: Option<usize>,
+    selection_anchor: Option<usize>,
+    selection_focus: Option<usize>,
     is_selecting: bool,
     search_state: SearchState,
     search_query: String,
+    is_search_input_focused: bool,
+    is_capture_flash_visible: bool,
     spinner_frame: usize,
     #[allow(dead_code)]
     theme_mode: ThemeMode,
     copy_state: CopyState,
+    text_clipboard_writer: fn(&str) -> Result<(), String>,
+    has_auto_copied: bool,
     image_copy_state: ImageCopyState,
     save_state: SaveState,
+    translation_state: TranslationState,
     draw_strokes: Vec<DrawStroke>,
+    redo_annotations: Vec<UndoableAnnotation>,
+    annotation_order: Vec<AnnotationLayer>,
+    has_unsaved_annotations: bool,
     current_stroke_points: Vec<Point>,
     is_drawing: bool,
     draw_color: Color,
     draw_width: f32,
+    draw_tool: DrawTool,
+    shape_tool: Option<Shape>,
+    shapes: Vec<ShapeAnnotation>,
     draw_mode_enabled: bool,
+    always_on_top: bool,
+    include_annotations_in_copy: bool,
+    reading_order_mode_enabled: bool,
+    reading_order: Vec<usize>,
+    step_marker_mode_enabled: bool,
+    step_markers: Vec<StepMarker>,
+    next_step_number: u32,
+    type_out_text_enabled: bool,
     show_help_hint: bool,
+    help_hint_enabled: bool,
+    min_ocr_confidence: f32,
     toolbar_offset: Vector,
     ocr_state: OcrState,
     draw_panel_position: Point,
     draw_panel_is_dragging: bool,
     draw_panel_drag_offset: Option<Vector>,
+    image_zoom: f32,
+    image_pan: Vector,
+    shortcuts: ResultWindowShortcuts,
 }
 #[derive(Debug, Clone)]
 pub enum InteractiveOcrMessage {
@@ -106,38 +224,87 @@ pub enum InteractiveOcrMessage {
     UpdateDrag(usize),
     EndDrag,
     CopySelected,
+    CopyAllText,
+    CopyTextLayoutJson,
+    CopyAsMarkdown,
+    TranslateSelected,
+    TranslationCompleted(String),
+    TranslationFailed(String),
+    DismissTranslation,
+    TypeSelectedText,
     SearchSelected,
+    SearchTextOnly,
     SearchQueryChanged(String),
+    SearchInputBlurred,
     SearchUploading,
     SearchCompleted,
     SearchFailed(String),
     SpinnerTick,
     HideToast,
+    CaptureFlashFinished,
     SelectAll,
+    SelectWord(usize),
+    SelectLine(usize),
+    ExtendSelectionLeft(SelectionStep),
+    ExtendSelectionRight(SelectionStep),
     DeselectAll,
     DismissHelpHint,
+    DismissHelpHintPermanently,
     StartDrawing(Point),
     UpdateDrawing(Point),
     EndDrawing,
     CopyImageToClipboard,
+    CopySelectedRegionToClipboard,
     CopyImagePreparing,
     CopyImageCopying,
     CopyImageSuccess,
     CopyImageFailed(String),
     SaveImageToFile,
+    SaveProject,
+    ExportTextToFile,
+    OpenDecodedCode(usize),
+    CopyDecodedCode(usize),
+    CopyImageUrl,
+    DismissDecodedCodes,
     SaveImagePreparing,
     SaveImageSaving,
     SaveSuccess(String),
+    ExportTextSuccess(String),
     SaveFailed(String),
     #[allow(dead_code)]
     HideSaveToast,
     Recrop,
+    RotateLeft,
+    RotateRight,
+    FlipHorizontal,
+    ToggleOcrPreprocessPanel,
+    AdjustOcrPreprocess(OcrPreprocess),
+    SetOcrPreprocess(OcrPreprocess),
+    ReprocessWithLanguage(String),
     ToggleDrawMode,
+    ToggleAlwaysOnTop,
+    ToggleIncludeAnnotationsInCopy,
+    ToggleReadingOrderMode,
+    ToggleReadingOrderBlock(usize),
+    ClearReadingOrder,
+    ToggleStepMarkerMode,
+    PlaceStepMarker(Point),
+    ResetStepNumbering,
     SetDrawColor(Color),
+    OpenColorPicker,
+    AdjustPickedColor(HsvColor),
+    ApplyPickedColor,
+    SetDrawWidth(f32),
+    SetDrawTool(DrawTool),
+    SetShapeTool(Option<Shape>),
     ClearDrawings,
+    EraseAnnotation(AnnotationLayer, usize),
+    UndoStroke,
+    RedoStroke,
     ToggleToolbarPosition,
     StartOcr,
     CancelOcr,
+    CancelSearch,
     ClearOcrOverlay,
     #[allow(dead_code)]
     OcrFailed(String),
@@ -145,6 +312,9 @@ pub enum InteractiveOcrMessage {
     DrawPanelDragStarted(f32, f32),
     DrawPanelMoved(f32, f32),
     DrawPanelReleased,
+    ZoomImage(f32, Point, iced::Size),
+    PanImage(Vector),
+    ResetImageZoom,
 }
 
 impl InteractiveOcrView {
@@ -161,29 +331,64 @@ impl InteractiveOcrView {
             image_height: capture_buffer.height,
             capture_buffer,
             ocr_result: None,
+            decoded_codes: Vec::new(),
+            hosted_image_url: None,
+            ocr_preprocess: OcrPreprocess::default(),
+            ocr_preprocess_panel_open: false,
+            color_picker_open: false,
+            color_picker_hsv: color_to_hsv(Color::from_rgb(1.0, 0.0, 0.0)),
+            recent_draw_colors: Vec::new(),
+            ocr_language: OcrLanguage::default().code().to_string(),
+            available_ocr_languages: OcrLanguage::ALL.to_vec(),
             char_positions: Vec::new(),
             selected_chars: Vec::new(),
             drag_start: None,
+            selection_anchor: None,
+            selection_focus: None,
             is_selecting: false,
             search_state: SearchState::Idle,
             search_query: String::new(),
+            is_search_input_focused: false,
+            is_capture_flash_visible: false,
             spinner_frame: 0,
             theme_mode,
             copy_state: CopyState::Idle,
+            text_clipboard_writer: copy_text_to_clipboard,
+            has_auto_copied: false,
             image_copy_state: ImageCopyState::Idle,
             save_state: SaveState::Idle,
+            translation_state: TranslationState::Idle,
             draw_strokes: Vec::new(),
+            redo_annotations: Vec::new(),
+            annotation_order: Vec::new(),
+            has_unsaved_annotations: false,
             current_stroke_points: Vec::new(),
             is_drawing: false,
             draw_color: Color::from_rgb(1.0, 0.0, 0.0),
-            draw_width: 3.0,
+            draw_width: crate::global_constants::DEFAULT_DRAW_WIDTH,
+            draw_tool: DrawTool::Pen,
+            shape_tool: None,
+            shapes: Vec::new(),
             draw_mode_enabled: false,
+            always_on_top: false,
+            include_annotations_in_copy: true,
+            reading_order_mode_enabled: false,
+            reading_order: Vec::new(),
+            step_marker_mode_enabled: false,
+            step_markers: Vec::new(),
+            next_step_number: 1,
+            type_out_text_enabled: false,
             show_help_hint: false,
+            help_hint_enabled: true,
+            min_ocr_confidence: 0.0,
             toolbar_offset: Vector::new(0.0, 0.0),
             ocr_state: OcrState::Idle,
             draw_panel_position: Point::new(16.0, 60.0),
             draw_panel_is_dragging: false,
             draw_panel_drag_offset: None,
+            image_zoom: 1.0,
+            image_pan: Vector::ZERO,
+            shortcuts: ResultWindowShortcuts::default(),
         }
     }
 
@@ -201,14 +406,195 @@ impl InteractiveOcrView {
     }
 
     pub fn get_draw_strokes(&self) -> Vec<DrawStroke> {
-        self.draw_strokes.clone()
+        let mut strokes = self.draw_strokes.clone();
+        for shape in &self.shapes {
+            strokes.extend(build_shape_strokes(shape));
+        }
+        for marker in &self.step_markers {
+            strokes.extend(build_step_marker_strokes(marker));
+        }
+        strokes
+    }
+
+    pub fn get_export_draw_strokes(&self) -> Vec<DrawStroke> {
+        if !self.include_annotations_in_copy {
+            return Vec::new();
+        }
+        self.get_draw_strokes()
+    }
+
+    pub fn can_transform_image(&self) -> bool {
+        self.draw_strokes.is_empty() && self.shapes.is_empty() && self.step_markers.is_empty()
     }
 
     pub fn set_draw_strokes(&mut self, strokes: Vec<DrawStroke>) {
+        self.has_unsaved_annotations = !strokes.is_empty();
+        self.annotation_order = vec![AnnotationLayer::Stroke; strokes.len()];
         self.draw_strokes = strokes;
+        self.redo_annotations.clear();
+        self.shapes.clear();
+    }
+
+    pub fn get_project_annotations(&self) -> (Vec<AnnotationStroke>, Vec<AnnotationStepMarker>) {
+        let shape_strokes: Vec<DrawStroke> =
+            self.shapes.iter().flat_map(build_shape_strokes).collect();
+        (
+            self.draw_strokes
+                .iter()
+                .chain(&shape_strokes)
+                .map(AnnotationStroke::from)
+                .collect(),
+            self.step_markers
+                .iter()
+                .map(AnnotationStepMarker::from)
+                .collect(),
+        )
+    }
+
+    pub fn restore_project_annotations(
+        &mut self,
+        strokes: Vec<AnnotationStroke>,
+        step_markers: Vec<AnnotationStepMarker>,
+    ) {
+        self.draw_strokes = strokes.into_iter().map(DrawStroke::from).collect();
+        self.annotation_order = vec![AnnotationLayer::Stroke; self.draw_strokes.len()];
+        self.redo_annotations.clear();
+        self.shapes.clear();
+        self.step_markers = step_markers.into_iter().map(StepMarker::from).collect();
+        self.next_step_number = self
+            .step_markers
+            .iter()
+            .map(|marker| marker.number + 1)
+            .max()
+            .unwrap_or(1);
+        self.has_unsaved_annotations = false;
+    }
+
+    pub fn get_translation_state(&self) -> &TranslationState {
+        &self.translation_state
+    }
+
+    pub fn has_copy_succeeded(&self) -> bool {
+        self.copy_state == CopyState::Success
+    }
+
+    pub fn has_auto_copied(&self) -> bool {
+        self.has_auto_copied
+    }
+
+    pub fn mark_auto_copied(&mut self) {
+        self.has_auto_copied = true;
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_text_clipboard_writer(&mut self, writer: fn(&str) -> Result<(), String>) {
+        self.text_clipboard_writer = writer;
+    }
+
+    pub fn set_decoded_codes(&mut self, codes: Vec<DecodedCode>) {
+        self.decoded_codes = codes;
+    }
+
+    pub fn get_decoded_code(&self, code_index: usize) -> Option<&DecodedCode> {
+        self.decoded_codes.get(code_index)
+    }
+
+    pub fn set_hosted_image_url(&mut self, hosted_image_url: String) {
+        self.hosted_image_url = Some(hosted_image_url);
+    }
+
+    pub fn get_hosted_image_url(&self) -> Option<&str> {
+        self.hosted_image_url.as_deref()
+    }
+
+    pub fn get_ocr_preprocess(&self) -> OcrPreprocess {
+        self.ocr_preprocess
+    }
+
+    pub fn set_ocr_language(&mut self, language_code: &str) {
+        self.ocr_language = language_code.to_string();
+    }
+
+    pub fn get_ocr_language(&self) -> &str {
+        &self.ocr_language
+    }
+
+    pub fn show_capture_flash(&mut self) {
+        self.is_capture_flash_visible = true;
+    }
+
+    pub fn set_available_ocr_languages(&mut self, languages: Vec<OcrLanguage>) {
+        self.available_ocr_languages = languages;
+    }
+
+    pub fn set_help_hint_enabled(&mut self, enabled: bool) {
+        self.help_hint_enabled = enabled;
+    }
+
+    pub fn set_draw_width(&mut self, width: f32) {
+        self.draw_width = width;
+    }
+
+    pub fn set_recent_draw_colors(&mut self, colors: &[(f32, f32, f32)]) {
+        self.recent_draw_colors = colors
+            .iter()
+            .map(|&(red, green, blue)| Color::from_rgb(red, green, blue))
+            .collect();
+    }
+
+    pub fn get_recent_draw_colors(&self) -> Vec<(f32, f32, f32)> {
+        self.recent_draw_colors
+            .iter()
+            .map(|color| (color.r, color.g, color.b))
+            .collect()
+    }
+
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.always_on_top = always_on_top;
+    }
+
+    pub fn is_always_on_top(&self) -> bool {
+        self.always_on_top
+    }
+
+    pub fn set_min_ocr_confidence(&mut self, min_confidence: f32) {
+        self.min_ocr_confidence = min_confidence;
+    }
+
+    pub fn set_type_out_text_enabled(&mut self, enabled: bool) {
+        self.type_out_text_enabled = enabled;
+    }
+
+    pub fn set_shortcuts(&mut self, shortcuts: ResultWindowShortcuts) {
+        self.shortcuts = shortcuts;
+    }
+
+    pub fn get_selected_text(&self) -> String {
+        self.get_selected_text_with_layout()
+    }
+
+    pub fn get_all_text(&self) -> String {
+        let all_chars: Vec<usize> = (0..self.char_positions.len()).collect();
+        build_selected_text_with_layout(&all_chars, &self.char_positions)
+    }
+
+    pub fn get_unsaved_annotation_count(&self) -> usize {
+        if self.has_unsaved_annotations {
+            self.draw_strokes.len() + self.step_markers.len()
+        } else {
+            0
+        }
+    }
+
+    pub fn get_selected_region(&self) -> Option<Rectangle> {
+        build_selection_bounds(&self.selected_chars, &self.char_positions)
     }
 
     fn get_selected_text_with_layout(&self) -> String {
+        if !self.reading_order.is_empty() {
+            return build_reading_order_text(&self.reading_order, &self.char_positions);
+        }
+
         build_selected_text_with_layout(&self.selected_chars, &self.char_positions)
     }
 
@@ -218,9 +604,22 @@ impl InteractiveOcrView {
             &self.image_copy_state,
             &self.search_state,
             &self.ocr_state,
-            self.draw_mode_enabled,
+            self.get_annotation_mode(),
             self.ocr_result.as_ref(),
             self.selected_chars.len(),
+            self.reading_order.len(),
         )
     }
+
+    fn get_annotation_mode(&self) -> AnnotationMode {
+        if self.draw_mode_enabled {
+            AnnotationMode::Draw
+        } else if self.reading_order_mode_enabled {
+            AnnotationMode::ReadingOrder
+        } else if self.step_marker_mode_enabled {
+            AnnotationMode::StepMarker
+        } else {
+            AnnotationMode::None
+        }
+    }
 }
@@ -1,9 +1,52 @@
+use std::time::{Duration, Instant};
+
 use iced::widget::canvas;
-use iced::{Color, Point, Rectangle, Size};
+use iced::{Color, Point, Rectangle, Size, Vector};
+
+use super::state::{
+    build_arrowhead_points, build_shape_from_drag, find_topmost_annotation_at, STEP_MARKER_RADIUS,
+};
+use super::{
+    AnnotationLayer, CharPosition, DrawStroke, DrawTool, InteractiveOcrMessage,
+    ResultWindowShortcuts, SelectionStep, Shape, ShapeAnnotation, StepMarker,
+};
+use crate::presentation::image_transform::ImageTransform;
+
+const READING_ORDER_BADGE_RADIUS: f32 = 8.0;
+const MULTI_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+const MULTI_CLICK_MAX_DISTANCE: f32 = 4.0;
+const MAX_TRACKED_CLICK_COUNT: u32 = 3;
+const SCROLL_PIXELS_PER_STEP: f32 = 40.0;
+
+#[derive(Debug, Default)]
+pub(super) struct ClickTracker {
+    last_click: Option<(Instant, Point)>,
+    click_count: u32,
+    pan_origin: Option<Point>,
+}
 
-use super::{CharPosition, DrawStroke, InteractiveOcrMessage};
+impl ClickTracker {
+    fn register_click(&mut self, position: Point) -> u32 {
+        let now = Instant::now();
+        let is_repeat_click = self.last_click.is_some_and(|(last_time, last_position)| {
+            now.duration_since(last_time) <= MULTI_CLICK_INTERVAL
+                && last_position.distance(position) <= MULTI_CLICK_MAX_DISTANCE
+        });
+
+        self.click_count = if is_repeat_click {
+            (self.click_count + 1).min(MAX_TRACKED_CLICK_COUNT)
+        } else {
+            1
+        };
+        self.last_click = Some((now, position));
+        self.click_count
+    }
+}
 
 pub(super) struct OcrOverlay {
+    pub(super) image_handle: iced::widget::image::Handle,
+    pub(super) image_zoom: f32,
+    pub(super) image_pan: Vector,
     pub(super) char_positions: Vec<CharPosition>,
     pub(super) image_width: u32,
     pub(super) image_height: u32,
@@ -13,13 +56,91 @@ pub(super) struct OcrOverlay {
     pub(super) is_drawing: bool,
     pub(super) draw_color: Color,
     pub(super) draw_width: f32,
+    pub(super) draw_tool: DrawTool,
+    pub(super) shape_tool: Option<Shape>,
+    pub(super) shapes: Vec<ShapeAnnotation>,
+    pub(super) annotation_order: Vec<AnnotationLayer>,
     pub(super) draw_mode_enabled: bool,
+    pub(super) reading_order_mode_enabled: bool,
+    pub(super) reading_order: Vec<usize>,
+    pub(super) step_marker_mode_enabled: bool,
+    pub(super) step_markers: Vec<StepMarker>,
     pub(super) draw_panel_position: Point,
     pub(super) draw_panel_is_dragging: bool,
+    pub(super) shortcuts: ResultWindowShortcuts,
+    pub(super) is_search_input_focused: bool,
+}
+
+impl OcrOverlay {
+    fn resolve_character_shortcut(
+        &self,
+        char_str: &str,
+        modifiers: iced::keyboard::Modifiers,
+    ) -> Option<InteractiveOcrMessage> {
+        if self.is_search_input_focused {
+            log::debug!("[INTERACTIVE_OCR] Shortcut left to the focused search field");
+            return None;
+        }
+
+        let is_cmd_or_ctrl = modifiers.command() || modifiers.control();
+
+        if is_cmd_or_ctrl && char_str == "0" {
+            log::debug!("[INTERACTIVE_OCR] Image zoom reset via keyboard shortcut");
+            return Some(InteractiveOcrMessage::ResetImageZoom);
+        }
+        if is_cmd_or_ctrl && char_str == "a" {
+            log::debug!("[INTERACTIVE_OCR] Select all triggered via keyboard shortcut");
+            return Some(InteractiveOcrMessage::SelectAll);
+        }
+        if is_cmd_or_ctrl && char_str.eq_ignore_ascii_case("c") {
+            if self.selected_indices.is_empty() {
+                log::debug!("[INTERACTIVE_OCR] Copy shortcut ignored with no text selected");
+                return None;
+            }
+            log::debug!("[INTERACTIVE_OCR] Copy text triggered via keyboard shortcut");
+            return Some(InteractiveOcrMessage::CopySelected);
+        }
+        if is_cmd_or_ctrl && char_str.eq_ignore_ascii_case("z") {
+            let message = if modifiers.shift() {
+                InteractiveOcrMessage::RedoStroke
+            } else {
+                InteractiveOcrMessage::UndoStroke
+            };
+            log::debug!(
+                "[INTERACTIVE_OCR] {:?} triggered via keyboard shortcut",
+                message
+            );
+            return Some(message);
+        }
+        if let Some(message) = self.shortcuts.resolve(char_str, modifiers) {
+            log::debug!(
+                "[INTERACTIVE_OCR] {:?} triggered via keyboard shortcut",
+                message
+            );
+            return Some(message);
+        }
+        if is_cmd_or_ctrl && char_str.eq_ignore_ascii_case("s") {
+            let message = if modifiers.shift() {
+                InteractiveOcrMessage::SaveProject
+            } else {
+                InteractiveOcrMessage::SaveImageToFile
+            };
+            log::debug!(
+                "[INTERACTIVE_OCR] {:?} triggered via keyboard shortcut",
+                message
+            );
+            return Some(message);
+        }
+        if is_cmd_or_ctrl && modifiers.shift() && char_str.eq_ignore_ascii_case("d") {
+            log::debug!("[INTERACTIVE_OCR] Copy selected region triggered via keyboard shortcut");
+            return Some(InteractiveOcrMessage::CopySelectedRegionToClipboard);
+        }
+        None
+    }
 }
 
 impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
-    type State = ();
+    type State = ClickTracker;
 
     fn draw(
         &self,
@@ -31,32 +152,14 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
     ) -> Vec<canvas::Geometry<iced::Renderer>> {
         let mut frame = canvas::Frame::new(renderer, bounds.size());
 
-        let img_width = self.image_width as f32;
-        let img_height = self.image_height as f32;
-        let img_aspect = img_width / img_height;
-        let bounds_aspect = bounds.width / bounds.height;
-
-        let (display_width, display_height, offset_x, offset_y) = if img_aspect > bounds_aspect {
-            let display_width = bounds.width;
-            let display_height = bounds.width / img_aspect;
-            let offset_y = (bounds.height - display_height) / 2.0;
-            (display_width, display_height, 0.0, offset_y)
-        } else {
-            let display_height = bounds.height;
-            let display_width = bounds.height * img_aspect;
-            let offset_x = (bounds.width - display_width) / 2.0;
-            (display_width, display_height, offset_x, 0.0)
-        };
-
-        let scale_x = display_width / img_width;
-        let scale_y = display_height / img_height;
+        let transform = self.build_transform(bounds);
+        frame.draw_image(
+            transform.display_bounds(),
+            canvas::Image::new(self.image_handle.clone()),
+        );
 
         for (idx, char_pos) in self.char_positions.iter().enumerate() {
-            let rect_bounds = &char_pos.bounds;
-            let scaled_x = offset_x + (rect_bounds.x * scale_x);
-            let scaled_y = offset_y + (rect_bounds.y * scale_y);
-            let scaled_width = rect_bounds.width * scale_x;
-            let scaled_height = rect_bounds.height * scale_y;
+            let char_rect = transform.rect_to_screen(char_pos.bounds);
             let is_selected = self.selected_indices.contains(&idx);
             let (fill_color, stroke_width) = if is_selected {
                 (Color::from_rgba(0.3, 0.8, 0.3, 0.4), 1.5)
@@ -64,15 +167,8 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
                 (Color::from_rgba(0.2, 0.6, 1.0, 0.15), 0.5)
             };
 
-            let rect_path = canvas::Path::rectangle(
-                Point::new(scaled_x, scaled_y),
-                Size::new(scaled_width, scaled_height),
-            );
-            frame.fill_rectangle(
-                Point::new(scaled_x, scaled_y),
-                Size::new(scaled_width, scaled_height),
-                fill_color,
-            );
+            let rect_path = canvas::Path::rectangle(char_rect.position(), char_rect.size());
+            frame.fill_rectangle(char_rect.position(), char_rect.size(), fill_color);
 
             if is_selected {
                 frame.stroke(
@@ -84,50 +180,98 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
             }
         }
 
+        for (order_index, word_index) in self.reading_order.iter().enumerate() {
+            let Some(block_bounds) = self.calculate_block_bounds(*word_index) else {
+                continue;
+            };
+            let block_rect = transform.rect_to_screen(block_bounds);
+            let block_origin = block_rect.position();
+            frame.stroke(
+                &canvas::Path::rectangle(block_origin, block_rect.size()),
+                canvas::Stroke::default()
+                    .with_color(Color::from_rgb(1.0, 0.6, 0.1))
+                    .with_width(1.5),
+            );
+            frame.fill(
+                &canvas::Path::circle(block_origin, READING_ORDER_BADGE_RADIUS),
+                Color::from_rgb(1.0, 0.6, 0.1),
+            );
+            frame.fill_text(canvas::Text {
+                content: (order_index + 1).to_string(),
+                position: block_origin,
+                color: Color::BLACK,
+                size: iced::Pixels(11.0),
+                align_x: iced::widget::text::Alignment::Center,
+                align_y: iced::alignment::Vertical::Center,
+                ..canvas::Text::default()
+            });
+        }
+
         for stroke in &self.draw_strokes {
             if stroke.points.len() > 1 {
                 let mut path_builder = canvas::path::Builder::new();
                 let first_point = stroke.points[0];
-                let first_scaled_x = offset_x + (first_point.x / img_width) * display_width;
-                let first_scaled_y = offset_y + (first_point.y / img_height) * display_height;
-                path_builder.move_to(Point::new(first_scaled_x, first_scaled_y));
+                path_builder.move_to(transform.to_screen(first_point));
 
                 for point in stroke.points.iter().skip(1) {
-                    let scaled_x = offset_x + (point.x / img_width) * display_width;
-                    let scaled_y = offset_y + (point.y / img_height) * display_height;
-                    path_builder.line_to(Point::new(scaled_x, scaled_y));
+                    path_builder.line_to(transform.to_screen(*point));
                 }
 
                 let path = path_builder.build();
                 frame.stroke(
                     &path,
-                    canvas::Stroke::default()
-                        .with_color(stroke.color)
-                        .with_width(stroke.width),
+                    build_canvas_stroke(stroke.tool, stroke.color, stroke.width),
                 );
             }
         }
 
-        if self.is_drawing && self.current_stroke_points.len() > 1 {
+        for shape in &self.shapes {
+            draw_shape(&mut frame, &transform, shape);
+        }
+
+        for marker in &self.step_markers {
+            let marker_center = transform.to_screen(marker.position);
+            let marker_radius = STEP_MARKER_RADIUS * transform.scale_x();
+            frame.fill(
+                &canvas::Path::circle(marker_center, marker_radius),
+                marker.color,
+            );
+            frame.fill_text(canvas::Text {
+                content: marker.number.to_string(),
+                position: marker_center,
+                color: Color::WHITE,
+                size: iced::Pixels(marker_radius * 1.1),
+                align_x: iced::widget::text::Alignment::Center,
+                align_y: iced::alignment::Vertical::Center,
+                ..canvas::Text::default()
+            });
+        }
+
+        if let (true, Some(shape), Some(start), Some(end)) = (
+            self.is_drawing,
+            self.shape_tool,
+            self.current_stroke_points.first(),
+            self.current_stroke_points.last(),
+        ) {
+            if let Some(preview) =
+                build_shape_from_drag(shape, *start, *end, self.draw_color, self.draw_width)
+            {
+                draw_shape(&mut frame, &transform, &preview);
+            }
+        } else if self.is_drawing && self.current_stroke_points.len() > 1 {
             let mut path_builder = canvas::path::Builder::new();
             let first_point = self.current_stroke_points[0];
-            let first_scaled_x = offset_x + (first_point.x / img_width) * display_width;
-            let first_scaled_y = offset_y + (first_point.y / img_height) * display_height;
-            path_builder.move_to(Point::new(first_scaled_x, first_scaled_y));
+            path_builder.move_to(transform.to_screen(first_point));
 
             for point in self.current_stroke_points.iter().skip(1) {
-                let scaled_x = offset_x + (point.x / img_width) * display_width;
-                let scaled_y = offset_y + (point.y / img_height) * display_height;
-                path_builder.line_to(Point::new(scaled_x, scaled_y));
+                path_builder.line_to(transform.to_screen(*point));
             }
 
             let path = path_builder.build();
-            frame.stroke(
-                &path,
-                canvas::Stroke::default()
-                    .with_color(self.draw_color)
-                    .with_width(self.draw_width),
-            );
+            let (color, width) = self
+                .draw_tool
+                .stroke_appearance(self.draw_color, self.draw_width);
+            frame.stroke(&path, build_canvas_stroke(self.draw_tool, color, width));
         }
 
         vec![frame.into_geometry()]
@@ -148,30 +292,12 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
 
     fn update(
         &self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         event: &iced::Event,
         bounds: Rectangle,
         cursor: iced::mouse::Cursor,
     ) -> Option<canvas::Action<InteractiveOcrMessage>> {
-        let img_width = self.image_width as f32;
-        let img_height = self.image_height as f32;
-        let img_aspect = img_width / img_height;
-        let bounds_aspect = bounds.width / bounds.height;
-
-        let (display_width, display_height, offset_x, offset_y) = if img_aspect > bounds_aspect {
-            let display_width = bounds.width;
-            let display_height = bounds.width / img_aspect;
-            let offset_y = (bounds.height - display_height) / 2.0;
-            (display_width, display_height, 0.0, offset_y)
-        } else {
-            let display_height = bounds.height;
-            let display_width = bounds.height * img_aspect;
-            let offset_x = (bounds.width - display_width) / 2.0;
-            (display_width, display_height, offset_x, 0.0)
-        };
-
-        let scale_x = display_width / img_width;
-        let scale_y = display_height / img_height;
+        let transform = self.build_transform(bounds);
 
         match event {
             iced::Event::Keyboard(keyboard_event) => match keyboard_event {
@@ -179,41 +305,52 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
                     key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape),
                     ..
                 } => {
+                    if self.is_search_input_focused {
+                        return Some(canvas::Action::publish(
+                            InteractiveOcrMessage::SearchInputBlurred,
+                        ));
+                    }
+                    if self.reading_order_mode_enabled {
+                        return Some(canvas::Action::publish(
+                            InteractiveOcrMessage::ToggleReadingOrderMode,
+                        ));
+                    }
                     if !self.selected_indices.is_empty() {
                         return Some(canvas::Action::publish(InteractiveOcrMessage::DeselectAll));
                     }
                     Some(canvas::Action::publish(InteractiveOcrMessage::Close))
                 }
                 iced::keyboard::Event::KeyPressed {
-                    key: iced::keyboard::Key::Character(c),
+                    key:
+                        iced::keyboard::Key::Named(
+                            named_key @ (iced::keyboard::key::Named::ArrowLeft
+                            | iced::keyboard::key::Named::ArrowRight),
+                        ),
                     modifiers,
                     ..
-                } => {
-                    let char_str = c.as_str();
-                    let is_cmd_or_ctrl = modifiers.command() || modifiers.control();
-
-                    if is_cmd_or_ctrl && char_str == "a" {
-                        log::debug!("[INTERACTIVE_OCR] Select all triggered via keyboard shortcut");
-                        return Some(canvas::Action::publish(InteractiveOcrMessage::SelectAll));
-                    }
-                    if is_cmd_or_ctrl && char_str == "c" {
-                        log::debug!("[INTERACTIVE_OCR] Copy text triggered via keyboard shortcut");
-                        return Some(canvas::Action::publish(InteractiveOcrMessage::CopySelected));
-                    }
-                    if is_cmd_or_ctrl && char_str == "s" {
-                        log::debug!("[INTERACTIVE_OCR] Save image triggered via keyboard shortcut");
-                        return Some(canvas::Action::publish(
-                            InteractiveOcrMessage::SaveImageToFile,
-                        ));
-                    }
-                    if is_cmd_or_ctrl && char_str == "d" {
-                        log::debug!("[INTERACTIVE_OCR] Copy image triggered via keyboard shortcut");
-                        return Some(canvas::Action::publish(
-                            InteractiveOcrMessage::CopyImageToClipboard,
-                        ));
-                    }
-                    None
+                } if modifiers.shift()
+                    && !self.selected_indices.is_empty()
+                    && !self.is_search_input_focused =>
+                {
+                    let step = if modifiers.command() || modifiers.control() {
+                        SelectionStep::Word
+                    } else {
+                        SelectionStep::Character
+                    };
+                    let message = if *named_key == iced::keyboard::key::Named::ArrowRight {
+                        InteractiveOcrMessage::ExtendSelectionRight(step)
+                    } else {
+                        InteractiveOcrMessage::ExtendSelectionLeft(step)
+                    };
+                    Some(canvas::Action::publish(message))
                 }
+                iced::keyboard::Event::KeyPressed {
+                    key: iced::keyboard::Key::Character(c),
+                    modifiers,
+                    ..
+                } => self
+                    .resolve_character_shortcut(c.as_str(), *modifiers)
+                    .map(canvas::Action::publish),
                 _ => None,
             },
             iced::Event::Mouse(mouse_event) => match mouse_event {
@@ -236,43 +373,119 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
                             ),
                         ));
                     }
+                    if self.draw_mode_enabled
+                        && self.draw_tool == DrawTool::Eraser
+                        && self.shape_tool.is_none()
+                    {
+                        let image_point = transform.to_image(cursor_position);
+                        return find_topmost_annotation_at(
+                            &self.draw_strokes,
+                            &self.shapes,
+                            &self.annotation_order,
+                            image_point,
+                            1.0 / transform.scale_x(),
+                        )
+                        .map(|(layer, index)| {
+                            canvas::Action::publish(InteractiveOcrMessage::EraseAnnotation(
+                                layer, index,
+                            ))
+                        });
+                    }
                     if self.draw_mode_enabled {
-                        let rel_x = (cursor_position.x - offset_x) / scale_x;
-                        let rel_y = (cursor_position.y - offset_y) / scale_y;
+                        let image_point = transform.to_image(cursor_position);
                         return Some(canvas::Action::publish(
-                            InteractiveOcrMessage::StartDrawing(Point::new(rel_x, rel_y)),
+                            InteractiveOcrMessage::StartDrawing(image_point),
                         ));
                     }
+                    if self.step_marker_mode_enabled {
+                        let image_point = transform.to_image(cursor_position);
+                        return Some(canvas::Action::publish(
+                            InteractiveOcrMessage::PlaceStepMarker(image_point),
+                        ));
+                    }
+                    if self.reading_order_mode_enabled {
+                        let image_point = transform.to_image(cursor_position);
+                        return self
+                            .char_positions
+                            .iter()
+                            .find(|char_pos| char_pos.bounds.contains(image_point))
+                            .map(|char_pos| {
+                                canvas::Action::publish(
+                                    InteractiveOcrMessage::ToggleReadingOrderBlock(
+                                        char_pos.word_index,
+                                    ),
+                                )
+                            });
+                    }
 
-                    for (idx, char_pos) in self.char_positions.iter().enumerate() {
-                        let rect_bounds = &char_pos.bounds;
-                        let scaled_x = offset_x + (rect_bounds.x * scale_x);
-                        let scaled_y = offset_y + (rect_bounds.y * scale_y);
-                        let scaled_width = rect_bounds.width * scale_x;
-                        let scaled_height = rect_bounds.height * scale_y;
-                        let char_rect = Rectangle::new(
-                            Point::new(scaled_x, scaled_y),
-                            Size::new(scaled_width, scaled_height),
-                        );
+                    let click_count = state.register_click(cursor_position);
+                    let clicked_char =
+                        self.char_positions
+                            .iter()
+                            .enumerate()
+                            .find(|(_, char_pos)| {
+                                transform
+                                    .rect_to_screen(char_pos.bounds)
+                                    .contains(cursor_position)
+                            });
 
-                        if char_rect.contains(cursor_position) {
+                    let message = match (click_count, clicked_char) {
+                        (_, None) if click_count > 1 => InteractiveOcrMessage::DeselectAll,
+                        (_, None) => {
+                            return self.is_search_input_focused.then(|| {
+                                canvas::Action::publish(InteractiveOcrMessage::SearchInputBlurred)
+                            })
+                        }
+                        (1, Some((idx, char_pos))) => {
                             log::debug!(
                                 "[OCR_OVERLAY] Started drag at char {}: '{}'",
                                 idx,
                                 char_pos.character
                             );
-                            return Some(canvas::Action::publish(
-                                InteractiveOcrMessage::StartDrag(idx),
-                            ));
+                            InteractiveOcrMessage::StartDrag(idx)
                         }
-                    }
-
+                        (2, Some((_, char_pos))) => {
+                            InteractiveOcrMessage::SelectWord(char_pos.word_index)
+                        }
+                        (_, Some((_, char_pos))) => {
+                            InteractiveOcrMessage::SelectLine(char_pos.word_index)
+                        }
+                    };
+                    Some(canvas::Action::publish(message))
+                }
+                iced::mouse::Event::ButtonPressed(iced::mouse::Button::Middle) => {
+                    state.pan_origin = cursor.position_in(bounds);
                     None
                 }
+                iced::mouse::Event::ButtonReleased(iced::mouse::Button::Middle) => {
+                    state.pan_origin = None;
+                    None
+                }
+                iced::mouse::Event::WheelScrolled { delta } => {
+                    let cursor_position = cursor.position_in(bounds)?;
+                    let scroll_steps = match delta {
+                        iced::mouse::ScrollDelta::Lines { y, .. } => *y,
+                        iced::mouse::ScrollDelta::Pixels { y, .. } => *y / SCROLL_PIXELS_PER_STEP,
+                    };
+                    Some(
+                        canvas::Action::publish(InteractiveOcrMessage::ZoomImage(
+                            scroll_steps,
+                            cursor_position,
+                            bounds.size(),
+                        ))
+                        .and_capture(),
+                    )
+                }
                 iced::mouse::Event::CursorMoved { .. } => {
                     let Some(cursor_position) = cursor.position_in(bounds) else {
                         return None;
                     };
+                    if let Some(pan_origin) = state.pan_origin {
+                        state.pan_origin = Some(cursor_position);
+                        return Some(canvas::Action::publish(InteractiveOcrMessage::PanImage(
+                            cursor_position - pan_origin,
+                        )));
+                    }
                     if self.draw_panel_is_dragging {
                         return Some(canvas::Action::publish(
                             InteractiveOcrMessage::DrawPanelMoved(
@@ -282,23 +495,17 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
                         ));
                     }
                     if self.is_drawing {
-                        let rel_x = (cursor_position.x - offset_x) / scale_x;
-                        let rel_y = (cursor_position.y - offset_y) / scale_y;
+                        let image_point = transform.to_image(cursor_position);
                         return Some(canvas::Action::publish(
-                            InteractiveOcrMessage::UpdateDrawing(Point::new(rel_x, rel_y)),
+                            InteractiveOcrMessage::UpdateDrawing(image_point),
                         ));
                     }
+                    if self.reading_order_mode_enabled || self.step_marker_mode_enabled {
+                        return None;
+                    }
 
                     for (idx, char_pos) in self.char_positions.iter().enumerate() {
-                        let rect_bounds = &char_pos.bounds;
-                        let scaled_x = offset_x + (rect_bounds.x * scale_x);
-                        let scaled_y = offset_y + (rect_bounds.y * scale_y);
-                        let scaled_width = rect_bounds.width * scale_x;
-                        let scaled_height = rect_bounds.height * scale_y;
-                        let char_rect = Rectangle::new(
-                            Point::new(scaled_x, scaled_y),
-                            Size::new(scaled_width, scaled_height),
-                        );
+                        let char_rect = transform.rect_to_screen(char_pos.bounds);
 
                         if char_rect.contains(cursor_position) {
                             return Some(canvas::Action::publish(
@@ -329,3 +536,174 @@ impl canvas::Program<InteractiveOcrMessage> for OcrOverlay {
         }
     }
 }
+
+impl OcrOverlay {
+    fn build_transform(&self, bounds: Rectangle) -> ImageTransform {
+        ImageTransform::fit(
+            self.image_width as f32,
+            self.image_height as f32,
+            bounds.size(),
+        )
+        .zoomed(self.image_zoom, self.image_pan, bounds.size())
+    }
+
+    fn calculate_block_bounds(&self, word_index: usize) -> Option<Rectangle> {
+        self.char_positions
+            .iter()
+            .filter(|char_pos| char_pos.word_index == word_index)
+            .map(|char_pos| char_pos.bounds)
+            .reduce(|combined, bounds| combined.union(&bounds))
+    }
+}
+
+fn build_canvas_stroke(tool: DrawTool, color: Color, width: f32) -> canvas::Stroke<'static> {
+    let stroke = canvas::Stroke::default()
+        .with_color(color)
+        .with_width(width);
+    match tool {
+        DrawTool::Pen | DrawTool::Eraser => stroke,
+        DrawTool::Highlighter => stroke.with_line_cap(canvas::LineCap::Square),
+    }
+}
+
+fn draw_shape(frame: &mut canvas::Frame, transform: &ImageTransform, shape: &ShapeAnnotation) {
+    let stroke = canvas::Stroke::default()
+        .with_color(shape.color)
+        .with_width(shape.width);
+    let start = transform.to_screen(shape.start);
+    let end = transform.to_screen(shape.end);
+
+    match shape.shape {
+        Shape::Rectangle => {
+            let top_left = Point::new(start.x.min(end.x), start.y.min(end.y));
+            let size = Size::new((end.x - start.x).abs(), (end.y - start.y).abs());
+            frame.stroke(&canvas::Path::rectangle(top_left, size), stroke);
+        }
+        Shape::Arrow => {
+            frame.stroke(&canvas::Path::line(start, end), stroke);
+            let [tip, left, right] = build_arrowhead_points(shape.start, shape.end, shape.width);
+            let arrowhead = canvas::Path::new(|builder| {
+                builder.move_to(transform.to_screen(tip));
+                builder.line_to(transform.to_screen(left));
+                builder.line_to(transform.to_screen(right));
+                builder.close();
+            });
+            frame.fill(&arrowhead, shape.color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::keyboard::Modifiers;
+
+    fn build_test_overlay(selected_indices: Vec<usize>) -> OcrOverlay {
+        OcrOverlay {
+            image_handle: iced::widget::image::Handle::from_rgba(10, 10, vec![0u8; 400]),
+            image_zoom: 1.0,
+            image_pan: Vector::ZERO,
+            char_positions: Vec::new(),
+            image_width: 10,
+            image_height: 10,
+            selected_indices,
+            draw_strokes: Vec::new(),
+            current_stroke_points: Vec::new(),
+            is_drawing: false,
+            draw_color: Color::WHITE,
+            draw_width: 2.0,
+            draw_tool: DrawTool::default(),
+            shape_tool: None,
+            shapes: Vec::new(),
+            annotation_order: Vec::new(),
+            draw_mode_enabled: false,
+            reading_order_mode_enabled: false,
+            reading_order: Vec::new(),
+            step_marker_mode_enabled: false,
+            step_markers: Vec::new(),
+            draw_panel_position: Point::ORIGIN,
+            draw_panel_is_dragging: false,
+            shortcuts: ResultWindowShortcuts::default(),
+            is_search_input_focused: false,
+        }
+    }
+
+    #[test]
+    fn test_copy_shortcut_publishes_copy_when_text_is_selected() {
+        let overlay = build_test_overlay(vec![0, 1]);
+
+        let message = overlay.resolve_character_shortcut("c", Modifiers::CTRL);
+
+        assert!(matches!(message, Some(InteractiveOcrMessage::CopySelected)));
+    }
+
+    #[test]
+    fn test_shortcuts_are_left_to_focused_search_field() {
+        let mut overlay = build_test_overlay(vec![0, 1]);
+        overlay.is_search_input_focused = true;
+
+        assert!(overlay
+            .resolve_character_shortcut("c", Modifiers::CTRL)
+            .is_none());
+        assert!(overlay
+            .resolve_character_shortcut("a", Modifiers::CTRL)
+            .is_none());
+        assert!(overlay
+            .resolve_character_shortcut("z", Modifiers::CTRL)
+            .is_none());
+    }
+
+    #[test]
+    fn test_copy_shortcut_is_ignored_without_selection() {
+        let overlay = build_test_overlay(Vec::new());
+
+        let message = overlay.resolve_character_shortcut("c", Modifiers::CTRL);
+
+        assert!(message.is_none());
+    }
+
+    #[test]
+    fn test_save_shortcut_publishes_save_image() {
+        let overlay = build_test_overlay(Vec::new());
+
+        let message = overlay.resolve_character_shortcut("s", Modifiers::CTRL);
+        let project_message =
+            overlay.resolve_character_shortcut("s", Modifiers::CTRL | Modifiers::SHIFT);
+
+        assert!(matches!(
+            message,
+            Some(InteractiveOcrMessage::SaveImageToFile)
+        ));
+        assert!(matches!(
+            project_message,
+            Some(InteractiveOcrMessage::SaveProject)
+        ));
+    }
+
+    #[test]
+    fn test_zoom_reset_shortcut_publishes_reset() {
+        let overlay = build_test_overlay(Vec::new());
+
+        let message = overlay.resolve_character_shortcut("0", Modifiers::CTRL);
+
+        assert!(matches!(
+            message,
+            Some(InteractiveOcrMessage::ResetImageZoom)
+        ));
+    }
+
+    #[test]
+    fn test_transform_follows_image_zoom_and_pan() {
+        let mut overlay = build_test_overlay(Vec::new());
+        overlay.image_zoom = 2.0;
+        overlay.image_pan = Vector::new(10.0, 0.0);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+
+        let transform = overlay.build_transform(bounds);
+
+        assert_eq!(
+            transform.display_bounds(),
+            Rectangle::new(Point::new(-40.0, -50.0), Size::new(200.0, 200.0))
+        );
+    }
+}
@@ -7,6 +7,16 @@ impl InteractiveOcrView {
             result.text_blocks.len()
         );
 
+        let total_blocks = result.text_blocks.len();
+        let result = result.retain_confident_blocks(self.min_ocr_confidence);
+        if result.text_blocks.len() < total_blocks {
+            log::info!(
+                "[INTERACTIVE_OCR] Dropped {} text blocks below confidence {:.2}",
+                total_blocks - result.text_blocks.len(),
+                self.min_ocr_confidence
+            );
+        }
+
         self.char_positions = Self::calculate_char_positions(&result);
         log::info!(
             "[INTERACTIVE_OCR] Calculated {} character positions",
@@ -15,7 +25,7 @@ impl InteractiveOcrView {
         self.ocr_result = Some(result);
         self.ocr_state = OcrState::Completed;
 
-        if !self.char_positions.is_empty() {
+        if self.help_hint_enabled && !self.char_positions.is_empty() {
             self.show_help_hint = true;
         }
     }
@@ -71,3 +81,93 @@ impl InteractiveOcrView {
         y_changes as f32 / positions.len() as f32 > 0.3
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::{CaptureBuffer, DetectedText, ThemeMode};
+
+    fn build_block(content: &str, x: f32, confidence: f32) -> DetectedText {
+        DetectedText::new(content.to_string(), x, 0.0, 30.0, 10.0, confidence, vec![])
+    }
+
+    fn build_multi_line_result() -> OcrResult {
+        OcrResult {
+            text_blocks: vec![
+                DetectedText::new("hello".to_string(), 0.0, 0.0, 50.0, 10.0, 0.9, vec![]),
+                DetectedText::new("world".to_string(), 60.0, 1.0, 50.0, 10.0, 0.9, vec![]),
+                DetectedText::new("again".to_string(), 0.0, 30.0, 50.0, 10.0, 0.9, vec![]),
+            ],
+            full_text: "hello world again".to_string(),
+        }
+    }
+
+    fn selected_characters(view: &InteractiveOcrView) -> String {
+        let mut selected = view.selected_chars.clone();
+        selected.sort_unstable();
+        selected
+            .iter()
+            .map(|&index| view.char_positions[index].character)
+            .collect()
+    }
+
+    #[test]
+    fn test_set_ocr_result_drops_blocks_below_confidence_threshold() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.set_min_ocr_confidence(0.5);
+
+        view.set_ocr_result(OcrResult {
+            text_blocks: vec![
+                build_block("low", 0.0, 0.2),
+                build_block("ok", 40.0, 0.5),
+                build_block("high", 80.0, 0.9),
+            ],
+            full_text: "low ok high".to_string(),
+        });
+
+        let characters: String = view
+            .char_positions
+            .iter()
+            .map(|position| position.character)
+            .collect();
+        assert_eq!(characters, "okhigh");
+        assert_eq!(view.ocr_result.as_ref().unwrap().full_text, "ok high");
+    }
+
+    #[test]
+    fn test_all_text_matches_selecting_everything() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        assert_eq!(view.get_all_text(), "");
+
+        view.set_ocr_result(build_multi_line_result());
+        let all_text = view.get_all_text();
+        view.update(InteractiveOcrMessage::SelectAll);
+
+        assert_eq!(all_text, view.get_selected_text());
+        assert_eq!(all_text.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_select_word_and_line_group_characters() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.set_ocr_result(build_multi_line_result());
+        let world_index = view
+            .char_positions
+            .iter()
+            .find(|char_pos| char_pos.character == 'w')
+            .unwrap()
+            .word_index;
+
+        view.update(InteractiveOcrMessage::SelectWord(world_index));
+        assert_eq!(selected_characters(&view), "world");
+
+        view.update(InteractiveOcrMessage::SelectLine(world_index));
+        assert_eq!(selected_characters(&view), "helloworld");
+
+        view.update(InteractiveOcrMessage::DeselectAll);
+        assert!(view.selected_chars.is_empty());
+    }
+}
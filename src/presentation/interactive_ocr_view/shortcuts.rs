@@ -0,0 +1,160 @@
+use iced::keyboard::Modifiers;
+
+use super::InteractiveOcrMessage;
+use crate::global_constants;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardShortcut {
+    key: String,
+    requires_command: bool,
+    requires_shift: bool,
+    requires_alt: bool,
+}
+
+impl KeyboardShortcut {
+    pub fn parse(binding: &str) -> Option<Self> {
+        let mut key = None;
+        let mut requires_command = false;
+        let mut requires_shift = false;
+        let mut requires_alt = false;
+
+        for part in binding.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" | "command" | "super" => requires_command = true,
+                "shift" => requires_shift = true,
+                "alt" | "option" => requires_alt = true,
+                "" => return None,
+                other if key.is_none() && other.chars().count() == 1 => {
+                    key = Some(other.to_string());
+                }
+                _ => return None,
+            }
+        }
+
+        Some(Self {
+            key: key?,
+            requires_command,
+            requires_shift,
+            requires_alt,
+        })
+    }
+
+    pub fn matches(&self, key: &str, modifiers: Modifiers) -> bool {
+        let is_cmd_or_ctrl = modifiers.command() || modifiers.control();
+
+        key.eq_ignore_ascii_case(&self.key)
+            && is_cmd_or_ctrl == self.requires_command
+            && modifiers.shift() == self.requires_shift
+            && modifiers.alt() == self.requires_alt
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultWindowShortcuts {
+    toggle_draw_mode: Option<KeyboardShortcut>,
+    save_image: Option<KeyboardShortcut>,
+    copy_image: Option<KeyboardShortcut>,
+}
+
+impl ResultWindowShortcuts {
+    pub fn from_bindings(toggle_draw_mode: &str, save_image: &str, copy_image: &str) -> Self {
+        Self {
+            toggle_draw_mode: Self::parse_binding(toggle_draw_mode),
+            save_image: Self::parse_binding(save_image),
+            copy_image: Self::parse_binding(copy_image),
+        }
+    }
+
+    pub fn resolve(&self, key: &str, modifiers: Modifiers) -> Option<InteractiveOcrMessage> {
+        let bindings = [
+            (
+                &self.toggle_draw_mode,
+                InteractiveOcrMessage::ToggleDrawMode,
+            ),
+            (&self.save_image, InteractiveOcrMessage::SaveImageToFile),
+            (
+                &self.copy_image,
+                InteractiveOcrMessage::CopyImageToClipboard,
+            ),
+        ];
+
+        bindings
+            .into_iter()
+            .find(|(shortcut, _)| {
+                shortcut
+                    .as_ref()
+                    .is_some_and(|shortcut| shortcut.matches(key, modifiers))
+            })
+            .map(|(_, message)| message)
+    }
+
+    fn parse_binding(binding: &str) -> Option<KeyboardShortcut> {
+        let shortcut = KeyboardShortcut::parse(binding);
+        if shortcut.is_none() && !binding.trim().is_empty() {
+            log::warn!(
+                "[INTERACTIVE_OCR] Ignoring invalid result window shortcut '{}'",
+                binding
+            );
+        }
+        shortcut
+    }
+}
+
+impl Default for ResultWindowShortcuts {
+    fn default() -> Self {
+        Self::from_bindings(
+            global_constants::DEFAULT_DRAW_MODE_SHORTCUT,
+            global_constants::DEFAULT_SAVE_IMAGE_SHORTCUT,
+            global_constants::DEFAULT_COPY_IMAGE_SHORTCUT,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shortcut_with_modifiers() {
+        let shortcut = KeyboardShortcut::parse("Ctrl+Shift+S").unwrap();
+
+        assert!(shortcut.matches("s", Modifiers::CTRL | Modifiers::SHIFT));
+        assert!(!shortcut.matches("s", Modifiers::CTRL));
+    }
+
+    #[test]
+    fn test_parse_shortcut_rejects_invalid_bindings() {
+        assert!(KeyboardShortcut::parse("").is_none());
+        assert!(KeyboardShortcut::parse("Ctrl+").is_none());
+        assert!(KeyboardShortcut::parse("Ctrl+Enter").is_none());
+        assert!(KeyboardShortcut::parse("A+B").is_none());
+    }
+
+    #[test]
+    fn test_default_shortcuts_resolve_result_window_actions() {
+        let shortcuts = ResultWindowShortcuts::default();
+
+        assert!(matches!(
+            shortcuts.resolve("d", Modifiers::empty()),
+            Some(InteractiveOcrMessage::ToggleDrawMode)
+        ));
+        assert!(matches!(
+            shortcuts.resolve("s", Modifiers::CTRL),
+            Some(InteractiveOcrMessage::SaveImageToFile)
+        ));
+        assert!(matches!(
+            shortcuts.resolve("d", Modifiers::CTRL),
+            Some(InteractiveOcrMessage::CopyImageToClipboard)
+        ));
+        assert!(shortcuts
+            .resolve("d", Modifiers::CTRL | Modifiers::SHIFT)
+            .is_none());
+    }
+
+    #[test]
+    fn test_empty_binding_disables_shortcut() {
+        let shortcuts = ResultWindowShortcuts::from_bindings("", "Ctrl+S", "Ctrl+D");
+
+        assert!(shortcuts.resolve("d", Modifiers::empty()).is_none());
+    }
+}
@@ -1,10 +1,17 @@
 use std::cmp::Ordering;
 use std::path::Path;
 
-use crate::core::models::OcrResult;
+use iced::{Color, Point, Rectangle};
 
-use super::{CharPosition, ImageCopyState, OcrState, SaveState, SearchState};
+use crate::core::models::{AnnotationStepMarker, AnnotationStroke, OcrResult};
+use crate::global_constants;
 
+use super::{
+    AnnotationLayer, AnnotationMode, CharPosition, DrawStroke, DrawTool, HsvColor, ImageCopyState,
+    OcrState, SaveState, SearchState, SelectionStep, Shape, ShapeAnnotation, StepMarker,
+};
+
+const MARKDOWN_BULLET_PREFIXES: [&str; 3] = ["-", "\u{2013}", "\u{2014}"];
 const STATUS_PREPARING_SAVE_IMAGE: &str = "⏳ Preparing to save image...";
 const STATUS_SAVING_IMAGE_FILE: &str = "💾 Saving image to file...";
 const STATUS_PREPARING_IMAGE: &str = "⏳ Preparing image...";
@@ -13,6 +20,13 @@ const STATUS_IMAGE_COPIED_TO_CLIPBOARD: &str = "✅ Image copied to clipboard";
 const STATUS_UPLOADING_IMAGE_FOR_SEARCH: &str = "🔍 Uploading image for search...";
 const STATUS_SEARCH_COMPLETED: &str = "✅ Search completed";
 const STATUS_DRAW_MODE_ENABLED: &str = "🖊️ Draw Mode ON - Click and drag to draw";
+const STATUS_READING_ORDER_MODE_ENABLED: &str =
+    "🔢 Reading Order Mode ON - Click text blocks in the order to copy them (Esc to exit)";
+const STATUS_READING_ORDER_COUNT_PREFIX: &str = "🔢 Reading Order: ";
+const STATUS_READING_ORDER_COUNT_SUFFIX: &str =
+    " blocks ordered - Click a block again to remove it (Esc to exit)";
+const STATUS_STEP_MARKER_MODE_ENABLED: &str =
+    "① Step Marker Mode ON - Click to drop numbered markers";
 const STATUS_PROMPT_PERFORM_OCR: &str = "Perform OCR text recognition?";
 const STATUS_PROCESSING_OCR: &str = "Processing OCR...";
 const STATUS_SAVE_SUCCESS_PREFIX: &str = "✅ Saved to ";
@@ -23,6 +37,142 @@ const STATUS_DETECTED_WORDS_PREFIX: &str = "✅ Detected ";
 const STATUS_DETECTED_WORDS_SUFFIX: &str = " words - Click to select text";
 const STATUS_SELECTED_CHARACTERS_PREFIX: &str = "Selected ";
 const STATUS_SELECTED_CHARACTERS_SUFFIX: &str = " characters";
+const COLUMN_GAP_CHAR_WIDTH_MULTIPLIER: f32 = 2.0;
+const COLUMN_MIN_LINE_HEIGHT_RATIO: f32 = 0.5;
+const LINE_BASELINE_TOLERANCE_RATIO: f32 = 0.5;
+pub const STEP_MARKER_RADIUS: f32 = 14.0;
+const STEP_MARKER_DIGIT_WIDTH_RATIO: f32 = 0.4;
+const STEP_MARKER_DIGIT_SPACING_RATIO: f32 = 0.75;
+const STEP_MARKER_DIGIT_STROKE_WIDTH: f32 = 2.0;
+const MIN_SHAPE_DRAG_DISTANCE: f32 = 2.0;
+const ERASER_HIT_RADIUS: f32 = 6.0;
+const ARROWHEAD_WIDTH_MULTIPLIER: f32 = 4.0;
+const ARROWHEAD_MIN_LENGTH: f32 = 10.0;
+const SEVEN_SEGMENT_LINES: [((f32, f32), (f32, f32)); 7] = [
+    ((0.0, 0.0), (1.0, 0.0)),
+    ((1.0, 0.0), (1.0, 1.0)),
+    ((1.0, 1.0), (1.0, 2.0)),
+    ((0.0, 2.0), (1.0, 2.0)),
+    ((0.0, 1.0), (0.0, 2.0)),
+    ((0.0, 0.0), (0.0, 1.0)),
+    ((0.0, 1.0), (1.0, 1.0)),
+];
+const DIGIT_SEGMENTS: [&[usize]; 10] = [
+    &[0, 1, 2, 3, 4, 5],
+    &[1, 2],
+    &[0, 1, 6, 4, 3],
+    &[0, 1, 6, 2, 3],
+    &[5, 6, 1, 2],
+    &[0, 5, 6, 2, 3],
+    &[0, 5, 6, 4, 3, 2],
+    &[0, 1, 2],
+    &[0, 1, 2, 3, 4, 5, 6],
+    &[0, 1, 2, 3, 5, 6],
+];
+
+pub fn collect_word_char_indices(word_index: usize, char_positions: &[CharPosition]) -> Vec<usize> {
+    char_positions
+        .iter()
+        .enumerate()
+        .filter(|(_, char_pos)| char_pos.word_index == word_index)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+pub fn collect_line_char_indices(word_index: usize, char_positions: &[CharPosition]) -> Vec<usize> {
+    let Some(word_bounds) = char_positions
+        .iter()
+        .filter(|char_pos| char_pos.word_index == word_index)
+        .map(|char_pos| char_pos.bounds)
+        .reduce(|combined, bounds| combined.union(&bounds))
+    else {
+        return Vec::new();
+    };
+    let baseline_y = word_bounds.y + word_bounds.height;
+    let baseline_tolerance = word_bounds.height * LINE_BASELINE_TOLERANCE_RATIO;
+
+    char_positions
+        .iter()
+        .enumerate()
+        .filter(|(_, char_pos)| {
+            let char_baseline_y = char_pos.bounds.y + char_pos.bounds.height;
+            (char_baseline_y - baseline_y).abs() <= baseline_tolerance
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+pub fn build_spatial_char_order(char_positions: &[CharPosition]) -> Vec<usize> {
+    let mut ordered: Vec<(usize, &CharPosition)> = char_positions.iter().enumerate().collect();
+    ordered.sort_by(|(_, left), (_, right)| compare_char_positions(left, right));
+    ordered.into_iter().map(|(index, _)| index).collect()
+}
+
+pub fn move_selection_focus(
+    focus: usize,
+    moving_right: bool,
+    step: SelectionStep,
+    char_positions: &[CharPosition],
+) -> usize {
+    let spatial_order = build_spatial_char_order(char_positions);
+    let Some(mut order_position) = spatial_order.iter().position(|&index| index == focus) else {
+        return focus;
+    };
+    let last_position = spatial_order.len() - 1;
+    let word_of = |position: usize| char_positions[spatial_order[position]].word_index;
+
+    if moving_right {
+        order_position = (order_position + 1).min(last_position);
+        if step == SelectionStep::Word {
+            while order_position < last_position
+                && word_of(order_position + 1) == word_of(order_position)
+            {
+                order_position += 1;
+            }
+        }
+    } else {
+        order_position = order_position.saturating_sub(1);
+        if step == SelectionStep::Word {
+            while order_position > 0 && word_of(order_position - 1) == word_of(order_position) {
+                order_position -= 1;
+            }
+        }
+    }
+
+    spatial_order[order_position]
+}
+
+pub fn find_spatial_selection_ends(
+    selected_chars: &[usize],
+    char_positions: &[CharPosition],
+) -> Option<(usize, usize)> {
+    let spatial_order = build_spatial_char_order(char_positions);
+    let mut selected_in_order = spatial_order
+        .into_iter()
+        .filter(|index| selected_chars.contains(index));
+    let first = selected_in_order.next()?;
+    Some((first, selected_in_order.last().unwrap_or(first)))
+}
+
+pub fn collect_spatial_range(
+    anchor: usize,
+    focus: usize,
+    char_positions: &[CharPosition],
+) -> Vec<usize> {
+    let spatial_order = build_spatial_char_order(char_positions);
+    let (Some(anchor_position), Some(focus_position)) = (
+        spatial_order.iter().position(|&index| index == anchor),
+        spatial_order.iter().position(|&index| index == focus),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut range = spatial_order
+        [anchor_position.min(focus_position)..=anchor_position.max(focus_position)]
+        .to_vec();
+    range.sort_unstable();
+    range
+}
 
 pub fn build_selected_text_with_layout(
     selected_chars: &[usize],
@@ -32,14 +182,23 @@ pub fn build_selected_text_with_layout(
         return String::new();
     }
 
-    let mut selected_positions: Vec<&CharPosition> = selected_chars
+    let selected_positions: Vec<&CharPosition> = selected_chars
         .iter()
         .filter_map(|&index| char_positions.get(index))
         .collect();
 
-    selected_positions.sort_by(compare_char_positions);
+    split_into_columns(selected_positions)
+        .into_iter()
+        .map(build_column_text)
+        .filter(|column_text| !column_text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn build_column_text(mut column_positions: Vec<&CharPosition>) -> String {
+    column_positions.sort_by(compare_char_positions);
 
-    let Some(first_position) = selected_positions.first().copied() else {
+    let Some(first_position) = column_positions.first().copied() else {
         return String::new();
     };
 
@@ -48,7 +207,7 @@ pub fn build_selected_text_with_layout(
     let mut last_word_index = first_position.word_index;
     let mut last_x_end = first_position.bounds.x + first_position.bounds.width;
 
-    for position in selected_positions {
+    for position in column_positions {
         let line_height_threshold = position.bounds.height * 0.5;
         let y_difference = (position.bounds.y - last_y).abs();
 
@@ -83,14 +242,187 @@ pub fn build_selected_text_with_layout(
     result
 }
 
+fn split_into_columns(positions: Vec<&CharPosition>) -> Vec<Vec<&CharPosition>> {
+    let (mut finite_positions, invalid_positions): (Vec<&CharPosition>, Vec<&CharPosition>) =
+        positions.into_iter().partition(|position| {
+            position.bounds.x.is_finite()
+                && position.bounds.y.is_finite()
+                && position.bounds.width.is_finite()
+                && position.bounds.height.is_finite()
+        });
+
+    if finite_positions.is_empty() {
+        return vec![invalid_positions];
+    }
+
+    finite_positions.sort_by(|left, right| left.bounds.x.total_cmp(&right.bounds.x));
+
+    let column_gap_threshold = median_value(
+        finite_positions
+            .iter()
+            .map(|position| position.bounds.width),
+    ) * COLUMN_GAP_CHAR_WIDTH_MULTIPLIER;
+    let min_column_height = median_value(finite_positions.iter().map(|p| p.bounds.height))
+        * COLUMN_MIN_LINE_HEIGHT_RATIO;
+
+    let mut columns: Vec<Vec<&CharPosition>> = Vec::new();
+    let mut current_column_right_edge = f32::NEG_INFINITY;
+
+    for position in finite_positions {
+        let starts_new_column = columns.is_empty()
+            || position.bounds.x - current_column_right_edge > column_gap_threshold;
+
+        if starts_new_column {
+            columns.push(Vec::new());
+            current_column_right_edge = f32::NEG_INFINITY;
+        }
+
+        current_column_right_edge =
+            current_column_right_edge.max(position.bounds.x + position.bounds.width);
+        if let Some(column) = columns.last_mut() {
+            column.push(position);
+        }
+    }
+
+    let every_column_spans_multiple_lines = columns
+        .iter()
+        .all(|column| column_vertical_span(column) > min_column_height);
+
+    if columns.len() < 2 || !every_column_spans_multiple_lines {
+        let mut single_column: Vec<&CharPosition> = columns.into_iter().flatten().collect();
+        single_column.extend(invalid_positions);
+        return vec![single_column];
+    }
+
+    if let Some(first_column) = columns.first_mut() {
+        first_column.extend(invalid_positions);
+    }
+
+    columns
+}
+
+fn column_vertical_span(column: &[&CharPosition]) -> f32 {
+    let min_y = column
+        .iter()
+        .map(|position| position.bounds.y)
+        .fold(f32::INFINITY, f32::min);
+    let max_y = column
+        .iter()
+        .map(|position| position.bounds.y)
+        .fold(f32::NEG_INFINITY, f32::max);
+    max_y - min_y
+}
+
+fn median_value(values: impl Iterator<Item = f32>) -> f32 {
+    let mut sorted_values: Vec<f32> = values.collect();
+    if sorted_values.is_empty() {
+        return 0.0;
+    }
+
+    sorted_values.sort_by(f32::total_cmp);
+    sorted_values[sorted_values.len() / 2]
+}
+
+pub fn build_reading_order_text(
+    reading_order: &[usize],
+    char_positions: &[CharPosition],
+) -> String {
+    reading_order
+        .iter()
+        .map(|&word_index| {
+            let mut block_positions: Vec<&CharPosition> = char_positions
+                .iter()
+                .filter(|position| position.word_index == word_index)
+                .collect();
+            block_positions.sort_by_key(|position| position.char_index);
+            block_positions
+                .into_iter()
+                .map(|position| position.character)
+                .collect::<String>()
+        })
+        .filter(|block_text| !block_text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn build_markdown_from_layout_text(layout_text: &str) -> String {
+    let mut markdown_blocks: Vec<String> = Vec::new();
+    let mut previous_line_was_bullet = false;
+
+    for line in layout_text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        let Some(bullet_item) = strip_markdown_bullet_prefix(line) else {
+            markdown_blocks.push(line.to_string());
+            previous_line_was_bullet = false;
+            continue;
+        };
+
+        let bullet_line = format!("- {}", bullet_item);
+        match markdown_blocks.last_mut() {
+            Some(bullet_list) if previous_line_was_bullet => {
+                bullet_list.push('\n');
+                bullet_list.push_str(&bullet_line);
+            }
+            _ => markdown_blocks.push(bullet_line),
+        }
+        previous_line_was_bullet = true;
+    }
+
+    markdown_blocks.join("\n\n")
+}
+
+fn strip_markdown_bullet_prefix(line: &str) -> Option<&str> {
+    MARKDOWN_BULLET_PREFIXES.iter().find_map(|prefix| {
+        let remainder = line.strip_prefix(prefix)?;
+        let bullet_item = remainder.trim_start();
+        (remainder.starts_with(char::is_whitespace) && !bullet_item.is_empty())
+            .then_some(bullet_item)
+    })
+}
+
+pub fn build_selection_bounds(
+    selected_chars: &[usize],
+    char_positions: &[CharPosition],
+) -> Option<Rectangle> {
+    let mut selected_bounds = selected_chars
+        .iter()
+        .filter_map(|&index| char_positions.get(index))
+        .map(|position| position.bounds)
+        .filter(|bounds| bounds.x.is_finite() && bounds.y.is_finite());
+
+    let first_bounds = selected_bounds.next()?;
+    let mut min_x = first_bounds.x;
+    let mut min_y = first_bounds.y;
+    let mut max_x = first_bounds.x + first_bounds.width;
+    let mut max_y = first_bounds.y + first_bounds.height;
+
+    for bounds in selected_bounds {
+        min_x = min_x.min(bounds.x);
+        min_y = min_y.min(bounds.y);
+        max_x = max_x.max(bounds.x + bounds.width);
+        max_y = max_y.max(bounds.y + bounds.height);
+    }
+
+    Some(Rectangle {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
+    })
+}
+
 pub fn build_status_text(
     save_state: &SaveState,
     image_copy_state: &ImageCopyState,
     search_state: &SearchState,
     ocr_state: &OcrState,
-    draw_mode_enabled: bool,
+    annotation_mode: AnnotationMode,
     ocr_result: Option<&OcrResult>,
     selected_char_count: usize,
+    reading_order_count: usize,
 ) -> String {
     match (save_state, image_copy_state, search_state, ocr_state) {
         (SaveState::Preparing, _, _, _) => STATUS_PREPARING_SAVE_IMAGE.to_string(),
@@ -116,7 +448,24 @@ pub fn build_status_text(
         (_, _, SearchState::Failed(error_message), _) => {
             format!("{}{}", STATUS_SEARCH_FAILED_PREFIX, error_message)
         }
-        (_, _, _, _) if draw_mode_enabled => STATUS_DRAW_MODE_ENABLED.to_string(),
+        (_, _, _, _) if annotation_mode == AnnotationMode::Draw => {
+            STATUS_DRAW_MODE_ENABLED.to_string()
+        }
+        (_, _, _, _) if annotation_mode == AnnotationMode::ReadingOrder => {
+            if reading_order_count == 0 {
+                STATUS_READING_ORDER_MODE_ENABLED.to_string()
+            } else {
+                format!(
+                    "{}{}{}",
+                    STATUS_READING_ORDER_COUNT_PREFIX,
+                    reading_order_count,
+                    STATUS_READING_ORDER_COUNT_SUFFIX,
+                )
+            }
+        }
+        (_, _, _, _) if annotation_mode == AnnotationMode::StepMarker => {
+            STATUS_STEP_MARKER_MODE_ENABLED.to_string()
+        }
         (_, _, _, OcrState::Idle) => STATUS_PROMPT_PERFORM_OCR.to_string(),
         (_, _, _, OcrState::Processing) => STATUS_PROCESSING_OCR.to_string(),
         (_, _, _, OcrState::Failed(_)) => String::new(),
@@ -138,6 +487,53 @@ pub fn build_status_text(
     }
 }
 
+pub fn build_step_marker_strokes(marker: &StepMarker) -> Vec<DrawStroke> {
+    let mut strokes = vec![DrawStroke {
+        points: vec![marker.position, marker.position],
+        color: marker.color,
+        width: STEP_MARKER_RADIUS * 2.0,
+        tool: DrawTool::Pen,
+    }];
+
+    let digits: Vec<usize> = marker
+        .number
+        .to_string()
+        .chars()
+        .filter_map(|character| character.to_digit(10))
+        .map(|digit| digit as usize)
+        .collect();
+    let segment_unit = STEP_MARKER_RADIUS * STEP_MARKER_DIGIT_WIDTH_RATIO;
+    let digit_spacing = segment_unit * STEP_MARKER_DIGIT_SPACING_RATIO;
+    let total_width =
+        digits.len() as f32 * segment_unit + digits.len().saturating_sub(1) as f32 * digit_spacing;
+    let origin_x = marker.position.x - total_width / 2.0;
+    let origin_y = marker.position.y - segment_unit;
+
+    for (digit_position, digit) in digits.iter().enumerate() {
+        let digit_x = origin_x + digit_position as f32 * (segment_unit + digit_spacing);
+        for segment_index in DIGIT_SEGMENTS[*digit] {
+            let ((start_x, start_y), (end_x, end_y)) = SEVEN_SEGMENT_LINES[*segment_index];
+            strokes.push(DrawStroke {
+                points: vec![
+                    Point::new(
+                        digit_x + start_x * segment_unit,
+                        origin_y + start_y * segment_unit,
+                    ),
+                    Point::new(
+                        digit_x + end_x * segment_unit,
+                        origin_y + end_y * segment_unit,
+                    ),
+                ],
+                color: Color::WHITE,
+                width: STEP_MARKER_DIGIT_STROKE_WIDTH,
+                tool: DrawTool::Pen,
+            });
+        }
+    }
+
+    strokes
+}
+
 fn compare_char_positions(left: &&CharPosition, right: &&CharPosition) -> Ordering {
     let left_y_valid = left.bounds.y.is_finite();
     let right_y_valid = right.bounds.y.is_finite();
@@ -187,10 +583,297 @@ fn extract_display_name(path: &str) -> String {
         .unwrap_or_else(|| path.to_string())
 }
 
+pub fn point_to_segment_distance(point: Point, segment_start: Point, segment_end: Point) -> f32 {
+    let segment_x = segment_end.x - segment_start.x;
+    let segment_y = segment_end.y - segment_start.y;
+    let segment_length_squared = segment_x * segment_x + segment_y * segment_y;
+    if segment_length_squared <= f32::EPSILON {
+        return point.distance(segment_start);
+    }
+
+    let projection = ((point.x - segment_start.x) * segment_x
+        + (point.y - segment_start.y) * segment_y)
+        / segment_length_squared;
+    let clamped_projection = projection.clamp(0.0, 1.0);
+    let closest_point = Point::new(
+        segment_start.x + clamped_projection * segment_x,
+        segment_start.y + clamped_projection * segment_y,
+    );
+    point.distance(closest_point)
+}
+
+pub fn find_topmost_stroke_at(
+    strokes: &[DrawStroke],
+    point: Point,
+    image_units_per_screen_pixel: f32,
+) -> Option<usize> {
+    strokes.iter().rposition(|stroke| {
+        let hit_tolerance = (ERASER_HIT_RADIUS + stroke.width / 2.0) * image_units_per_screen_pixel;
+        match stroke.points.as_slice() {
+            [] => false,
+            [single_point] => point.distance(*single_point) <= hit_tolerance,
+            points => points.windows(2).any(|segment| {
+                point_to_segment_distance(point, segment[0], segment[1]) <= hit_tolerance
+            }),
+        }
+    })
+}
+
+pub fn find_topmost_annotation_at(
+    strokes: &[DrawStroke],
+    shapes: &[ShapeAnnotation],
+    annotation_order: &[AnnotationLayer],
+    point: Point,
+    image_units_per_screen_pixel: f32,
+) -> Option<(AnnotationLayer, usize)> {
+    let mut stroke_index = strokes.len();
+    let mut shape_index = shapes.len();
+
+    annotation_order.iter().rev().find_map(|layer| {
+        let (index, outline) = match layer {
+            AnnotationLayer::Stroke => {
+                stroke_index = stroke_index.checked_sub(1)?;
+                (stroke_index, vec![strokes[stroke_index].clone()])
+            }
+            AnnotationLayer::Shape => {
+                shape_index = shape_index.checked_sub(1)?;
+                (shape_index, build_shape_strokes(&shapes[shape_index]))
+            }
+        };
+        find_topmost_stroke_at(&outline, point, image_units_per_screen_pixel)
+            .map(|_| (*layer, index))
+    })
+}
+
+pub fn find_annotation_order_position(
+    annotation_order: &[AnnotationLayer],
+    layer: AnnotationLayer,
+    index: usize,
+) -> Option<usize> {
+    annotation_order
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| **entry == layer)
+        .nth(index)
+        .map(|(position, _)| position)
+}
+
+pub fn build_shape_from_drag(
+    shape: Shape,
+    start: Point,
+    end: Point,
+    color: Color,
+    width: f32,
+) -> Option<ShapeAnnotation> {
+    let delta_x = (end.x - start.x).abs();
+    let delta_y = (end.y - start.y).abs();
+    let is_degenerate = match shape {
+        Shape::Arrow => start.distance(end) < MIN_SHAPE_DRAG_DISTANCE,
+        Shape::Rectangle => delta_x < MIN_SHAPE_DRAG_DISTANCE || delta_y < MIN_SHAPE_DRAG_DISTANCE,
+    };
+    if is_degenerate {
+        return None;
+    }
+
+    Some(ShapeAnnotation {
+        shape,
+        start,
+        end,
+        color,
+        width,
+    })
+}
+
+pub fn build_arrowhead_points(start: Point, end: Point, width: f32) -> [Point; 3] {
+    let length = start.distance(end).max(f32::EPSILON);
+    let direction_x = (end.x - start.x) / length;
+    let direction_y = (end.y - start.y) / length;
+    let head_length = (width * ARROWHEAD_WIDTH_MULTIPLIER)
+        .max(ARROWHEAD_MIN_LENGTH)
+        .min(length);
+    let half_width = head_length / 2.0;
+    let base_x = end.x - direction_x * head_length;
+    let base_y = end.y - direction_y * head_length;
+
+    [
+        end,
+        Point::new(
+            base_x - direction_y * half_width,
+            base_y + direction_x * half_width,
+        ),
+        Point::new(
+            base_x + direction_y * half_width,
+            base_y - direction_x * half_width,
+        ),
+    ]
+}
+
+pub fn hsv_to_color(hsv: HsvColor) -> Color {
+    let hue = hsv.hue.rem_euclid(360.0) / 60.0;
+    let saturation = hsv.saturation.clamp(0.0, 1.0);
+    let value = hsv.value.clamp(0.0, 1.0);
+    let chroma = value * saturation;
+    let secondary = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (red, green, blue) = match hue as u32 {
+        0 => (chroma, secondary, 0.0),
+        1 => (secondary, chroma, 0.0),
+        2 => (0.0, chroma, secondary),
+        3 => (0.0, secondary, chroma),
+        4 => (secondary, 0.0, chroma),
+        _ => (chroma, 0.0, secondary),
+    };
+    let offset = value - chroma;
+
+    Color::from_rgb(red + offset, green + offset, blue + offset)
+}
+
+pub fn color_to_hsv(color: Color) -> HsvColor {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+    let hue = if delta <= f32::EPSILON {
+        0.0
+    } else if max == color.r {
+        60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        60.0 * ((color.b - color.r) / delta + 2.0)
+    } else {
+        60.0 * ((color.r - color.g) / delta + 4.0)
+    };
+    let saturation = if max <= f32::EPSILON {
+        0.0
+    } else {
+        delta / max
+    };
+
+    HsvColor {
+        hue,
+        saturation,
+        value: max,
+    }
+}
+
+pub fn push_recent_draw_color(recent_colors: &[Color], color: Color, limit: usize) -> Vec<Color> {
+    let mut updated_colors = vec![color];
+    updated_colors.extend(
+        recent_colors
+            .iter()
+            .filter(|existing| existing.into_rgba8() != color.into_rgba8())
+            .copied(),
+    );
+    updated_colors.truncate(limit);
+    updated_colors
+}
+
+pub fn build_shape_strokes(shape: &ShapeAnnotation) -> Vec<DrawStroke> {
+    let outline = |points: Vec<Point>| DrawStroke {
+        points,
+        color: shape.color,
+        width: shape.width,
+        tool: DrawTool::Pen,
+    };
+
+    match shape.shape {
+        Shape::Rectangle => vec![outline(vec![
+            shape.start,
+            Point::new(shape.end.x, shape.start.y),
+            shape.end,
+            Point::new(shape.start.x, shape.end.y),
+            shape.start,
+        ])],
+        Shape::Arrow => {
+            let [tip, left, right] = build_arrowhead_points(shape.start, shape.end, shape.width);
+            vec![
+                outline(vec![shape.start, shape.end]),
+                outline(vec![tip, left, right, tip]),
+            ]
+        }
+    }
+}
+
+impl DrawTool {
+    pub fn stroke_appearance(self, color: Color, width: f32) -> (Color, f32) {
+        match self {
+            DrawTool::Pen | DrawTool::Eraser => (color, width),
+            DrawTool::Highlighter => (
+                Color {
+                    a: global_constants::HIGHLIGHTER_ALPHA,
+                    ..color
+                },
+                width * global_constants::HIGHLIGHTER_WIDTH_MULTIPLIER,
+            ),
+        }
+    }
+}
+
+impl From<&DrawStroke> for AnnotationStroke {
+    fn from(stroke: &DrawStroke) -> Self {
+        AnnotationStroke {
+            points: stroke
+                .points
+                .iter()
+                .map(|point| (point.x, point.y))
+                .collect(),
+            color: (
+                stroke.color.r,
+                stroke.color.g,
+                stroke.color.b,
+                stroke.color.a,
+            ),
+            width: stroke.width,
+            is_highlighter: stroke.tool == DrawTool::Highlighter,
+        }
+    }
+}
+
+impl From<AnnotationStroke> for DrawStroke {
+    fn from(stroke: AnnotationStroke) -> Self {
+        let (r, g, b, a) = stroke.color;
+        DrawStroke {
+            points: stroke
+                .points
+                .into_iter()
+                .map(|(x, y)| Point::new(x, y))
+                .collect(),
+            color: Color::from_rgba(r, g, b, a),
+            width: stroke.width,
+            tool: if stroke.is_highlighter {
+                DrawTool::Highlighter
+            } else {
+                DrawTool::Pen
+            },
+        }
+    }
+}
+
+impl From<&StepMarker> for AnnotationStepMarker {
+    fn from(marker: &StepMarker) -> Self {
+        AnnotationStepMarker {
+            position: (marker.position.x, marker.position.y),
+            number: marker.number,
+            color: (
+                marker.color.r,
+                marker.color.g,
+                marker.color.b,
+                marker.color.a,
+            ),
+        }
+    }
+}
+
+impl From<AnnotationStepMarker> for StepMarker {
+    fn from(marker: AnnotationStepMarker) -> Self {
+        let (r, g, b, a) = marker.color;
+        StepMarker {
+            position: Point::new(marker.position.0, marker.position.1),
+            number: marker.number,
+            color: Color::from_rgba(r, g, b, a),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use iced::Rectangle;
-
     use crate::core::models::OcrResult;
 
     use super::*;
@@ -215,6 +898,109 @@ mod tests {
         }
     }
 
+    fn build_two_line_layout() -> Vec<CharPosition> {
+        vec![
+            build_position(2, 0, 0.0, 40.0, 'e'),
+            build_position(2, 1, 10.0, 40.0, 'f'),
+            build_position(0, 0, 0.0, 0.0, 'a'),
+            build_position(0, 1, 10.0, 0.0, 'b'),
+            build_position(1, 0, 30.0, 0.0, 'c'),
+            build_position(1, 1, 40.0, 0.0, 'd'),
+        ]
+    }
+
+    fn characters_at(indices: &[usize], char_positions: &[CharPosition]) -> String {
+        build_spatial_char_order(char_positions)
+            .into_iter()
+            .filter(|index| indices.contains(index))
+            .map(|index| char_positions[index].character)
+            .collect()
+    }
+
+    #[test]
+    fn test_move_selection_focus_steps_by_character_in_spatial_order() {
+        let char_positions = build_two_line_layout();
+
+        let after_b = move_selection_focus(3, true, SelectionStep::Character, &char_positions);
+        let after_d = move_selection_focus(5, true, SelectionStep::Character, &char_positions);
+
+        assert_eq!(char_positions[after_b].character, 'c');
+        assert_eq!(char_positions[after_d].character, 'e');
+        assert_eq!(
+            characters_at(
+                &collect_spatial_range(2, after_d, &char_positions),
+                &char_positions
+            ),
+            "abcde"
+        );
+    }
+
+    #[test]
+    fn test_move_selection_focus_jumps_by_word() {
+        let char_positions = build_two_line_layout();
+
+        let word_right = move_selection_focus(2, true, SelectionStep::Word, &char_positions);
+        let word_left = move_selection_focus(1, false, SelectionStep::Word, &char_positions);
+
+        assert_eq!(char_positions[word_right].character, 'b');
+        assert_eq!(char_positions[word_left].character, 'e');
+        assert_eq!(
+            char_positions
+                [move_selection_focus(word_right, true, SelectionStep::Word, &char_positions)]
+            .character,
+            'd'
+        );
+    }
+
+    #[test]
+    fn test_move_selection_focus_clamps_at_first_and_last_character() {
+        let char_positions = build_two_line_layout();
+
+        assert_eq!(
+            move_selection_focus(2, false, SelectionStep::Character, &char_positions),
+            2
+        );
+        assert_eq!(
+            move_selection_focus(1, true, SelectionStep::Word, &char_positions),
+            1
+        );
+    }
+
+    #[test]
+    fn test_find_spatial_selection_ends_uses_reading_order() {
+        let char_positions = build_two_line_layout();
+
+        assert_eq!(
+            find_spatial_selection_ends(&[0, 3, 4], &char_positions),
+            Some((3, 0))
+        );
+        assert_eq!(find_spatial_selection_ends(&[], &char_positions), None);
+    }
+
+    #[test]
+    fn test_build_markdown_from_layout_text_turns_two_line_selection_into_paragraphs() {
+        let char_positions = build_two_line_layout();
+
+        let layout_text = build_selected_text_with_layout(&[0, 1, 2, 3, 4, 5], &char_positions);
+        let markdown = build_markdown_from_layout_text(&layout_text);
+
+        assert_eq!(markdown, "ab cd\n\nef");
+    }
+
+    #[test]
+    fn test_build_markdown_from_layout_text_keeps_single_line_as_plain_paragraph() {
+        assert_eq!(build_markdown_from_layout_text("  ab cd  "), "ab cd");
+    }
+
+    #[test]
+    fn test_build_markdown_from_layout_text_groups_dash_lines_into_a_list() {
+        let layout_text = "Shopping\n- milk\n\u{2013} eggs\n-5 degrees outside";
+
+        let markdown = build_markdown_from_layout_text(layout_text);
+
+        assert_eq!(markdown, "Shopping\n\n- milk\n- eggs\n\n-5 degrees outside");
+    }
+
     #[test]
     fn test_build_selected_text_with_layout_sorts_nan_coordinates_without_panicking() {
         let char_positions = vec![
@@ -241,6 +1027,140 @@ mod tests {
         assert_eq!(result, "Hi Th");
     }
 
+    #[test]
+    fn test_build_selected_text_with_layout_reads_columns_in_order() {
+        let char_positions = vec![
+            build_position(0, 0, 0.0, 0.0, 'a'),
+            build_position(0, 1, 10.0, 0.0, 'b'),
+            build_position(1, 0, 100.0, 0.0, 'e'),
+            build_position(1, 1, 110.0, 0.0, 'f'),
+            build_position(2, 0, 0.0, 30.0, 'c'),
+            build_position(2, 1, 10.0, 30.0, 'd'),
+            build_position(3, 0, 100.0, 30.0, 'g'),
+            build_position(3, 1, 110.0, 30.0, 'h'),
+        ];
+
+        let result = build_selected_text_with_layout(&[0, 1, 2, 3, 4, 5, 6, 7], &char_positions);
+
+        assert_eq!(result, "ab\ncd\nef\ngh");
+    }
+
+    #[test]
+    fn test_build_selected_text_with_layout_keeps_wide_gap_on_single_line() {
+        let char_positions = vec![
+            build_position(0, 0, 0.0, 0.0, 'a'),
+            build_position(1, 0, 100.0, 0.0, 'b'),
+        ];
+
+        let result = build_selected_text_with_layout(&[0, 1], &char_positions);
+
+        assert_eq!(result, "a b");
+    }
+
+    #[test]
+    fn test_build_reading_order_text_follows_chosen_block_order() {
+        let char_positions = vec![
+            build_position(0, 0, 0.0, 0.0, 'a'),
+            build_position(0, 1, 10.0, 0.0, 'b'),
+            build_position(1, 1, 110.0, 0.0, 'd'),
+            build_position(1, 0, 100.0, 0.0, 'c'),
+        ];
+
+        let result = build_reading_order_text(&[1, 0], &char_positions);
+
+        assert_eq!(result, "cd ab");
+    }
+
+    #[test]
+    fn test_build_reading_order_text_skips_unknown_blocks() {
+        let char_positions = vec![build_position(0, 0, 0.0, 0.0, 'a')];
+
+        let result = build_reading_order_text(&[5, 0], &char_positions);
+
+        assert_eq!(result, "a");
+    }
+
+    #[test]
+    fn test_build_status_text_reports_reading_order_mode() {
+        let result = build_status_text(
+            &SaveState::Idle,
+            &ImageCopyState::Idle,
+            &SearchState::Idle,
+            &OcrState::Completed,
+            AnnotationMode::ReadingOrder,
+            None,
+            0,
+            0,
+        );
+
+        assert_eq!(
+            result,
+            "🔢 Reading Order Mode ON - Click text blocks in the order to copy them (Esc to exit)"
+        );
+    }
+
+    #[test]
+    fn test_build_status_text_reports_reading_order_count() {
+        let result = build_status_text(
+            &SaveState::Idle,
+            &ImageCopyState::Idle,
+            &SearchState::Idle,
+            &OcrState::Completed,
+            AnnotationMode::ReadingOrder,
+            None,
+            4,
+            2,
+        );
+
+        assert_eq!(
+            result,
+            "🔢 Reading Order: 2 blocks ordered - Click a block again to remove it (Esc to exit)"
+        );
+    }
+
+    #[test]
+    fn test_build_selection_bounds_returns_none_without_selection() {
+        let char_positions = vec![build_position(0, 0, 0.0, 10.0, 'A')];
+
+        let result = build_selection_bounds(&[], &char_positions);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_build_selection_bounds_spans_all_selected_characters() {
+        let char_positions = vec![
+            build_position(0, 0, 5.0, 10.0, 'A'),
+            build_position(0, 1, 15.0, 10.0, 'B'),
+            build_position(1, 0, 40.0, 50.0, 'C'),
+        ];
+
+        let result = build_selection_bounds(&[0, 2], &char_positions).unwrap();
+
+        assert_eq!(
+            result,
+            Rectangle {
+                x: 5.0,
+                y: 10.0,
+                width: 45.0,
+                height: 60.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_selection_bounds_ignores_non_finite_coordinates() {
+        let char_positions = vec![
+            build_position(0, 0, f32::NAN, 10.0, 'A'),
+            build_position(0, 1, 20.0, 10.0, 'B'),
+        ];
+
+        let result = build_selection_bounds(&[0, 1], &char_positions).unwrap();
+
+        assert_eq!(result.x, 20.0);
+        assert_eq!(result.width, 10.0);
+    }
+
     #[test]
     fn test_build_status_text_uses_file_name_for_save_success() {
         let result = build_status_text(
@@ -248,9 +1168,10 @@ mod tests {
             &ImageCopyState::Idle,
             &SearchState::Idle,
             &OcrState::Completed,
-            false,
+            AnnotationMode::None,
             None,
             0,
+            0,
         );
 
         assert_eq!(result, "✅ Saved to capture.png");
@@ -263,14 +1184,236 @@ mod tests {
             &ImageCopyState::Idle,
             &SearchState::Idle,
             &OcrState::Completed,
-            false,
+            AnnotationMode::None,
             Some(&OcrResult {
                 text_blocks: vec![],
                 full_text: String::new(),
             }),
             0,
+            0,
         );
 
         assert_eq!(result, "✅ Detected 0 words - Click to select text");
     }
+
+    #[test]
+    fn test_build_step_marker_strokes_draws_badge_and_single_digit() {
+        let marker = StepMarker {
+            position: Point::new(50.0, 50.0),
+            number: 1,
+            color: Color::from_rgb(1.0, 0.0, 0.0),
+        };
+
+        let strokes = build_step_marker_strokes(&marker);
+
+        assert_eq!(strokes.len(), 3);
+        assert_eq!(strokes[0].points, vec![marker.position, marker.position]);
+        assert_eq!(strokes[0].width, STEP_MARKER_RADIUS * 2.0);
+        assert!(strokes[1..]
+            .iter()
+            .all(|stroke| stroke.color == Color::WHITE));
+    }
+
+    #[test]
+    fn test_build_step_marker_strokes_centers_multi_digit_numbers_inside_badge() {
+        let marker = StepMarker {
+            position: Point::new(100.0, 40.0),
+            number: 20,
+            color: Color::from_rgb(0.2, 0.6, 1.0),
+        };
+
+        let strokes = build_step_marker_strokes(&marker);
+        let digit_points: Vec<Point> = strokes[1..]
+            .iter()
+            .flat_map(|stroke| stroke.points.clone())
+            .collect();
+        let min_x = digit_points
+            .iter()
+            .map(|point| point.x)
+            .fold(f32::MAX, f32::min);
+        let max_x = digit_points
+            .iter()
+            .map(|point| point.x)
+            .fold(f32::MIN, f32::max);
+
+        assert_eq!(strokes.len(), 1 + 5 + 6);
+        assert!(((min_x + max_x) / 2.0 - marker.position.x).abs() < 0.001);
+        assert!(digit_points.iter().all(|point| {
+            (point.x - marker.position.x).abs() <= STEP_MARKER_RADIUS
+                && (point.y - marker.position.y).abs() <= STEP_MARKER_RADIUS
+        }));
+    }
+
+    #[test]
+    fn test_point_to_segment_distance_clamps_to_segment_ends() {
+        let start = Point::new(0.0, 0.0);
+        let end = Point::new(10.0, 0.0);
+
+        assert_eq!(
+            point_to_segment_distance(Point::new(5.0, 3.0), start, end),
+            3.0
+        );
+        assert_eq!(
+            point_to_segment_distance(Point::new(-3.0, 4.0), start, end),
+            5.0
+        );
+        assert_eq!(
+            point_to_segment_distance(Point::new(13.0, -4.0), start, end),
+            5.0
+        );
+        assert_eq!(
+            point_to_segment_distance(Point::new(3.0, 4.0), start, start),
+            5.0
+        );
+    }
+
+    #[test]
+    fn test_find_topmost_stroke_at_prefers_latest_overlapping_stroke() {
+        let build_stroke = |y: f32| DrawStroke {
+            points: vec![Point::new(0.0, y), Point::new(100.0, y)],
+            color: Color::BLACK,
+            width: 4.0,
+            tool: DrawTool::Pen,
+        };
+        let strokes = vec![build_stroke(10.0), build_stroke(12.0), build_stroke(80.0)];
+
+        assert_eq!(
+            find_topmost_stroke_at(&strokes, Point::new(50.0, 11.0), 1.0),
+            Some(1)
+        );
+        assert_eq!(
+            find_topmost_stroke_at(&strokes, Point::new(50.0, 40.0), 1.0),
+            None
+        );
+        assert_eq!(
+            find_topmost_stroke_at(&strokes, Point::new(50.0, 40.0), 10.0),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_build_arrowhead_points_for_horizontal_arrow() {
+        let [tip, left, right] =
+            build_arrowhead_points(Point::new(0.0, 0.0), Point::new(100.0, 0.0), 3.0);
+
+        assert_eq!(tip, Point::new(100.0, 0.0));
+        assert!((left.x - 88.0).abs() < 0.001 && (left.y - 6.0).abs() < 0.001);
+        assert!((right.x - 88.0).abs() < 0.001 && (right.y + 6.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_find_topmost_annotation_at_follows_drawing_order() {
+        let shape = build_shape_from_drag(
+            Shape::Arrow,
+            Point::new(0.0, 10.0),
+            Point::new(100.0, 10.0),
+            Color::WHITE,
+            2.0,
+        )
+        .unwrap();
+        let stroke = DrawStroke {
+            points: vec![Point::new(0.0, 10.0), Point::new(100.0, 10.0)],
+            color: Color::WHITE,
+            width: 2.0,
+            tool: DrawTool::Pen,
+        };
+        let strokes = vec![stroke];
+        let shapes = vec![shape];
+
+        assert_eq!(
+            find_topmost_annotation_at(
+                &strokes,
+                &shapes,
+                &[AnnotationLayer::Stroke, AnnotationLayer::Shape],
+                Point::new(50.0, 10.0),
+                1.0,
+            ),
+            Some((AnnotationLayer::Shape, 0))
+        );
+        assert_eq!(
+            find_topmost_annotation_at(
+                &strokes,
+                &shapes,
+                &[AnnotationLayer::Shape, AnnotationLayer::Stroke],
+                Point::new(50.0, 10.0),
+                1.0,
+            ),
+            Some((AnnotationLayer::Stroke, 0))
+        );
+    }
+
+    #[test]
+    fn test_build_shape_from_drag_rejects_degenerate_shapes() {
+        let origin = Point::new(10.0, 10.0);
+
+        assert!(build_shape_from_drag(Shape::Arrow, origin, origin, Color::BLACK, 3.0).is_none());
+        assert!(build_shape_from_drag(
+            Shape::Rectangle,
+            origin,
+            Point::new(50.0, 10.0),
+            Color::BLACK,
+            3.0
+        )
+        .is_none());
+        assert!(build_shape_from_drag(
+            Shape::Arrow,
+            origin,
+            Point::new(50.0, 10.0),
+            Color::BLACK,
+            3.0
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_draw_stroke_round_trips_through_annotation_stroke() {
+        let stroke = DrawStroke {
+            points: vec![Point::new(1.0, 2.0), Point::new(3.5, 4.5)],
+            color: Color::from_rgba(0.1, 0.2, 0.3, 0.4),
+            width: 5.0,
+            tool: DrawTool::Highlighter,
+        };
+
+        let restored = DrawStroke::from(AnnotationStroke::from(&stroke));
+
+        assert_eq!(restored.points, stroke.points);
+        assert_eq!(restored.color, stroke.color);
+        assert_eq!(restored.width, stroke.width);
+        assert_eq!(restored.tool, stroke.tool);
+    }
+
+    #[test]
+    fn test_step_marker_round_trips_through_annotation_step_marker() {
+        let marker = StepMarker {
+            position: Point::new(12.0, 34.0),
+            number: 7,
+            color: Color::from_rgb(0.0, 0.5, 1.0),
+        };
+
+        let restored = StepMarker::from(AnnotationStepMarker::from(&marker));
+
+        assert_eq!(restored.position, marker.position);
+        assert_eq!(restored.number, marker.number);
+        assert_eq!(restored.color, marker.color);
+    }
+
+    #[test]
+    fn test_hsv_color_round_trips_through_rgb() {
+        let color = Color::from_rgb(0.2, 0.6, 1.0);
+
+        let restored = hsv_to_color(color_to_hsv(color));
+
+        assert_eq!(restored.into_rgba8(), color.into_rgba8());
+    }
+
+    #[test]
+    fn test_push_recent_draw_color_moves_duplicate_to_front_and_limits() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+
+        let updated = push_recent_draw_color(&[red, green, blue], green, 2);
+
+        assert_eq!(updated, vec![green, red]);
+    }
 }
@@ -11,10 +11,12 @@ const OCR_FAILED_PREFIX: &str = "\u{274c} OCR Failed \u{2014} ";
 const OCR_RETRY_BUTTON_LABEL: &str = "\u{21ba} Retry OCR";
 const TOAST_TEXT_COPIED: &str = "\u{2713} Text copied!";
 const TOAST_COPY_TEXT_FAILED: &str = "\u{2717} Copy failed";
+const TOAST_NO_TEXT_TO_COPY: &str = "No recognized text to copy";
 const TOAST_IMAGE_COPIED: &str = "\u{2713} Image copied!";
 const TOAST_COPY_IMAGE_FAILED_PREFIX: &str = "\u{2717} Copy failed: ";
 const TOAST_SAVE_SUCCESS_PREFIX: &str = "\u{2713} Saved to ";
 const TOAST_SAVE_FAILED_PREFIX: &str = "\u{2717} Save failed: ";
+const CAPTURE_FLASH_OPACITY: f32 = 0.6;
 
 impl InteractiveOcrView {
     pub fn render_ui(&self) -> Element<'_, InteractiveOcrMessage> {
@@ -24,6 +26,10 @@ impl InteractiveOcrView {
             .height(Length::Fill);
         let mut layers: Vec<Element<'_, InteractiveOcrMessage>> = vec![image_layer.into()];
 
+        if self.is_capture_flash_visible {
+            layers.push(self.build_capture_flash());
+        }
+
         layers.push(self.build_status_banner().into());
 
         if let Some(toast) = self.build_copy_toast() {
@@ -36,6 +42,23 @@ impl InteractiveOcrView {
             layers.push(self.position_top_centered(toast, 100.0));
         }
 
+        let mut panel_top = 140.0;
+        if let Some(banner) = self.build_decoded_codes_banner() {
+            layers.push(self.position_top_centered(banner, panel_top));
+            panel_top += 60.0 + 30.0 * self.decoded_codes.len() as f32;
+        }
+        if let Some(panel) = self.build_translation_panel() {
+            layers.push(self.position_top_centered(panel, panel_top));
+            panel_top += 70.0;
+        }
+        if let Some(panel) = self.build_ocr_preprocess_panel() {
+            layers.push(self.position_top_centered(panel, panel_top));
+            panel_top += 60.0;
+        }
+        if let Some(panel) = self.build_color_picker_panel() {
+            layers.push(self.position_top_centered(panel, panel_top));
+        }
+
         if self.show_help_hint && !self.char_positions.is_empty() {
             let hint_positioned = container(self.build_help_hint())
                 .width(Length::Fill)
@@ -64,6 +87,22 @@ impl InteractiveOcrView {
             .into()
     }
 
+    fn build_capture_flash(&self) -> Element<'_, InteractiveOcrMessage> {
+        container(iced::widget::Space::new(Length::Fill, Length::Fill))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_theme| iced::widget::container::Style {
+                background: Some(iced::Background::Color(Color::from_rgba(
+                    1.0,
+                    1.0,
+                    1.0,
+                    CAPTURE_FLASH_OPACITY,
+                ))),
+                ..Default::default()
+            })
+            .into()
+    }
+
     fn build_status_banner(&self) -> Element<'_, InteractiveOcrMessage> {
         let status_text = self.build_status_text();
         let banner_inner_content: Element<'_, InteractiveOcrMessage> =
@@ -249,6 +288,9 @@ impl InteractiveOcrView {
             CopyState::Success => {
                 Some(self.build_toast(TOAST_TEXT_COPIED, Color::from_rgb(0.2, 0.8, 0.4)))
             }
+            CopyState::Empty => {
+                Some(self.build_toast(TOAST_NO_TEXT_TO_COPY, Color::from_rgb(0.7, 0.7, 0.7)))
+            }
             CopyState::Failed => {
                 Some(self.build_toast(TOAST_COPY_TEXT_FAILED, Color::from_rgb(0.9, 0.3, 0.3)))
             }
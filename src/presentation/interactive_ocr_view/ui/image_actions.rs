@@ -2,12 +2,58 @@ use super::*;
 
 const KEYBOARD_SHORTCUT_COPY_IMAGE_MACOS: &str = "\u{2318}D";
 const KEYBOARD_SHORTCUT_COPY_IMAGE_OTHER: &str = "Ctrl+D";
+const KEYBOARD_SHORTCUT_COPY_REGION_MACOS: &str = "\u{21e7}\u{2318}D";
+const KEYBOARD_SHORTCUT_COPY_REGION_OTHER: &str = "Ctrl+Shift+D";
 const KEYBOARD_SHORTCUT_SAVE_IMAGE_MACOS: &str = "\u{2318}S";
 const KEYBOARD_SHORTCUT_SAVE_IMAGE_OTHER: &str = "Ctrl+S";
+const KEYBOARD_SHORTCUT_SAVE_PROJECT_MACOS: &str = "\u{21e7}\u{2318}S";
+const KEYBOARD_SHORTCUT_SAVE_PROJECT_OTHER: &str = "Ctrl+Shift+S";
 const RECROP_BUTTON_TOOLTIP: &str = "Recrop Selection";
+const ROTATE_LEFT_BUTTON_TOOLTIP: &str = "Rotate Left and Re-run OCR";
+const ROTATE_RIGHT_BUTTON_TOOLTIP: &str = "Rotate Right and Re-run OCR";
+const FLIP_HORIZONTAL_BUTTON_TOOLTIP: &str = "Flip Horizontally and Re-run OCR";
+const OCR_PREPROCESS_BUTTON_TOOLTIP: &str = "Adjust Brightness/Contrast for OCR";
+const OCR_LANGUAGE_PICKER_TOOLTIP: &str = "Re-run OCR in Another Language";
+const OCR_PREPROCESS_BRIGHTNESS_LABEL: &str = "Brightness";
+const OCR_PREPROCESS_CONTRAST_LABEL: &str = "Contrast";
+const OCR_PREPROCESS_THRESHOLD_LABEL: &str = "Black & white";
+const OCR_PREPROCESS_RESET_LABEL: &str = "Reset";
+const OCR_PREPROCESS_BRIGHTNESS_RANGE: std::ops::RangeInclusive<i16> = -100..=100;
+const OCR_PREPROCESS_BRIGHTNESS_STEP: i16 = 5;
+const OCR_PREPROCESS_CONTRAST_RANGE: std::ops::RangeInclusive<f32> = 0.5..=3.0;
+const OCR_PREPROCESS_CONTRAST_STEP: f32 = 0.1;
+const OCR_PREPROCESS_SLIDER_WIDTH: f32 = 160.0;
 const CLOSE_BUTTON_TOOLTIP: &str = "Close (Esc)";
+const INCLUDE_ANNOTATIONS_ENABLED_TOOLTIP: &str =
+    "Copy/Save Includes Annotations (click to use original image)";
+const INCLUDE_ANNOTATIONS_DISABLED_TOOLTIP: &str =
+    "Copy/Save Uses Original Image (click to include annotations)";
 
 impl InteractiveOcrView {
+    pub(super) fn push_include_annotations_toggle<'a>(
+        &'a self,
+        action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        if self.can_transform_image() {
+            return action_row;
+        }
+
+        let toggle_btn = button(text("🖍").size(16))
+            .padding([8, 10])
+            .style(move |_theme: &iced::Theme, status| {
+                self.floating_btn_style(status, self.include_annotations_in_copy)
+            })
+            .on_press(InteractiveOcrMessage::ToggleIncludeAnnotationsInCopy);
+        let toggle_tooltip = if self.include_annotations_in_copy {
+            INCLUDE_ANNOTATIONS_ENABLED_TOOLTIP
+        } else {
+            INCLUDE_ANNOTATIONS_DISABLED_TOOLTIP
+        };
+        action_row.push(
+            tooltip(toggle_btn, toggle_tooltip, tooltip::Position::Top).style(Self::tooltip_style),
+        )
+    }
+
     pub(super) fn push_copy_image_button<'a>(
         &self,
         mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
@@ -50,6 +96,50 @@ impl InteractiveOcrView {
         action_row
     }
 
+    pub(super) fn push_copy_region_button<'a>(
+        &self,
+        mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        if self.selected_chars.is_empty() {
+            return action_row;
+        }
+
+        let is_copying = !matches!(self.image_copy_state, ImageCopyState::Idle);
+        let copy_region_shortcut = if cfg!(target_os = "macos") {
+            KEYBOARD_SHORTCUT_COPY_REGION_MACOS
+        } else {
+            KEYBOARD_SHORTCUT_COPY_REGION_OTHER
+        };
+        let mut copy_region_btn =
+            button(text("✂").size(20))
+                .padding([10, 14])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.3, 0.3, 0.3, 0.95),
+                        Color::from_rgba(0.2, 0.2, 0.2, 0.95),
+                        Color::from_rgba(0.5, 0.5, 0.5, 0.4),
+                    )
+                });
+        if !is_copying {
+            copy_region_btn =
+                copy_region_btn.on_press(InteractiveOcrMessage::CopySelectedRegionToClipboard);
+        }
+        action_row = action_row.push(
+            tooltip(
+                copy_region_btn,
+                text(format!(
+                    "Copy Selected Region as Image ({})",
+                    copy_region_shortcut
+                )),
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        );
+        action_row
+    }
+
     pub(super) fn push_save_button<'a>(
         &self,
         mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
@@ -92,6 +182,223 @@ impl InteractiveOcrView {
         action_row
     }
 
+    pub(super) fn push_save_project_button<'a>(
+        &self,
+        mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let is_saving = !matches!(self.save_state, SaveState::Idle);
+        let save_project_shortcut = if cfg!(target_os = "macos") {
+            KEYBOARD_SHORTCUT_SAVE_PROJECT_MACOS
+        } else {
+            KEYBOARD_SHORTCUT_SAVE_PROJECT_OTHER
+        };
+        let mut save_project_btn =
+            button(text("🗂").size(20))
+                .padding([10, 14])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.2, 0.6, 0.3, 0.95),
+                        Color::from_rgba(0.1, 0.5, 0.2, 0.95),
+                        Color::from_rgba(0.3, 0.7, 0.4, 0.5),
+                    )
+                });
+        if !is_saving {
+            save_project_btn = save_project_btn.on_press(InteractiveOcrMessage::SaveProject);
+        }
+        action_row = action_row.push(
+            tooltip(
+                save_project_btn,
+                text(format!(
+                    "Save Editable Annotation Project ({})",
+                    save_project_shortcut
+                )),
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        );
+        action_row
+    }
+
+    pub(super) fn push_orientation_buttons<'a>(
+        &self,
+        mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        if !self.can_transform_image() {
+            return action_row;
+        }
+
+        for (icon, tooltip_text, message) in [
+            (
+                "↺",
+                ROTATE_LEFT_BUTTON_TOOLTIP,
+                InteractiveOcrMessage::RotateLeft,
+            ),
+            (
+                "↻",
+                ROTATE_RIGHT_BUTTON_TOOLTIP,
+                InteractiveOcrMessage::RotateRight,
+            ),
+            (
+                "⇆",
+                FLIP_HORIZONTAL_BUTTON_TOOLTIP,
+                InteractiveOcrMessage::FlipHorizontal,
+            ),
+        ] {
+            let orientation_btn = button(text(icon).size(20))
+                .padding([10, 14])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.4, 0.4, 0.5, 0.95),
+                        Color::from_rgba(0.3, 0.3, 0.4, 0.95),
+                        Color::from_rgba(0.5, 0.5, 0.6, 0.5),
+                    )
+                })
+                .on_press(message);
+            action_row = action_row.push(
+                tooltip(orientation_btn, tooltip_text, tooltip::Position::Top)
+                    .style(Self::tooltip_style),
+            );
+        }
+        action_row
+    }
+
+    pub(super) fn push_ocr_preprocess_button<'a>(
+        &self,
+        mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let is_active = self.ocr_preprocess_panel_open || !self.ocr_preprocess.is_identity();
+        let preprocess_btn = button(text("🔆").size(20))
+            .padding([10, 14])
+            .style(move |_theme: &iced::Theme, status| self.floating_btn_style(status, is_active))
+            .on_press(InteractiveOcrMessage::ToggleOcrPreprocessPanel);
+        action_row = action_row.push(
+            tooltip(
+                preprocess_btn,
+                OCR_PREPROCESS_BUTTON_TOOLTIP,
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        );
+        action_row
+    }
+
+    pub(super) fn push_ocr_language_picker<'a>(
+        &self,
+        action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let language_picker = iced::widget::pick_list(
+            self.available_ocr_languages.clone(),
+            OcrLanguage::from_code(&self.ocr_language),
+            |language| InteractiveOcrMessage::ReprocessWithLanguage(language.code().to_string()),
+        )
+        .text_size(14)
+        .padding([8, 10]);
+        action_row.push(
+            tooltip(
+                language_picker,
+                OCR_LANGUAGE_PICKER_TOOLTIP,
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        )
+    }
+
+    pub(super) fn build_ocr_preprocess_panel(&self) -> Option<Element<'_, InteractiveOcrMessage>> {
+        if !self.ocr_preprocess_panel_open {
+            return None;
+        }
+
+        let preprocess = self.ocr_preprocess;
+        let brightness_slider = iced::widget::slider(
+            OCR_PREPROCESS_BRIGHTNESS_RANGE,
+            preprocess.brightness,
+            move |brightness| {
+                InteractiveOcrMessage::AdjustOcrPreprocess(OcrPreprocess {
+                    brightness,
+                    ..preprocess
+                })
+            },
+        )
+        .step(OCR_PREPROCESS_BRIGHTNESS_STEP)
+        .on_release(InteractiveOcrMessage::SetOcrPreprocess(preprocess))
+        .width(Length::Fixed(OCR_PREPROCESS_SLIDER_WIDTH));
+        let contrast_slider = iced::widget::slider(
+            OCR_PREPROCESS_CONTRAST_RANGE,
+            preprocess.contrast,
+            move |contrast| {
+                InteractiveOcrMessage::AdjustOcrPreprocess(OcrPreprocess {
+                    contrast,
+                    ..preprocess
+                })
+            },
+        )
+        .step(OCR_PREPROCESS_CONTRAST_STEP)
+        .on_release(InteractiveOcrMessage::SetOcrPreprocess(preprocess))
+        .width(Length::Fixed(OCR_PREPROCESS_SLIDER_WIDTH));
+        let threshold_toggle =
+            iced::widget::checkbox(preprocess.threshold).on_toggle(move |threshold| {
+                InteractiveOcrMessage::SetOcrPreprocess(OcrPreprocess {
+                    threshold,
+                    ..preprocess
+                })
+            });
+        let mut reset_btn = button(text(OCR_PREPROCESS_RESET_LABEL).size(12))
+            .padding([4, 10])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.2, 0.2, 0.2, 0.9),
+                    Color::from_rgba(0.4, 0.4, 0.5, 0.95),
+                    Color::from_rgba(0.3, 0.3, 0.4, 0.95),
+                    Color::from_rgba(0.5, 0.5, 0.6, 0.5),
+                )
+            });
+        if !preprocess.is_identity() {
+            reset_btn = reset_btn.on_press(InteractiveOcrMessage::SetOcrPreprocess(
+                OcrPreprocess::default(),
+            ));
+        }
+
+        let panel_content = row![
+            text(OCR_PREPROCESS_BRIGHTNESS_LABEL)
+                .size(12)
+                .color(Color::WHITE),
+            brightness_slider,
+            text(OCR_PREPROCESS_CONTRAST_LABEL)
+                .size(12)
+                .color(Color::WHITE),
+            contrast_slider,
+            threshold_toggle,
+            text(OCR_PREPROCESS_THRESHOLD_LABEL)
+                .size(12)
+                .color(Color::WHITE),
+            reset_btn,
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        Some(
+            container(panel_content)
+                .padding([10, 16])
+                .style(|_theme| iced::widget::container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgba(
+                        0.1, 0.1, 0.1, 0.92,
+                    ))),
+                    border: Border {
+                        color: Color::from_rgba(0.4, 0.4, 0.4, 0.6),
+                        width: 1.0,
+                        radius: 8.0.into(),
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
     pub(super) fn push_recrop_button<'a>(
         &self,
         mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
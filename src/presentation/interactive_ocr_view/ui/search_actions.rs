@@ -4,17 +4,35 @@ const KEYBOARD_SHORTCUT_COPY_TEXT_MACOS: &str = "\u{2318}C";
 const KEYBOARD_SHORTCUT_COPY_TEXT_OTHER: &str = "Ctrl+C";
 const KEYBOARD_SHORTCUT_SELECT_ALL_MACOS: &str = "\u{2318}A";
 const KEYBOARD_SHORTCUT_SELECT_ALL_OTHER: &str = "Ctrl+A";
+const COPY_ALL_TEXT_TOOLTIP: &str = "Copy All Recognized Text";
+const COPY_AS_MARKDOWN_TOOLTIP: &str = "Copy Selected (or All) Text as Markdown";
+const EXPORT_TEXT_TO_FILE_TOOLTIP: &str = "Export All Recognized Text to a .txt File";
+const COPY_TEXT_LAYOUT_JSON_TOOLTIP: &str = "Copy Text Layout as JSON (text, bounds, confidence)";
+const TRANSLATE_SELECTED_TOOLTIP: &str = "Translate Selected Text";
+const TRANSLATION_IN_PROGRESS_TEXT: &str = "\u{1f310} Translating\u{2026}";
+const TRANSLATION_FAILED_PREFIX: &str = "\u{2717} Translation failed: ";
+const TRANSLATION_PANEL_MAX_WIDTH: f32 = 480.0;
+const TYPE_OUT_TEXT_TOOLTIP: &str = "Type Selected Text into the Focused App";
 const SEARCH_INPUT_PLACEHOLDER: &str = "Optional: Add text to refine your search";
 const SEARCH_BUTTON_TOOLTIP: &str = "Search Image on Google";
+const CANCEL_SEARCH_BUTTON_TOOLTIP: &str = "Cancel Image Search";
+const TEXT_SEARCH_BUTTON_TOOLTIP: &str = "Search the Web for the Typed Text Only";
+const COPY_IMAGE_URL_TOOLTIP: &str = "Copy Hosted Image URL";
+const COPY_IMAGE_URL_DISABLED_TOOLTIP: &str = "Run an image search first to get a hosted URL";
 const HELP_HINT_PREFIX: &str = "\u{1f4a1} Click and drag on text to select \u{2022} ";
 const HELP_HINT_SUFFIX: &str = " to select all \u{2022} Esc to deselect";
+const HELP_HINT_DONT_SHOW_AGAIN: &str = "Don't show again";
+const DECODED_CODE_ICON: &str = "\u{25a6}";
+const DECODED_CODE_OPEN_LABEL: &str = "Open";
+const DECODED_CODE_COPY_LABEL: &str = "Copy";
+const DECODED_CODE_MAX_DISPLAY_CHARS: usize = 60;
 
 impl InteractiveOcrView {
     pub(super) fn push_copy_text_button<'a>(
         &self,
         mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
     ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
-        if self.selected_chars.is_empty() {
+        if self.selected_chars.is_empty() && self.reading_order.is_empty() {
             return action_row;
         }
 
@@ -43,9 +61,206 @@ impl InteractiveOcrView {
             )
             .style(Self::tooltip_style),
         );
+
+        if !self.type_out_text_enabled {
+            return action_row;
+        }
+
+        let type_text_btn = button(text("⌨️").size(20))
+            .padding([10, 14])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.4, 0.2, 0.6, 0.9),
+                    Color::from_rgba(0.5, 0.3, 0.8, 0.95),
+                    Color::from_rgba(0.4, 0.2, 0.7, 0.95),
+                    Color::from_rgba(0.6, 0.4, 0.9, 0.6),
+                )
+            })
+            .on_press(InteractiveOcrMessage::TypeSelectedText);
+        action_row.push(
+            tooltip(type_text_btn, TYPE_OUT_TEXT_TOOLTIP, tooltip::Position::Top)
+                .style(Self::tooltip_style),
+        )
+    }
+
+    pub(super) fn push_translate_button<'a>(
+        &self,
+        action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        if self.selected_chars.is_empty() && self.reading_order.is_empty() {
+            return action_row;
+        }
+
+        let is_translating = matches!(self.translation_state, TranslationState::Translating);
+        let translate_btn = button(text("🌐").size(20))
+            .padding([10, 14])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.4, 0.2, 0.6, 0.9),
+                    Color::from_rgba(0.5, 0.3, 0.8, 0.95),
+                    Color::from_rgba(0.4, 0.2, 0.7, 0.95),
+                    Color::from_rgba(0.6, 0.4, 0.9, 0.6),
+                )
+            })
+            .on_press_maybe((!is_translating).then_some(InteractiveOcrMessage::TranslateSelected));
+        action_row.push(
+            tooltip(
+                translate_btn,
+                TRANSLATE_SELECTED_TOOLTIP,
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        )
+    }
+
+    pub(super) fn build_translation_panel(&self) -> Option<Element<'_, InteractiveOcrMessage>> {
+        let (panel_text, text_color) = match &self.translation_state {
+            TranslationState::Idle => return None,
+            TranslationState::Translating => (
+                TRANSLATION_IN_PROGRESS_TEXT.to_string(),
+                Color::from_rgba(0.9, 0.9, 0.9, 0.95),
+            ),
+            TranslationState::Completed(translated_text) => (
+                translated_text.clone(),
+                Color::from_rgba(0.95, 0.95, 0.95, 1.0),
+            ),
+            TranslationState::Failed(error) => (
+                format!("{}{}", TRANSLATION_FAILED_PREFIX, error),
+                Color::from_rgb(1.0, 0.5, 0.5),
+            ),
+        };
+
+        let panel_content = row![
+            container(
+                text(panel_text)
+                    .size(14)
+                    .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                        color: Some(text_color),
+                    })
+            )
+            .max_width(TRANSLATION_PANEL_MAX_WIDTH),
+            button(text("\u{2715}").size(12))
+                .padding([4, 8])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::TRANSPARENT,
+                        Color::from_rgba(0.4, 0.4, 0.4, 0.8),
+                        Color::from_rgba(0.3, 0.3, 0.3, 0.8),
+                        Color::TRANSPARENT,
+                    )
+                })
+                .on_press(InteractiveOcrMessage::DismissTranslation)
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center);
+
+        Some(
+            container(panel_content)
+                .padding([10, 16])
+                .style(|_theme| iced::widget::container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgba(
+                        0.1, 0.1, 0.15, 0.92,
+                    ))),
+                    border: Border {
+                        color: Color::from_rgba(0.5, 0.3, 0.8, 0.5),
+                        width: 1.0,
+                        radius: 8.0.into(),
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.4),
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 8.0,
+                    },
+                    text_color: None,
+                    snap: false,
+                })
+                .into(),
+        )
+    }
+
+    pub(super) fn push_all_text_buttons<'a>(
+        &self,
+        mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        if self.ocr_result.is_none() {
+            return action_row;
+        }
+
+        for (icon, tooltip_text, message) in [
+            (
+                "📄",
+                COPY_ALL_TEXT_TOOLTIP,
+                InteractiveOcrMessage::CopyAllText,
+            ),
+            (
+                "M\u{2193}",
+                COPY_AS_MARKDOWN_TOOLTIP,
+                InteractiveOcrMessage::CopyAsMarkdown,
+            ),
+            (
+                "📝",
+                EXPORT_TEXT_TO_FILE_TOOLTIP,
+                InteractiveOcrMessage::ExportTextToFile,
+            ),
+        ] {
+            let all_text_btn = button(text(icon).size(18))
+                .padding([10, 14])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.3, 0.3, 0.3, 0.95),
+                        Color::from_rgba(0.2, 0.2, 0.2, 0.95),
+                        Color::from_rgba(0.5, 0.5, 0.5, 0.4),
+                    )
+                })
+                .on_press(message);
+            action_row = action_row.push(
+                tooltip(all_text_btn, tooltip_text, tooltip::Position::Top)
+                    .style(Self::tooltip_style),
+            );
+        }
+
         action_row
     }
 
+    pub(super) fn push_copy_text_layout_json_button<'a>(
+        &self,
+        action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let has_text_blocks = self
+            .ocr_result
+            .as_ref()
+            .is_some_and(|ocr_result| !ocr_result.text_blocks.is_empty());
+        if !has_text_blocks {
+            return action_row;
+        }
+
+        let copy_layout_btn = button(text("{ }").size(18))
+            .padding([10, 14])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                    Color::from_rgba(0.3, 0.3, 0.3, 0.95),
+                    Color::from_rgba(0.2, 0.2, 0.2, 0.95),
+                    Color::from_rgba(0.5, 0.5, 0.5, 0.4),
+                )
+            })
+            .on_press(InteractiveOcrMessage::CopyTextLayoutJson);
+        action_row.push(
+            tooltip(
+                copy_layout_btn,
+                COPY_TEXT_LAYOUT_JSON_TOOLTIP,
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        )
+    }
+
     pub(super) fn push_search_controls<'a>(
         &self,
         mut action_row: iced::widget::Row<'a, InteractiveOcrMessage>,
@@ -95,11 +310,65 @@ impl InteractiveOcrView {
                 )
             },
         );
-        if !is_searching {
+        let is_uploading = matches!(self.search_state, SearchState::UploadingImage);
+        if is_uploading {
+            search_btn = search_btn.on_press(InteractiveOcrMessage::CancelSearch);
+        } else if !is_searching {
             search_btn = search_btn.on_press(InteractiveOcrMessage::SearchSelected);
         }
+        let search_tooltip = if is_uploading {
+            CANCEL_SEARCH_BUTTON_TOOLTIP
+        } else {
+            SEARCH_BUTTON_TOOLTIP
+        };
+        action_row = action_row.push(
+            tooltip(search_btn, search_tooltip, tooltip::Position::Top).style(Self::tooltip_style),
+        );
+
+        let mut text_search_btn =
+            button(text("🔤").size(20))
+                .padding([10, 14])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.2, 0.5, 0.9, 0.95),
+                        Color::from_rgba(0.1, 0.4, 0.8, 0.95),
+                        Color::from_rgba(0.3, 0.6, 1.0, 0.5),
+                    )
+                });
+        if !is_searching && !self.search_query.trim().is_empty() {
+            text_search_btn = text_search_btn.on_press(InteractiveOcrMessage::SearchTextOnly);
+        }
+        action_row = action_row.push(
+            tooltip(
+                text_search_btn,
+                TEXT_SEARCH_BUTTON_TOOLTIP,
+                tooltip::Position::Top,
+            )
+            .style(Self::tooltip_style),
+        );
+
+        let mut copy_url_btn =
+            button(text("🔗").size(20))
+                .padding([10, 14])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.2, 0.5, 0.9, 0.95),
+                        Color::from_rgba(0.1, 0.4, 0.8, 0.95),
+                        Color::from_rgba(0.3, 0.6, 1.0, 0.5),
+                    )
+                });
+        let copy_url_tooltip = if self.hosted_image_url.is_some() {
+            copy_url_btn = copy_url_btn.on_press(InteractiveOcrMessage::CopyImageUrl);
+            COPY_IMAGE_URL_TOOLTIP
+        } else {
+            COPY_IMAGE_URL_DISABLED_TOOLTIP
+        };
         action_row = action_row.push(
-            tooltip(search_btn, SEARCH_BUTTON_TOOLTIP, tooltip::Position::Top)
+            tooltip(copy_url_btn, copy_url_tooltip, tooltip::Position::Top)
                 .style(Self::tooltip_style),
         );
         action_row
@@ -159,6 +428,111 @@ impl InteractiveOcrView {
         stack![search_input, placeholder_overlay].into()
     }
 
+    pub(super) fn build_decoded_codes_banner(&self) -> Option<Element<'_, InteractiveOcrMessage>> {
+        if self.decoded_codes.is_empty() {
+            return None;
+        }
+
+        let mut code_rows = iced::widget::column![].spacing(6);
+        for (code_index, code) in self.decoded_codes.iter().enumerate() {
+            let display_payload: String =
+                if code.payload.chars().count() > DECODED_CODE_MAX_DISPLAY_CHARS {
+                    let truncated: String = code
+                        .payload
+                        .chars()
+                        .take(DECODED_CODE_MAX_DISPLAY_CHARS)
+                        .collect();
+                    format!("{}\u{2026}", truncated)
+                } else {
+                    code.payload.clone()
+                };
+            let mut code_row = row![text(format!(
+                "{} {}: {}",
+                DECODED_CODE_ICON, code.format, display_payload
+            ))
+            .size(13)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(0.9, 0.9, 0.9, 0.95)),
+            })]
+            .spacing(8)
+            .align_y(Alignment::Center);
+
+            if code.is_url() {
+                code_row = code_row.push(
+                    button(text(DECODED_CODE_OPEN_LABEL).size(12))
+                        .padding([4, 8])
+                        .style(|_theme: &iced::Theme, status| {
+                            Self::solid_button_style(
+                                status,
+                                Color::from_rgba(0.2, 0.4, 0.9, 0.8),
+                                Color::from_rgba(0.3, 0.5, 1.0, 0.9),
+                                Color::from_rgba(0.15, 0.35, 0.8, 0.9),
+                                Color::from_rgba(0.4, 0.6, 1.0, 0.5),
+                            )
+                        })
+                        .on_press(InteractiveOcrMessage::OpenDecodedCode(code_index)),
+                );
+            }
+
+            code_row = code_row.push(
+                button(text(DECODED_CODE_COPY_LABEL).size(12))
+                    .padding([4, 8])
+                    .style(|_theme: &iced::Theme, status| {
+                        Self::solid_button_style(
+                            status,
+                            Color::TRANSPARENT,
+                            Color::from_rgba(0.4, 0.4, 0.4, 0.8),
+                            Color::from_rgba(0.3, 0.3, 0.3, 0.8),
+                            Color::from_rgba(0.4, 0.4, 0.4, 0.5),
+                        )
+                    })
+                    .on_press(InteractiveOcrMessage::CopyDecodedCode(code_index)),
+            );
+            code_rows = code_rows.push(code_row);
+        }
+
+        let banner_content = row![
+            code_rows,
+            button(text("\u{2715}").size(12))
+                .padding([4, 8])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::TRANSPARENT,
+                        Color::from_rgba(0.4, 0.4, 0.4, 0.8),
+                        Color::from_rgba(0.3, 0.3, 0.3, 0.8),
+                        Color::TRANSPARENT,
+                    )
+                })
+                .on_press(InteractiveOcrMessage::DismissDecodedCodes)
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center);
+
+        Some(
+            container(banner_content)
+                .padding([10, 16])
+                .style(|_theme| iced::widget::container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgba(
+                        0.1, 0.1, 0.15, 0.92,
+                    ))),
+                    border: Border {
+                        color: Color::from_rgba(0.3, 0.5, 0.8, 0.5),
+                        width: 1.0,
+                        radius: 8.0.into(),
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.4),
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 8.0,
+                    },
+                    text_color: None,
+                    snap: false,
+                })
+                .into(),
+        )
+    }
+
     pub(super) fn build_help_hint(&self) -> Element<'_, InteractiveOcrMessage> {
         let select_all_shortcut = if cfg!(target_os = "macos") {
             KEYBOARD_SHORTCUT_SELECT_ALL_MACOS
@@ -174,6 +548,18 @@ impl InteractiveOcrView {
             .style(|_theme: &iced::Theme| iced::widget::text::Style {
                 color: Some(Color::from_rgba(0.9, 0.9, 0.9, 0.95)),
             }),
+            button(text(HELP_HINT_DONT_SHOW_AGAIN).size(12))
+                .padding([4, 8])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::TRANSPARENT,
+                        Color::from_rgba(0.4, 0.4, 0.4, 0.8),
+                        Color::from_rgba(0.3, 0.3, 0.3, 0.8),
+                        Color::TRANSPARENT,
+                    )
+                })
+                .on_press(InteractiveOcrMessage::DismissHelpHintPermanently),
             button(text("✕").size(12))
                 .padding([4, 8])
                 .style(|_theme: &iced::Theme, status| {
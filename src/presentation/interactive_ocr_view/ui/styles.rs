@@ -14,23 +14,7 @@ impl InteractiveOcrView {
                 }),
         )
         .padding([8, 16])
-        .style(|_theme| iced::widget::container::Style {
-            background: Some(iced::Background::Color(Color::from_rgba(
-                0.1, 0.1, 0.1, 0.9,
-            ))),
-            border: Border {
-                color: Color::from_rgba(0.4, 0.4, 0.4, 0.5),
-                width: 1.0,
-                radius: 8.0.into(),
-            },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
-                offset: Vector::new(0.0, 2.0),
-                blur_radius: 6.0,
-            },
-            text_color: None,
-            snap: false,
-        })
+        .style(crate::presentation::app_theme::toast_container_style)
         .into()
     }
 
@@ -46,23 +30,7 @@ impl InteractiveOcrView {
                 }),
         )
         .padding([8, 16])
-        .style(|_theme| iced::widget::container::Style {
-            background: Some(iced::Background::Color(Color::from_rgba(
-                0.1, 0.1, 0.1, 0.9,
-            ))),
-            border: Border {
-                color: Color::from_rgba(0.4, 0.4, 0.4, 0.5),
-                width: 1.0,
-                radius: 8.0.into(),
-            },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
-                offset: Vector::new(0.0, 2.0),
-                blur_radius: 6.0,
-            },
-            text_color: None,
-            snap: false,
-        })
+        .style(crate::presentation::app_theme::toast_container_style)
         .into()
     }
 
@@ -152,10 +120,10 @@ impl InteractiveOcrView {
     }
 
     pub(super) fn render_image_with_overlay(&self) -> Element<'_, InteractiveOcrMessage> {
-        let image_viewer = image::viewer(self.image_handle.clone())
-            .width(Length::Fill)
-            .height(Length::Fill);
         let ocr_overlay = OcrOverlay {
+            image_handle: self.image_handle.clone(),
+            image_zoom: self.image_zoom,
+            image_pan: self.image_pan,
             char_positions: self.char_positions.clone(),
             image_width: self.image_width,
             image_height: self.image_height,
@@ -165,17 +133,24 @@ impl InteractiveOcrView {
             is_drawing: self.is_drawing,
             draw_color: self.draw_color,
             draw_width: self.draw_width,
+            draw_tool: self.draw_tool,
+            shape_tool: self.shape_tool,
+            shapes: self.shapes.clone(),
+            annotation_order: self.annotation_order.clone(),
             draw_mode_enabled: self.draw_mode_enabled,
+            reading_order_mode_enabled: self.reading_order_mode_enabled,
+            reading_order: self.reading_order.clone(),
+            step_marker_mode_enabled: self.step_marker_mode_enabled,
+            step_markers: self.step_markers.clone(),
             draw_panel_position: self.draw_panel_position,
             draw_panel_is_dragging: self.draw_panel_is_dragging,
+            shortcuts: self.shortcuts.clone(),
+            is_search_input_focused: self.is_search_input_focused,
         };
-        let overlay_canvas =
-            container(canvas(ocr_overlay).width(Length::Fill).height(Length::Fill))
-                .width(Length::Fill)
-                .height(Length::Fill);
-        stack![image_viewer, overlay_canvas]
+        container(canvas(ocr_overlay).width(Length::Fill).height(Length::Fill))
             .width(Length::Fill)
             .height(Length::Fill)
+            .clip(true)
             .into()
     }
 
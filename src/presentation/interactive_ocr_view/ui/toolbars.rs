@@ -3,9 +3,37 @@ use super::*;
 const TOOLBAR_DRAW_DISABLE_LABEL: &str = "Disable Draw Mode";
 const TOOLBAR_DRAW_ENABLE_LABEL: &str = "Enable Draw Mode";
 const TOOLBAR_CLEAR_DRAWINGS_LABEL: &str = "Clear Drawings";
+const TOOLBAR_PEN_TOOL_LABEL: &str = "Pen";
+const TOOLBAR_HIGHLIGHTER_TOOL_LABEL: &str = "Highlighter";
+const TOOLBAR_ERASER_TOOL_LABEL: &str = "Eraser (click a stroke to remove it)";
+const TOOLBAR_ARROW_SHAPE_LABEL: &str = "Arrow";
+const TOOLBAR_RECTANGLE_SHAPE_LABEL: &str = "Rectangle";
+const TOOLBAR_DRAW_WIDTH_THIN_LABEL: &str = "Thin Brush";
+const TOOLBAR_DRAW_WIDTH_MEDIUM_LABEL: &str = "Medium Brush";
+const TOOLBAR_DRAW_WIDTH_THICK_LABEL: &str = "Thick Brush";
+const TOOLBAR_UNDO_STROKE_LABEL: &str = "Undo Stroke (Ctrl+Z)";
+const TOOLBAR_REDO_STROKE_LABEL: &str = "Redo Stroke (Ctrl+Shift+Z)";
+const TOOLBAR_READING_ORDER_DISABLE_LABEL: &str = "Disable Reading Order Mode";
+const TOOLBAR_READING_ORDER_ENABLE_LABEL: &str = "Set Reading Order (click blocks in order)";
+const TOOLBAR_CLEAR_READING_ORDER_LABEL: &str = "Clear Reading Order";
+const TOOLBAR_STEP_MARKER_DISABLE_LABEL: &str = "Disable Step Markers";
+const TOOLBAR_STEP_MARKER_ENABLE_LABEL: &str = "Drop Numbered Step Markers";
+const TOOLBAR_RESET_STEP_NUMBERING_LABEL: &str = "Restart Numbering at 1";
 const TOOLBAR_POSITION_BOTTOM_LABEL: &str = "Move toolbar to bottom";
 const TOOLBAR_POSITION_TOP_LABEL: &str = "Move toolbar to top";
+const TOOLBAR_UNPIN_WINDOW_LABEL: &str = "Unpin Window";
+const TOOLBAR_PIN_WINDOW_LABEL: &str = "Keep Window on Top";
 const TOOLBAR_DRAG_HANDLE_TEXT: &str = "⠿  drag  ⠿";
+const TOOLBAR_CUSTOM_COLOR_LABEL: &str = "Custom Color";
+const COLOR_PICKER_HUE_LABEL: &str = "Hue";
+const COLOR_PICKER_SATURATION_LABEL: &str = "Saturation";
+const COLOR_PICKER_VALUE_LABEL: &str = "Brightness";
+const COLOR_PICKER_APPLY_LABEL: &str = "Use Color";
+const COLOR_PICKER_HUE_RANGE: std::ops::RangeInclusive<f32> = 0.0..=359.0;
+const COLOR_PICKER_UNIT_RANGE: std::ops::RangeInclusive<f32> = 0.0..=1.0;
+const COLOR_PICKER_UNIT_STEP: f32 = 0.01;
+const COLOR_PICKER_SLIDER_WIDTH: f32 = 120.0;
+const COLOR_PICKER_SWATCH_SIZE: f32 = 24.0;
 
 impl InteractiveOcrView {
     pub(super) fn build_draw_toolbar(&self) -> Element<'_, InteractiveOcrMessage> {
@@ -26,13 +54,27 @@ impl InteractiveOcrView {
                 .style(Self::tooltip_style),
         );
 
+        draw_row = self.push_step_marker_controls(draw_row);
+
+        if !self.char_positions.is_empty() {
+            draw_row = self.push_reading_order_controls(draw_row);
+        }
+
         if self.draw_mode_enabled {
-            for color in [
+            draw_row = self.push_draw_tool_controls(draw_row);
+        }
+
+        if self.draw_mode_enabled || self.step_marker_mode_enabled {
+            let preset_colors = [
                 Color::from_rgb(1.0, 0.2, 0.2),
                 Color::from_rgb(0.2, 0.6, 1.0),
                 Color::from_rgb(0.2, 0.8, 0.2),
                 Color::from_rgb(1.0, 0.85, 0.0),
-            ] {
+            ];
+            for color in preset_colors
+                .into_iter()
+                .chain(self.recent_draw_colors.iter().copied())
+            {
                 let is_selected = (self.draw_color.r - color.r).abs() < 0.1
                     && (self.draw_color.g - color.g).abs() < 0.1
                     && (self.draw_color.b - color.b).abs() < 0.1;
@@ -47,6 +89,24 @@ impl InteractiveOcrView {
                 draw_row = draw_row.push(color_btn);
             }
 
+            let color_picker_btn = button(text("🎨").size(14))
+                .padding([6, 10])
+                .style(move |_theme: &iced::Theme, status| {
+                    self.color_btn_style(status, self.color_picker_open)
+                })
+                .on_press(InteractiveOcrMessage::OpenColorPicker);
+            draw_row = draw_row.push(
+                tooltip(
+                    color_picker_btn,
+                    TOOLBAR_CUSTOM_COLOR_LABEL,
+                    tooltip::Position::Bottom,
+                )
+                .style(Self::tooltip_style),
+            );
+
+            draw_row = self.push_draw_width_controls(draw_row);
+            draw_row = self.push_undo_redo_controls(draw_row);
+
             let clear_btn = button(text("🗑").size(14))
                 .padding([8, 10])
                 .style(|_theme: &iced::Theme, status| {
@@ -149,6 +209,310 @@ impl InteractiveOcrView {
             .into()
     }
 
+    pub(super) fn build_color_picker_panel(&self) -> Option<Element<'_, InteractiveOcrMessage>> {
+        if !self.color_picker_open || !(self.draw_mode_enabled || self.step_marker_mode_enabled) {
+            return None;
+        }
+
+        let hsv = self.color_picker_hsv;
+        let picked_color = hsv_to_color(hsv);
+        let hue_slider = iced::widget::slider(COLOR_PICKER_HUE_RANGE, hsv.hue, move |hue| {
+            InteractiveOcrMessage::AdjustPickedColor(HsvColor { hue, ..hsv })
+        })
+        .width(Length::Fixed(COLOR_PICKER_SLIDER_WIDTH));
+        let saturation_slider =
+            iced::widget::slider(COLOR_PICKER_UNIT_RANGE, hsv.saturation, move |saturation| {
+                InteractiveOcrMessage::AdjustPickedColor(HsvColor { saturation, ..hsv })
+            })
+            .step(COLOR_PICKER_UNIT_STEP)
+            .width(Length::Fixed(COLOR_PICKER_SLIDER_WIDTH));
+        let value_slider = iced::widget::slider(COLOR_PICKER_UNIT_RANGE, hsv.value, move |value| {
+            InteractiveOcrMessage::AdjustPickedColor(HsvColor { value, ..hsv })
+        })
+        .step(COLOR_PICKER_UNIT_STEP)
+        .width(Length::Fixed(COLOR_PICKER_SLIDER_WIDTH));
+        let [red, green, blue, _] = picked_color.into_rgba8();
+        let swatch = container(iced::widget::Space::new())
+            .width(Length::Fixed(COLOR_PICKER_SWATCH_SIZE))
+            .height(Length::Fixed(COLOR_PICKER_SWATCH_SIZE))
+            .style(move |_theme| iced::widget::container::Style {
+                background: Some(iced::Background::Color(picked_color)),
+                border: Border {
+                    color: Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            });
+        let apply_btn = button(text(COLOR_PICKER_APPLY_LABEL).size(12))
+            .padding([4, 10])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.2, 0.2, 0.2, 0.9),
+                    Color::from_rgba(0.4, 0.4, 0.5, 0.95),
+                    Color::from_rgba(0.3, 0.3, 0.4, 0.95),
+                    Color::from_rgba(0.5, 0.5, 0.6, 0.5),
+                )
+            })
+            .on_press(InteractiveOcrMessage::ApplyPickedColor);
+
+        let panel_content = row![
+            text(COLOR_PICKER_HUE_LABEL).size(12).color(Color::WHITE),
+            hue_slider,
+            text(COLOR_PICKER_SATURATION_LABEL)
+                .size(12)
+                .color(Color::WHITE),
+            saturation_slider,
+            text(COLOR_PICKER_VALUE_LABEL).size(12).color(Color::WHITE),
+            value_slider,
+            swatch,
+            text(format!("#{:02X}{:02X}{:02X}", red, green, blue))
+                .size(12)
+                .color(Color::WHITE),
+            apply_btn,
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        Some(
+            container(panel_content)
+                .padding([10, 16])
+                .style(|_theme| iced::widget::container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgba(
+                        0.1, 0.1, 0.1, 0.92,
+                    ))),
+                    border: Border {
+                        color: Color::from_rgba(0.4, 0.4, 0.4, 0.6),
+                        width: 1.0,
+                        radius: 8.0.into(),
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    fn push_draw_tool_controls<'a>(
+        &'a self,
+        mut draw_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        for (tool, icon, label) in [
+            (DrawTool::Pen, "✏️", TOOLBAR_PEN_TOOL_LABEL),
+            (DrawTool::Highlighter, "🖍️", TOOLBAR_HIGHLIGHTER_TOOL_LABEL),
+            (DrawTool::Eraser, "🧽", TOOLBAR_ERASER_TOOL_LABEL),
+        ] {
+            let is_selected = self.draw_tool == tool && self.shape_tool.is_none();
+            let tool_btn = button(text(icon).size(14))
+                .padding([6, 10])
+                .style(move |_theme: &iced::Theme, status| {
+                    self.color_btn_style(status, is_selected)
+                })
+                .on_press(InteractiveOcrMessage::SetDrawTool(tool));
+            draw_row = draw_row.push(
+                tooltip(tool_btn, label, tooltip::Position::Bottom).style(Self::tooltip_style),
+            );
+        }
+
+        for (shape, icon, label) in [
+            (Shape::Arrow, "➜", TOOLBAR_ARROW_SHAPE_LABEL),
+            (Shape::Rectangle, "▭", TOOLBAR_RECTANGLE_SHAPE_LABEL),
+        ] {
+            let is_selected = self.shape_tool == Some(shape);
+            let shape_btn = button(text(icon).size(14))
+                .padding([6, 10])
+                .style(move |_theme: &iced::Theme, status| {
+                    self.color_btn_style(status, is_selected)
+                })
+                .on_press(InteractiveOcrMessage::SetShapeTool(
+                    (!is_selected).then_some(shape),
+                ));
+            draw_row = draw_row.push(
+                tooltip(shape_btn, label, tooltip::Position::Bottom).style(Self::tooltip_style),
+            );
+        }
+
+        draw_row
+    }
+
+    fn push_draw_width_controls<'a>(
+        &'a self,
+        mut draw_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        for (width, dot_size, label) in [
+            (
+                global_constants::DRAW_WIDTH_THIN,
+                8,
+                TOOLBAR_DRAW_WIDTH_THIN_LABEL,
+            ),
+            (
+                global_constants::DEFAULT_DRAW_WIDTH,
+                12,
+                TOOLBAR_DRAW_WIDTH_MEDIUM_LABEL,
+            ),
+            (
+                global_constants::DRAW_WIDTH_THICK,
+                18,
+                TOOLBAR_DRAW_WIDTH_THICK_LABEL,
+            ),
+        ] {
+            let is_selected = (self.draw_width - width).abs() < f32::EPSILON;
+            let width_btn = button(
+                container(text("●").size(dot_size))
+                    .center_x(Length::Fixed(18.0))
+                    .center_y(Length::Fixed(18.0)),
+            )
+            .padding([4, 6])
+            .style(move |_theme: &iced::Theme, status| self.color_btn_style(status, is_selected))
+            .on_press(InteractiveOcrMessage::SetDrawWidth(width));
+            draw_row = draw_row.push(
+                tooltip(width_btn, label, tooltip::Position::Bottom).style(Self::tooltip_style),
+            );
+        }
+
+        draw_row
+    }
+
+    fn push_undo_redo_controls<'a>(
+        &'a self,
+        mut draw_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let undo_redo_buttons = [
+            (
+                "↶",
+                TOOLBAR_UNDO_STROKE_LABEL,
+                !self.annotation_order.is_empty(),
+                InteractiveOcrMessage::UndoStroke,
+            ),
+            (
+                "↷",
+                TOOLBAR_REDO_STROKE_LABEL,
+                !self.redo_annotations.is_empty(),
+                InteractiveOcrMessage::RedoStroke,
+            ),
+        ];
+
+        for (icon, label, is_enabled, message) in undo_redo_buttons {
+            let history_btn = button(text(icon).size(14))
+                .padding([8, 10])
+                .style(|_theme: &iced::Theme, status| {
+                    Self::solid_button_style(
+                        status,
+                        Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                        Color::from_rgba(0.3, 0.3, 0.3, 0.95),
+                        Color::from_rgba(0.2, 0.2, 0.2, 0.95),
+                        Color::from_rgba(0.5, 0.5, 0.5, 0.4),
+                    )
+                })
+                .on_press_maybe(is_enabled.then_some(message));
+            draw_row = draw_row.push(
+                tooltip(history_btn, label, tooltip::Position::Bottom).style(Self::tooltip_style),
+            );
+        }
+
+        draw_row
+    }
+
+    fn push_step_marker_controls<'a>(
+        &'a self,
+        mut draw_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let step_marker_toggle = button(text("①").size(16))
+            .padding([8, 12])
+            .style(move |_theme: &iced::Theme, status| {
+                self.floating_btn_style(status, self.step_marker_mode_enabled)
+            })
+            .on_press(InteractiveOcrMessage::ToggleStepMarkerMode);
+        let step_marker_tooltip_text = if self.step_marker_mode_enabled {
+            TOOLBAR_STEP_MARKER_DISABLE_LABEL
+        } else {
+            TOOLBAR_STEP_MARKER_ENABLE_LABEL
+        };
+        draw_row = draw_row.push(
+            tooltip(
+                step_marker_toggle,
+                step_marker_tooltip_text,
+                tooltip::Position::Bottom,
+            )
+            .style(Self::tooltip_style),
+        );
+
+        if !self.step_marker_mode_enabled || self.next_step_number == 1 {
+            return draw_row;
+        }
+
+        let reset_numbering_btn = button(text("1↺").size(14))
+            .padding([8, 10])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                    Color::from_rgba(0.3, 0.3, 0.3, 0.95),
+                    Color::from_rgba(0.2, 0.2, 0.2, 0.95),
+                    Color::from_rgba(0.5, 0.5, 0.5, 0.4),
+                )
+            })
+            .on_press(InteractiveOcrMessage::ResetStepNumbering);
+        draw_row.push(
+            tooltip(
+                reset_numbering_btn,
+                TOOLBAR_RESET_STEP_NUMBERING_LABEL,
+                tooltip::Position::Bottom,
+            )
+            .style(Self::tooltip_style),
+        )
+    }
+
+    fn push_reading_order_controls<'a>(
+        &'a self,
+        mut draw_row: iced::widget::Row<'a, InteractiveOcrMessage>,
+    ) -> iced::widget::Row<'a, InteractiveOcrMessage> {
+        let reading_order_toggle = button(text("🔢").size(16))
+            .padding([8, 12])
+            .style(move |_theme: &iced::Theme, status| {
+                self.floating_btn_style(status, self.reading_order_mode_enabled)
+            })
+            .on_press(InteractiveOcrMessage::ToggleReadingOrderMode);
+        let reading_order_tooltip_text = if self.reading_order_mode_enabled {
+            TOOLBAR_READING_ORDER_DISABLE_LABEL
+        } else {
+            TOOLBAR_READING_ORDER_ENABLE_LABEL
+        };
+        draw_row = draw_row.push(
+            tooltip(
+                reading_order_toggle,
+                reading_order_tooltip_text,
+                tooltip::Position::Bottom,
+            )
+            .style(Self::tooltip_style),
+        );
+
+        if self.reading_order.is_empty() {
+            return draw_row;
+        }
+
+        let clear_order_btn = button(text("⟲").size(14))
+            .padding([8, 10])
+            .style(|_theme: &iced::Theme, status| {
+                Self::solid_button_style(
+                    status,
+                    Color::from_rgba(0.15, 0.15, 0.15, 0.85),
+                    Color::from_rgba(0.8, 0.2, 0.2, 0.9),
+                    Color::from_rgba(0.6, 0.1, 0.1, 0.9),
+                    Color::from_rgba(0.5, 0.5, 0.5, 0.4),
+                )
+            })
+            .on_press(InteractiveOcrMessage::ClearReadingOrder);
+        draw_row.push(
+            tooltip(
+                clear_order_btn,
+                TOOLBAR_CLEAR_READING_ORDER_LABEL,
+                tooltip::Position::Bottom,
+            )
+            .style(Self::tooltip_style),
+        )
+    }
+
     pub(super) fn build_action_toolbar(&self) -> Element<'_, InteractiveOcrMessage> {
         let mut action_row = row![].spacing(6).align_y(Alignment::Center);
         let position_icon = if self.toolbar_offset.y > 50.0 {
@@ -182,10 +546,33 @@ impl InteractiveOcrView {
             .style(Self::tooltip_style),
         );
 
+        let pin_btn = button(text("📌").size(16))
+            .padding([8, 10])
+            .style(move |_theme: &iced::Theme, status| {
+                self.floating_btn_style(status, self.always_on_top)
+            })
+            .on_press(InteractiveOcrMessage::ToggleAlwaysOnTop);
+        let pin_tooltip = if self.always_on_top {
+            TOOLBAR_UNPIN_WINDOW_LABEL
+        } else {
+            TOOLBAR_PIN_WINDOW_LABEL
+        };
+        action_row = action_row
+            .push(tooltip(pin_btn, pin_tooltip, tooltip::Position::Top).style(Self::tooltip_style));
+
         action_row = self.push_copy_text_button(action_row);
+        action_row = self.push_translate_button(action_row);
+        action_row = self.push_all_text_buttons(action_row);
+        action_row = self.push_copy_text_layout_json_button(action_row);
         action_row = self.push_search_controls(action_row);
+        action_row = self.push_include_annotations_toggle(action_row);
         action_row = self.push_copy_image_button(action_row);
+        action_row = self.push_copy_region_button(action_row);
         action_row = self.push_save_button(action_row);
+        action_row = self.push_save_project_button(action_row);
+        action_row = self.push_orientation_buttons(action_row);
+        action_row = self.push_ocr_preprocess_button(action_row);
+        action_row = self.push_ocr_language_picker(action_row);
         action_row = self.push_recrop_button(action_row);
         action_row = self.push_close_button(action_row);
 
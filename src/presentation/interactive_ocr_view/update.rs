@@ -1,36 +1,89 @@
-use iced::{Point, Vector};
+use iced::{Point, Size, Vector};
 
+use super::state::{
+    build_shape_from_drag, find_annotation_order_position, find_spatial_selection_ends,
+};
 use super::*;
+use crate::presentation::image_transform::ImageTransform;
 
 const TOOLBAR_TOP_OFFSET: f32 = 500.0;
+const IMAGE_MIN_ZOOM: f32 = 1.0;
+const IMAGE_MAX_ZOOM: f32 = 8.0;
+const IMAGE_ZOOM_STEP_FACTOR: f32 = 1.25;
 
 impl InteractiveOcrView {
     pub fn update(&mut self, message: InteractiveOcrMessage) {
+        if is_canvas_press_message(&message) {
+            self.is_search_input_focused = false;
+        }
+
         match message {
             InteractiveOcrMessage::Close => {}
             InteractiveOcrMessage::StartDrag(char_index) => self.handle_start_drag(char_index),
             InteractiveOcrMessage::UpdateDrag(char_index) => self.handle_update_drag(char_index),
             InteractiveOcrMessage::EndDrag => self.handle_end_drag(),
             InteractiveOcrMessage::CopySelected => self.handle_copy_selected(),
-            InteractiveOcrMessage::SearchSelected => self.handle_search_selected(),
+            InteractiveOcrMessage::CopyAllText => self.handle_copy_all_text(),
+            InteractiveOcrMessage::CopyDecodedCode(code_index) => {
+                self.handle_copy_decoded_code(code_index)
+            }
+            InteractiveOcrMessage::DismissDecodedCodes => self.decoded_codes.clear(),
+            InteractiveOcrMessage::CopyImageUrl => self.handle_copy_image_url(),
+            InteractiveOcrMessage::CopyTextLayoutJson => self.handle_copy_text_layout_json(),
+            InteractiveOcrMessage::CopyAsMarkdown => self.handle_copy_as_markdown(),
+            InteractiveOcrMessage::TranslateSelected => self.handle_translate_selected(),
+            InteractiveOcrMessage::TranslationCompleted(translated_text) => {
+                self.translation_state = TranslationState::Completed(translated_text);
+            }
+            InteractiveOcrMessage::TranslationFailed(error) => {
+                self.translation_state = TranslationState::Failed(error);
+            }
+            InteractiveOcrMessage::DismissTranslation => {
+                self.translation_state = TranslationState::Idle;
+            }
+            InteractiveOcrMessage::SearchSelected => {
+                self.is_search_input_focused = false;
+                self.handle_search_selected()
+            }
             InteractiveOcrMessage::SearchQueryChanged(query) => {
                 self.search_query = query;
+                self.is_search_input_focused = true;
             }
+            InteractiveOcrMessage::SearchInputBlurred => {}
             InteractiveOcrMessage::SearchUploading => self.handle_search_uploading(),
             InteractiveOcrMessage::SearchCompleted => self.handle_search_completed(),
             InteractiveOcrMessage::SearchFailed(error) => self.handle_search_failed(error),
             InteractiveOcrMessage::SpinnerTick => self.handle_spinner_tick(),
+            InteractiveOcrMessage::CaptureFlashFinished => self.is_capture_flash_visible = false,
             InteractiveOcrMessage::HideToast => self.handle_hide_toast(),
             InteractiveOcrMessage::SelectAll => self.handle_select_all(),
+            InteractiveOcrMessage::SelectWord(word_index) => self.handle_select_word(word_index),
+            InteractiveOcrMessage::SelectLine(word_index) => self.handle_select_line(word_index),
+            InteractiveOcrMessage::ExtendSelectionLeft(step) => {
+                self.handle_extend_selection(false, step)
+            }
+            InteractiveOcrMessage::ExtendSelectionRight(step) => {
+                self.handle_extend_selection(true, step)
+            }
             InteractiveOcrMessage::DeselectAll => self.handle_deselect_all(),
             InteractiveOcrMessage::DismissHelpHint => {
                 self.show_help_hint = false;
             }
+            InteractiveOcrMessage::DismissHelpHintPermanently => {
+                self.show_help_hint = false;
+                self.help_hint_enabled = false;
+            }
             InteractiveOcrMessage::StartDrawing(point) => self.handle_start_drawing(point),
             InteractiveOcrMessage::UpdateDrawing(point) => self.handle_update_drawing(point),
             InteractiveOcrMessage::EndDrawing => self.handle_end_drawing(),
-            InteractiveOcrMessage::CopyImageToClipboard
+            InteractiveOcrMessage::TypeSelectedText
+            | InteractiveOcrMessage::CopyImageToClipboard
+            | InteractiveOcrMessage::CopySelectedRegionToClipboard
             | InteractiveOcrMessage::SaveImageToFile
+            | InteractiveOcrMessage::SaveProject
+            | InteractiveOcrMessage::ExportTextToFile
+            | InteractiveOcrMessage::OpenDecodedCode(_)
+            | InteractiveOcrMessage::SearchTextOnly
             | InteractiveOcrMessage::Recrop => {}
             InteractiveOcrMessage::CopyImagePreparing => self.handle_copy_image_preparing(),
             InteractiveOcrMessage::CopyImageCopying => self.handle_copy_image_copying(),
@@ -40,6 +93,10 @@ impl InteractiveOcrView {
             InteractiveOcrMessage::SaveImageSaving => self.handle_save_image_saving(),
             InteractiveOcrMessage::SaveSuccess(path) => {
                 self.save_state = SaveState::Success(path);
+                self.has_unsaved_annotations = false;
+            }
+            InteractiveOcrMessage::ExportTextSuccess(path) => {
+                self.save_state = SaveState::Success(path);
             }
             InteractiveOcrMessage::SaveFailed(error) => {
                 self.save_state = SaveState::Failed(error);
@@ -48,14 +105,63 @@ impl InteractiveOcrView {
                 self.save_state = SaveState::Idle;
             }
             InteractiveOcrMessage::ToggleDrawMode => self.handle_toggle_draw_mode(),
+            InteractiveOcrMessage::ToggleAlwaysOnTop => {
+                self.always_on_top = !self.always_on_top;
+            }
+            InteractiveOcrMessage::ToggleIncludeAnnotationsInCopy => {
+                self.include_annotations_in_copy = !self.include_annotations_in_copy;
+            }
+            InteractiveOcrMessage::ToggleReadingOrderMode => {
+                self.handle_toggle_reading_order_mode()
+            }
+            InteractiveOcrMessage::ToggleReadingOrderBlock(word_index) => {
+                self.handle_toggle_reading_order_block(word_index)
+            }
+            InteractiveOcrMessage::ClearReadingOrder => self.handle_clear_reading_order(),
+            InteractiveOcrMessage::ToggleStepMarkerMode => self.handle_toggle_step_marker_mode(),
+            InteractiveOcrMessage::PlaceStepMarker(point) => self.handle_place_step_marker(point),
+            InteractiveOcrMessage::ResetStepNumbering => self.handle_reset_step_numbering(),
             InteractiveOcrMessage::SetDrawColor(color) => self.handle_set_draw_color(color),
+            InteractiveOcrMessage::OpenColorPicker => self.handle_open_color_picker(),
+            InteractiveOcrMessage::AdjustPickedColor(hsv) => self.color_picker_hsv = hsv,
+            InteractiveOcrMessage::ApplyPickedColor => self.handle_apply_picked_color(),
+            InteractiveOcrMessage::SetDrawWidth(width) => self.handle_set_draw_width(width),
+            InteractiveOcrMessage::SetDrawTool(tool) => self.handle_set_draw_tool(tool),
+            InteractiveOcrMessage::SetShapeTool(shape) => self.handle_set_shape_tool(shape),
             InteractiveOcrMessage::ClearDrawings => self.handle_clear_drawings(),
+            InteractiveOcrMessage::EraseAnnotation(layer, index) => {
+                self.handle_erase_annotation(layer, index)
+            }
+            InteractiveOcrMessage::UndoStroke => self.handle_undo_stroke(),
+            InteractiveOcrMessage::RedoStroke => self.handle_redo_stroke(),
             InteractiveOcrMessage::ToggleToolbarPosition => self.handle_toggle_toolbar_position(),
             InteractiveOcrMessage::StartOcr => self.handle_start_ocr(),
             InteractiveOcrMessage::CancelOcr => self.handle_cancel_ocr(),
+            InteractiveOcrMessage::CancelSearch => self.handle_cancel_search(),
             InteractiveOcrMessage::ClearOcrOverlay => self.handle_clear_ocr_overlay(),
             InteractiveOcrMessage::OcrFailed(error) => self.handle_ocr_failed(error),
             InteractiveOcrMessage::RetryOcr => self.handle_retry_ocr(),
+            InteractiveOcrMessage::RotateLeft => {
+                self.handle_transform_image(CaptureBuffer::rotate_counter_clockwise)
+            }
+            InteractiveOcrMessage::RotateRight => {
+                self.handle_transform_image(CaptureBuffer::rotate_clockwise)
+            }
+            InteractiveOcrMessage::FlipHorizontal => {
+                self.handle_transform_image(CaptureBuffer::flip_horizontal)
+            }
+            InteractiveOcrMessage::ToggleOcrPreprocessPanel => {
+                self.ocr_preprocess_panel_open = !self.ocr_preprocess_panel_open;
+            }
+            InteractiveOcrMessage::AdjustOcrPreprocess(preprocess) => {
+                self.ocr_preprocess = preprocess;
+            }
+            InteractiveOcrMessage::SetOcrPreprocess(preprocess) => {
+                self.handle_set_ocr_preprocess(preprocess)
+            }
+            InteractiveOcrMessage::ReprocessWithLanguage(language_code) => {
+                self.handle_reprocess_with_language(language_code)
+            }
             InteractiveOcrMessage::DrawPanelDragStarted(cursor_x, cursor_y) => {
                 self.handle_draw_panel_drag_started(cursor_x, cursor_y)
             }
@@ -63,6 +169,18 @@ impl InteractiveOcrView {
                 self.handle_draw_panel_moved(cursor_x, cursor_y)
             }
             InteractiveOcrMessage::DrawPanelReleased => self.handle_draw_panel_released(),
+            InteractiveOcrMessage::ZoomImage(scroll_steps, anchor, viewport) => {
+                self.handle_zoom_image(scroll_steps, anchor, viewport)
+            }
+            InteractiveOcrMessage::PanImage(delta) => {
+                if self.image_zoom > IMAGE_MIN_ZOOM {
+                    self.image_pan = self.image_pan + delta;
+                }
+            }
+            InteractiveOcrMessage::ResetImageZoom => {
+                self.image_zoom = IMAGE_MIN_ZOOM;
+                self.image_pan = Vector::ZERO;
+            }
         }
     }
 
@@ -79,6 +197,8 @@ impl InteractiveOcrView {
             char_index
         );
         self.drag_start = Some(char_index);
+        self.selection_anchor = Some(char_index);
+        self.selection_focus = Some(char_index);
         self.is_selecting = true;
         self.show_help_hint = false;
     }
@@ -104,6 +224,7 @@ impl InteractiveOcrView {
 
         combined_selection.sort_unstable();
         self.selected_chars = combined_selection;
+        self.selection_focus = Some(char_index);
     }
 
     fn handle_end_drag(&self) {
@@ -121,7 +242,7 @@ impl InteractiveOcrView {
         }
 
         log::info!("[INTERACTIVE_OCR] Copying text: {}", selected_text);
-        match copy_text_to_clipboard(&selected_text) {
+        match (self.text_clipboard_writer)(&selected_text) {
             Ok(()) => {
                 log::info!("[INTERACTIVE_OCR] Text copied to clipboard");
                 self.copy_state = CopyState::Success;
@@ -133,6 +254,130 @@ impl InteractiveOcrView {
         }
     }
 
+    fn handle_copy_all_text(&mut self) {
+        let all_text = self.get_all_text();
+
+        if all_text.is_empty() {
+            log::info!("[INTERACTIVE_OCR] No recognized text to copy");
+            self.copy_state = CopyState::Empty;
+            return;
+        }
+
+        match (self.text_clipboard_writer)(&all_text) {
+            Ok(()) => {
+                log::info!(
+                    "[INTERACTIVE_OCR] Copied all {} recognized characters",
+                    self.char_positions.len()
+                );
+                self.copy_state = CopyState::Success;
+            }
+            Err(error) => {
+                log::error!("[INTERACTIVE_OCR] Failed to copy all text: {}", error);
+                self.copy_state = CopyState::Failed;
+            }
+        }
+    }
+
+    fn handle_copy_as_markdown(&mut self) {
+        let selected_text = self.get_selected_text_with_layout();
+        let layout_text = if selected_text.trim().is_empty() {
+            self.get_all_text()
+        } else {
+            selected_text
+        };
+        let markdown = build_markdown_from_layout_text(&layout_text);
+
+        if markdown.is_empty() {
+            log::info!("[INTERACTIVE_OCR] No recognized text to copy as Markdown");
+            self.copy_state = CopyState::Empty;
+            return;
+        }
+
+        match (self.text_clipboard_writer)(&markdown) {
+            Ok(()) => {
+                log::info!(
+                    "[INTERACTIVE_OCR] Copied {} characters as Markdown",
+                    markdown.len()
+                );
+                self.copy_state = CopyState::Success;
+            }
+            Err(error) => {
+                log::error!("[INTERACTIVE_OCR] Failed to copy Markdown: {}", error);
+                self.copy_state = CopyState::Failed;
+            }
+        }
+    }
+
+    fn handle_copy_decoded_code(&mut self, code_index: usize) {
+        let Some(code) = self.decoded_codes.get(code_index) else {
+            return;
+        };
+
+        match (self.text_clipboard_writer)(&code.payload) {
+            Ok(()) => {
+                log::info!("[INTERACTIVE_OCR] Copied decoded code payload");
+                self.copy_state = CopyState::Success;
+            }
+            Err(error) => {
+                log::error!("[INTERACTIVE_OCR] Failed to copy decoded code: {}", error);
+                self.copy_state = CopyState::Failed;
+            }
+        }
+    }
+
+    fn handle_copy_image_url(&mut self) {
+        let Some(hosted_image_url) = &self.hosted_image_url else {
+            return;
+        };
+
+        match (self.text_clipboard_writer)(hosted_image_url) {
+            Ok(()) => {
+                log::info!("[INTERACTIVE_OCR] Copied hosted image URL");
+                self.copy_state = CopyState::Success;
+            }
+            Err(error) => {
+                log::error!(
+                    "[INTERACTIVE_OCR] Failed to copy hosted image URL: {}",
+                    error
+                );
+                self.copy_state = CopyState::Failed;
+            }
+        }
+    }
+
+    fn handle_translate_selected(&mut self) {
+        if self.get_selected_text_with_layout().trim().is_empty() {
+            return;
+        }
+
+        log::info!("[INTERACTIVE_OCR] Translating selected text");
+        self.translation_state = TranslationState::Translating;
+    }
+
+    fn handle_copy_text_layout_json(&mut self) {
+        let Some(ocr_result) = &self.ocr_result else {
+            return;
+        };
+
+        let copy_result = ocr_result
+            .to_layout_json()
+            .map_err(|error| format!("{:#}", error))
+            .and_then(|layout_json| (self.text_clipboard_writer)(&layout_json));
+        match copy_result {
+            Ok(()) => {
+                log::info!(
+                    "[INTERACTIVE_OCR] Copied layout JSON for {} text blocks",
+                    ocr_result.text_blocks.len()
+                );
+                self.copy_state = CopyState::Success;
+            }
+            Err(error) => {
+                log::error!("[INTERACTIVE_OCR] Failed to copy layout JSON: {}", error);
+                self.copy_state = CopyState::Failed;
+            }
+        }
+    }
+
     fn handle_search_selected(&mut self) {
         if !matches!(self.search_state, SearchState::Idle) {
             return;
@@ -198,6 +443,37 @@ impl InteractiveOcrView {
             self.char_positions.len()
         );
         self.selected_chars = (0..self.char_positions.len()).collect();
+        self.selection_anchor = None;
+        self.selection_focus = None;
+        self.show_help_hint = false;
+    }
+
+    fn handle_select_word(&mut self, word_index: usize) {
+        let word_chars = collect_word_char_indices(word_index, &self.char_positions);
+        log::debug!(
+            "[INTERACTIVE_OCR] Selecting word {} ({} characters)",
+            word_index,
+            word_chars.len()
+        );
+        self.replace_selection(word_chars);
+    }
+
+    fn handle_select_line(&mut self, word_index: usize) {
+        let line_chars = collect_line_char_indices(word_index, &self.char_positions);
+        log::debug!(
+            "[INTERACTIVE_OCR] Selecting line of word {} ({} characters)",
+            word_index,
+            line_chars.len()
+        );
+        self.replace_selection(line_chars);
+    }
+
+    fn replace_selection(&mut self, char_indices: Vec<usize>) {
+        self.selected_chars = char_indices;
+        self.is_selecting = false;
+        self.drag_start = None;
+        self.selection_anchor = None;
+        self.selection_focus = None;
         self.show_help_hint = false;
     }
 
@@ -206,6 +482,32 @@ impl InteractiveOcrView {
         self.selected_chars.clear();
         self.is_selecting = false;
         self.drag_start = None;
+        self.selection_anchor = None;
+        self.selection_focus = None;
+    }
+
+    fn handle_extend_selection(&mut self, moving_right: bool, step: SelectionStep) {
+        let (anchor, focus) = match (self.selection_anchor, self.selection_focus) {
+            (Some(anchor), Some(focus)) => (anchor, focus),
+            _ => {
+                let Some(selection_ends) =
+                    find_spatial_selection_ends(&self.selected_chars, &self.char_positions)
+                else {
+                    return;
+                };
+                selection_ends
+            }
+        };
+
+        let new_focus = move_selection_focus(focus, moving_right, step, &self.char_positions);
+        log::debug!(
+            "[INTERACTIVE_OCR] Extending selection from anchor {} to {}",
+            anchor,
+            new_focus
+        );
+        self.selected_chars = collect_spatial_range(anchor, new_focus, &self.char_positions);
+        self.selection_anchor = Some(anchor);
+        self.selection_focus = Some(new_focus);
     }
 
     fn handle_start_drawing(&mut self, point: Point) {
@@ -227,15 +529,45 @@ impl InteractiveOcrView {
             return;
         }
 
+        if let Some(shape) = self.shape_tool {
+            self.commit_shape(shape);
+            return;
+        }
+
+        let (color, width) = self
+            .draw_tool
+            .stroke_appearance(self.draw_color, self.draw_width);
         self.draw_strokes.push(DrawStroke {
             points: self.current_stroke_points.clone(),
-            color: self.draw_color,
-            width: self.draw_width,
+            color,
+            width,
+            tool: self.draw_tool,
         });
+        self.annotation_order.push(AnnotationLayer::Stroke);
+        self.redo_annotations.clear();
+        self.has_unsaved_annotations = true;
         self.current_stroke_points.clear();
         self.is_drawing = false;
     }
 
+    fn commit_shape(&mut self, shape: Shape) {
+        let start = self.current_stroke_points[0];
+        let end = self.current_stroke_points[self.current_stroke_points.len() - 1];
+        self.current_stroke_points.clear();
+        self.is_drawing = false;
+
+        let Some(shape_annotation) =
+            build_shape_from_drag(shape, start, end, self.draw_color, self.draw_width)
+        else {
+            log::debug!("[INTERACTIVE_OCR] Ignoring zero-length {:?} drag", shape);
+            return;
+        };
+        self.shapes.push(shape_annotation);
+        self.annotation_order.push(AnnotationLayer::Shape);
+        self.redo_annotations.clear();
+        self.has_unsaved_annotations = true;
+    }
+
     fn handle_copy_image_preparing(&mut self) {
         log::debug!("[INTERACTIVE_OCR] Preparing to copy image");
         self.image_copy_state = ImageCopyState::Preparing;
@@ -268,6 +600,10 @@ impl InteractiveOcrView {
 
     fn handle_toggle_draw_mode(&mut self) {
         self.draw_mode_enabled = !self.draw_mode_enabled;
+        if self.draw_mode_enabled {
+            self.reading_order_mode_enabled = false;
+            self.step_marker_mode_enabled = false;
+        }
         log::info!(
             "[INTERACTIVE_OCR] Draw mode {}",
             if self.draw_mode_enabled {
@@ -278,16 +614,225 @@ impl InteractiveOcrView {
         );
     }
 
+    fn handle_toggle_reading_order_mode(&mut self) {
+        self.reading_order_mode_enabled = !self.reading_order_mode_enabled;
+        if self.reading_order_mode_enabled {
+            self.draw_mode_enabled = false;
+            self.step_marker_mode_enabled = false;
+        }
+        log::info!(
+            "[INTERACTIVE_OCR] Reading order mode {}",
+            if self.reading_order_mode_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        );
+    }
+
+    fn handle_toggle_reading_order_block(&mut self, word_index: usize) {
+        if let Some(order_position) = self
+            .reading_order
+            .iter()
+            .position(|ordered_index| *ordered_index == word_index)
+        {
+            self.reading_order.remove(order_position);
+            self.selected_chars.retain(|&char_index| {
+                self.char_positions
+                    .get(char_index)
+                    .map_or(true, |position| position.word_index != word_index)
+            });
+            log::debug!(
+                "[INTERACTIVE_OCR] Removed block {} from reading order",
+                word_index
+            );
+            return;
+        }
+
+        self.reading_order.push(word_index);
+        for (char_index, position) in self.char_positions.iter().enumerate() {
+            if position.word_index == word_index && !self.selected_chars.contains(&char_index) {
+                self.selected_chars.push(char_index);
+            }
+        }
+        self.show_help_hint = false;
+        log::debug!(
+            "[INTERACTIVE_OCR] Added block {} to reading order at position {}",
+            word_index,
+            self.reading_order.len()
+        );
+    }
+
+    fn handle_clear_reading_order(&mut self) {
+        let ordered_blocks = std::mem::take(&mut self.reading_order);
+        self.selected_chars.retain(|&char_index| {
+            self.char_positions
+                .get(char_index)
+                .map_or(true, |position| {
+                    !ordered_blocks.contains(&position.word_index)
+                })
+        });
+        log::info!("[INTERACTIVE_OCR] Cleared reading order");
+    }
+
+    fn handle_toggle_step_marker_mode(&mut self) {
+        self.step_marker_mode_enabled = !self.step_marker_mode_enabled;
+        if self.step_marker_mode_enabled {
+            self.draw_mode_enabled = false;
+            self.reading_order_mode_enabled = false;
+        }
+        log::info!(
+            "[INTERACTIVE_OCR] Step marker mode {}",
+            if self.step_marker_mode_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        );
+    }
+
+    fn handle_place_step_marker(&mut self, point: Point) {
+        self.step_markers.push(StepMarker {
+            position: point,
+            number: self.next_step_number,
+            color: self.draw_color,
+        });
+        self.has_unsaved_annotations = true;
+        log::debug!(
+            "[INTERACTIVE_OCR] Placed step marker {} at ({}, {})",
+            self.next_step_number,
+            point.x,
+            point.y
+        );
+        self.next_step_number += 1;
+    }
+
+    fn handle_reset_step_numbering(&mut self) {
+        self.next_step_number = 1;
+        log::info!("[INTERACTIVE_OCR] Reset step marker numbering");
+    }
+
     fn handle_set_draw_color(&mut self, color: iced::Color) {
         self.draw_color = color;
         log::debug!("[INTERACTIVE_OCR] Draw color changed");
     }
 
+    fn handle_open_color_picker(&mut self) {
+        self.color_picker_open = !self.color_picker_open;
+        if self.color_picker_open {
+            self.color_picker_hsv = color_to_hsv(self.draw_color);
+        }
+    }
+
+    fn handle_apply_picked_color(&mut self) {
+        let color = hsv_to_color(self.color_picker_hsv);
+        self.handle_set_draw_color(color);
+        self.recent_draw_colors = push_recent_draw_color(
+            &self.recent_draw_colors,
+            color,
+            crate::global_constants::RECENT_DRAW_COLORS_LIMIT,
+        );
+        self.color_picker_open = false;
+        log::info!(
+            "[INTERACTIVE_OCR] Custom draw color applied ({} recent colors)",
+            self.recent_draw_colors.len()
+        );
+    }
+
+    fn handle_set_draw_width(&mut self, width: f32) {
+        self.draw_width = width;
+        log::debug!("[INTERACTIVE_OCR] Draw width changed to {}", width);
+    }
+
+    fn handle_set_draw_tool(&mut self, tool: DrawTool) {
+        self.draw_tool = tool;
+        self.shape_tool = None;
+        log::debug!("[INTERACTIVE_OCR] Draw tool changed to {:?}", tool);
+    }
+
+    fn handle_set_shape_tool(&mut self, shape: Option<Shape>) {
+        self.shape_tool = shape;
+        log::debug!("[INTERACTIVE_OCR] Shape tool changed to {:?}", shape);
+    }
+
+    fn handle_erase_annotation(&mut self, layer: AnnotationLayer, index: usize) {
+        let layer_len = match layer {
+            AnnotationLayer::Stroke => self.draw_strokes.len(),
+            AnnotationLayer::Shape => self.shapes.len(),
+        };
+        if index >= layer_len {
+            return;
+        }
+
+        match layer {
+            AnnotationLayer::Stroke => {
+                self.draw_strokes.remove(index);
+            }
+            AnnotationLayer::Shape => {
+                self.shapes.remove(index);
+            }
+        }
+        if let Some(order_position) =
+            find_annotation_order_position(&self.annotation_order, layer, index)
+        {
+            self.annotation_order.remove(order_position);
+        }
+        self.has_unsaved_annotations = true;
+        log::debug!("[INTERACTIVE_OCR] Erased {:?} {}", layer, index);
+    }
+
     fn handle_clear_drawings(&mut self) {
         self.draw_strokes.clear();
+        self.redo_annotations.clear();
+        self.annotation_order.clear();
+        self.shapes.clear();
+        self.step_markers.clear();
+        self.next_step_number = 1;
+        self.has_unsaved_annotations = false;
         log::info!("[INTERACTIVE_OCR] Cleared all drawings");
     }
 
+    fn handle_undo_stroke(&mut self) {
+        let Some(layer) = self.annotation_order.pop() else {
+            return;
+        };
+        let undone = match layer {
+            AnnotationLayer::Stroke => self.draw_strokes.pop().map(UndoableAnnotation::Stroke),
+            AnnotationLayer::Shape => self.shapes.pop().map(UndoableAnnotation::Shape),
+        };
+        let Some(annotation) = undone else {
+            return;
+        };
+        self.redo_annotations.push(annotation);
+        self.has_unsaved_annotations = true;
+        log::debug!(
+            "[INTERACTIVE_OCR] Undid {:?}, {} left to redo",
+            layer,
+            self.redo_annotations.len()
+        );
+    }
+
+    fn handle_redo_stroke(&mut self) {
+        let Some(annotation) = self.redo_annotations.pop() else {
+            return;
+        };
+        match annotation {
+            UndoableAnnotation::Stroke(stroke) => {
+                self.draw_strokes.push(stroke);
+                self.annotation_order.push(AnnotationLayer::Stroke);
+            }
+            UndoableAnnotation::Shape(shape) => {
+                self.shapes.push(shape);
+                self.annotation_order.push(AnnotationLayer::Shape);
+            }
+        }
+        self.has_unsaved_annotations = true;
+        log::debug!(
+            "[INTERACTIVE_OCR] Redid annotation, {} left to redo",
+            self.redo_annotations.len()
+        );
+    }
+
     fn handle_toggle_toolbar_position(&mut self) {
         if self.toolbar_offset.y > 50.0 {
             self.toolbar_offset = Vector::new(0.0, 0.0);
@@ -304,8 +849,18 @@ impl InteractiveOcrView {
         self.ocr_state = OcrState::Processing;
     }
 
-    fn handle_cancel_ocr(&self) {
+    fn handle_cancel_ocr(&mut self) {
         log::info!("[INTERACTIVE_OCR] OCR cancellation requested by user");
+        if matches!(self.ocr_state, OcrState::Processing) {
+            self.ocr_state = OcrState::Idle;
+        }
+    }
+
+    fn handle_cancel_search(&mut self) {
+        log::info!("[INTERACTIVE_OCR] Search cancellation requested by user");
+        if matches!(self.search_state, SearchState::UploadingImage) {
+            self.search_state = SearchState::Idle;
+        }
     }
 
     fn handle_clear_ocr_overlay(&mut self) {
@@ -313,6 +868,7 @@ impl InteractiveOcrView {
         self.ocr_result = None;
         self.char_positions.clear();
         self.selected_chars.clear();
+        self.reading_order.clear();
         self.ocr_state = OcrState::Idle;
     }
 
@@ -327,6 +883,68 @@ impl InteractiveOcrView {
         self.ocr_result = None;
         self.char_positions.clear();
         self.selected_chars.clear();
+        self.reading_order.clear();
+    }
+
+    fn handle_transform_image(
+        &mut self,
+        transform: fn(&CaptureBuffer) -> anyhow::Result<CaptureBuffer>,
+    ) {
+        if !self.can_transform_image() {
+            log::warn!("[INTERACTIVE_OCR] Ignoring image transform while annotations exist");
+            return;
+        }
+
+        let transformed = match transform(&self.capture_buffer) {
+            Ok(transformed) => transformed,
+            Err(error) => {
+                log::error!("[INTERACTIVE_OCR] Failed to transform image: {}", error);
+                return;
+            }
+        };
+
+        log::info!(
+            "[INTERACTIVE_OCR] Transformed image {}x{} -> {}x{}",
+            self.image_width,
+            self.image_height,
+            transformed.width,
+            transformed.height
+        );
+        self.image_handle = transformed.image_handle.clone();
+        self.image_width = transformed.width;
+        self.image_height = transformed.height;
+        self.capture_buffer = transformed;
+        self.handle_retry_ocr();
+        self.selection_anchor = None;
+        self.selection_focus = None;
+        self.decoded_codes.clear();
+        self.hosted_image_url = None;
+        self.ocr_state = OcrState::Processing;
+    }
+
+    fn handle_set_ocr_preprocess(&mut self, preprocess: OcrPreprocess) {
+        log::info!(
+            "[INTERACTIVE_OCR] OCR preprocessing set to {:?}",
+            preprocess
+        );
+        self.ocr_preprocess = preprocess;
+        self.restart_ocr_processing();
+    }
+
+    fn handle_reprocess_with_language(&mut self, language_code: String) {
+        log::info!(
+            "[INTERACTIVE_OCR] Re-running OCR with language {}",
+            language_code
+        );
+        self.ocr_language = language_code;
+        self.restart_ocr_processing();
+    }
+
+    fn restart_ocr_processing(&mut self) {
+        self.handle_retry_ocr();
+        self.selection_anchor = None;
+        self.selection_focus = None;
+        self.ocr_state = OcrState::Processing;
     }
 
     fn handle_draw_panel_drag_started(&mut self, cursor_x: f32, cursor_y: f32) {
@@ -362,4 +980,466 @@ impl InteractiveOcrView {
         self.draw_panel_is_dragging = false;
         self.draw_panel_drag_offset = None;
     }
+
+    fn handle_zoom_image(&mut self, scroll_steps: f32, anchor: Point, viewport: Size) {
+        let next_zoom = (self.image_zoom * IMAGE_ZOOM_STEP_FACTOR.powf(scroll_steps))
+            .clamp(IMAGE_MIN_ZOOM, IMAGE_MAX_ZOOM);
+        if next_zoom <= IMAGE_MIN_ZOOM {
+            self.image_zoom = IMAGE_MIN_ZOOM;
+            self.image_pan = Vector::ZERO;
+            return;
+        }
+
+        let fitted =
+            ImageTransform::fit(self.image_width as f32, self.image_height as f32, viewport);
+        self.image_pan =
+            fitted.pan_keeping_anchor(self.image_zoom, self.image_pan, next_zoom, anchor, viewport);
+        self.image_zoom = next_zoom;
+        log::debug!(
+            "[INTERACTIVE_OCR] Image zoom={} pan=({}, {})",
+            self.image_zoom,
+            self.image_pan.x,
+            self.image_pan.y
+        );
+    }
+}
+
+fn is_canvas_press_message(message: &InteractiveOcrMessage) -> bool {
+    matches!(
+        message,
+        InteractiveOcrMessage::SearchInputBlurred
+            | InteractiveOcrMessage::StartDrag(_)
+            | InteractiveOcrMessage::SelectWord(_)
+            | InteractiveOcrMessage::SelectLine(_)
+            | InteractiveOcrMessage::StartDrawing(_)
+            | InteractiveOcrMessage::PlaceStepMarker(_)
+            | InteractiveOcrMessage::ToggleReadingOrderBlock(_)
+            | InteractiveOcrMessage::EraseAnnotation(..)
+            | InteractiveOcrMessage::DrawPanelDragStarted(..)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::{CaptureBuffer, ThemeMode};
+    use crate::global_constants;
+
+    fn draw_stroke_at(view: &mut InteractiveOcrView, x: f32) {
+        view.update(InteractiveOcrMessage::StartDrawing(Point::new(x, 0.0)));
+        view.update(InteractiveOcrMessage::UpdateDrawing(Point::new(x, 10.0)));
+        view.update(InteractiveOcrMessage::EndDrawing);
+    }
+
+    fn stroke_origins(strokes: &[DrawStroke]) -> Vec<f32> {
+        strokes.iter().map(|stroke| stroke.points[0].x).collect()
+    }
+
+    fn redo_stroke_origins(view: &InteractiveOcrView) -> Vec<f32> {
+        view.redo_annotations
+            .iter()
+            .filter_map(|annotation| match annotation {
+                UndoableAnnotation::Stroke(stroke) => Some(stroke.points[0].x),
+                UndoableAnnotation::Shape(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_zoom_image_keeps_anchor_and_reset_restores_fit() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 100, 100, vec![0u8; 40_000]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        let viewport = Size::new(200.0, 200.0);
+        let anchor = Point::new(50.0, 50.0);
+
+        view.update(InteractiveOcrMessage::ZoomImage(1.0, anchor, viewport));
+
+        assert_eq!(view.image_zoom, IMAGE_ZOOM_STEP_FACTOR);
+        let zoomed = ImageTransform::fit(100.0, 100.0, viewport).zoomed(
+            view.image_zoom,
+            view.image_pan,
+            viewport,
+        );
+        let anchored_point = zoomed.to_image(anchor);
+        assert!((anchored_point.x - 25.0).abs() < 1e-3);
+        assert!((anchored_point.y - 25.0).abs() < 1e-3);
+
+        view.update(InteractiveOcrMessage::PanImage(Vector::new(5.0, 5.0)));
+        view.update(InteractiveOcrMessage::ResetImageZoom);
+
+        assert_eq!(view.image_zoom, IMAGE_MIN_ZOOM);
+        assert_eq!(view.image_pan, Vector::ZERO);
+    }
+
+    #[test]
+    fn test_pan_image_is_ignored_at_fit_zoom() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        view.update(InteractiveOcrMessage::PanImage(Vector::new(30.0, 0.0)));
+        view.update(InteractiveOcrMessage::ZoomImage(
+            -3.0,
+            Point::ORIGIN,
+            Size::new(100.0, 100.0),
+        ));
+
+        assert_eq!(view.image_pan, Vector::ZERO);
+        assert_eq!(view.image_zoom, IMAGE_MIN_ZOOM);
+    }
+
+    #[test]
+    fn test_copy_all_without_recognized_text_is_not_a_successful_copy() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        view.update(InteractiveOcrMessage::CopyAllText);
+
+        assert_eq!(view.copy_state, CopyState::Empty);
+        assert!(!view.has_copy_succeeded());
+    }
+
+    #[test]
+    fn test_capture_flash_clears_when_finished() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        view.show_capture_flash();
+        assert!(view.is_capture_flash_visible);
+
+        view.update(InteractiveOcrMessage::CaptureFlashFinished);
+        assert!(!view.is_capture_flash_visible);
+    }
+
+    #[test]
+    fn test_typing_in_search_field_holds_focus_until_canvas_is_clicked() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        view.update(InteractiveOcrMessage::SearchQueryChanged("cat".to_string()));
+
+        assert!(view.is_search_input_focused);
+
+        view.update(InteractiveOcrMessage::SearchInputBlurred);
+
+        assert!(!view.is_search_input_focused);
+
+        view.update(InteractiveOcrMessage::SearchQueryChanged(
+            "cats".to_string(),
+        ));
+        view.update(InteractiveOcrMessage::StartDrawing(Point::new(1.0, 1.0)));
+
+        assert!(!view.is_search_input_focused);
+    }
+
+    #[test]
+    fn test_undo_redo_moves_strokes_between_stacks() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        draw_stroke_at(&mut view, 1.0);
+        draw_stroke_at(&mut view, 2.0);
+        draw_stroke_at(&mut view, 3.0);
+        view.update(InteractiveOcrMessage::UndoStroke);
+        view.update(InteractiveOcrMessage::UndoStroke);
+
+        assert_eq!(stroke_origins(&view.draw_strokes), vec![1.0]);
+        assert_eq!(redo_stroke_origins(&view), vec![3.0, 2.0]);
+
+        view.update(InteractiveOcrMessage::RedoStroke);
+
+        assert_eq!(stroke_origins(&view.draw_strokes), vec![1.0, 2.0]);
+        assert_eq!(redo_stroke_origins(&view), vec![3.0]);
+
+        draw_stroke_at(&mut view, 4.0);
+
+        assert_eq!(stroke_origins(&view.draw_strokes), vec![1.0, 2.0, 4.0]);
+        assert!(view.redo_annotations.is_empty());
+
+        view.update(InteractiveOcrMessage::RedoStroke);
+        for _ in 0..5 {
+            view.update(InteractiveOcrMessage::UndoStroke);
+        }
+
+        assert!(view.draw_strokes.is_empty());
+        assert_eq!(redo_stroke_origins(&view), vec![4.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_undo_removes_latest_shape_before_older_strokes() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        draw_stroke_at(&mut view, 1.0);
+        view.update(InteractiveOcrMessage::SetShapeTool(Some(Shape::Arrow)));
+        draw_stroke_at(&mut view, 2.0);
+        view.update(InteractiveOcrMessage::UndoStroke);
+
+        assert_eq!(stroke_origins(&view.draw_strokes), vec![1.0]);
+        assert!(view.shapes.is_empty());
+
+        view.update(InteractiveOcrMessage::RedoStroke);
+
+        assert_eq!(view.shapes.len(), 1);
+        assert_eq!(
+            view.annotation_order,
+            vec![AnnotationLayer::Stroke, AnnotationLayer::Shape]
+        );
+    }
+
+    #[test]
+    fn test_erasing_shape_keeps_undo_history_in_sync() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        view.update(InteractiveOcrMessage::SetShapeTool(Some(Shape::Arrow)));
+        draw_stroke_at(&mut view, 1.0);
+        view.update(InteractiveOcrMessage::SetShapeTool(None));
+        draw_stroke_at(&mut view, 2.0);
+        view.update(InteractiveOcrMessage::EraseAnnotation(
+            AnnotationLayer::Shape,
+            0,
+        ));
+        view.update(InteractiveOcrMessage::UndoStroke);
+
+        assert!(view.shapes.is_empty());
+        assert!(view.draw_strokes.is_empty());
+        assert_eq!(redo_stroke_origins(&view), vec![2.0]);
+    }
+
+    #[test]
+    fn test_set_draw_width_only_applies_to_new_strokes() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        draw_stroke_at(&mut view, 1.0);
+        view.update(InteractiveOcrMessage::SetDrawWidth(
+            global_constants::DRAW_WIDTH_THICK,
+        ));
+        draw_stroke_at(&mut view, 2.0);
+
+        let widths: Vec<f32> = view
+            .draw_strokes
+            .iter()
+            .map(|stroke| stroke.width)
+            .collect();
+        assert_eq!(
+            widths,
+            vec![
+                global_constants::DEFAULT_DRAW_WIDTH,
+                global_constants::DRAW_WIDTH_THICK
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlighter_strokes_are_wide_and_translucent() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        draw_stroke_at(&mut view, 1.0);
+        view.update(InteractiveOcrMessage::SetDrawTool(DrawTool::Highlighter));
+        draw_stroke_at(&mut view, 2.0);
+
+        let pen_stroke = &view.draw_strokes[0];
+        let highlighter_stroke = &view.draw_strokes[1];
+        assert_eq!(pen_stroke.tool, DrawTool::Pen);
+        assert_eq!(pen_stroke.color.a, 1.0);
+        assert_eq!(highlighter_stroke.tool, DrawTool::Highlighter);
+        assert_eq!(
+            highlighter_stroke.color.a,
+            global_constants::HIGHLIGHTER_ALPHA
+        );
+        assert_eq!(highlighter_stroke.color.r, pen_stroke.color.r);
+        assert!(highlighter_stroke.width > pen_stroke.width);
+    }
+
+    #[test]
+    fn test_shape_tool_records_shapes_instead_of_strokes() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.update(InteractiveOcrMessage::SetShapeTool(Some(Shape::Arrow)));
+
+        view.update(InteractiveOcrMessage::StartDrawing(Point::new(4.0, 4.0)));
+        view.update(InteractiveOcrMessage::EndDrawing);
+        draw_stroke_at(&mut view, 1.0);
+
+        assert!(view.draw_strokes.is_empty());
+        assert_eq!(view.shapes.len(), 1);
+        assert_eq!(view.shapes[0].start, Point::new(1.0, 0.0));
+        assert_eq!(view.shapes[0].end, Point::new(1.0, 10.0));
+        assert!(!view.is_drawing);
+    }
+
+    #[test]
+    fn test_rotate_right_swaps_dimensions_and_restarts_ocr() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 4, 2, vec![255u8; 32]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+
+        view.update(InteractiveOcrMessage::RotateRight);
+
+        assert_eq!((view.image_width, view.image_height), (2, 4));
+        assert_eq!(
+            (view.capture_buffer.width, view.capture_buffer.height),
+            (2, 4)
+        );
+        assert_eq!(view.capture_buffer.raw_data.len(), 32);
+        assert!(matches!(view.ocr_state, OcrState::Processing));
+    }
+
+    #[test]
+    fn test_rotate_is_ignored_while_annotations_exist() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 4, 2, vec![255u8; 32]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        draw_stroke_at(&mut view, 1.0);
+
+        view.update(InteractiveOcrMessage::RotateLeft);
+
+        assert_eq!((view.image_width, view.image_height), (4, 2));
+        assert!(!view.can_transform_image());
+    }
+
+    #[test]
+    fn test_set_ocr_preprocess_keeps_display_buffer_and_restarts_ocr() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![100u8; 16]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        let preprocess = OcrPreprocess {
+            brightness: 50,
+            ..OcrPreprocess::default()
+        };
+
+        view.update(InteractiveOcrMessage::SetOcrPreprocess(preprocess));
+
+        assert_eq!(view.get_ocr_preprocess(), preprocess);
+        assert_eq!(view.capture_buffer.raw_data, vec![100u8; 16]);
+        assert!(matches!(view.ocr_state, OcrState::Processing));
+    }
+
+    fn build_reading_order_view() -> InteractiveOcrView {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![100u8; 16]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.char_positions = [(0, 'a'), (0, 'b'), (1, 'c'), (1, 'd')]
+            .into_iter()
+            .enumerate()
+            .map(|(index, (word_index, character))| CharPosition {
+                word_index,
+                char_index: index % 2,
+                bounds: Rectangle {
+                    x: index as f32 * 10.0,
+                    y: 0.0,
+                    width: 10.0,
+                    height: 20.0,
+                },
+                character,
+            })
+            .collect();
+        view.update(InteractiveOcrMessage::ToggleReadingOrderMode);
+        view
+    }
+
+    #[test]
+    fn test_toggle_reading_order_block_extends_and_shrinks_selection() {
+        let mut view = build_reading_order_view();
+
+        view.update(InteractiveOcrMessage::ToggleReadingOrderBlock(1));
+        view.update(InteractiveOcrMessage::ToggleReadingOrderBlock(0));
+
+        assert_eq!(view.reading_order, vec![1, 0]);
+        assert_eq!(view.selected_chars, vec![2, 3, 0, 1]);
+
+        view.update(InteractiveOcrMessage::ToggleReadingOrderBlock(1));
+
+        assert_eq!(view.reading_order, vec![0]);
+        assert_eq!(view.selected_chars, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_clear_reading_order_drops_only_ordered_selection() {
+        let mut view = build_reading_order_view();
+        view.selected_chars = vec![2];
+        view.update(InteractiveOcrMessage::ToggleReadingOrderBlock(0));
+
+        view.update(InteractiveOcrMessage::ClearReadingOrder);
+
+        assert!(view.reading_order.is_empty());
+        assert_eq!(view.selected_chars, vec![2]);
+    }
+
+    #[test]
+    fn test_reprocess_with_language_clears_selection_and_restarts_ocr() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![100u8; 16]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.selected_chars = vec![0, 1];
+        view.selection_anchor = Some(0);
+        view.selection_focus = Some(1);
+
+        view.update(InteractiveOcrMessage::ReprocessWithLanguage(
+            "deu".to_string(),
+        ));
+
+        assert_eq!(view.get_ocr_language(), "deu");
+        assert!(view.selected_chars.is_empty());
+        assert!(view.char_positions.is_empty());
+        assert!(view.selection_anchor.is_none());
+        assert!(view.selection_focus.is_none());
+        assert!(matches!(view.ocr_state, OcrState::Processing));
+    }
+
+    #[test]
+    fn test_cancel_ocr_returns_to_idle_only_while_processing() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 2, 2, vec![0u8; 16]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.ocr_state = OcrState::Processing;
+
+        view.update(InteractiveOcrMessage::CancelOcr);
+        assert!(matches!(view.ocr_state, OcrState::Idle));
+
+        view.ocr_state = OcrState::Failed("timeout".to_string());
+        view.update(InteractiveOcrMessage::CancelOcr);
+        assert!(matches!(view.ocr_state, OcrState::Failed(_)));
+    }
+
+    #[test]
+    fn test_toggle_include_annotations_in_copy_drops_export_strokes() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        draw_stroke_at(&mut view, 1.0);
+
+        assert_eq!(view.get_export_draw_strokes().len(), 1);
+
+        view.update(InteractiveOcrMessage::ToggleIncludeAnnotationsInCopy);
+        assert!(view.get_export_draw_strokes().is_empty());
+        assert_eq!(view.get_draw_strokes().len(), 1);
+
+        view.update(InteractiveOcrMessage::ToggleIncludeAnnotationsInCopy);
+        assert_eq!(view.get_export_draw_strokes().len(), 1);
+    }
+
+    #[test]
+    fn test_apply_picked_color_sets_draw_color_and_dedupes_recents() {
+        let buffer = CaptureBuffer::build_from_raw_data(1.0, 10, 10, vec![0u8; 400]);
+        let mut view = InteractiveOcrView::build(buffer, ThemeMode::Dark);
+        view.set_recent_draw_colors(&[(0.0, 0.0, 1.0)]);
+        let purple = HsvColor {
+            hue: 270.0,
+            saturation: 1.0,
+            value: 1.0,
+        };
+
+        view.update(InteractiveOcrMessage::OpenColorPicker);
+        view.update(InteractiveOcrMessage::AdjustPickedColor(purple));
+        view.update(InteractiveOcrMessage::ApplyPickedColor);
+
+        assert_eq!(view.draw_color, Color::from_rgb(0.5, 0.0, 1.0));
+        assert!(!view.color_picker_open);
+        assert_eq!(
+            view.get_recent_draw_colors(),
+            vec![(0.5, 0.0, 1.0), (0.0, 0.0, 1.0)]
+        );
+
+        view.update(InteractiveOcrMessage::OpenColorPicker);
+        view.update(InteractiveOcrMessage::AdjustPickedColor(purple));
+        view.update(InteractiveOcrMessage::ApplyPickedColor);
+
+        assert_eq!(view.get_recent_draw_colors().len(), 2);
+    }
 }
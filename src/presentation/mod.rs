@@ -1,10 +1,20 @@
 pub mod app_theme;
+mod capture_history_view;
 mod capture_view;
+mod image_transform;
 mod interactive_ocr_view;
 mod onboarding_view;
 mod window_picker_view;
 
+pub use capture_history_view::{
+    format_capture_age, CaptureHistoryItem, CaptureHistoryMessage, CaptureHistoryView,
+};
 pub use capture_view::{CaptureView, CaptureViewMessage};
-pub use interactive_ocr_view::{DrawStroke, InteractiveOcrMessage, InteractiveOcrView};
-pub use onboarding_view::{OnboardingMessage, OnboardingView};
+pub use interactive_ocr_view::{
+    DrawStroke, DrawTool, InteractiveOcrMessage, InteractiveOcrView, ResultWindowShortcuts,
+    TranslationState,
+};
+pub use onboarding_view::{
+    CaptureTestStatus, ConnectivityStatus, OnboardingMessage, OnboardingView,
+};
 pub use window_picker_view::{WindowPickerMessage, WindowPickerView};
@@ -9,6 +9,7 @@ pub enum OnboardingStep {
     ScreenRecording,
     InputMonitoring,
     AutoStart,
+    TestCapture,
     Complete,
 }
 
@@ -18,7 +19,8 @@ impl OnboardingStep {
             OnboardingStep::Welcome => OnboardingStep::ScreenRecording,
             OnboardingStep::ScreenRecording => OnboardingStep::InputMonitoring,
             OnboardingStep::InputMonitoring => OnboardingStep::AutoStart,
-            OnboardingStep::AutoStart => OnboardingStep::Complete,
+            OnboardingStep::AutoStart => OnboardingStep::TestCapture,
+            OnboardingStep::TestCapture => OnboardingStep::Complete,
             OnboardingStep::Complete => OnboardingStep::Complete,
         }
     }
@@ -29,15 +31,31 @@ impl OnboardingStep {
             OnboardingStep::ScreenRecording => 2,
             OnboardingStep::InputMonitoring => 3,
             OnboardingStep::AutoStart => 4,
-            OnboardingStep::Complete => 5,
+            OnboardingStep::TestCapture => 5,
+            OnboardingStep::Complete => 6,
         }
     }
 
     pub fn total_steps() -> usize {
-        5
+        6
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectivityStatus {
+    Checking,
+    Online,
+    Offline,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureTestStatus {
+    NotRun,
+    Running,
+    Passed,
+    Failed(String),
+}
+
 #[derive(Debug, Clone)]
 pub enum OnboardingMessage {
     NextStep,
@@ -47,6 +65,11 @@ pub enum OnboardingMessage {
     ToggleLaunchAtLogin(bool),
     FinishOnboarding,
     RefreshPermissions,
+    ConnectivityChecked(bool),
+    RetryConnectivityCheck,
+    ContinueOffline,
+    RunCaptureTest,
+    CaptureTestFinished(Result<(), String>),
 }
 
 pub struct OnboardingView {
@@ -55,6 +78,8 @@ pub struct OnboardingView {
     input_monitoring_granted: bool,
     launch_at_login: bool,
     toast_message: Option<(String, bool)>,
+    connectivity_status: ConnectivityStatus,
+    capture_test_status: CaptureTestStatus,
 }
 
 impl OnboardingView {
@@ -69,6 +94,8 @@ impl OnboardingView {
             input_monitoring_granted,
             launch_at_login,
             toast_message: None,
+            connectivity_status: ConnectivityStatus::Checking,
+            capture_test_status: CaptureTestStatus::NotRun,
         }
     }
 
@@ -110,6 +137,14 @@ impl OnboardingView {
         self.current_step
     }
 
+    pub fn capture_test_status(&self) -> &CaptureTestStatus {
+        &self.capture_test_status
+    }
+
+    pub fn connectivity_status(&self) -> ConnectivityStatus {
+        self.connectivity_status
+    }
+
     pub fn is_launch_at_login_enabled(&self) -> bool {
         self.launch_at_login
     }
@@ -119,6 +154,11 @@ impl OnboardingView {
             OnboardingMessage::NextStep => {
                 self.current_step = self.current_step.next();
                 self.toast_message = None;
+                if self.current_step == OnboardingStep::TestCapture
+                    && self.capture_test_status == CaptureTestStatus::NotRun
+                {
+                    self.capture_test_status = CaptureTestStatus::Running;
+                }
                 false
             }
             OnboardingMessage::BackToStart => {
@@ -130,6 +170,34 @@ impl OnboardingView {
                 self.launch_at_login = enabled;
                 false
             }
+            OnboardingMessage::ConnectivityChecked(is_online) => {
+                self.connectivity_status = if is_online {
+                    ConnectivityStatus::Online
+                } else {
+                    ConnectivityStatus::Offline
+                };
+                false
+            }
+            OnboardingMessage::RetryConnectivityCheck => {
+                self.connectivity_status = ConnectivityStatus::Checking;
+                false
+            }
+            OnboardingMessage::ContinueOffline => {
+                self.current_step = self.current_step.next();
+                self.toast_message = None;
+                false
+            }
+            OnboardingMessage::RunCaptureTest => {
+                self.capture_test_status = CaptureTestStatus::Running;
+                false
+            }
+            OnboardingMessage::CaptureTestFinished(result) => {
+                self.capture_test_status = match result {
+                    Ok(()) => CaptureTestStatus::Passed,
+                    Err(capture_error) => CaptureTestStatus::Failed(capture_error),
+                };
+                false
+            }
             OnboardingMessage::FinishOnboarding => true,
             OnboardingMessage::OpenScreenRecordingSettings
             | OnboardingMessage::OpenInputMonitoringSettings
@@ -143,6 +211,7 @@ impl OnboardingView {
             OnboardingStep::ScreenRecording => self.render_screen_recording_step(),
             OnboardingStep::InputMonitoring => self.render_input_monitoring_step(),
             OnboardingStep::AutoStart => self.render_auto_start_step(),
+            OnboardingStep::TestCapture => self.render_test_capture_step(),
             OnboardingStep::Complete => self.render_complete_step(),
         };
 
@@ -283,6 +352,8 @@ impl OnboardingView {
             description,
             text("").size(16),
             features_panel,
+            text("").size(12),
+            self.render_connectivity_panel(),
             text("").size(24),
             next_button,
         ]
@@ -292,6 +363,71 @@ impl OnboardingView {
         .into()
     }
 
+    fn render_connectivity_panel(&self) -> Element<'_, OnboardingMessage> {
+        let (icon, icon_color, message) = match self.connectivity_status {
+            ConnectivityStatus::Checking => (
+                "…",
+                Color::from_rgba(0.6, 0.6, 0.6, 1.0),
+                "Checking your internet connection...",
+            ),
+            ConnectivityStatus::Online => (
+                "✓",
+                Color::from_rgb(0.2, 0.8, 0.4),
+                "Online - reverse image search and OCR model downloads are available",
+            ),
+            ConnectivityStatus::Offline => (
+                "⚠",
+                Color::from_rgb(1.0, 0.7, 0.0),
+                "You appear to be offline. Reverse image search and the Neural OCR model \
+                 download won't work until you're back online. Capturing, Tesseract OCR, \
+                 copying and saving still work.",
+            ),
+        };
+
+        let status_row = row![
+            text(icon)
+                .size(18)
+                .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(icon_color),
+                }),
+            text(message).size(14),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center);
+
+        let content = if self.connectivity_status == ConnectivityStatus::Offline {
+            let retry_button = button(text("Retry").size(14))
+                .padding([10, 20])
+                .style(app_theme::secondary_button_style)
+                .on_press(OnboardingMessage::RetryConnectivityCheck);
+
+            let offline_button = button(text("Continue Offline").size(14))
+                .padding([10, 20])
+                .style(app_theme::secondary_button_style)
+                .on_press(OnboardingMessage::ContinueOffline);
+
+            column![status_row, row![retry_button, offline_button].spacing(12)]
+                .spacing(12)
+                .align_x(Alignment::Center)
+        } else {
+            column![status_row]
+        };
+
+        container(content)
+            .padding([12, 20])
+            .width(Length::Fill)
+            .style(|_theme| iced::widget::container::Style {
+                background: Some(Background::Color(Color::from_rgba(0.2, 0.2, 0.2, 0.3))),
+                border: Border {
+                    color: Color::from_rgba(0.4, 0.4, 0.4, 0.3),
+                    width: 1.0,
+                    radius: 12.0.into(),
+                },
+                ..Default::default()
+            })
+            .into()
+    }
+
     fn render_screen_recording_step(&self) -> Element<'_, OnboardingMessage> {
         let title = text("Screen Recording Permission").size(24);
 
@@ -596,6 +732,115 @@ impl OnboardingView {
         .into()
     }
 
+    fn render_test_capture_step(&self) -> Element<'_, OnboardingMessage> {
+        let title = text("Test Capture").size(24);
+
+        let description = text(
+            "Let's make sure Circle to Search can actually read your screen by taking a \
+             quick test capture. Nothing is saved or uploaded.",
+        )
+        .size(16);
+
+        let (status_icon, status_label, status_color) = match &self.capture_test_status {
+            CaptureTestStatus::NotRun | CaptureTestStatus::Running => (
+                "…",
+                "Running test capture...",
+                Color::from_rgba(0.6, 0.6, 0.6, 1.0),
+            ),
+            CaptureTestStatus::Passed => {
+                ("✓", "Screen capture works", Color::from_rgb(0.2, 0.8, 0.4))
+            }
+            CaptureTestStatus::Failed(_) => {
+                ("✗", "Screen capture failed", Color::from_rgb(1.0, 0.4, 0.4))
+            }
+        };
+
+        let mut test_content = column![row![
+            text(status_icon)
+                .size(20)
+                .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(status_color),
+                }),
+            text(format!(" {}", status_label)).size(18)
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center)]
+        .spacing(10);
+
+        if let CaptureTestStatus::Failed(capture_error) = &self.capture_test_status {
+            test_content = test_content
+                .push(
+                    text(capture_error.clone())
+                        .size(13)
+                        .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                            color: Some(Color::from_rgba(0.6, 0.6, 0.6, 1.0)),
+                        }),
+                )
+                .push(text(capture_test_remediation(capture_error)).size(14));
+        }
+
+        let test_panel = container(test_content)
+            .padding([16, 20])
+            .width(Length::Fill)
+            .style(|_theme| iced::widget::container::Style {
+                background: Some(Background::Color(Color::from_rgba(0.2, 0.2, 0.2, 0.3))),
+                border: Border {
+                    color: Color::from_rgba(0.4, 0.4, 0.4, 0.3),
+                    width: 1.0,
+                    radius: 12.0.into(),
+                },
+                ..Default::default()
+            });
+
+        let is_running = self.capture_test_status == CaptureTestStatus::Running;
+        let is_failed = matches!(self.capture_test_status, CaptureTestStatus::Failed(_));
+
+        let mut action_buttons = row![].spacing(12).align_y(Alignment::Center);
+        if is_failed && cfg!(target_os = "macos") {
+            action_buttons = action_buttons.push(
+                button(text("Open System Settings").size(16))
+                    .padding([14, 24])
+                    .style(app_theme::primary_button_style)
+                    .on_press(OnboardingMessage::OpenScreenRecordingSettings),
+            );
+        }
+        action_buttons = action_buttons.push(
+            button(text("Run Test Again").size(16))
+                .padding([14, 24])
+                .style(app_theme::purple_button_style)
+                .on_press_maybe((!is_running).then_some(OnboardingMessage::RunCaptureTest)),
+        );
+
+        let continue_button = if is_failed {
+            button(text("Continue anyway").size(14))
+                .padding([12, 24])
+                .style(app_theme::secondary_button_style)
+                .on_press(OnboardingMessage::NextStep)
+        } else {
+            button(text("Continue").size(16))
+                .padding([14, 32])
+                .style(app_theme::primary_button_style)
+                .on_press_maybe((!is_running).then_some(OnboardingMessage::NextStep))
+        };
+
+        column![
+            title,
+            text("").size(16),
+            description,
+            text("").size(20),
+            test_panel,
+            text("").size(20),
+            action_buttons,
+            text("").size(16),
+            continue_button,
+        ]
+        .spacing(4)
+        .align_x(Alignment::Center)
+        .width(Length::Fill)
+        .max_width(500)
+        .into()
+    }
+
     fn render_complete_step(&self) -> Element<'_, OnboardingMessage> {
         let title = text("You're All Set!").size(28);
 
@@ -631,6 +876,29 @@ impl OnboardingView {
             "✗ Input Monitoring: Not enabled"
         };
 
+        let connectivity_color = if self.connectivity_status == ConnectivityStatus::Offline {
+            Color::from_rgb(1.0, 0.7, 0.0)
+        } else {
+            Color::from_rgb(0.2, 0.8, 0.4)
+        };
+
+        let connectivity_summary = match self.connectivity_status {
+            ConnectivityStatus::Offline => "⚠ Internet: Offline (search available once online)",
+            ConnectivityStatus::Checking => "… Internet: Not checked yet",
+            ConnectivityStatus::Online => "✓ Internet: Connected",
+        };
+
+        let (capture_test_summary, capture_test_color) = match self.capture_test_status {
+            CaptureTestStatus::Passed => ("✓ Test Capture: Passed", Color::from_rgb(0.2, 0.8, 0.4)),
+            CaptureTestStatus::Failed(_) => {
+                ("✗ Test Capture: Failed", Color::from_rgb(1.0, 0.4, 0.4))
+            }
+            CaptureTestStatus::NotRun | CaptureTestStatus::Running => (
+                "○ Test Capture: Not run",
+                Color::from_rgba(0.6, 0.6, 0.6, 1.0),
+            ),
+        };
+
         let auto_start_status = if self.launch_at_login {
             "✓ Auto-start: Enabled"
         } else {
@@ -660,6 +928,20 @@ impl OnboardingView {
                         color: Some(auto_start_color),
                     }
                 }),
+            text(connectivity_summary)
+                .size(14)
+                .style(move |_theme: &iced::Theme| {
+                    iced::widget::text::Style {
+                        color: Some(connectivity_color),
+                    }
+                }),
+            text(capture_test_summary)
+                .size(14)
+                .style(move |_theme: &iced::Theme| {
+                    iced::widget::text::Style {
+                        color: Some(capture_test_color),
+                    }
+                }),
         ]
         .spacing(8);
 
@@ -727,3 +1009,69 @@ impl OnboardingView {
         .into()
     }
 }
+
+fn capture_test_remediation(capture_error: &str) -> &'static str {
+    let error_lower = capture_error.to_lowercase();
+
+    if cfg!(target_os = "macos") {
+        return "Screen Recording permission is most likely missing. Enable Circle to Search \
+                under System Settings → Privacy & Security → Screen Recording, then restart the app.";
+    }
+
+    if error_lower.contains("wayland") || error_lower.contains("portal") {
+        return "Your Wayland session refused the screenshot request. Allow Circle to Search \
+                when the desktop asks to share the screen, or make sure xdg-desktop-portal is running.";
+    }
+
+    if error_lower.contains("denied") || error_lower.contains("permission") {
+        return "The system denied screen access. Check that no privacy or security software \
+                is blocking Circle to Search from capturing the screen.";
+    }
+
+    "Make sure a display is connected and unlocked, then run the test again."
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view_at_step(step: OnboardingStep) -> OnboardingView {
+        let mut view = OnboardingView::new(true, true, false);
+        while view.current_step() != step {
+            view.handle_message(OnboardingMessage::NextStep);
+        }
+        view
+    }
+
+    #[test]
+    fn test_auto_start_continues_to_capture_test_before_complete() {
+        let mut view = view_at_step(OnboardingStep::AutoStart);
+
+        view.handle_message(OnboardingMessage::NextStep);
+
+        assert_eq!(view.current_step(), OnboardingStep::TestCapture);
+        assert_eq!(view.capture_test_status(), &CaptureTestStatus::Running);
+        assert_eq!(OnboardingStep::TestCapture.next(), OnboardingStep::Complete);
+        assert_eq!(
+            OnboardingStep::Complete.step_number(),
+            OnboardingStep::total_steps()
+        );
+    }
+
+    #[test]
+    fn test_capture_test_result_updates_status() {
+        let mut view = view_at_step(OnboardingStep::TestCapture);
+
+        view.handle_message(OnboardingMessage::CaptureTestFinished(Err(
+            "no displays".to_string()
+        )));
+        assert_eq!(
+            view.capture_test_status(),
+            &CaptureTestStatus::Failed("no displays".to_string())
+        );
+
+        view.handle_message(OnboardingMessage::RunCaptureTest);
+        view.handle_message(OnboardingMessage::CaptureTestFinished(Ok(())));
+        assert_eq!(view.capture_test_status(), &CaptureTestStatus::Passed);
+    }
+}